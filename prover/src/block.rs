@@ -3,6 +3,10 @@
 use std::{collections::HashSet, path::Path};
 
 use bitcoin::{consensus::serialize, Block, MerkleBlock, Transaction};
+use ckb_bitcoin_spv_verifier::{
+    types::{core, packed, prelude::*},
+    utilities::mmr::hash_concat,
+};
 
 use crate::{utilities, Error, Result};
 
@@ -75,4 +79,86 @@ impl BlockProofGenerator {
         let mb = MerkleBlock::from_block_with_predicate(block, |t| match_txids.contains(t));
         Ok(serialize(&mb))
     }
+
+    /// Generate the merkle branch of a transaction, from bottom to top.
+    pub fn generate_merkle_branch(&self, index: usize) -> Result<Vec<core::Hash>> {
+        let block = self.as_ref();
+        let _ = self.get_transaction(index)?;
+        let hashes = block
+            .txdata
+            .iter()
+            .map(|tx| tx.txid().to_raw_hash())
+            .collect::<Vec<_>>();
+        Ok(merkle_branch(hashes, index))
+    }
+
+    /// Generate the witness merkle branch of a transaction, from bottom to top.
+    ///
+    /// The `wtxid` of the coinbase transaction is treated as zeros (BIP141).
+    pub fn generate_witness_merkle_branch(&self, index: usize) -> Result<Vec<core::Hash>> {
+        let block = self.as_ref();
+        let _ = self.get_transaction(index)?;
+        let hashes = block
+            .txdata
+            .iter()
+            .enumerate()
+            .map(|(i, tx)| {
+                if i == 0 {
+                    core::Hash::all_zeros()
+                } else {
+                    tx.wtxid().to_raw_hash()
+                }
+            })
+            .collect::<Vec<_>>();
+        Ok(merkle_branch(hashes, index))
+    }
+
+    /// Generate the transaction proof with a plain Merkle branch.
+    ///
+    /// The `height` and the `header_proof` are for the header of this block.
+    pub fn generate_transaction_branch_proof(
+        &self,
+        index: usize,
+        height: u32,
+        header_proof: core::MmrProof,
+    ) -> Result<packed::TransactionBranchProof> {
+        let merkle_branch = self.generate_merkle_branch(index)?;
+        let proof = packed::TransactionBranchProof::new_builder()
+            .tx_index((index as u32).pack())
+            .height(height.pack())
+            .header(self.as_ref().header.pack())
+            .merkle_branch(merkle_branch.pack())
+            .header_proof(header_proof.pack())
+            .build();
+        Ok(proof)
+    }
+
+    /// Generate the coinbase proof, which contains the coinbase transaction
+    /// and its merkle branch.
+    pub fn generate_coinbase_proof(&self) -> Result<packed::CoinbaseProof> {
+        let coinbase = self.get_transaction(0)?;
+        let merkle_branch = self.generate_merkle_branch(0)?;
+        let proof = packed::CoinbaseProof::new_builder()
+            .transaction(core::Bytes::from(serialize(coinbase)).pack())
+            .merkle_branch(merkle_branch.pack())
+            .build();
+        Ok(proof)
+    }
+}
+
+fn merkle_branch(mut hashes: Vec<core::Hash>, mut index: usize) -> Vec<core::Hash> {
+    let mut branch = Vec::new();
+    while hashes.len() > 1 {
+        if hashes.len() % 2 == 1 {
+            let last = hashes[hashes.len() - 1];
+            hashes.push(last);
+        }
+        branch.push(hashes[index ^ 1]);
+        hashes = hashes
+            .chunks(2)
+            .map(|pair| hash_concat(&pair[0], &pair[1]))
+            .collect();
+        index >>= 1;
+    }
+    branch
 }
@@ -1,177 +1,94 @@
 //! A dummy service for testing purpose only.
 
-use std::collections::HashMap;
+use bitcoin::Block;
+use ckb_bitcoin_spv_verifier::types::{core, packed};
 
-use bitcoin::blockdata::constants::DIFFCHANGE_INTERVAL;
-use ckb_bitcoin_spv_verifier::{
-    types::{core, packed, prelude::*},
-    utilities::{bitcoin::calculate_next_target, mmr},
-};
-
-use crate::result::{Error, Result};
+use crate::{result::Result, storage::MemStorage, SpvService};
 
 /// A dummy service for testing the SPV client cells's bootstrap and update.
-pub struct DummyService {
-    client: core::SpvClient,
-    store: mmr::lib::util::MemStore<packed::HeaderDigest>,
-    headers: HashMap<u32, core::Header>,
-}
+///
+/// It's an [`SpvService`] which keeps all data in memory.
+pub struct DummyService(SpvService<MemStorage>);
 
 impl DummyService {
-    pub fn bootstrap(height: u32, header: core::Header) -> Result<Self> {
-        if height % DIFFCHANGE_INTERVAL != 0 {
-            let msg = format!(
-                "bad bootstrap height, expected multiples of \
-                {DIFFCHANGE_INTERVAL} but got {height}"
-            );
-            return Err(Error::other(msg));
-        }
-        let mut headers = HashMap::new();
-        let store = mmr::lib::util::MemStore::default();
-        let client = {
-            let mut mmr = mmr::ClientRootMMR::new(0, &store);
-            let block_hash = header.block_hash().into();
-            let digest = core::HeaderDigest::new_leaf(height, &header).pack();
-            mmr.push(digest)?;
-            let headers_mmr_root = mmr.get_root()?.unpack();
-            mmr.commit()?;
-            headers.insert(height, header);
-            let target_adjust_info = packed::TargetAdjustInfo::encode(header.time, header.bits);
-            core::SpvClient {
-                id: 0,
-                tip_block_hash: block_hash,
-                headers_mmr_root,
-                target_adjust_info,
-            }
-        };
-        Ok(Self {
-            client,
-            store,
-            headers,
-        })
+    pub fn bootstrap(height: u32, header: core::Header, flags: u8) -> Result<Self> {
+        SpvService::bootstrap(MemStorage::default(), height, header, flags).map(Self)
     }
 
-    pub fn update(&mut self, headers: Vec<core::Header>) -> Result<packed::SpvUpdate> {
-        let mut mmr = {
-            let last_index =
-                self.client.headers_mmr_root.max_height - self.client.headers_mmr_root.min_height;
-            let mmr_size = mmr::lib::leaf_index_to_mmr_size(u64::from(last_index));
-            mmr::ClientRootMMR::new(mmr_size, &self.store)
-        };
-
-        let mut positions = Vec::new();
-        let mut block_hash = core::Hash::all_zeros();
-        let mut height = self.client.headers_mmr_root.max_height;
-
-        for header in &headers {
-            height += 1;
-
-            let index = height - self.client.headers_mmr_root.min_height;
-            let position = mmr::lib::leaf_index_to_pos(u64::from(index));
-
-            block_hash = header.block_hash().into();
-            let digest = core::HeaderDigest::new_leaf(height, header).pack();
-
-            positions.push(position);
-            mmr.push(digest)?;
-            self.headers.insert(height, header.to_owned());
+    /// Bootstraps at any height, the `period_headers` are the headers from
+    /// the first header of the current difficulty adjustment period to the
+    /// previous header of the input header.
+    pub fn bootstrap_with_period_headers(
+        height: u32,
+        header: core::Header,
+        period_headers: Vec<core::Header>,
+        flags: u8,
+    ) -> Result<Self> {
+        SpvService::bootstrap_with_period_headers(
+            MemStorage::default(),
+            height,
+            header,
+            period_headers,
+            flags,
+        )
+        .map(Self)
+    }
 
-            match (height + 1) % DIFFCHANGE_INTERVAL {
-                0 => {
-                    let curr_target: core::Target = header.bits.into();
-                    log::trace!(
-                        ">>> height {height:07}, time: {}, target {curr_target:#x}",
-                        header.time
-                    );
-                    let start_time: u32 = self.client.target_adjust_info.start_time().unpack();
-                    let next_target =
-                        calculate_next_target(curr_target, start_time, header.time, 0);
-                    log::info!(">>> calculated new target  {next_target:#x}");
-                    let next_bits = next_target.to_compact_lossy();
-                    let next_target: core::Target = next_bits.into();
-                    log::info!(">>> after definition lossy {next_target:#x}");
+    pub fn update(&mut self, headers: Vec<core::Header>) -> Result<packed::SpvUpdate> {
+        self.0.update(headers)
+    }
 
-                    self.client.target_adjust_info =
-                        packed::TargetAdjustInfo::encode(start_time, next_bits);
-                }
-                1 => {
-                    self.client.target_adjust_info =
-                        packed::TargetAdjustInfo::encode(header.time, header.bits);
-                }
-                _ => {}
-            };
-        }
+    /// Appends blocks to the tip, with the coinbase proofs of them.
+    ///
+    /// See [`SpvService::update_with_blocks`].
+    pub fn update_with_blocks(&mut self, blocks: &[Block]) -> Result<packed::SpvUpdate> {
+        self.0.update_with_blocks(blocks)
+    }
 
-        self.client.tip_block_hash = block_hash;
-        self.client.headers_mmr_root.max_height = height;
-        self.client.headers_mmr_root = mmr.get_root()?.unpack();
+    /// Appends headers to the tip, with the coinbase proofs of them.
+    pub fn update_with_coinbase_proofs(
+        &mut self,
+        headers: Vec<core::Header>,
+        coinbase_proofs: Vec<packed::CoinbaseProof>,
+    ) -> Result<packed::SpvUpdate> {
+        self.0.update_with_coinbase_proofs(headers, coinbase_proofs)
+    }
 
-        let headers_mmr_proof_items = mmr
-            .gen_proof(positions)?
-            .proof_items()
-            .iter()
-            .map(Clone::clone)
-            .collect::<Vec<_>>();
-        mmr.commit()?;
-        let headers_mmr_proof = packed::MmrProof::new_builder()
-            .set(headers_mmr_proof_items)
-            .build();
-        Ok(packed::SpvUpdate::new_builder()
-            .headers(headers.pack())
-            .new_headers_mmr_proof(headers_mmr_proof)
-            .build())
+    /// Replaces the headers after the fork point with the headers of a new branch.
+    ///
+    /// See [`SpvService::reorg`] for the requirements of the fork point.
+    pub fn reorg(
+        &mut self,
+        fork_height: u32,
+        headers: Vec<core::Header>,
+    ) -> Result<packed::SpvReorg> {
+        self.0.reorg(fork_height, headers)
     }
 
-    // The `prev_client` is not checked, since this is just a dummy service for testing purpose only.
     pub fn rollback_to(&mut self, prev_client: core::SpvClient) -> Result<()> {
-        let prev_height = prev_client.headers_mmr_root.max_height;
-        if prev_height < self.client.headers_mmr_root.min_height
-            || self.client.headers_mmr_root.max_height < prev_height
-        {
-            let msg = format!(
-                "the previous header (height: {prev_height}) is not found (current: [{}, {}])",
-                self.client.headers_mmr_root.min_height, self.client.headers_mmr_root.max_height
-            );
-            return Err(Error::other(msg));
-        }
-        let curr_height = self.client.headers_mmr_root.max_height;
-        for h in (prev_height + 1)..=curr_height {
-            self.headers.remove(&h);
-        }
-        self.client = prev_client;
-        Ok(())
+        self.0.rollback_to(prev_client)
     }
 
     pub fn tip_client(&self) -> core::SpvClient {
-        self.client.clone()
+        self.0.tip_client()
     }
 
     pub fn min_height(&self) -> u32 {
-        self.client.headers_mmr_root.min_height
+        self.0.min_height()
     }
 
     pub fn max_height(&self) -> u32 {
-        self.client.headers_mmr_root.max_height
+        self.0.max_height()
     }
 
     pub fn generate_header_proof(&self, height: u32) -> Result<Option<core::MmrProof>> {
-        if height < self.client.headers_mmr_root.min_height
-            || self.client.headers_mmr_root.max_height < height
-        {
-            return Ok(None);
-        }
-        let index = height - self.client.headers_mmr_root.min_height;
-        let position = mmr::lib::leaf_index_to_pos(u64::from(index));
-        let last_index =
-            self.client.headers_mmr_root.max_height - self.client.headers_mmr_root.min_height;
-        let mmr_size = mmr::lib::leaf_index_to_mmr_size(u64::from(last_index));
-        let mmr = mmr::ClientRootMMR::new(mmr_size, &self.store);
-        let proof = mmr
-            .gen_proof(vec![position])?
-            .proof_items()
-            .iter()
-            .map(|item| item.unpack())
-            .collect::<Vec<_>>();
-        Ok(Some(proof))
+        self.0.generate_header_proof(height)
+    }
+
+    /// Generates one MMR proof for multiple headers.
+    ///
+    /// Returns `None` if any height is out of the range of current client.
+    pub fn generate_headers_proof(&self, heights: &[u32]) -> Result<Option<core::MmrProof>> {
+        self.0.generate_headers_proof(heights)
     }
 }
@@ -0,0 +1,206 @@
+//! A tree of headers, which tracks the main chain and its competing branches.
+
+use std::collections::{HashMap, HashSet};
+
+use bitcoin::{pow::Work, BlockHash};
+use ckb_bitcoin_spv_verifier::types::core;
+
+use crate::result::{Error, Result};
+
+/// A header which is not in the main chain.
+#[derive(Clone)]
+pub struct BranchNode {
+    pub height: u32,
+    pub header: core::Header,
+    /// The chain work since the base of the tree.
+    pub chain_work: Work,
+}
+
+/// A tree of headers, indexed by block hashes.
+///
+/// The tree starts from a base header in the main chain, the chain work of
+/// all headers are accumulated from the base header.
+///
+/// The main chain is maintained by the owner of the tree, other headers are
+/// branches, which could have more chain work than the main chain.
+#[derive(Clone)]
+pub struct HeaderTree {
+    base_height: u32,
+    // The block hashes and the chain work of the main chain, from the base height.
+    main: Vec<(BlockHash, Work)>,
+    main_index: HashMap<BlockHash, u32>,
+    branches: HashMap<BlockHash, BranchNode>,
+}
+
+impl HeaderTree {
+    /// Creates a tree with the base header of the main chain.
+    pub fn new(base_height: u32, base_header: &core::Header) -> Self {
+        let block_hash = base_header.block_hash();
+        Self {
+            base_height,
+            main: vec![(block_hash, Work::from_le_bytes([0; 32]))],
+            main_index: HashMap::from([(block_hash, base_height)]),
+            branches: HashMap::new(),
+        }
+    }
+
+    pub fn base_height(&self) -> u32 {
+        self.base_height
+    }
+
+    pub fn tip_height(&self) -> u32 {
+        self.base_height + self.main.len() as u32 - 1
+    }
+
+    pub fn tip_hash(&self) -> BlockHash {
+        self.main[self.main.len() - 1].0
+    }
+
+    /// Gets the height of a header in the main chain.
+    pub fn main_height(&self, block_hash: &BlockHash) -> Option<u32> {
+        self.main_index.get(block_hash).copied()
+    }
+
+    /// Gets a header which is not in the main chain.
+    pub fn branch_node(&self, block_hash: &BlockHash) -> Option<&BranchNode> {
+        self.branches.get(block_hash)
+    }
+
+    pub fn contains(&self, block_hash: &BlockHash) -> bool {
+        self.main_index.contains_key(block_hash) || self.branches.contains_key(block_hash)
+    }
+
+    /// Appends a header to the main chain.
+    ///
+    /// The header is not checked, since the main chain is maintained by the owner.
+    pub fn push_main(&mut self, header: &core::Header) {
+        let block_hash = header.block_hash();
+        let chain_work = self.main[self.main.len() - 1].1 + header.work();
+        self.branches.remove(&block_hash);
+        self.main.push((block_hash, chain_work));
+        self.main_index.insert(block_hash, self.tip_height());
+    }
+
+    /// Inserts a header as a branch header.
+    ///
+    /// Its parent should be in the tree; if it's in the tree already, nothing changes.
+    pub fn insert(&mut self, header: &core::Header) -> Result<()> {
+        let block_hash = header.block_hash();
+        if self.contains(&block_hash) {
+            return Ok(());
+        }
+        let (parent_height, parent_work) =
+            if let Some(height) = self.main_height(&header.prev_blockhash) {
+                (height, self.main[(height - self.base_height) as usize].1)
+            } else if let Some(parent) = self.branches.get(&header.prev_blockhash) {
+                (parent.height, parent.chain_work)
+            } else {
+                let msg = format!(
+                    "header {block_hash:#x} is an orphan, its parent {:#x} is unknown",
+                    header.prev_blockhash
+                );
+                return Err(Error::other(msg));
+            };
+        let node = BranchNode {
+            height: parent_height + 1,
+            header: *header,
+            chain_work: parent_work + header.work(),
+        };
+        self.branches.insert(block_hash, node);
+        Ok(())
+    }
+
+    /// Finds the branch which has the most chain work, only if it has more
+    /// chain work than the main chain.
+    ///
+    /// If several branches have the same chain work, the one which has the
+    /// largest block hash is chosen.
+    ///
+    /// Returns the height of the fork point and the headers of the branch.
+    pub fn best_branch(&self) -> Option<(u32, Vec<core::Header>)> {
+        let main_work = self.main[self.main.len() - 1].1;
+        let best = self
+            .branches
+            .values()
+            .filter(|node| node.chain_work > main_work)
+            .max_by_key(|node| (node.chain_work, node.header.block_hash()))?;
+        self.branch_to(best)
+    }
+
+    // Collects the headers from the fork point to the branch node.
+    fn branch_to(&self, node: &BranchNode) -> Option<(u32, Vec<core::Header>)> {
+        let mut headers = vec![node.header];
+        let mut prev_blockhash = node.header.prev_blockhash;
+        loop {
+            if let Some(fork_height) = self.main_height(&prev_blockhash) {
+                headers.reverse();
+                return Some((fork_height, headers));
+            }
+            let parent = self.branches.get(&prev_blockhash)?;
+            headers.push(parent.header);
+            prev_blockhash = parent.header.prev_blockhash;
+        }
+    }
+
+    /// Replaces the main chain after the fork point with the new headers.
+    ///
+    /// The dropped headers of the main chain are kept as a branch.
+    pub fn reorganize(
+        &mut self,
+        fork_height: u32,
+        headers: &[core::Header],
+        dropped_headers: &[core::Header],
+    ) {
+        let index = (fork_height + 1 - self.base_height) as usize;
+        let mut chain_work = self.main[index - 1].1;
+        for (block_hash, _) in self.main.drain(index..) {
+            self.main_index.remove(&block_hash);
+        }
+        for (height, header) in ((fork_height + 1)..).zip(dropped_headers.iter()) {
+            chain_work = chain_work + header.work();
+            let node = BranchNode {
+                height,
+                header: *header,
+                chain_work,
+            };
+            self.branches.insert(header.block_hash(), node);
+        }
+        for header in headers {
+            self.push_main(header);
+        }
+    }
+
+    /// Drops the main chain after the height, and the branches which are
+    /// not connected to the main chain anymore.
+    pub fn rollback(&mut self, height: u32) {
+        let index = (height + 1 - self.base_height) as usize;
+        for (block_hash, _) in self.main.drain(index..) {
+            self.main_index.remove(&block_hash);
+        }
+        self.retain_connected_branches();
+    }
+
+    /// Moves the base of the tree forward, drops all headers before it.
+    pub fn set_base(&mut self, base_height: u32) {
+        if base_height <= self.base_height || base_height > self.tip_height() {
+            return;
+        }
+        let count = (base_height - self.base_height) as usize;
+        for (block_hash, _) in self.main.drain(..count) {
+            self.main_index.remove(&block_hash);
+        }
+        self.base_height = base_height;
+        self.retain_connected_branches();
+    }
+
+    fn retain_connected_branches(&mut self) {
+        let connected = self
+            .branches
+            .values()
+            .filter(|node| self.branch_to(node).is_some())
+            .map(|node| node.header.block_hash())
+            .collect::<HashSet<_>>();
+        self.branches
+            .retain(|block_hash, _| connected.contains(block_hash));
+    }
+}
@@ -2,7 +2,11 @@
 
 mod block;
 mod dummy_service;
+pub mod header_tree;
 mod result;
+mod service;
+pub mod source;
+pub mod storage;
 pub mod utilities;
 
 #[cfg(test)]
@@ -10,4 +14,6 @@ mod tests;
 
 pub use block::BlockProofGenerator;
 pub use dummy_service::DummyService;
+pub use header_tree::HeaderTree;
 pub use result::{Error, Result};
+pub use service::{SpvChange, SpvService};
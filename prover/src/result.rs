@@ -9,6 +9,15 @@ pub enum Error {
     #[error("mmr error: {0}")]
     Mmr(#[from] mmr::lib::Error),
 
+    #[error("rpc error: {0}")]
+    Rpc(#[from] bitcoincore_rpc::Error),
+
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+
     #[error("{0}")]
     Other(String),
 }
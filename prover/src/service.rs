@@ -0,0 +1,658 @@
+//! The SPV service, which persists its data into a storage.
+
+use bitcoin::{blockdata::constants::DIFFCHANGE_INTERVAL, Block};
+use ckb_bitcoin_spv_verifier::{
+    constants::MEDIAN_TIME_SPAN,
+    types::{core, packed, prelude::*},
+    utilities::{
+        bitcoin::{append_timestamp, calculate_next_compact_target, pow_limit},
+        mmr,
+    },
+};
+
+use crate::{
+    block::BlockProofGenerator,
+    header_tree::HeaderTree,
+    result::{Error, Result},
+    storage::{MmrStore, SpvState, SpvStorage},
+};
+
+/// A change of the SPV client, which is caused by new headers.
+pub enum SpvChange {
+    /// The new headers are appended to the tip.
+    Update(packed::SpvUpdate),
+    /// A branch, which has more chain work, replaces the headers after the fork point.
+    Reorg(packed::SpvReorg),
+}
+
+/// A service to generate the SPV bootstraps, updates, reorgs and header proofs.
+///
+/// All data are persisted into the storage, so the service could be resumed
+/// from the storage, and generates the same results as before.
+///
+/// The headers of competing branches are tracked in a [`HeaderTree`], which
+/// is kept in memory only.
+pub struct SpvService<S> {
+    storage: S,
+    state: SpvState,
+    tree: HeaderTree,
+}
+
+impl<S: SpvStorage> SpvService<S> {
+    /// Bootstraps the service into an empty storage.
+    pub fn bootstrap(storage: S, height: u32, header: core::Header, flags: u8) -> Result<Self> {
+        Self::bootstrap_with_period_headers(storage, height, header, Vec::new(), flags)
+    }
+
+    /// Bootstraps at any height, the `period_headers` are the headers from
+    /// the first header of the current difficulty adjustment period to the
+    /// previous header of the input header.
+    ///
+    /// If the input header is the last header of the current period, the
+    /// target of the next period is calculated at once.
+    pub fn bootstrap_with_period_headers(
+        mut storage: S,
+        height: u32,
+        header: core::Header,
+        period_headers: Vec<core::Header>,
+        flags: u8,
+    ) -> Result<Self> {
+        if storage.state()?.is_some() {
+            return Err(Error::other(
+                "failed to bootstrap, the storage is not empty",
+            ));
+        }
+        let offset = height % DIFFCHANGE_INTERVAL;
+        if period_headers.len() != offset as usize {
+            let msg = format!(
+                "bad bootstrap height, {offset} period headers are required \
+                for height {height} but got {}",
+                period_headers.len()
+            );
+            return Err(Error::other(msg));
+        }
+        let first_header = period_headers.first().unwrap_or(&header);
+        if first_header.target() > pow_limit(flags) {
+            let msg = format!(
+                "bad bootstrap header, its target {:#x} is easier than the proof of work limit",
+                first_header.target()
+            );
+            return Err(Error::other(msg));
+        }
+        let mut recent_timestamps = [0; MEDIAN_TIME_SPAN];
+        let mut prev_blockhash = None;
+        for h in period_headers.iter().chain([&header]) {
+            if prev_blockhash.is_some_and(|prev| prev != h.prev_blockhash) {
+                let msg = "bad bootstrap header, period headers are uncontinuous";
+                return Err(Error::other(msg));
+            }
+            prev_blockhash = Some(h.block_hash());
+            append_timestamp(&mut recent_timestamps, h.time);
+        }
+        let headers_mmr_root = {
+            let mut mmr = mmr::ClientRootMMR::new(0, MmrStore(&mut storage));
+            let digest = core::HeaderDigest::new_leaf(height, &header).pack();
+            mmr.push(digest)?;
+            let root = mmr.get_root()?.unpack();
+            mmr.commit()?;
+            root
+        };
+        storage.append_headers(height, &[header])?;
+        // The input header is the last block for current difficulty.
+        let next_bits = if (height + 1) % DIFFCHANGE_INTERVAL == 0 {
+            calculate_next_compact_target(first_header.bits, first_header.time, &header, flags)
+        } else {
+            first_header.bits
+        };
+        let target_adjust_info = packed::TargetAdjustInfo::encode(first_header.time, next_bits);
+        let client = core::SpvClient {
+            id: 0,
+            tip_block_hash: header.block_hash().into(),
+            headers_mmr_root,
+            target_adjust_info,
+            recent_timestamps,
+        };
+        let state = SpvState { flags, client };
+        storage.save_state(&state)?;
+        let tree = HeaderTree::new(height, &header);
+        Ok(Self {
+            storage,
+            state,
+            tree,
+        })
+    }
+
+    /// Resumes the service from a storage.
+    ///
+    /// The MMR root and the tip block hash of the stored client are checked
+    /// against the stored MMR and headers, since an interrupted update or reorg
+    /// could leave them inconsistent.
+    pub fn resume(storage: S) -> Result<Self> {
+        let state = storage
+            .state()?
+            .ok_or_else(|| Error::other("failed to resume, the storage is empty"))?;
+        if !is_stored_client(&storage, &state.client)? {
+            let msg = "failed to resume, the stored client doesn't match the stored headers";
+            return Err(Error::other(msg));
+        }
+        let tree = load_header_tree(&storage, &state.client)?;
+        Ok(Self {
+            storage,
+            state,
+            tree,
+        })
+    }
+
+    /// Submits new headers, which could extend the tip or any known header.
+    ///
+    /// Returns the change of the tip client, if the best chain is changed.
+    /// The headers of other branches are kept, a later submission could make
+    /// any of them become the best chain.
+    ///
+    /// Before any data is saved, the change is verified as the SPV type script
+    /// does, for example, the difficulty and the median time past of headers.
+    /// If it's invalid, all submitted headers are dropped.
+    pub fn submit_headers(&mut self, headers: &[core::Header]) -> Result<Option<SpvChange>> {
+        let mut tree = self.tree.clone();
+        for header in headers {
+            if header.validate_pow(header.target()).is_err() {
+                let msg = format!(
+                    "header {:#x} has invalid proof of work",
+                    header.block_hash()
+                );
+                return Err(Error::other(msg));
+            }
+            tree.insert(header)?;
+        }
+        let Some((fork_height, headers)) = tree.best_branch() else {
+            self.tree = tree;
+            return Ok(None);
+        };
+        let flags = self.state.flags;
+        let old_client = self.state.client.pack();
+        let result = if fork_height == self.max_height() {
+            let (new_client, update) = self.preview_headers(self.tip_client(), &headers)?;
+            old_client.verify_new_client(&new_client.pack(), update, flags)
+        } else {
+            let (fork_client, _, reorg) = self.prepare_reorg(fork_height, &headers)?;
+            let (new_client, update) = self.preview_headers(fork_client, &headers)?;
+            let reorg = reorg.as_builder().update(update).build();
+            old_client.verify_reorg_client(&new_client.pack(), reorg, flags)
+        };
+        if let Err(err) = result {
+            let msg = format!(
+                "the new best chain (fork height: {fork_height}) is invalid, error code: {}",
+                err as i8
+            );
+            return Err(Error::other(msg));
+        }
+        self.tree = tree;
+        let change = if fork_height == self.max_height() {
+            SpvChange::Update(self.update(headers)?)
+        } else {
+            SpvChange::Reorg(self.reorg(fork_height, headers)?)
+        };
+        Ok(Some(change))
+    }
+
+    /// Appends headers to the tip.
+    ///
+    /// The headers are not checked, the returned `SpvUpdate` could be invalid.
+    pub fn update(&mut self, headers: Vec<core::Header>) -> Result<packed::SpvUpdate> {
+        if headers.is_empty() {
+            return Err(Error::other("failed to update, no headers"));
+        }
+        let update = self.append_headers(self.state.client.clone(), &headers)?;
+        for header in &headers {
+            self.tree.push_main(header);
+        }
+        self.tree
+            .set_base(header_tree_base_height(&self.state.client));
+        Ok(update)
+    }
+
+    /// Appends blocks to the tip, the update carries the coinbase proofs of
+    /// the blocks, which are required to verify the signet block solutions.
+    ///
+    /// The blocks are not checked, the returned `SpvUpdate` could be invalid.
+    pub fn update_with_blocks(&mut self, blocks: &[Block]) -> Result<packed::SpvUpdate> {
+        let coinbase_proofs = blocks
+            .iter()
+            .map(|block| BlockProofGenerator::new(block.clone()).generate_coinbase_proof())
+            .collect::<Result<Vec<_>>>()?;
+        let headers = blocks.iter().map(|block| block.header).collect();
+        self.update_with_coinbase_proofs(headers, coinbase_proofs)
+    }
+
+    /// Appends headers to the tip, with the coinbase proofs of them.
+    ///
+    /// The headers are not checked, the returned `SpvUpdate` could be invalid.
+    pub fn update_with_coinbase_proofs(
+        &mut self,
+        headers: Vec<core::Header>,
+        coinbase_proofs: Vec<packed::CoinbaseProof>,
+    ) -> Result<packed::SpvUpdate> {
+        if headers.len() != coinbase_proofs.len() {
+            let msg = format!(
+                "failed to update, {} headers but {} coinbase proofs",
+                headers.len(),
+                coinbase_proofs.len()
+            );
+            return Err(Error::other(msg));
+        }
+        let update = self.update(headers)?;
+        let coinbase_proofs = packed::CoinbaseProofVec::new_builder()
+            .set(coinbase_proofs)
+            .build();
+        Ok(update.as_builder().coinbase_proofs(coinbase_proofs).build())
+    }
+
+    // Appends headers to the client, then saves the new client as the tip client.
+    fn append_headers(
+        &mut self,
+        client: core::SpvClient,
+        headers: &[core::Header],
+    ) -> Result<packed::SpvUpdate> {
+        let flags = self.state.flags;
+        let start_height = client.headers_mmr_root.max_height + 1;
+        let mut mmr = {
+            let root = &client.headers_mmr_root;
+            let mmr_size = mmr_size(root.min_height, root.max_height);
+            mmr::ClientRootMMR::new(mmr_size, MmrStore(&mut self.storage))
+        };
+        let (client, update) = append_headers_to_mmr(&mut mmr, client, headers, flags)?;
+        mmr.commit()?;
+        self.storage.append_headers(start_height, headers)?;
+        self.save_client(client)?;
+        Ok(update)
+    }
+
+    // Appends headers to the client, but nothing is saved.
+    //
+    // Returns the new client and the update.
+    fn preview_headers(
+        &self,
+        client: core::SpvClient,
+        headers: &[core::Header],
+    ) -> Result<(core::SpvClient, packed::SpvUpdate)> {
+        let mut mmr = {
+            let root = &client.headers_mmr_root;
+            let mmr_size = mmr_size(root.min_height, root.max_height);
+            mmr::ClientRootMMR::new(mmr_size, MmrStore(&self.storage))
+        };
+        append_headers_to_mmr(&mut mmr, client, headers, self.state.flags)
+    }
+
+    /// Replaces the headers after the fork point with the headers of a new branch.
+    ///
+    /// The latest 11 headers until the fork point should be in the current
+    /// client. If the fork point is in an earlier difficulty adjustment period
+    /// than the next header of the current tip, the first header of its period
+    /// should be in the current client too.
+    ///
+    /// The client at the fork point is saved before the headers are replaced,
+    /// so if it's interrupted, the service is resumed at the fork point.
+    pub fn reorg(
+        &mut self,
+        fork_height: u32,
+        headers: Vec<core::Header>,
+    ) -> Result<packed::SpvReorg> {
+        let (fork_client, dropped_headers, reorg) = self.prepare_reorg(fork_height, &headers)?;
+        // Roll back to the fork point before the stored MMR nodes and headers
+        // are replaced, so the saved client always matches the stored data,
+        // even if the reorg is interrupted.
+        self.save_client(fork_client.clone())?;
+        let update = match self.append_headers(fork_client, &headers) {
+            Ok(update) => update,
+            Err(err) => {
+                self.tree = load_header_tree(&self.storage, &self.state.client)?;
+                return Err(err);
+            }
+        };
+        self.tree
+            .reorganize(fork_height, &headers, &dropped_headers);
+        self.tree
+            .set_base(header_tree_base_height(&self.state.client));
+        Ok(reorg.as_builder().update(update).build())
+    }
+
+    // Checks the fork point, then returns the client at the fork point, the
+    // dropped headers, and the reorg without the update.
+    fn prepare_reorg(
+        &self,
+        fork_height: u32,
+        headers: &[core::Header],
+    ) -> Result<(core::SpvClient, Vec<core::Header>, packed::SpvReorg)> {
+        if headers.is_empty() {
+            return Err(Error::other("failed to reorg, no headers"));
+        }
+        let min_height = self.min_height();
+        let max_height = self.max_height();
+        if max_height <= fork_height || fork_height + 1 < min_height + MEDIAN_TIME_SPAN as u32 {
+            let msg = format!(
+                "bad fork point (height: {fork_height}), current: [{min_height}, {max_height}]"
+            );
+            return Err(Error::other(msg));
+        }
+        // The first header of the difficulty adjustment period of the fork point
+        // is required, if the target adjust info of the fork point is changed.
+        let period_height = fork_height - fork_height % DIFFCHANGE_INTERVAL;
+        let fork_period_height = if fork_height / DIFFCHANGE_INTERVAL
+            == max_height / DIFFCHANGE_INTERVAL
+            && (max_height + 1) % DIFFCHANGE_INTERVAL != 0
+        {
+            None
+        } else if period_height < min_height {
+            let msg = format!(
+                "bad fork point (height: {fork_height}), the first header of its \
+                difficulty adjustment period (height: {period_height}) is not in the client"
+            );
+            return Err(Error::other(msg));
+        } else {
+            Some(period_height)
+        };
+
+        let first_height = fork_height + 1 - MEDIAN_TIME_SPAN as u32;
+        let fork_heights = (first_height..=fork_height).collect::<Vec<_>>();
+        let fork_headers = fork_heights
+            .iter()
+            .map(|height| self.stored_header(*height))
+            .collect::<Result<Vec<_>>>()?;
+        let fork_period_headers = fork_period_height
+            .map(|height| self.stored_header(height))
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?;
+        let proof_heights = fork_period_height
+            .filter(|height| *height < first_height)
+            .into_iter()
+            .chain(fork_heights.iter().copied())
+            .collect::<Vec<_>>();
+        let dropped_headers = ((fork_height + 1)..=max_height)
+            .map(|height| self.stored_header(height))
+            .collect::<Result<Vec<_>>>()?;
+        let fork_headers_proof = self
+            .generate_headers_proof(&proof_heights)?
+            .ok_or_else(|| Error::other("fork headers are not found"))?;
+        let (fork_headers_mmr_root, old_headers_mmr_proof_items) = {
+            let mmr =
+                mmr::ClientRootMMR::new(mmr_size(min_height, max_height), MmrStore(&self.storage));
+            let positions = ((fork_height + 1)..=max_height)
+                .map(|height| mmr::lib::leaf_index_to_pos(u64::from(height - min_height)))
+                .collect();
+            let proof_items = mmr
+                .gen_proof(positions)?
+                .proof_items()
+                .iter()
+                .map(Clone::clone)
+                .collect::<Vec<_>>();
+            let fork_mmr =
+                mmr::ClientRootMMR::new(mmr_size(min_height, fork_height), MmrStore(&self.storage));
+            (fork_mmr.get_root()?, proof_items)
+        };
+
+        let mut recent_timestamps = [0; MEDIAN_TIME_SPAN];
+        for header in &fork_headers {
+            append_timestamp(&mut recent_timestamps, header.time);
+        }
+        let fork_header = &fork_headers[MEDIAN_TIME_SPAN - 1];
+        let target_adjust_info = match fork_period_headers.first() {
+            Some(period_header) => {
+                let next_bits = if (fork_height + 1) % DIFFCHANGE_INTERVAL == 0 {
+                    let flags = self.state.flags;
+                    let (bits, time) = (period_header.bits, period_header.time);
+                    calculate_next_compact_target(bits, time, fork_header, flags)
+                } else {
+                    period_header.bits
+                };
+                packed::TargetAdjustInfo::encode(period_header.time, next_bits)
+            }
+            None => self.state.client.target_adjust_info.clone(),
+        };
+        let fork_client = core::SpvClient {
+            id: self.state.client.id,
+            tip_block_hash: fork_header.block_hash().into(),
+            headers_mmr_root: fork_headers_mmr_root.unpack(),
+            target_adjust_info,
+            recent_timestamps,
+        };
+        let old_headers_mmr_proof = packed::MmrProof::new_builder()
+            .set(old_headers_mmr_proof_items)
+            .build();
+        let reorg = packed::SpvReorg::new_builder()
+            .dropped_headers(dropped_headers.clone().pack())
+            .fork_headers_mmr_root(fork_headers_mmr_root)
+            .fork_headers(fork_headers.pack())
+            .fork_period_headers(fork_period_headers.pack())
+            .fork_headers_proof(fork_headers_proof.pack())
+            .old_headers_mmr_proof(old_headers_mmr_proof)
+            .build();
+        Ok((fork_client, dropped_headers, reorg))
+    }
+
+    /// Rolls back to a previous client.
+    ///
+    /// The previous client should be a client which was generated by current
+    /// service, its MMR root and its tip block hash are checked.
+    pub fn rollback_to(&mut self, prev_client: core::SpvClient) -> Result<()> {
+        let min_height = self.min_height();
+        let max_height = self.max_height();
+        let prev_root = &prev_client.headers_mmr_root;
+        let prev_height = prev_root.max_height;
+        if prev_root.min_height != min_height
+            || prev_height < min_height
+            || max_height < prev_height
+        {
+            let msg = format!(
+                "the previous header (height: {prev_height}) is not found \
+                (current: [{min_height}, {max_height}])"
+            );
+            return Err(Error::other(msg));
+        }
+        if !is_stored_client(&self.storage, &prev_client)? {
+            let msg = format!(
+                "the previous client (height: {prev_height}) is not generated by current service"
+            );
+            return Err(Error::other(msg));
+        }
+        self.save_client(prev_client)?;
+        if prev_height < self.tree.base_height() {
+            self.tree = load_header_tree(&self.storage, &self.state.client)?;
+        } else {
+            self.tree.rollback(prev_height);
+        }
+        Ok(())
+    }
+
+    pub fn tip_client(&self) -> core::SpvClient {
+        self.state.client.clone()
+    }
+
+    pub fn flags(&self) -> u8 {
+        self.state.flags
+    }
+
+    pub fn min_height(&self) -> u32 {
+        self.state.client.headers_mmr_root.min_height
+    }
+
+    pub fn max_height(&self) -> u32 {
+        self.state.client.headers_mmr_root.max_height
+    }
+
+    /// Gets a header in the range of current client.
+    pub fn header(&self, height: u32) -> Result<Option<core::Header>> {
+        if height < self.min_height() || self.max_height() < height {
+            return Ok(None);
+        }
+        self.storage.header(height)
+    }
+
+    pub fn header_tree(&self) -> &HeaderTree {
+        &self.tree
+    }
+
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    pub fn into_storage(self) -> S {
+        self.storage
+    }
+
+    pub fn generate_header_proof(&self, height: u32) -> Result<Option<core::MmrProof>> {
+        self.generate_headers_proof(&[height])
+    }
+
+    /// Generates one MMR proof for multiple headers.
+    ///
+    /// Returns `None` if any height is out of the range of current client.
+    pub fn generate_headers_proof(&self, heights: &[u32]) -> Result<Option<core::MmrProof>> {
+        let min_height = self.min_height();
+        let max_height = self.max_height();
+        if heights.is_empty() || heights.iter().any(|h| *h < min_height || max_height < *h) {
+            return Ok(None);
+        }
+        let positions = heights
+            .iter()
+            .map(|height| mmr::lib::leaf_index_to_pos(u64::from(height - min_height)))
+            .collect();
+        let mmr =
+            mmr::ClientRootMMR::new(mmr_size(min_height, max_height), MmrStore(&self.storage));
+        let proof = mmr
+            .gen_proof(positions)?
+            .proof_items()
+            .iter()
+            .map(|item| item.unpack())
+            .collect::<Vec<_>>();
+        Ok(Some(proof))
+    }
+
+    fn stored_header(&self, height: u32) -> Result<core::Header> {
+        self.header(height)?.ok_or_else(|| {
+            let msg = format!("header-{height} is not found");
+            Error::other(msg)
+        })
+    }
+
+    fn save_client(&mut self, client: core::SpvClient) -> Result<()> {
+        let state = SpvState {
+            flags: self.state.flags,
+            client,
+        };
+        self.storage.save_state(&state)?;
+        self.state = state;
+        Ok(())
+    }
+}
+
+// Only the headers in the current and the previous difficulty adjustment
+// periods could be reorganized.
+fn header_tree_base_height(client: &core::SpvClient) -> u32 {
+    let min_height = client.headers_mmr_root.min_height;
+    let max_height = client.headers_mmr_root.max_height;
+    let period_height = max_height - max_height % DIFFCHANGE_INTERVAL;
+    min_height.max(period_height.saturating_sub(DIFFCHANGE_INTERVAL))
+}
+
+fn load_header_tree<S: SpvStorage>(storage: &S, client: &core::SpvClient) -> Result<HeaderTree> {
+    let base_height = header_tree_base_height(client);
+    let get_header = |height: u32| {
+        storage.header(height)?.ok_or_else(|| {
+            let msg = format!("header-{height} is not found in the storage");
+            Error::other(msg)
+        })
+    };
+    let mut tree = HeaderTree::new(base_height, &get_header(base_height)?);
+    for height in (base_height + 1)..=client.headers_mmr_root.max_height {
+        tree.push_main(&get_header(height)?);
+    }
+    Ok(tree)
+}
+
+// Appends headers to the client and the MMR, but the MMR is not committed.
+//
+// Returns the new client and the update.
+fn append_headers_to_mmr<T>(
+    mmr: &mut mmr::ClientRootMMR<T>,
+    mut client: core::SpvClient,
+    headers: &[core::Header],
+    flags: u8,
+) -> Result<(core::SpvClient, packed::SpvUpdate)>
+where
+    T: mmr::lib::MMRStoreReadOps<packed::HeaderDigest>,
+{
+    let min_height = client.headers_mmr_root.min_height;
+    let start_height = client.headers_mmr_root.max_height + 1;
+    let mut positions = Vec::new();
+    for (height, header) in (start_height..).zip(headers.iter()) {
+        let digest = core::HeaderDigest::new_leaf(height, header).pack();
+        positions.push(mmr::lib::leaf_index_to_pos(u64::from(height - min_height)));
+        mmr.push(digest)?;
+        client.tip_block_hash = header.block_hash().into();
+        append_header_to_client(&mut client, height, header, flags);
+    }
+    client.headers_mmr_root = mmr.get_root()?.unpack();
+
+    let headers_mmr_proof_items = mmr
+        .gen_proof(positions)?
+        .proof_items()
+        .iter()
+        .map(Clone::clone)
+        .collect::<Vec<_>>();
+    let headers_mmr_proof = packed::MmrProof::new_builder()
+        .set(headers_mmr_proof_items)
+        .build();
+    let update = packed::SpvUpdate::new_builder()
+        .headers(headers.to_vec().pack())
+        .new_headers_mmr_proof(headers_mmr_proof)
+        .build();
+    Ok((client, update))
+}
+
+// Checks whether the MMR root and the tip block hash of the client are the
+// same as the ones calculated from the storage.
+fn is_stored_client<S: SpvStorage>(storage: &S, client: &core::SpvClient) -> Result<bool> {
+    let root = &client.headers_mmr_root;
+    let mmr = mmr::ClientRootMMR::new(
+        mmr_size(root.min_height, root.max_height),
+        MmrStore(storage),
+    );
+    let stored_root = mmr.get_root()?;
+    let Some(tip_header) = storage.header(root.max_height)? else {
+        return Ok(false);
+    };
+    Ok(stored_root.as_slice() == root.pack().as_slice()
+        && core::Hash::from(tip_header.block_hash()) == client.tip_block_hash)
+}
+
+fn mmr_size(min_height: u32, max_height: u32) -> u64 {
+    mmr::lib::leaf_index_to_mmr_size(u64::from(max_height - min_height))
+}
+
+/// Appends a header to the client, the recent timestamps and the target
+/// adjust info are updated, but the tip block hash and the MMR root are not.
+fn append_header_to_client(
+    client: &mut core::SpvClient,
+    height: u32,
+    header: &core::Header,
+    flags: u8,
+) {
+    append_timestamp(&mut client.recent_timestamps, header.time);
+    match (height + 1) % DIFFCHANGE_INTERVAL {
+        0 => {
+            let start_time: u32 = client.target_adjust_info.start_time().unpack();
+            let cached_bits: u32 = client.target_adjust_info.next_compact_target().unpack();
+            let cached_bits = core::CompactTarget::from_consensus(cached_bits);
+            let next_bits = calculate_next_compact_target(cached_bits, start_time, header, flags);
+            log::info!(
+                ">>> height {height:07}, calculated new target {:#x}",
+                core::Target::from(next_bits)
+            );
+            client.target_adjust_info = packed::TargetAdjustInfo::encode(start_time, next_bits);
+        }
+        1 => {
+            client.target_adjust_info = packed::TargetAdjustInfo::encode(header.time, header.bits);
+        }
+        _ => {}
+    }
+}
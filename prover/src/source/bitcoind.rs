@@ -0,0 +1,74 @@
+//! A source through the JSON-RPC interface of Bitcoin Core.
+
+use bitcoin::{Block, BlockHash, Txid};
+use bitcoincore_rpc::{Client, RpcApi as _};
+use ckb_bitcoin_spv_verifier::types::core;
+
+use super::{BlockSource, HeaderSource};
+use crate::result::{Error, Result};
+
+pub use bitcoincore_rpc::Auth;
+
+/// A client of the JSON-RPC interface of Bitcoin Core.
+///
+/// Supports cookie authentication (`Auth::CookieFile`) and user/password
+/// authentication (`Auth::UserPass`).
+pub struct BitcoindRpcClient {
+    client: Client,
+}
+
+impl BitcoindRpcClient {
+    /// Creates a client, for example, `BitcoindRpcClient::new("http://127.0.0.1:8332", auth)`.
+    pub fn new(url: &str, auth: Auth) -> Result<Self> {
+        let client = Client::new(url, auth)?;
+        Ok(Self { client })
+    }
+}
+
+impl HeaderSource for BitcoindRpcClient {
+    fn tip_height(&self) -> Result<u32> {
+        let height = self.client.get_block_count()?;
+        u32::try_from(height).map_err(|_| {
+            let msg = format!("the height of the tip ({height}) is too large");
+            Error::other(msg)
+        })
+    }
+
+    fn block_hash(&self, height: u32) -> Result<BlockHash> {
+        self.client
+            .get_block_hash(u64::from(height))
+            .map_err(Into::into)
+    }
+
+    fn header(&self, block_hash: &BlockHash) -> Result<core::Header> {
+        let header = self.client.get_block_header(block_hash)?;
+        if header.block_hash() != *block_hash {
+            let msg = format!(
+                "the header of block {block_hash:#x} is mismatched, got {:#x}",
+                header.block_hash()
+            );
+            return Err(Error::other(msg));
+        }
+        Ok(header)
+    }
+}
+
+impl BlockSource for BitcoindRpcClient {
+    fn block(&self, block_hash: &BlockHash) -> Result<Block> {
+        let block = self.client.get_block(block_hash)?;
+        if block.block_hash() != *block_hash {
+            let msg = format!(
+                "the block {block_hash:#x} is mismatched, got {:#x}",
+                block.block_hash()
+            );
+            return Err(Error::other(msg));
+        }
+        Ok(block)
+    }
+
+    fn txout_proof(&self, txids: &[Txid], block_hash: &BlockHash) -> Result<Vec<u8>> {
+        self.client
+            .get_tx_out_proof(txids, Some(block_hash))
+            .map_err(Into::into)
+    }
+}
@@ -0,0 +1,422 @@
+//! A client of the Electrum protocol.
+
+use std::{
+    collections::HashMap,
+    io::{BufRead as _, BufReader, Write as _},
+    net::{SocketAddr, TcpStream},
+    str::FromStr as _,
+    sync::Mutex,
+    time::Duration,
+};
+
+use bitcoin::{consensus::encode, hex::FromHex as _, BlockHash, Transaction, Txid};
+use ckb_bitcoin_spv_verifier::{
+    types::{core, packed, prelude::*},
+    utilities::bitcoin::calculate_merkle_root,
+};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Value};
+
+use super::HeaderSource;
+use crate::{
+    result::{Error, Result},
+    storage::SpvStorage,
+    SpvChange, SpvService,
+};
+
+/// The version of the Electrum protocol, which supports `blockchain.block.headers`.
+const PROTOCOL_VERSION: &str = "1.4";
+/// The max count of headers in one `blockchain.block.headers` request.
+const MAX_HEADERS_COUNT: u32 = 2016;
+const HEADER_SIZE: usize = 80;
+const CLIENT_NAME: &str = concat!("ckb-bitcoin-spv-prover/", env!("CARGO_PKG_VERSION"));
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// A client which connects to an Electrum server through plain TCP.
+///
+/// Headers are requested by heights, and the proofs of transactions are
+/// built from the Merkle branches of `blockchain.transaction.get_merkle`,
+/// so full blocks are never downloaded.
+///
+/// A `TransactionProof` can't be built, since it's a partial Merkle tree
+/// which requires the count of transactions in the block, but Electrum
+/// servers don't return it. So a `TransactionBranchProof` is built instead.
+/// A plain Merkle branch doesn't authenticate the depth of the Merkle tree,
+/// so it should be verified with a coinbase proof, which is built from
+/// `blockchain.transaction.id_from_pos`, by
+/// `SpvClient::verify_transaction_with_branch_hardened(..)`.
+pub struct ElectrumClient {
+    conn: Mutex<Connection>,
+    server_version: String,
+    // Headers which have been fetched, since Electrum servers can't look up
+    // headers by block hashes.
+    headers: Mutex<HashMap<BlockHash, core::Header>>,
+}
+
+struct Connection {
+    next_id: u64,
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+#[derive(Deserialize)]
+struct HeadersResponse {
+    count: u32,
+    hex: String,
+}
+
+#[derive(Deserialize)]
+struct TipResponse {
+    height: u32,
+}
+
+#[derive(Deserialize)]
+struct IdFromPosResponse {
+    tx_hash: String,
+    merkle: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct MerkleResponse {
+    block_height: u32,
+    merkle: Vec<String>,
+    pos: u32,
+}
+
+impl ElectrumClient {
+    /// Connects to a server, and negotiates the protocol version.
+    pub fn connect(addr: SocketAddr) -> Result<Self> {
+        let stream = TcpStream::connect_timeout(&addr, DEFAULT_TIMEOUT)?;
+        stream.set_read_timeout(Some(DEFAULT_TIMEOUT))?;
+        stream.set_write_timeout(Some(DEFAULT_TIMEOUT))?;
+        let mut conn = Connection {
+            next_id: 0,
+            reader: BufReader::new(stream.try_clone()?),
+            writer: stream,
+        };
+        let (server_version, protocol_version): (String, String) =
+            conn.request("server.version", json!([CLIENT_NAME, PROTOCOL_VERSION]))?;
+        log::debug!("connected to {server_version} with protocol {protocol_version}");
+        let client = Self {
+            conn: Mutex::new(conn),
+            server_version,
+            headers: Mutex::new(HashMap::new()),
+        };
+        Ok(client)
+    }
+
+    /// The software version of the server.
+    pub fn server_version(&self) -> &str {
+        &self.server_version
+    }
+
+    fn request<T: DeserializeOwned>(&self, method: &str, params: Value) -> Result<T> {
+        self.conn
+            .lock()
+            .map_err(|_| Error::other("the connection is poisoned"))?
+            .request(method, params)
+    }
+
+    fn cache_headers(&self, headers: &[core::Header]) {
+        if let Ok(mut cache) = self.headers.lock() {
+            cache.extend(headers.iter().map(|header| (header.block_hash(), *header)));
+        }
+    }
+
+    /// Requests the header at the height, by `blockchain.block.header`.
+    pub fn get_header(&self, height: u32) -> Result<core::Header> {
+        let hex: String = self.request("blockchain.block.header", json!([height]))?;
+        let header = decode_headers(&hex)?.pop().ok_or_else(|| {
+            let msg = format!("the header at height {height} is empty");
+            Error::other(msg)
+        })?;
+        self.cache_headers(&[header]);
+        Ok(header)
+    }
+
+    /// Requests continuous headers from the start height, by `blockchain.block.headers`.
+    ///
+    /// At most 2016 headers are returned, and less headers are returned if
+    /// the tip of the best chain is reached.
+    pub fn get_headers(&self, start_height: u32, count: u32) -> Result<Vec<core::Header>> {
+        let count = count.min(MAX_HEADERS_COUNT);
+        let response: HeadersResponse =
+            self.request("blockchain.block.headers", json!([start_height, count]))?;
+        let headers = decode_headers(&response.hex)?;
+        if headers.len() != response.count as usize || response.count > count {
+            let msg = format!(
+                "expect at most {count} headers from height {start_height}, \
+                 but got {} headers while the count is {}",
+                headers.len(),
+                response.count
+            );
+            return Err(Error::other(msg));
+        }
+        for (prev, header) in headers.iter().zip(headers.iter().skip(1)) {
+            if header.prev_blockhash != prev.block_hash() {
+                let msg = format!(
+                    "headers from height {start_height} are not continuous at {:#x}",
+                    header.block_hash()
+                );
+                return Err(Error::other(msg));
+            }
+        }
+        self.cache_headers(&headers);
+        Ok(headers)
+    }
+
+    /// Requests the Merkle branch of a transaction in the block at the height,
+    /// by `blockchain.transaction.get_merkle`.
+    ///
+    /// Returns the index of the transaction in the block, and the Merkle
+    /// branch from bottom to top.
+    pub fn get_merkle_branch(&self, txid: &Txid, height: u32) -> Result<(u32, Vec<core::Hash>)> {
+        let response: MerkleResponse = self.request(
+            "blockchain.transaction.get_merkle",
+            json!([txid.to_string(), height]),
+        )?;
+        if response.block_height != height {
+            let msg = format!(
+                "transaction {txid:#x} is in block {}, not in block {height}",
+                response.block_height
+            );
+            return Err(Error::other(msg));
+        }
+        // The hashes are in the reversed byte order, the same as txids.
+        let merkle_branch = response
+            .merkle
+            .iter()
+            .map(|hex| core::Hash::from_str(hex).map_err(Error::other))
+            .collect::<Result<Vec<_>>>()?;
+        Ok((response.pos, merkle_branch))
+    }
+
+    /// Generates the proof of a transaction with the Merkle branch from the
+    /// server.
+    ///
+    /// It's a `TransactionBranchProof` rather than a `TransactionProof`, since
+    /// the count of transactions in the block is unknown. The block at the
+    /// height should be in the service, and the branch is checked against its
+    /// header.
+    ///
+    /// Since the depth of the Merkle tree is not authenticated by the branch,
+    /// verify it with the transaction data and the proof from
+    /// `self.generate_coinbase_proof(..)`, by
+    /// `SpvClient::verify_transaction_with_branch_hardened(..)`.
+    /// `SpvClient::verify_transaction_with_branch(..)` alone could be cheated
+    /// by a forged transaction.
+    pub fn generate_transaction_branch_proof<S: SpvStorage>(
+        &self,
+        service: &SpvService<S>,
+        txid: &Txid,
+        height: u32,
+    ) -> Result<packed::TransactionBranchProof> {
+        let header = service.header(height)?.ok_or_else(|| {
+            let msg = format!("the header at height {height} is not in the service");
+            Error::other(msg)
+        })?;
+        let (tx_index, merkle_branch) = self.get_merkle_branch(txid, height)?;
+        let merkle_root =
+            calculate_merkle_root(txid.to_raw_hash(), tx_index, merkle_branch.iter().copied());
+        if merkle_root != Some(header.merkle_root.to_raw_hash()) {
+            let msg = format!(
+                "the merkle branch of transaction {txid:#x} is not matched block {:#x}",
+                header.block_hash()
+            );
+            return Err(Error::other(msg));
+        }
+        let header_proof = service.generate_header_proof(height)?.ok_or_else(|| {
+            let msg = format!("failed to generate the header proof at height {height}");
+            Error::other(msg)
+        })?;
+        let proof = packed::TransactionBranchProof::new_builder()
+            .tx_index(tx_index.pack())
+            .height(height.pack())
+            .header(header.pack())
+            .merkle_branch(merkle_branch.pack())
+            .header_proof(header_proof.pack())
+            .build();
+        Ok(proof)
+    }
+
+    /// Generates the proof of the coinbase transaction in the block at the
+    /// height, by `blockchain.transaction.id_from_pos` and `blockchain.transaction.get`.
+    ///
+    /// The block at the height should be in the service, and the branch is
+    /// checked against its header.
+    pub fn generate_coinbase_proof<S: SpvStorage>(
+        &self,
+        service: &SpvService<S>,
+        height: u32,
+    ) -> Result<packed::CoinbaseProof> {
+        let header = service.header(height)?.ok_or_else(|| {
+            let msg = format!("the header at height {height} is not in the service");
+            Error::other(msg)
+        })?;
+        let response: IdFromPosResponse = self.request(
+            "blockchain.transaction.id_from_pos",
+            json!([height, 0, true]),
+        )?;
+        let hex: String = self.request("blockchain.transaction.get", json!([response.tx_hash]))?;
+        let bytes = Vec::from_hex(&hex).map_err(Error::other)?;
+        let coinbase: Transaction = encode::deserialize(&bytes).map_err(Error::other)?;
+        if !coinbase.is_coinbase() || coinbase.txid().to_string() != response.tx_hash {
+            let msg = format!("the coinbase transaction of block {height} is invalid");
+            return Err(Error::other(msg));
+        }
+        // The hashes are in the reversed byte order, the same as txids.
+        let merkle_branch = response
+            .merkle
+            .iter()
+            .map(|hex| core::Hash::from_str(hex).map_err(Error::other))
+            .collect::<Result<Vec<_>>>()?;
+        let merkle_root = calculate_merkle_root(
+            coinbase.txid().to_raw_hash(),
+            0,
+            merkle_branch.iter().copied(),
+        );
+        if merkle_root != Some(header.merkle_root.to_raw_hash()) {
+            let msg = format!(
+                "the merkle branch of the coinbase transaction is not matched block {:#x}",
+                header.block_hash()
+            );
+            return Err(Error::other(msg));
+        }
+        let proof = packed::CoinbaseProof::new_builder()
+            .transaction(core::Bytes::from(bytes).pack())
+            .merkle_branch(merkle_branch.pack())
+            .build();
+        Ok(proof)
+    }
+
+    /// Submits the headers of the best chain of the server to the service,
+    /// until the tip of the server is reached.
+    ///
+    /// If the server is on another branch, the headers are requested from
+    /// the fork point.
+    pub fn sync_headers<S: SpvStorage>(
+        &self,
+        service: &mut SpvService<S>,
+    ) -> Result<Vec<SpvChange>> {
+        let tip_height = self.tip_height()?;
+        let mut start_height = self.find_fork_height(service, tip_height)? + 1;
+        let mut changes = Vec::new();
+        while start_height <= tip_height {
+            let headers = self.get_headers(start_height, tip_height - start_height + 1)?;
+            log::debug!(
+                "received {} headers from height {start_height}",
+                headers.len()
+            );
+            if headers.is_empty() {
+                break;
+            }
+            start_height += headers.len() as u32;
+            if let Some(change) = service.submit_headers(&headers)? {
+                changes.push(change);
+            }
+        }
+        Ok(changes)
+    }
+
+    // Finds the highest header in the service, which is also in the best chain of the server.
+    fn find_fork_height<S: SpvStorage>(
+        &self,
+        service: &SpvService<S>,
+        tip_height: u32,
+    ) -> Result<u32> {
+        let base_height = service.header_tree().base_height();
+        let mut height = service.max_height().min(tip_height);
+        loop {
+            let header = service.header(height)?.ok_or_else(|| {
+                let msg = format!("the header at height {height} is not in the service");
+                Error::other(msg)
+            })?;
+            if self.get_header(height)?.block_hash() == header.block_hash() {
+                return Ok(height);
+            }
+            if height <= base_height {
+                let msg = format!("the server forks before height {base_height}");
+                return Err(Error::other(msg));
+            }
+            height -= 1;
+        }
+    }
+}
+
+impl HeaderSource for ElectrumClient {
+    fn tip_height(&self) -> Result<u32> {
+        let response: TipResponse = self.request("blockchain.headers.subscribe", json!([]))?;
+        Ok(response.height)
+    }
+
+    fn block_hash(&self, height: u32) -> Result<BlockHash> {
+        self.get_header(height).map(|header| header.block_hash())
+    }
+
+    /// Only the headers which have been fetched by heights are available.
+    fn header(&self, block_hash: &BlockHash) -> Result<core::Header> {
+        self.headers
+            .lock()
+            .ok()
+            .and_then(|cache| cache.get(block_hash).copied())
+            .ok_or_else(|| {
+                let msg = format!("header {block_hash:#x} is unknown, fetch it by height first");
+                Error::other(msg)
+            })
+    }
+
+    fn headers(&self, start_height: u32, count: u32) -> Result<Vec<core::Header>> {
+        let mut headers = Vec::new();
+        while (headers.len() as u32) < count {
+            let height = start_height + headers.len() as u32;
+            let batch = self.get_headers(height, count - headers.len() as u32)?;
+            if batch.is_empty() {
+                break;
+            }
+            headers.extend(batch);
+        }
+        Ok(headers)
+    }
+}
+
+impl Connection {
+    fn request<T: DeserializeOwned>(&mut self, method: &str, params: Value) -> Result<T> {
+        let id = self.next_id;
+        self.next_id += 1;
+        let request = json!({"jsonrpc": "2.0", "id": id, "method": method, "params": params});
+        let mut line = serde_json::to_string(&request)?;
+        line.push('\n');
+        self.writer.write_all(line.as_bytes())?;
+        log::trace!("sent request {method} ({id})");
+        loop {
+            let mut line = String::new();
+            if self.reader.read_line(&mut line)? == 0 {
+                return Err(Error::other("the connection is closed by the server"));
+            }
+            let mut response: Value = serde_json::from_str(&line)?;
+            // Skip notifications of subscriptions.
+            if response.get("id") != Some(&json!(id)) {
+                log::trace!("skipped message {}", line.trim_end());
+                continue;
+            }
+            if let Some(error) = response.get("error").filter(|error| !error.is_null()) {
+                let msg = format!("electrum error for {method}: {error}");
+                return Err(Error::other(msg));
+            }
+            let result = response.get_mut("result").map(Value::take);
+            return serde_json::from_value(result.unwrap_or_default()).map_err(Into::into);
+        }
+    }
+}
+
+fn decode_headers(hex: &str) -> Result<Vec<core::Header>> {
+    let bytes = Vec::from_hex(hex).map_err(Error::other)?;
+    if bytes.len() % HEADER_SIZE != 0 {
+        let msg = format!("the size of headers ({}) is invalid", bytes.len());
+        return Err(Error::other(msg));
+    }
+    bytes
+        .chunks(HEADER_SIZE)
+        .map(|chunk| encode::deserialize(chunk).map_err(Error::other))
+        .collect()
+}
@@ -0,0 +1,72 @@
+//! The sources of Bitcoin headers, blocks and transaction proofs.
+
+use bitcoin::{Block, BlockHash, Txid};
+use ckb_bitcoin_spv_verifier::types::core;
+
+use crate::{result::Result, BlockProofGenerator, Error};
+
+mod bitcoind;
+mod electrum;
+mod p2p;
+
+pub use bitcoind::{Auth, BitcoindRpcClient};
+pub use electrum::ElectrumClient;
+pub use p2p::P2pClient;
+
+#[cfg(test)]
+pub(crate) use p2p::{murmur3_32, BloomFilter};
+
+/// A source of headers in the best chain.
+pub trait HeaderSource {
+    /// Gets the height of the tip of the best chain.
+    fn tip_height(&self) -> Result<u32>;
+
+    /// Gets the hash of the block at the height, in the best chain.
+    fn block_hash(&self, height: u32) -> Result<BlockHash>;
+
+    /// Gets a header by its block hash.
+    fn header(&self, block_hash: &BlockHash) -> Result<core::Header>;
+
+    /// Gets continuous headers in the best chain, from the start height.
+    ///
+    /// Less headers are returned if the tip of the best chain is reached.
+    fn headers(&self, start_height: u32, count: u32) -> Result<Vec<core::Header>> {
+        let tip_height = self.tip_height()?;
+        let end_height = tip_height.min(start_height.saturating_add(count).saturating_sub(1));
+        (start_height..=end_height)
+            .map(|height| {
+                self.block_hash(height)
+                    .and_then(|block_hash| self.header(&block_hash))
+            })
+            .collect()
+    }
+}
+
+/// A source of blocks and transaction proofs.
+pub trait BlockSource: HeaderSource {
+    /// Gets a block by its block hash.
+    fn block(&self, block_hash: &BlockHash) -> Result<Block>;
+
+    /// Gets the proof of transactions in a block, which is the same as the
+    /// result of `gettxoutproof` in Bitcoin Core.
+    ///
+    /// By default, the proof is generated from the whole block.
+    fn txout_proof(&self, txids: &[Txid], block_hash: &BlockHash) -> Result<Vec<u8>> {
+        let block = self.block(block_hash)?;
+        let indexes = txids
+            .iter()
+            .map(|txid| {
+                block
+                    .txdata
+                    .iter()
+                    .position(|tx| tx.txid() == *txid)
+                    .map(|index| index as u32)
+                    .ok_or_else(|| {
+                        let msg = format!("transaction {txid:#x} is not in block {block_hash:#x}");
+                        Error::other(msg)
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        BlockProofGenerator::new(block).generate_txoutproof_via_indexes(&indexes)
+    }
+}
@@ -0,0 +1,445 @@
+//! A minimal client of the Bitcoin P2P network.
+
+use std::{
+    io::{BufReader, Write as _},
+    net::{SocketAddr, TcpStream},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use bitcoin::{
+    consensus::{encode, Decodable as _},
+    hashes::Hash as _,
+    p2p::{
+        address::Address,
+        message::{NetworkMessage, RawNetworkMessage},
+        message_blockdata::{GetHeadersMessage, Inventory},
+        message_bloom::{BloomFlags, FilterLoad},
+        message_network::VersionMessage,
+        Magic, ServiceFlags,
+    },
+    Block, BlockHash, MerkleBlock, Network, Txid,
+};
+use ckb_bitcoin_spv_verifier::types::core;
+
+use crate::{
+    result::{Error, Result},
+    storage::SpvStorage,
+    BlockProofGenerator, SpvChange, SpvService,
+};
+
+/// The protocol version which supports `sendheaders` and witness blocks.
+const PROTOCOL_VERSION: u32 = 70016;
+/// The inventory type of merkle blocks (`MSG_FILTERED_BLOCK`).
+const MSG_FILTERED_BLOCK: u32 = 3;
+/// The max count of headers in a `headers` message.
+const MAX_HEADERS_COUNT: usize = 2000;
+const USER_AGENT: &str = concat!("/ckb-bitcoin-spv-prover:", env!("CARGO_PKG_VERSION"), "/");
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// A client which connects to a Bitcoin peer.
+///
+/// It requests headers by `getheaders`, blocks by `getdata`, and merkle
+/// blocks by `getdata` with a BIP37 bloom filter, if the peer supports it.
+pub struct P2pClient {
+    conn: Connection,
+    peer_version: VersionMessage,
+}
+
+struct Connection {
+    magic: Magic,
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl P2pClient {
+    /// Connects to a peer, and finishes the handshake.
+    pub fn connect(addr: SocketAddr, network: Network) -> Result<Self> {
+        let stream = TcpStream::connect_timeout(&addr, DEFAULT_TIMEOUT)?;
+        stream.set_read_timeout(Some(DEFAULT_TIMEOUT))?;
+        stream.set_write_timeout(Some(DEFAULT_TIMEOUT))?;
+        let mut conn = Connection {
+            magic: Magic::from(network),
+            reader: BufReader::new(stream.try_clone()?),
+            writer: stream,
+        };
+        let peer_version = conn.handshake(addr)?;
+        Ok(Self { conn, peer_version })
+    }
+
+    /// The `version` message from the peer.
+    pub fn peer_version(&self) -> &VersionMessage {
+        &self.peer_version
+    }
+
+    /// Requests the headers after the first known hash in the locator.
+    ///
+    /// At most 2000 headers are returned.
+    pub fn get_headers(&mut self, locator: Vec<BlockHash>) -> Result<Vec<core::Header>> {
+        let request = GetHeadersMessage {
+            version: PROTOCOL_VERSION,
+            ..GetHeadersMessage::new(locator, BlockHash::all_zeros())
+        };
+        self.conn.send(NetworkMessage::GetHeaders(request))?;
+        self.conn.receive_until(|message| match message {
+            NetworkMessage::Headers(headers) => Ok(Some(headers)),
+            _ => Ok(None),
+        })
+    }
+
+    /// Requests a block, with the witnesses of its transactions.
+    pub fn get_block(&mut self, block_hash: &BlockHash) -> Result<Block> {
+        let inv = Inventory::WitnessBlock(*block_hash);
+        self.conn.send(NetworkMessage::GetData(vec![inv]))?;
+        self.conn.receive_until(|message| match message {
+            NetworkMessage::Block(block) if block.block_hash() == *block_hash => Ok(Some(block)),
+            NetworkMessage::NotFound(invs) if invs.contains(&inv) => {
+                let msg = format!("block {block_hash:#x} is not found");
+                Err(Error::other(msg))
+            }
+            _ => Ok(None),
+        })
+    }
+
+    /// Requests a block and creates a proof generator for it.
+    pub fn get_block_proof_generator(
+        &mut self,
+        block_hash: &BlockHash,
+    ) -> Result<BlockProofGenerator> {
+        self.get_block(block_hash).map(BlockProofGenerator::new)
+    }
+
+    /// Requests a merkle block, which matches the transactions.
+    ///
+    /// A bloom filter, which only contains the transactions, is loaded before
+    /// the request, and it's cleared after the request.
+    /// The merkle block may match more transactions, since a bloom filter has
+    /// false positives.
+    ///
+    /// If the peer doesn't support bloom filters (`NODE_BLOOM` is not set in
+    /// its services), the whole block is requested, then the merkle block is
+    /// created from it, which only matches the transactions.
+    pub fn get_merkle_block(
+        &mut self,
+        txids: &[Txid],
+        block_hash: &BlockHash,
+    ) -> Result<MerkleBlock> {
+        if !self.peer_version.services.has(ServiceFlags::BLOOM) {
+            log::debug!("the peer doesn't support bloom filters, request the whole block");
+            let block = self.get_block(block_hash)?;
+            if let Some(txid) = txids
+                .iter()
+                .find(|txid| !block.txdata.iter().any(|tx| tx.txid() == **txid))
+            {
+                let msg = format!("transaction {txid:#x} is not in block {block_hash:#x}");
+                return Err(Error::other(msg));
+            }
+            let merkle_block =
+                MerkleBlock::from_block_with_predicate(&block, |txid| txids.contains(txid));
+            return Ok(merkle_block);
+        }
+        let mut filter = BloomFilter::new(txids.len(), 0.000_001, rand_nonce() as u32);
+        for txid in txids {
+            filter.insert(txid.as_byte_array());
+        }
+        self.conn.send(NetworkMessage::FilterLoad(filter.into()))?;
+        let inv = Inventory::Unknown {
+            inv_type: MSG_FILTERED_BLOCK,
+            hash: block_hash.to_byte_array(),
+        };
+        self.conn.send(NetworkMessage::GetData(vec![inv]))?;
+        let result = self.conn.receive_until(|message| match message {
+            NetworkMessage::MerkleBlock(merkle_block)
+                if merkle_block.header.block_hash() == *block_hash =>
+            {
+                Ok(Some(merkle_block))
+            }
+            NetworkMessage::NotFound(invs) if invs.contains(&inv) => {
+                let msg = format!("merkle block {block_hash:#x} is not found");
+                Err(Error::other(msg))
+            }
+            _ => Ok(None),
+        });
+        self.conn.send(NetworkMessage::FilterClear)?;
+        let merkle_block = result?;
+
+        let mut matches = Vec::new();
+        let mut indexes = Vec::new();
+        let root = merkle_block
+            .txn
+            .extract_matches(&mut matches, &mut indexes)
+            .map_err(|err| {
+                let msg = format!("invalid merkle block {block_hash:#x} since {err}");
+                Error::other(msg)
+            })?;
+        if root != merkle_block.header.merkle_root {
+            let msg =
+                format!("invalid merkle block {block_hash:#x}, the merkle root is mismatched");
+            return Err(Error::other(msg));
+        }
+        if let Some(txid) = txids.iter().find(|txid| !matches.contains(txid)) {
+            let msg = format!("transaction {txid:#x} is not in block {block_hash:#x}");
+            return Err(Error::other(msg));
+        }
+        Ok(merkle_block)
+    }
+
+    /// Requests the proof of transactions in a block, which is a serialized
+    /// merkle block, the same format as the result of `gettxoutproof`.
+    pub fn get_txout_proof(&mut self, txids: &[Txid], block_hash: &BlockHash) -> Result<Vec<u8>> {
+        self.get_merkle_block(txids, block_hash)
+            .map(|merkle_block| encode::serialize(&merkle_block))
+    }
+
+    /// Syncs headers from the peer, and feeds them into the SPV service.
+    ///
+    /// Returns the changes of the SPV client, in order.
+    /// An update is returned for every batch of headers, which are at most 2000.
+    pub fn sync_headers<S: SpvStorage>(
+        &mut self,
+        service: &mut SpvService<S>,
+    ) -> Result<Vec<SpvChange>> {
+        let mut changes = Vec::new();
+        let mut last_hash = None;
+        loop {
+            // Continue from the last received header, since it may be not in the main chain.
+            let locator = last_hash
+                .into_iter()
+                .chain(block_locator(service)?)
+                .collect();
+            let headers = self.get_headers(locator)?;
+            log::debug!("received {} headers", headers.len());
+            let Some(last_header) = headers.last() else {
+                break;
+            };
+            last_hash = Some(last_header.block_hash());
+            let has_more = headers.len() >= MAX_HEADERS_COUNT;
+            if let Some(change) = service.submit_headers(&headers)? {
+                changes.push(change);
+            }
+            if !has_more {
+                break;
+            }
+        }
+        Ok(changes)
+    }
+}
+
+impl Connection {
+    fn handshake(&mut self, addr: SocketAddr) -> Result<VersionMessage> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs() as i64)
+            .unwrap_or_default();
+        let local_addr = self.writer.local_addr()?;
+        let version = VersionMessage {
+            version: PROTOCOL_VERSION,
+            ..VersionMessage::new(
+                ServiceFlags::NONE,
+                timestamp,
+                Address::new(&addr, ServiceFlags::NONE),
+                Address::new(&local_addr, ServiceFlags::NONE),
+                rand_nonce(),
+                USER_AGENT.to_owned(),
+                0,
+            )
+        };
+        self.send(NetworkMessage::Version(version))?;
+
+        let mut peer_version = None;
+        let mut verack = false;
+        while peer_version.is_none() || !verack {
+            match self.receive()? {
+                NetworkMessage::Version(version) => {
+                    log::debug!(
+                        "peer {addr}: version {}, user agent {}, height {}",
+                        version.version,
+                        version.user_agent,
+                        version.start_height
+                    );
+                    self.send(NetworkMessage::Verack)?;
+                    peer_version = Some(version);
+                }
+                NetworkMessage::Verack => verack = true,
+                message => log::trace!("peer {addr}: ignore message \"{}\"", message.cmd()),
+            }
+        }
+        Ok(peer_version.expect("checked"))
+    }
+
+    fn send(&mut self, payload: NetworkMessage) -> Result<()> {
+        log::trace!("send message \"{}\"", payload.cmd());
+        let message = RawNetworkMessage::new(self.magic, payload);
+        self.writer.write_all(&encode::serialize(&message))?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    // Receives a message, `ping` messages are replied automatically.
+    fn receive(&mut self) -> Result<NetworkMessage> {
+        loop {
+            let message = RawNetworkMessage::consensus_decode(&mut self.reader).map_err(|err| {
+                let msg = format!("failed to decode a message from the peer since {err}");
+                Error::other(msg)
+            })?;
+            if *message.magic() != self.magic {
+                let msg = format!("unexpected network magic {}", message.magic());
+                return Err(Error::other(msg));
+            }
+            log::trace!("receive message \"{}\"", message.cmd());
+            match message.payload() {
+                NetworkMessage::Ping(nonce) => {
+                    let nonce = *nonce;
+                    self.send(NetworkMessage::Pong(nonce))?;
+                }
+                payload => return Ok(payload.clone()),
+            }
+        }
+    }
+
+    // Receives messages until the expected one, other messages are ignored.
+    fn receive_until<T, F>(&mut self, mut expect: F) -> Result<T>
+    where
+        F: FnMut(NetworkMessage) -> Result<Option<T>>,
+    {
+        loop {
+            if let Some(result) = expect(self.receive()?)? {
+                return Ok(result);
+            }
+        }
+    }
+}
+
+// Builds a block locator from the tip of the service: the latest 10 headers,
+// then the step doubles, until the first header in the service.
+fn block_locator<S: SpvStorage>(service: &SpvService<S>) -> Result<Vec<BlockHash>> {
+    let min_height = service.min_height();
+    let mut height = service.max_height();
+    let mut step = 1;
+    let mut locator = Vec::new();
+    loop {
+        let header = service.header(height)?.ok_or_else(|| {
+            let msg = format!("header-{height} is not found in the service");
+            Error::other(msg)
+        })?;
+        locator.push(header.block_hash());
+        if height == min_height {
+            break;
+        }
+        if locator.len() >= 10 {
+            step *= 2;
+        }
+        height = height.saturating_sub(step).max(min_height);
+    }
+    Ok(locator)
+}
+
+fn rand_nonce() -> u64 {
+    use std::{
+        collections::hash_map::RandomState,
+        hash::{BuildHasher as _, Hasher as _},
+    };
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_nanos())
+            .unwrap_or_default(),
+    );
+    hasher.finish()
+}
+
+/// A BIP37 bloom filter.
+pub(crate) struct BloomFilter {
+    data: Vec<u8>,
+    hash_funcs: u32,
+    tweak: u32,
+}
+
+impl BloomFilter {
+    const MAX_FILTER_SIZE: usize = 36_000;
+    const MAX_HASH_FUNCS: u32 = 50;
+
+    pub(crate) fn new(elements: usize, false_positive_rate: f64, tweak: u32) -> Self {
+        let ln2 = std::f64::consts::LN_2;
+        let elements = elements.max(1) as f64;
+        let size = (-1.0 / (ln2 * ln2) * elements * false_positive_rate.ln() / 8.0) as usize;
+        let size = size.clamp(1, Self::MAX_FILTER_SIZE);
+        let hash_funcs = (size as f64 * 8.0 / elements * ln2) as u32;
+        let hash_funcs = hash_funcs.clamp(1, Self::MAX_HASH_FUNCS);
+        Self {
+            data: vec![0; size],
+            hash_funcs,
+            tweak,
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn from_filter_load(filter: &FilterLoad) -> Self {
+        Self {
+            data: filter.filter.clone(),
+            hash_funcs: filter.hash_funcs,
+            tweak: filter.tweak,
+        }
+    }
+
+    fn bit_index(&self, n: u32, data: &[u8]) -> usize {
+        let seed = n.wrapping_mul(0xFBA4_C795).wrapping_add(self.tweak);
+        murmur3_32(seed, data) as usize % (self.data.len() * 8)
+    }
+
+    pub(crate) fn insert(&mut self, data: &[u8]) {
+        for n in 0..self.hash_funcs {
+            let index = self.bit_index(n, data);
+            self.data[index >> 3] |= 1 << (7 & index);
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn contains(&self, data: &[u8]) -> bool {
+        !self.data.is_empty()
+            && (0..self.hash_funcs).all(|n| {
+                let index = self.bit_index(n, data);
+                self.data[index >> 3] & (1 << (7 & index)) != 0
+            })
+    }
+}
+
+impl From<BloomFilter> for FilterLoad {
+    fn from(filter: BloomFilter) -> Self {
+        Self {
+            filter: filter.data,
+            hash_funcs: filter.hash_funcs,
+            tweak: filter.tweak,
+            flags: BloomFlags::None,
+        }
+    }
+}
+
+pub(crate) fn murmur3_32(seed: u32, data: &[u8]) -> u32 {
+    const C1: u32 = 0xcc9e_2d51;
+    const C2: u32 = 0x1b87_3593;
+    let mut h1 = seed;
+    let mut chunks = data.chunks_exact(4);
+    for chunk in &mut chunks {
+        let mut k1 = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        k1 = k1.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+        h1 ^= k1;
+        h1 = h1.rotate_left(13).wrapping_mul(5).wrapping_add(0xe654_6b64);
+    }
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        let mut k1 = 0u32;
+        for (i, byte) in tail.iter().enumerate() {
+            k1 ^= u32::from(*byte) << (8 * i);
+        }
+        k1 = k1.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+        h1 ^= k1;
+    }
+    h1 ^= data.len() as u32;
+    h1 ^= h1 >> 16;
+    h1 = h1.wrapping_mul(0x85eb_ca6b);
+    h1 ^= h1 >> 13;
+    h1 = h1.wrapping_mul(0xc2b2_ae35);
+    h1 ^= h1 >> 16;
+    h1
+}
@@ -0,0 +1,209 @@
+//! A storage in files.
+
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{Read as _, Seek as _, SeekFrom, Write as _},
+    path::{Path, PathBuf},
+};
+
+use bitcoin::consensus::serialize;
+use ckb_bitcoin_spv_verifier::types::{core, packed, prelude::*};
+
+use super::{SpvState, SpvStorage};
+use crate::{
+    result::{Error, Result},
+    utilities,
+};
+
+const MMR_FILE: &str = "mmr.dat";
+const HEADERS_FILE: &str = "headers.dat";
+const STATE_FILE: &str = "state.dat";
+const STATE_TMP_FILE: &str = "state.dat.tmp";
+
+const HEADER_SIZE: u64 = 80;
+// The headers file starts with the height of the first header.
+const HEADERS_FILE_PREFIX_SIZE: u64 = 4;
+
+/// A storage which persists all data into files in a directory.
+///
+/// - MMR nodes and headers are stored in append-only files, as fixed-size records.
+/// - The state is replaced atomically, by renaming a temporary file.
+///
+/// Incomplete records, which are left by an interrupted write, are dropped
+/// when the storage is opened.
+pub struct FileStorage {
+    dir: PathBuf,
+    mmr_file: File,
+    nodes_count: u64,
+    headers_file: File,
+    base_height: u32,
+    headers_count: u64,
+}
+
+impl FileStorage {
+    /// Opens a storage in the directory, creates it if it doesn't exist.
+    pub fn open<P: AsRef<Path>>(dir: P) -> Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+        let open_file = |name: &str| {
+            OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(dir.join(name))
+        };
+
+        let node_size = packed::HeaderDigest::TOTAL_SIZE as u64;
+        let mmr_file = open_file(MMR_FILE)?;
+        let nodes_count = mmr_file.metadata()?.len() / node_size;
+        mmr_file.set_len(nodes_count * node_size)?;
+
+        let mut headers_file = open_file(HEADERS_FILE)?;
+        let headers_file_size = headers_file.metadata()?.len();
+        let (base_height, headers_count) = if headers_file_size < HEADERS_FILE_PREFIX_SIZE {
+            headers_file.set_len(0)?;
+            (0, 0)
+        } else {
+            let mut buf = [0u8; HEADERS_FILE_PREFIX_SIZE as usize];
+            headers_file.read_exact(&mut buf)?;
+            let headers_count = (headers_file_size - HEADERS_FILE_PREFIX_SIZE) / HEADER_SIZE;
+            headers_file.set_len(HEADERS_FILE_PREFIX_SIZE + headers_count * HEADER_SIZE)?;
+            (u32::from_le_bytes(buf), headers_count)
+        };
+
+        Ok(Self {
+            dir,
+            mmr_file,
+            nodes_count,
+            headers_file,
+            base_height,
+            headers_count,
+        })
+    }
+
+    /// The directory of the storage.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+}
+
+fn read_at(mut file: &File, offset: u64, buf: &mut [u8]) -> Result<()> {
+    file.seek(SeekFrom::Start(offset))?;
+    file.read_exact(buf)?;
+    Ok(())
+}
+
+fn write_at(mut file: &File, offset: u64, data: &[u8]) -> Result<()> {
+    file.set_len(offset)?;
+    file.seek(SeekFrom::Start(offset))?;
+    file.write_all(data)?;
+    file.sync_data()?;
+    Ok(())
+}
+
+impl SpvStorage for FileStorage {
+    fn mmr_node(&self, position: u64) -> Result<Option<packed::HeaderDigest>> {
+        if position >= self.nodes_count {
+            return Ok(None);
+        }
+        let node_size = packed::HeaderDigest::TOTAL_SIZE;
+        let mut buf = vec![0u8; node_size];
+        read_at(&self.mmr_file, position * node_size as u64, &mut buf)?;
+        let node = packed::HeaderDigest::from_slice(&buf).map_err(|err| {
+            let msg = format!("failed to decode the MMR node at position {position} since {err}");
+            Error::other(msg)
+        })?;
+        Ok(Some(node))
+    }
+
+    fn append_mmr_nodes(&mut self, position: u64, nodes: Vec<packed::HeaderDigest>) -> Result<()> {
+        if position > self.nodes_count {
+            let msg = format!(
+                "failed to append MMR nodes at position {position}, only {} nodes are stored",
+                self.nodes_count
+            );
+            return Err(Error::other(msg));
+        }
+        let data = nodes
+            .iter()
+            .flat_map(|node| node.as_slice().iter().copied())
+            .collect::<Vec<_>>();
+        let offset = position * packed::HeaderDigest::TOTAL_SIZE as u64;
+        write_at(&self.mmr_file, offset, &data)?;
+        self.nodes_count = position + nodes.len() as u64;
+        Ok(())
+    }
+
+    fn header(&self, height: u32) -> Result<Option<core::Header>> {
+        let index = match height.checked_sub(self.base_height) {
+            Some(index) if u64::from(index) < self.headers_count => u64::from(index),
+            _ => return Ok(None),
+        };
+        let mut buf = [0u8; HEADER_SIZE as usize];
+        read_at(
+            &self.headers_file,
+            HEADERS_FILE_PREFIX_SIZE + index * HEADER_SIZE,
+            &mut buf,
+        )?;
+        utilities::decode_from_slice(&buf).map(Some)
+    }
+
+    fn append_headers(&mut self, height: u32, headers: &[core::Header]) -> Result<()> {
+        let mut data = Vec::with_capacity(headers.len() * HEADER_SIZE as usize + 4);
+        let offset = if self.headers_count == 0 {
+            data.extend_from_slice(&height.to_le_bytes());
+            self.base_height = height;
+            0
+        } else {
+            let end_height = u64::from(self.base_height) + self.headers_count;
+            if height < self.base_height || u64::from(height) > end_height {
+                let msg = format!(
+                    "failed to append headers at height {height}, \
+                    stored headers are in [{}, {end_height})",
+                    self.base_height
+                );
+                return Err(Error::other(msg));
+            }
+            HEADERS_FILE_PREFIX_SIZE + u64::from(height - self.base_height) * HEADER_SIZE
+        };
+        for header in headers {
+            data.extend_from_slice(&serialize(header));
+        }
+        write_at(&self.headers_file, offset, &data)?;
+        self.headers_count = u64::from(height - self.base_height) + headers.len() as u64;
+        Ok(())
+    }
+
+    fn state(&self) -> Result<Option<SpvState>> {
+        let data = match fs::read(self.dir.join(STATE_FILE)) {
+            Ok(data) => data,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let state = data
+            .split_first()
+            .and_then(|(flags, client)| {
+                packed::SpvClient::from_slice(client)
+                    .ok()
+                    .map(|client| SpvState {
+                        flags: *flags,
+                        client: client.unpack(),
+                    })
+            })
+            .ok_or_else(|| Error::other("failed to decode the state file"))?;
+        Ok(Some(state))
+    }
+
+    fn save_state(&mut self, state: &SpvState) -> Result<()> {
+        let tmp_path = self.dir.join(STATE_TMP_FILE);
+        {
+            let mut file = File::create(&tmp_path)?;
+            file.write_all(&[state.flags])?;
+            file.write_all(state.client.pack().as_slice())?;
+            file.sync_all()?;
+        }
+        fs::rename(tmp_path, self.dir.join(STATE_FILE))?;
+        Ok(())
+    }
+}
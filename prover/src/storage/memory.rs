@@ -0,0 +1,70 @@
+//! A storage in memory.
+
+use ckb_bitcoin_spv_verifier::types::{core, packed};
+
+use super::{SpvState, SpvStorage};
+use crate::result::{Error, Result};
+
+/// A storage which keeps all data in memory, nothing is persisted.
+#[derive(Default)]
+pub struct MemStorage {
+    nodes: Vec<packed::HeaderDigest>,
+    base_height: u32,
+    headers: Vec<core::Header>,
+    state: Option<SpvState>,
+}
+
+impl SpvStorage for MemStorage {
+    fn mmr_node(&self, position: u64) -> Result<Option<packed::HeaderDigest>> {
+        Ok(self.nodes.get(position as usize).cloned())
+    }
+
+    fn append_mmr_nodes(&mut self, position: u64, nodes: Vec<packed::HeaderDigest>) -> Result<()> {
+        let position = position as usize;
+        if position > self.nodes.len() {
+            let msg = format!(
+                "failed to append MMR nodes at position {position}, only {} nodes are stored",
+                self.nodes.len()
+            );
+            return Err(Error::other(msg));
+        }
+        self.nodes.truncate(position);
+        self.nodes.extend(nodes);
+        Ok(())
+    }
+
+    fn header(&self, height: u32) -> Result<Option<core::Header>> {
+        let header = height
+            .checked_sub(self.base_height)
+            .and_then(|index| self.headers.get(index as usize))
+            .cloned();
+        Ok(header)
+    }
+
+    fn append_headers(&mut self, height: u32, headers: &[core::Header]) -> Result<()> {
+        if self.headers.is_empty() {
+            self.base_height = height;
+        }
+        let end_height = self.base_height + self.headers.len() as u32;
+        if height < self.base_height || height > end_height {
+            let msg = format!(
+                "failed to append headers at height {height}, \
+                stored headers are in [{}, {end_height})",
+                self.base_height
+            );
+            return Err(Error::other(msg));
+        }
+        self.headers.truncate((height - self.base_height) as usize);
+        self.headers.extend_from_slice(headers);
+        Ok(())
+    }
+
+    fn state(&self) -> Result<Option<SpvState>> {
+        Ok(self.state.clone())
+    }
+
+    fn save_state(&mut self, state: &SpvState) -> Result<()> {
+        self.state = Some(state.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+//! The storages for the SPV service.
+//!
+//! A storage keeps 3 kinds of data:
+//! - The MMR nodes, indexed by their positions.
+//! - The headers, indexed by their heights.
+//! - The state of the service, which includes the chain flags and the tip client.
+//!
+//! Both MMR nodes and headers are append-only, but an append could start
+//! before the end, then all data after the start will be replaced.
+//! This happens after a rollback.
+//!
+//! The state is the only source of truth: data beyond the extent of the tip
+//! client are ignored. So it should be saved after the MMR nodes and the headers.
+
+use ckb_bitcoin_spv_verifier::{
+    types::{core, packed},
+    utilities::mmr,
+};
+
+use crate::result::{Error, Result};
+
+mod file;
+mod memory;
+
+pub use file::FileStorage;
+pub use memory::MemStorage;
+
+/// The state of an SPV service.
+#[derive(Clone)]
+pub struct SpvState {
+    /// The bit flags, which are the same as the flags in `SpvTypeArgs`.
+    pub flags: u8,
+    /// The tip SPV client.
+    pub client: core::SpvClient,
+}
+
+/// The storage for MMR nodes, headers and the state of an SPV service.
+pub trait SpvStorage {
+    /// Gets an MMR node by its position.
+    fn mmr_node(&self, position: u64) -> Result<Option<packed::HeaderDigest>>;
+    /// Appends MMR nodes from the position, all nodes after it are dropped.
+    fn append_mmr_nodes(&mut self, position: u64, nodes: Vec<packed::HeaderDigest>) -> Result<()>;
+
+    /// Gets a header by its height.
+    fn header(&self, height: u32) -> Result<Option<core::Header>>;
+    /// Appends headers from the height, all headers after it are dropped.
+    fn append_headers(&mut self, height: u32, headers: &[core::Header]) -> Result<()>;
+
+    /// Loads the state, returns `None` if the storage is empty.
+    fn state(&self) -> Result<Option<SpvState>>;
+    /// Saves the state.
+    fn save_state(&mut self, state: &SpvState) -> Result<()>;
+}
+
+/// An adapter to use an [`SpvStorage`] as the store of an MMR.
+pub(crate) struct MmrStore<T>(pub(crate) T);
+
+impl<S: SpvStorage> mmr::lib::MMRStoreReadOps<packed::HeaderDigest> for MmrStore<&S> {
+    fn get_elem(&self, pos: u64) -> mmr::lib::Result<Option<packed::HeaderDigest>> {
+        self.0.mmr_node(pos).map_err(into_mmr_error)
+    }
+}
+
+impl<S: SpvStorage> mmr::lib::MMRStoreReadOps<packed::HeaderDigest> for MmrStore<&mut S> {
+    fn get_elem(&self, pos: u64) -> mmr::lib::Result<Option<packed::HeaderDigest>> {
+        self.0.mmr_node(pos).map_err(into_mmr_error)
+    }
+}
+
+impl<S: SpvStorage> mmr::lib::MMRStoreWriteOps<packed::HeaderDigest> for MmrStore<&mut S> {
+    fn append(&mut self, pos: u64, elems: Vec<packed::HeaderDigest>) -> mmr::lib::Result<()> {
+        self.0.append_mmr_nodes(pos, elems).map_err(into_mmr_error)
+    }
+}
+
+fn into_mmr_error(err: Error) -> mmr::lib::Error {
+    mmr::lib::Error::StoreError(err.to_string())
+}
@@ -1,6 +1,10 @@
 use std::{fs::File, io::Read as _};
 
-use bitcoin::merkle_tree::MerkleBlock;
+use bitcoin::{
+    absolute::LockTime, blockdata::constants::genesis_block, merkle_tree::MerkleBlock,
+    transaction::Version, Block, Network, Transaction, TxIn, TxOut,
+};
+use ckb_bitcoin_spv_verifier::utilities::bitcoin::calculate_merkle_root;
 
 use crate::{tests, utilities, BlockProofGenerator};
 
@@ -67,3 +71,42 @@ fn test_generate_txoutproof(case_txoutproofs: &str, case_blocks: &str) {
 fn generate_txoutproof_case_1() {
     test_generate_txoutproof("case-0831332", "case-0831328_0831335");
 }
+
+pub(crate) fn build_block(txs_count: u32) -> Block {
+    let txdata = (0..txs_count)
+        .map(|i| Transaction {
+            version: Version::TWO,
+            lock_time: LockTime::from_consensus(i),
+            input: vec![TxIn::default()],
+            output: vec![TxOut::NULL],
+        })
+        .collect::<Vec<_>>();
+    let mut block = Block {
+        header: genesis_block(Network::Regtest).header,
+        txdata,
+    };
+    block.header.merkle_root = block.compute_merkle_root().unwrap();
+    block
+}
+
+#[test]
+fn generate_merkle_branch() {
+    tests::setup();
+
+    for txs_count in 1..=9 {
+        let block = build_block(txs_count);
+        let bpg = BlockProofGenerator::new(block.clone());
+        for index in 0..txs_count {
+            let txid = block.txdata[index as usize].txid().to_raw_hash();
+            let branch = bpg.generate_merkle_branch(index as usize).unwrap();
+            let root = calculate_merkle_root(txid, index, branch).unwrap();
+            assert_eq!(root, block.header.merkle_root.to_raw_hash());
+        }
+        assert!(bpg.generate_merkle_branch(txs_count as usize).is_err());
+
+        let proof = bpg.generate_coinbase_proof().unwrap();
+        let coinbase = proof.as_reader().decode_transaction().unwrap();
+        let root = proof.as_reader().calc_merkle_root(&coinbase);
+        assert_eq!(root, block.header.merkle_root.to_raw_hash());
+    }
+}
@@ -0,0 +1,320 @@
+use std::{
+    io::{BufRead as _, BufReader, Write as _},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use bitcoin::{
+    blockdata::constants::genesis_block, consensus::serialize, hashes::Hash as _,
+    hex::DisplayHex as _, Block, Network, Txid,
+};
+use ckb_bitcoin_spv_verifier::{
+    constants::*,
+    error::VerifyTxError,
+    types::{packed, prelude::*},
+};
+use serde_json::{json, Value};
+
+use crate::{
+    source::{ElectrumClient, HeaderSource as _},
+    storage::MemStorage,
+    tests::{self, block::build_block, proof::regtest_service_with},
+    BlockProofGenerator, SpvChange, SpvService,
+};
+
+type Chain = Arc<Mutex<Vec<Block>>>;
+
+// Starts a mock Electrum server, which serves the blocks in the chain.
+fn start_mock_server(chain: Chain) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let chain = Arc::clone(&chain);
+            thread::spawn(move || serve_client(stream.unwrap(), &chain));
+        }
+    });
+    addr
+}
+
+fn serve_client(stream: TcpStream, chain: &Chain) {
+    let reader = BufReader::new(stream.try_clone().unwrap());
+    let mut writer = stream;
+    let mut send = |message: Value| {
+        writer.write_all(format!("{message}\n").as_bytes()).unwrap();
+    };
+    for line in reader.lines() {
+        let request: Value = serde_json::from_str(&line.unwrap()).unwrap();
+        let id = request["id"].clone();
+        let params = &request["params"];
+        let blocks = chain.lock().unwrap().clone();
+        let tip_height = blocks.len() - 1;
+        let result = match request["method"].as_str().unwrap() {
+            "server.version" => {
+                assert_eq!(params[1], "1.4");
+                Ok(json!(["MockElectrum 0.1", "1.4"]))
+            }
+            "blockchain.headers.subscribe" => {
+                let tip = serialize(&blocks[tip_height].header).to_lower_hex_string();
+                let result = json!({"height": tip_height, "hex": tip});
+                send(json!({"jsonrpc": "2.0", "id": id, "result": result}));
+                // A notification, which should be skipped by the client.
+                send(json!({
+                    "jsonrpc": "2.0",
+                    "method": "blockchain.headers.subscribe",
+                    "params": [result]
+                }));
+                continue;
+            }
+            "blockchain.block.header" => {
+                let height = params[0].as_u64().unwrap() as usize;
+                blocks
+                    .get(height)
+                    .map(|block| json!(serialize(&block.header).to_lower_hex_string()))
+                    .ok_or_else(|| format!("height {height} out of range"))
+            }
+            "blockchain.block.headers" => {
+                let start = params[0].as_u64().unwrap() as usize;
+                let count = params[1].as_u64().unwrap().min(2016) as usize;
+                let headers = blocks
+                    .iter()
+                    .skip(start)
+                    .take(count)
+                    .flat_map(|block| serialize(&block.header))
+                    .collect::<Vec<_>>();
+                let hex = headers.to_lower_hex_string();
+                Ok(json!({"count": headers.len() / 80, "hex": hex, "max": 2016}))
+            }
+            "blockchain.transaction.get_merkle" => {
+                let txid: Txid = params[0].as_str().unwrap().parse().unwrap();
+                let height = params[1].as_u64().unwrap() as usize;
+                blocks
+                    .get(height)
+                    .and_then(|block| {
+                        let index = block.txdata.iter().position(|tx| tx.txid() == txid)?;
+                        let proof = BlockProofGenerator::new(block.clone())
+                            .generate_transaction_branch_proof(index, height as u32, Vec::new())
+                            .unwrap();
+                        let merkle = proof
+                            .merkle_branch()
+                            .into_iter()
+                            .map(|hash| format!("{:x}", hash.unpack()))
+                            .collect::<Vec<_>>();
+                        Some(json!({"block_height": height, "merkle": merkle, "pos": index}))
+                    })
+                    .ok_or_else(|| format!("tx {txid} not in block at height {height}"))
+            }
+            "blockchain.transaction.id_from_pos" => {
+                let height = params[0].as_u64().unwrap() as usize;
+                let pos = params[1].as_u64().unwrap() as usize;
+                assert_eq!(params[2], true);
+                blocks
+                    .get(height)
+                    .filter(|block| pos < block.txdata.len())
+                    .map(|block| {
+                        let proof = BlockProofGenerator::new(block.clone())
+                            .generate_transaction_branch_proof(pos, height as u32, Vec::new())
+                            .unwrap();
+                        let merkle = proof
+                            .merkle_branch()
+                            .into_iter()
+                            .map(|hash| format!("{:x}", hash.unpack()))
+                            .collect::<Vec<_>>();
+                        let tx_hash = block.txdata[pos].txid().to_string();
+                        json!({"tx_hash": tx_hash, "merkle": merkle})
+                    })
+                    .ok_or_else(|| format!("no tx at position {pos} in block at height {height}"))
+            }
+            "blockchain.transaction.get" => {
+                let txid: Txid = params[0].as_str().unwrap().parse().unwrap();
+                blocks
+                    .iter()
+                    .flat_map(|block| block.txdata.iter())
+                    .find(|tx| tx.txid() == txid)
+                    .map(|tx| json!(serialize(tx).to_lower_hex_string()))
+                    .ok_or_else(|| format!("tx {txid} not found"))
+            }
+            method => panic!("unexpected method {method}"),
+        };
+        let response = match result {
+            Ok(result) => json!({"jsonrpc": "2.0", "id": id, "result": result}),
+            Err(message) => {
+                let error = json!({"code": 1, "message": message});
+                json!({"jsonrpc": "2.0", "id": id, "error": error})
+            }
+        };
+        send(response);
+    }
+}
+
+#[test]
+fn electrum_client() {
+    tests::setup();
+
+    let flags = FLAG_CHAIN_TYPE_REGTEST;
+    let genesis = genesis_block(Network::Regtest).header;
+    let (_, blocks) = regtest_service_with(2100, |height| build_block(1 + (height % 8) as u32));
+    let addr = start_mock_server(Arc::new(Mutex::new(blocks.clone())));
+    let client = ElectrumClient::connect(addr).unwrap();
+    assert_eq!(client.server_version(), "MockElectrum 0.1");
+
+    // Headers, by heights.
+    assert_eq!(client.tip_height().unwrap(), 2100);
+    assert_eq!(client.block_hash(7).unwrap(), blocks[7].block_hash());
+    assert_eq!(
+        client.header(&blocks[7].block_hash()).unwrap(),
+        blocks[7].header
+    );
+    assert!(client.header(&blocks[8].block_hash()).is_err());
+    let headers = client.headers(10, 3000).unwrap();
+    assert_eq!(headers.len(), 2091);
+    assert_eq!(headers[0], blocks[10].header);
+    assert_eq!(headers[2090], blocks[2100].header);
+    assert!(client.get_header(2101).is_err());
+
+    // Sync headers, at most 2016 headers in one batch.
+    let mut service = SpvService::bootstrap(MemStorage::default(), 0, genesis, flags).unwrap();
+    let mut expected = SpvService::bootstrap(MemStorage::default(), 0, genesis, flags).unwrap();
+    let changes = client.sync_headers(&mut service).unwrap();
+    assert_eq!(changes.len(), 2);
+    for (change, range) in changes.into_iter().zip([1..2017, 2017..2101]) {
+        let SpvChange::Update(update) = change else {
+            panic!("an update is expected");
+        };
+        let old_client: packed::SpvClient = expected.tip_client().pack();
+        let headers = blocks[range].iter().map(|block| block.header).collect();
+        let expected_update = expected.update(headers).unwrap();
+        assert_eq!(update.as_slice(), expected_update.as_slice());
+        let new_client: packed::SpvClient = expected.tip_client().pack();
+        old_client
+            .verify_new_client(&new_client, update, flags)
+            .map_err(|err| err as i8)
+            .unwrap();
+    }
+    assert_eq!(
+        service.tip_client().pack().as_slice(),
+        expected.tip_client().pack().as_slice()
+    );
+    assert!(client.sync_headers(&mut service).unwrap().is_empty());
+
+    // Transaction proofs, from Merkle branches.
+    let tip_client: packed::SpvClient = service.tip_client().pack();
+    let height = 2095u32;
+    let block = &blocks[height as usize];
+    assert_eq!(block.txdata.len(), 8);
+    for (tx_index, tx) in block.txdata.iter().enumerate() {
+        let txid = tx.txid();
+        let proof = client
+            .generate_transaction_branch_proof(&service, &txid, height)
+            .unwrap();
+        assert_eq!(Unpack::<u32>::unpack(&proof.tx_index()), tx_index as u32);
+        let result = tip_client
+            .verify_transaction_with_branch(txid.as_ref(), proof.as_reader(), 5)
+            .map_err(|err| err as i8);
+        assert_eq!(result, Ok(block.header));
+        let result = tip_client
+            .verify_transaction_with_branch(txid.as_ref(), proof.as_reader(), 6)
+            .map_err(|err| err as i8);
+        assert_eq!(result, Err(VerifyTxError::TransactionUnconfirmed as i8));
+    }
+
+    // Transaction proofs in the hardened mode, with the coinbase proof.
+    let coinbase_proof = client.generate_coinbase_proof(&service, height).unwrap();
+    for tx in &block.txdata {
+        let proof = client
+            .generate_transaction_branch_proof(&service, &tx.txid(), height)
+            .unwrap();
+        let result = tip_client
+            .verify_transaction_with_branch_hardened(
+                &serialize(tx),
+                proof.as_reader(),
+                coinbase_proof.as_reader(),
+                5,
+            )
+            .map(|(header, _)| header)
+            .map_err(|err| err as i8);
+        assert_eq!(result, Ok(block.header));
+    }
+    // The coinbase proof of another block.
+    let coinbase_proof = client.generate_coinbase_proof(&service, 2094).unwrap();
+    let tx = &block.txdata[1];
+    let proof = client
+        .generate_transaction_branch_proof(&service, &tx.txid(), height)
+        .unwrap();
+    let result = tip_client
+        .verify_transaction_with_branch_hardened(
+            &serialize(tx),
+            proof.as_reader(),
+            coinbase_proof.as_reader(),
+            5,
+        )
+        .map(|(header, _)| header)
+        .map_err(|err| err as i8);
+    assert_eq!(result, Err(VerifyTxError::CoinbaseProof as i8));
+    assert!(client.generate_coinbase_proof(&service, 2101).is_err());
+
+    // The transaction is not in the block, or the block is not in the service.
+    assert!(client
+        .generate_transaction_branch_proof(&service, &Txid::all_zeros(), height)
+        .is_err());
+    let txid = block.txdata[1].txid();
+    assert!(client
+        .generate_transaction_branch_proof(&service, &txid, 2101)
+        .is_err());
+}
+
+#[test]
+fn electrum_client_reorg() {
+    tests::setup();
+
+    let flags = FLAG_CHAIN_TYPE_REGTEST;
+    let genesis = genesis_block(Network::Regtest).header;
+    let (_, blocks) = regtest_service_with(30, |height| build_block(1 + (height % 4) as u32));
+    let chain = Arc::new(Mutex::new(blocks.clone()));
+    let addr = start_mock_server(Arc::clone(&chain));
+    let client = ElectrumClient::connect(addr).unwrap();
+
+    let mut service = SpvService::bootstrap(MemStorage::default(), 0, genesis, flags).unwrap();
+    assert_eq!(client.sync_headers(&mut service).unwrap().len(), 1);
+
+    // The server switches to a branch which has more chain work.
+    let fork_height = 25;
+    let mut branch = blocks[..=fork_height].to_vec();
+    for _ in 0..7 {
+        let prev = branch.last().unwrap().header;
+        let mut block = build_block(2);
+        block.header.prev_blockhash = prev.block_hash();
+        block.header.time = prev.time + 601;
+        block.header.bits = prev.bits;
+        while block.header.validate_pow(block.header.target()).is_err() {
+            block.header.nonce += 1;
+        }
+        branch.push(block);
+    }
+    *chain.lock().unwrap() = branch.clone();
+
+    let old_client: packed::SpvClient = service.tip_client().pack();
+    let mut changes = client.sync_headers(&mut service).unwrap();
+    assert_eq!(changes.len(), 1);
+    let Some(SpvChange::Reorg(reorg)) = changes.pop() else {
+        panic!("a reorg is expected");
+    };
+    let new_client: packed::SpvClient = service.tip_client().pack();
+    old_client
+        .verify_reorg_client(&new_client, reorg, flags)
+        .map_err(|err| err as i8)
+        .unwrap();
+    assert_eq!(service.max_height(), 32);
+    assert_eq!(service.header(32).unwrap().unwrap(), branch[32].header);
+
+    // The proof is generated against the new branch.
+    let txid = branch[30].txdata[1].txid();
+    let proof = client
+        .generate_transaction_branch_proof(&service, &txid, 30)
+        .unwrap();
+    let tip_client: packed::SpvClient = service.tip_client().pack();
+    assert!(tip_client
+        .verify_transaction_with_branch(txid.as_ref(), proof.as_reader(), 2)
+        .is_ok());
+}
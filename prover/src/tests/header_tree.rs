@@ -0,0 +1,230 @@
+use bitcoin::{
+    blockdata::constants::{genesis_block, DIFFCHANGE_INTERVAL},
+    Network,
+};
+use ckb_bitcoin_spv_verifier::{
+    constants::*,
+    types::{core, packed, prelude::*},
+};
+
+use crate::{
+    storage::{FileStorage, MemStorage},
+    tests::{
+        self,
+        regtest::{mine_header, mine_headers},
+    },
+    HeaderTree, SpvChange, SpvService,
+};
+
+fn build_client(headers: &[core::Header]) -> packed::SpvClient {
+    let genesis = genesis_block(Network::Regtest).header;
+    let mut service =
+        SpvService::bootstrap(MemStorage::default(), 0, genesis, FLAG_CHAIN_TYPE_REGTEST).unwrap();
+    service.update(headers.to_vec()).unwrap();
+    service.tip_client().pack()
+}
+
+#[test]
+fn header_tree_best_branch() {
+    tests::setup();
+
+    let genesis = genesis_block(Network::Regtest).header;
+    let main_headers = mine_headers(&genesis, 5, 0);
+    let mut tree = HeaderTree::new(0, &genesis);
+    for header in &main_headers {
+        tree.push_main(header);
+    }
+    assert_eq!(tree.tip_height(), 5);
+    assert_eq!(tree.tip_hash(), main_headers[4].block_hash());
+    assert!(tree.best_branch().is_none());
+
+    // The branch has the same chain work as the main chain.
+    let branch = mine_headers(&main_headers[2], 2, 1);
+    for header in &branch {
+        tree.insert(header).unwrap();
+    }
+    assert!(tree.best_branch().is_none());
+    let node = tree.branch_node(&branch[1].block_hash()).unwrap();
+    assert_eq!(node.height, 5);
+
+    let extra = mine_headers(&branch[1], 1, 1);
+    tree.insert(&extra[0]).unwrap();
+    let (fork_height, headers) = tree.best_branch().unwrap();
+    assert_eq!(fork_height, 3);
+    assert_eq!(headers, [branch, extra.clone()].concat());
+
+    let orphan = mine_headers(&extra[0], 2, 0);
+    assert!(tree.insert(&orphan[1]).is_err());
+
+    // Drop the fork point, then the branch is dropped too.
+    tree.rollback(2);
+    assert!(tree.best_branch().is_none());
+    assert!(!tree.contains(&extra[0].block_hash()));
+    assert!(!tree.contains(&main_headers[2].block_hash()));
+    assert_eq!(tree.main_height(&main_headers[1].block_hash()), Some(2));
+}
+
+#[test]
+fn submit_headers() {
+    tests::setup();
+
+    let flags = FLAG_CHAIN_TYPE_REGTEST;
+    let genesis = genesis_block(Network::Regtest).header;
+    let dir = tests::TempDir::new();
+    let storage = FileStorage::open(dir.path()).unwrap();
+    let mut service = SpvService::bootstrap(storage, 0, genesis, flags).unwrap();
+
+    // Extend the tip.
+    let main_headers = mine_headers(&genesis, 30, 0);
+    let old_client: packed::SpvClient = service.tip_client().pack();
+    let change = service.submit_headers(&main_headers).unwrap();
+    let Some(SpvChange::Update(update)) = change else {
+        panic!("an update is expected");
+    };
+    let new_client: packed::SpvClient = service.tip_client().pack();
+    old_client
+        .verify_new_client(&new_client, update, flags)
+        .map_err(|err| err as i8)
+        .unwrap();
+    assert!(service.submit_headers(&main_headers).unwrap().is_none());
+
+    // A branch which has less chain work.
+    let fork_height = 25;
+    let branch = mine_headers(&main_headers[fork_height - 1], 7, 1);
+    assert!(service.submit_headers(&branch[..5]).unwrap().is_none());
+    assert_eq!(
+        service.tip_client().pack().as_slice(),
+        new_client.as_slice()
+    );
+
+    // The branch has more chain work, then the headers are reorganized.
+    let old_client = new_client;
+    let change = service.submit_headers(&branch[5..]).unwrap();
+    let Some(SpvChange::Reorg(reorg)) = change else {
+        panic!("a reorg is expected");
+    };
+    let new_client: packed::SpvClient = service.tip_client().pack();
+    old_client
+        .verify_reorg_client(&new_client, reorg, flags)
+        .map_err(|err| err as i8)
+        .unwrap();
+    let expected = build_client(&[&main_headers[..fork_height], &branch[..]].concat());
+    assert_eq!(new_client.as_slice(), expected.as_slice());
+
+    // The old main chain is kept as a branch, so it could be back.
+    let old_client = new_client;
+    let extra = mine_headers(&main_headers[29], 3, 0);
+    let change = service.submit_headers(&extra).unwrap();
+    let Some(SpvChange::Reorg(reorg)) = change else {
+        panic!("a reorg is expected");
+    };
+    let new_client: packed::SpvClient = service.tip_client().pack();
+    old_client
+        .verify_reorg_client(&new_client, reorg, flags)
+        .map_err(|err| err as i8)
+        .unwrap();
+    let expected = build_client(&[&main_headers[..], &extra[..]].concat());
+    assert_eq!(new_client.as_slice(), expected.as_slice());
+
+    // Headers which have an unknown parent or invalid proof of work are rejected.
+    let orphans = mine_headers(&extra[2], 2, 0);
+    assert!(service.submit_headers(&orphans[1..]).is_err());
+    let mut invalid = orphans[0];
+    invalid.nonce = invalid.nonce.wrapping_add(1);
+    while invalid.validate_pow(invalid.target()).is_ok() {
+        invalid.nonce = invalid.nonce.wrapping_add(1);
+    }
+    assert!(service.submit_headers(&[invalid]).is_err());
+
+    // Branches are not persisted, but the main chain is.
+    let mut service = SpvService::resume(service.into_storage()).unwrap();
+    assert!(service
+        .header_tree()
+        .branch_node(&branch[6].block_hash())
+        .is_none());
+    let change = service.submit_headers(&orphans).unwrap();
+    assert!(matches!(change, Some(SpvChange::Update(_))));
+}
+
+#[test]
+fn submit_invalid_headers() {
+    tests::setup();
+
+    let flags = FLAG_CHAIN_TYPE_REGTEST;
+    let genesis = genesis_block(Network::Regtest).header;
+    let mut service = SpvService::bootstrap(MemStorage::default(), 0, genesis, flags).unwrap();
+    let main_headers = mine_headers(&genesis, 30, 0);
+    service.submit_headers(&main_headers).unwrap();
+    let tip_client: packed::SpvClient = service.tip_client().pack();
+
+    // The time is not greater than the median time past.
+    let tip = main_headers[29];
+    let too_early = mine_header(tip.block_hash(), main_headers[24].time, tip.bits);
+    // The difficulty of regtest is never changed.
+    let bad_bits = mine_header(
+        tip.block_hash(),
+        tip.time + 600,
+        core::CompactTarget::from_consensus(0x2000ffff),
+    );
+    // A branch which has more chain work, but the last header is invalid.
+    let mut branch = mine_headers(&main_headers[24], 5, 1);
+    let last = branch[4];
+    branch.push(mine_header(
+        last.block_hash(),
+        main_headers[20].time,
+        last.bits,
+    ));
+
+    for headers in [vec![too_early], vec![bad_bits], branch.clone()] {
+        assert!(service.submit_headers(&headers).is_err());
+        assert_eq!(
+            service.tip_client().pack().as_slice(),
+            tip_client.as_slice()
+        );
+        for header in &headers {
+            assert!(!service.header_tree().contains(&header.block_hash()));
+        }
+    }
+
+    // Valid headers are still accepted.
+    let change = service.submit_headers(&branch[..5]).unwrap();
+    assert!(change.is_none());
+    let extra = mine_headers(&branch[4], 1, 0);
+    let change = service.submit_headers(&extra).unwrap();
+    let Some(SpvChange::Reorg(reorg)) = change else {
+        panic!("a reorg is expected");
+    };
+    let new_client: packed::SpvClient = service.tip_client().pack();
+    tip_client
+        .verify_reorg_client(&new_client, reorg, flags)
+        .map_err(|err| err as i8)
+        .unwrap();
+}
+
+#[test]
+fn submit_headers_across_difficulty_adjustment() {
+    tests::setup();
+
+    let flags = FLAG_CHAIN_TYPE_REGTEST;
+    let genesis = genesis_block(Network::Regtest).header;
+    let main_headers = mine_headers(&genesis, DIFFCHANGE_INTERVAL + 4, 0);
+    let mut service = SpvService::bootstrap(MemStorage::default(), 0, genesis, flags).unwrap();
+    service.submit_headers(&main_headers).unwrap();
+
+    // The fork point is in the previous difficulty adjustment period.
+    let fork_height = DIFFCHANGE_INTERVAL - 2;
+    let branch = mine_headers(&main_headers[fork_height as usize - 1], 8, 1);
+    let old_client: packed::SpvClient = service.tip_client().pack();
+    let change = service.submit_headers(&branch).unwrap();
+    let Some(SpvChange::Reorg(reorg)) = change else {
+        panic!("a reorg is expected");
+    };
+    assert_eq!(reorg.fork_period_headers().len(), 1);
+    let new_client: packed::SpvClient = service.tip_client().pack();
+    old_client
+        .verify_reorg_client(&new_client, reorg, flags)
+        .map_err(|err| err as i8)
+        .unwrap();
+    let expected = build_client(&[&main_headers[..fork_height as usize], &branch[..]].concat());
+    assert_eq!(new_client.as_slice(), expected.as_slice());
+}
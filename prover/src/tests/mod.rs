@@ -1,8 +1,24 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
 use env_logger::{Builder, Target};
 use log::LevelFilter;
 
-mod block;
+pub(crate) mod block;
+mod electrum;
+mod header_tree;
+mod p2p;
+mod proof;
+mod regtest;
 mod service;
+mod signet;
+mod source;
+mod storage;
+mod transition;
 
 pub(crate) mod data;
 
@@ -14,3 +30,31 @@ pub(crate) fn setup() {
         .try_init();
     println!();
 }
+
+/// A temporary directory, which is removed when it's dropped.
+pub(crate) struct TempDir(PathBuf);
+
+impl TempDir {
+    pub(crate) fn new() -> Self {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        let name = format!(
+            "ckb-bitcoin-spv-prover-{}-{}",
+            process::id(),
+            COUNT.fetch_add(1, Ordering::SeqCst)
+        );
+        let path = env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
@@ -0,0 +1,294 @@
+use std::{
+    io::{BufReader, Write as _},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use bitcoin::{
+    blockdata::constants::genesis_block,
+    consensus::{encode, Decodable as _},
+    hashes::Hash as _,
+    p2p::{
+        address::Address,
+        message::{NetworkMessage, RawNetworkMessage},
+        message_blockdata::Inventory,
+        message_network::VersionMessage,
+        Magic, ServiceFlags,
+    },
+    Block, MerkleBlock, Network,
+};
+use ckb_bitcoin_spv_verifier::{
+    constants::*,
+    types::{packed, prelude::*},
+};
+
+use crate::{
+    source::{murmur3_32, BloomFilter, P2pClient},
+    storage::MemStorage,
+    tests::{self, block::build_block, proof::regtest_service_with},
+    SpvChange, SpvService,
+};
+
+type Chain = Arc<Mutex<Vec<Block>>>;
+
+// Starts a fake peer, which serves the blocks in the chain.
+fn start_fake_peer(chain: Chain) -> SocketAddr {
+    start_fake_peer_with_services(chain, ServiceFlags::NETWORK | ServiceFlags::BLOOM)
+}
+
+// Starts a fake peer, which advertises the services.
+//
+// As Bitcoin Core, the peer disconnects if a bloom filter is loaded but it
+// doesn't advertise `NODE_BLOOM`.
+fn start_fake_peer_with_services(chain: Chain, services: ServiceFlags) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let chain = Arc::clone(&chain);
+            thread::spawn(move || serve_peer(stream.unwrap(), &chain, services));
+        }
+    });
+    addr
+}
+
+fn serve_peer(stream: TcpStream, chain: &Chain, services: ServiceFlags) {
+    let magic = Magic::REGTEST;
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut writer = stream;
+    let mut send = |payload| {
+        let message = RawNetworkMessage::new(magic, payload);
+        writer.write_all(&encode::serialize(&message)).unwrap();
+    };
+    let mut filter = None;
+    while let Ok(message) = RawNetworkMessage::consensus_decode(&mut reader) {
+        assert_eq!(*message.magic(), magic);
+        let blocks = chain.lock().unwrap().clone();
+        let find_block = |inv: &Inventory| {
+            let hash = inv.network_hash().unwrap();
+            blocks
+                .iter()
+                .find(|block| block.block_hash().to_byte_array() == hash)
+        };
+        match message.payload().clone() {
+            NetworkMessage::Version(version) => {
+                assert_eq!(version.version, 70016);
+                let addr = Address::new(&"127.0.0.1:18444".parse().unwrap(), ServiceFlags::NONE);
+                let version = VersionMessage::new(
+                    services | ServiceFlags::WITNESS,
+                    0,
+                    addr.clone(),
+                    addr,
+                    0,
+                    "/fake-peer/".to_owned(),
+                    blocks.len() as i32 - 1,
+                );
+                send(NetworkMessage::Version(version));
+                send(NetworkMessage::SendHeaders);
+                send(NetworkMessage::Verack);
+                send(NetworkMessage::Ping(7));
+            }
+            NetworkMessage::GetHeaders(request) => {
+                let start = request
+                    .locator_hashes
+                    .iter()
+                    .find_map(|hash| blocks.iter().position(|block| block.block_hash() == *hash))
+                    .map(|index| index + 1)
+                    .unwrap_or(0);
+                let headers = blocks
+                    .iter()
+                    .skip(start)
+                    .take(2000)
+                    .map(|block| block.header)
+                    .collect();
+                send(NetworkMessage::Headers(headers));
+            }
+            NetworkMessage::GetData(invs) => {
+                for inv in invs {
+                    let Some(block) = find_block(&inv) else {
+                        send(NetworkMessage::NotFound(vec![inv]));
+                        continue;
+                    };
+                    match inv {
+                        Inventory::WitnessBlock(_) => send(NetworkMessage::Block(block.clone())),
+                        Inventory::Unknown { inv_type: 3, .. } => {
+                            let filter: &BloomFilter = filter.as_ref().unwrap();
+                            let merkle_block =
+                                MerkleBlock::from_block_with_predicate(block, |txid| {
+                                    filter.contains(txid.as_byte_array())
+                                });
+                            send(NetworkMessage::MerkleBlock(merkle_block));
+                        }
+                        _ => panic!("unexpected inventory {inv:?}"),
+                    }
+                }
+            }
+            NetworkMessage::FilterLoad(_) if !services.has(ServiceFlags::BLOOM) => break,
+            NetworkMessage::FilterLoad(filter_load) => {
+                filter = Some(BloomFilter::from_filter_load(&filter_load));
+            }
+            NetworkMessage::FilterClear => filter = None,
+            NetworkMessage::Pong(nonce) => assert_eq!(nonce, 7),
+            NetworkMessage::Verack => {}
+            payload => panic!("unexpected message {}", payload.cmd()),
+        }
+    }
+}
+
+#[test]
+fn bloom_filter() {
+    tests::setup();
+
+    // The test vectors in Bitcoin Core.
+    for (seed, data, expected) in [
+        (0x0000_0000, &[][..], 0x0000_0000),
+        (0xFBA4_C795, &[][..], 0x6a39_6f08),
+        (0x0000_0000, &[0x00][..], 0x514e_28b7),
+        (0xFBA4_C795, &[0x00][..], 0xea3f_0b17),
+        (0x0000_0000, &[0x21, 0x43, 0x65, 0x87][..], 0xf55b_516b),
+        (0x0000_0000, &[0x21, 0x43, 0x65][..], 0x7e4a_8634),
+    ] {
+        assert_eq!(murmur3_32(seed, data), expected);
+    }
+
+    let block = build_block(10);
+    let mut filter = BloomFilter::new(2, 0.000_001, 0);
+    filter.insert(block.txdata[3].txid().as_byte_array());
+    filter.insert(block.txdata[7].txid().as_byte_array());
+    for (index, tx) in block.txdata.iter().enumerate() {
+        let expected = index == 3 || index == 7;
+        assert_eq!(filter.contains(tx.txid().as_byte_array()), expected);
+    }
+}
+
+#[test]
+fn p2p_client() {
+    tests::setup();
+
+    let flags = FLAG_CHAIN_TYPE_REGTEST;
+    let genesis = genesis_block(Network::Regtest).header;
+    let (_, blocks) = regtest_service_with(2100, |height| build_block(1 + (height % 4) as u32));
+    let addr = start_fake_peer(Arc::new(Mutex::new(blocks.clone())));
+    let mut client = P2pClient::connect(addr, Network::Regtest).unwrap();
+    assert_eq!(client.peer_version().user_agent, "/fake-peer/");
+
+    // Sync headers, at most 2000 headers in one batch.
+    let mut service = SpvService::bootstrap(MemStorage::default(), 0, genesis, flags).unwrap();
+    let mut expected = SpvService::bootstrap(MemStorage::default(), 0, genesis, flags).unwrap();
+    let changes = client.sync_headers(&mut service).unwrap();
+    assert_eq!(changes.len(), 2);
+    for (change, range) in changes.into_iter().zip([1..2001, 2001..2101]) {
+        let SpvChange::Update(update) = change else {
+            panic!("an update is expected");
+        };
+        let old_client: packed::SpvClient = expected.tip_client().pack();
+        let headers = blocks[range].iter().map(|block| block.header).collect();
+        let expected_update = expected.update(headers).unwrap();
+        assert_eq!(update.as_slice(), expected_update.as_slice());
+        let new_client: packed::SpvClient = expected.tip_client().pack();
+        old_client
+            .verify_new_client(&new_client, update, flags)
+            .map_err(|err| err as i8)
+            .unwrap();
+    }
+    assert_eq!(
+        service.tip_client().pack().as_slice(),
+        expected.tip_client().pack().as_slice()
+    );
+    assert!(client.sync_headers(&mut service).unwrap().is_empty());
+
+    // Fetch blocks and merkle blocks on demand.
+    let block = &blocks[2099];
+    let block_hash = block.block_hash();
+    assert_eq!(client.get_block(&block_hash).unwrap(), *block);
+    let generator = client.get_block_proof_generator(&block_hash).unwrap();
+    assert_eq!(generator.as_ref(), block);
+    let txids = [block.txdata[1].txid(), block.txdata[3].txid()];
+    let proof = client.get_txout_proof(&txids, &block_hash).unwrap();
+    let merkle_block: MerkleBlock = encode::deserialize(&proof).unwrap();
+    let mut matches = Vec::new();
+    let mut indexes = Vec::new();
+    merkle_block
+        .txn
+        .extract_matches(&mut matches, &mut indexes)
+        .unwrap();
+    assert!(txids.iter().all(|txid| matches.contains(txid)));
+
+    // The block 2096 only has the coinbase transaction.
+    assert!(client
+        .get_txout_proof(&txids, &blocks[2096].block_hash())
+        .is_err());
+    assert!(client.get_block(&genesis.prev_blockhash).is_err());
+}
+
+#[test]
+fn p2p_client_without_bloom() {
+    tests::setup();
+
+    let (_, blocks) = regtest_service_with(10, |height| build_block(1 + (height % 4) as u32));
+    let addr =
+        start_fake_peer_with_services(Arc::new(Mutex::new(blocks.clone())), ServiceFlags::NETWORK);
+    let mut client = P2pClient::connect(addr, Network::Regtest).unwrap();
+    assert!(!client.peer_version().services.has(ServiceFlags::BLOOM));
+
+    // The merkle block is created from the whole block, since no bloom filter is loaded.
+    let block = &blocks[7];
+    let block_hash = block.block_hash();
+    let txids = [block.txdata[1].txid(), block.txdata[3].txid()];
+    let proof = client.get_txout_proof(&txids, &block_hash).unwrap();
+    let expected = MerkleBlock::from_block_with_predicate(block, |txid| txids.contains(txid));
+    assert_eq!(proof, encode::serialize(&expected));
+
+    // The block 8 only has the coinbase transaction.
+    assert!(client
+        .get_txout_proof(&txids, &blocks[8].block_hash())
+        .is_err());
+    // The connection is still alive.
+    assert_eq!(client.get_block(&block_hash).unwrap(), *block);
+}
+
+#[test]
+fn p2p_client_reorg() {
+    tests::setup();
+
+    let flags = FLAG_CHAIN_TYPE_REGTEST;
+    let genesis = genesis_block(Network::Regtest).header;
+    let (_, blocks) = regtest_service_with(30, |height| build_block(1 + (height % 4) as u32));
+    let chain = Arc::new(Mutex::new(blocks.clone()));
+    let addr = start_fake_peer(Arc::clone(&chain));
+    let mut client = P2pClient::connect(addr, Network::Regtest).unwrap();
+
+    let mut service = SpvService::bootstrap(MemStorage::default(), 0, genesis, flags).unwrap();
+    assert_eq!(client.sync_headers(&mut service).unwrap().len(), 1);
+
+    // The peer switches to a branch which has more chain work.
+    let fork_height = 25;
+    let mut branch = blocks[..=fork_height].to_vec();
+    for _ in 0..7 {
+        let prev = branch.last().unwrap().header;
+        let mut block = build_block(2);
+        block.header.prev_blockhash = prev.block_hash();
+        block.header.time = prev.time + 601;
+        block.header.bits = prev.bits;
+        while block.header.validate_pow(block.header.target()).is_err() {
+            block.header.nonce += 1;
+        }
+        branch.push(block);
+    }
+    *chain.lock().unwrap() = branch.clone();
+
+    let old_client: packed::SpvClient = service.tip_client().pack();
+    let mut changes = client.sync_headers(&mut service).unwrap();
+    assert_eq!(changes.len(), 1);
+    let Some(SpvChange::Reorg(reorg)) = changes.pop() else {
+        panic!("a reorg is expected");
+    };
+    let new_client: packed::SpvClient = service.tip_client().pack();
+    old_client
+        .verify_reorg_client(&new_client, reorg, flags)
+        .map_err(|err| err as i8)
+        .unwrap();
+    assert_eq!(service.max_height(), 32);
+    assert_eq!(service.header(32).unwrap().unwrap(), branch[32].header);
+}
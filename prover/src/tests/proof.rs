@@ -0,0 +1,894 @@
+use bitcoin::{
+    blockdata::{constants::genesis_block, script},
+    consensus::serialize,
+    hashes::{sha256, sha256d, Hash as _},
+    Amount, Block, Network, OutPoint, ScriptBuf, TxOut, Txid, Witness,
+};
+use ckb_bitcoin_spv_verifier::{
+    constants::*,
+    error::VerifyTxError,
+    types::{core, packed, prelude::*},
+};
+
+use crate::{
+    tests::{self, block::build_block},
+    BlockProofGenerator, DummyService,
+};
+
+// Creates a regtest chain with `count` blocks after the genesis block,
+// and a service which contains all of them.
+pub(crate) fn regtest_service(count: usize) -> (DummyService, Vec<Block>) {
+    regtest_service_with(count, |height| build_block(1 + (height % 10) as u32))
+}
+
+// Same as `regtest_service(..)`, but the blocks are built by `build`,
+// only the headers are updated to make the chain valid.
+pub(crate) fn regtest_service_with<F>(count: usize, build: F) -> (DummyService, Vec<Block>)
+where
+    F: Fn(usize) -> Block,
+{
+    let genesis = genesis_block(Network::Regtest);
+    let mut blocks = vec![genesis];
+    for height in 1..=count {
+        let prev = &blocks.last().unwrap().header;
+        let mut block = build(height);
+        block.header.prev_blockhash = prev.block_hash();
+        block.header.time = prev.time + 600;
+        block.header.bits = prev.bits;
+        while block.header.validate_pow(block.header.target()).is_err() {
+            block.header.nonce += 1;
+        }
+        blocks.push(block);
+    }
+    let mut service =
+        DummyService::bootstrap(0, blocks[0].header, FLAG_CHAIN_TYPE_REGTEST).unwrap();
+    if count > 0 {
+        let headers = blocks[1..].iter().map(|block| block.header).collect();
+        service.update(headers).unwrap();
+    }
+    (service, blocks)
+}
+
+// Builds a block, all transactions, except the coinbase, have witnesses.
+pub(crate) fn build_segwit_block(txs_count: u32) -> Block {
+    let mut block = build_block(txs_count);
+    for (i, tx) in block.txdata.iter_mut().enumerate().skip(1) {
+        tx.input[0].witness = Witness::from_slice(&[vec![i as u8; 64], vec![0x51]]);
+    }
+    let reserved_value = [0u8; 32];
+    block.txdata[0].input[0].witness = Witness::from_slice(&[reserved_value]);
+    let witness_root = block.witness_root().unwrap();
+    let commitment = Block::compute_witness_commitment(&witness_root, &reserved_value);
+    let mut script = vec![0x6a, 0x24, 0xaa, 0x21, 0xa9, 0xed];
+    script.extend_from_slice(commitment.as_ref());
+    block.txdata[0].output.push(TxOut {
+        value: Amount::ZERO,
+        script_pubkey: ScriptBuf::from(script),
+    });
+    block.header.merkle_root = block.compute_merkle_root().unwrap();
+    assert!(block.check_witness_commitment());
+    block
+}
+
+// Builds a transaction proof, the block should be at the height in the service.
+pub(crate) fn build_transaction_proof(
+    service: &DummyService,
+    block: &Block,
+    height: u32,
+    tx_index: u32,
+) -> packed::TransactionProof {
+    let bpg = BlockProofGenerator::new(block.clone());
+    let transaction_proof = bpg.generate_txoutproof_via_indexes(&[tx_index]).unwrap();
+    let header_proof = service.generate_header_proof(height).unwrap().unwrap();
+    packed::TransactionProof::new_builder()
+        .tx_index(tx_index.pack())
+        .height(height.pack())
+        .transaction_proof(core::Bytes::from(transaction_proof).pack())
+        .header_proof(header_proof.pack())
+        .build()
+}
+
+#[test]
+fn verify_header() {
+    tests::setup();
+
+    let (service, blocks) = regtest_service(30);
+    let tip_client: packed::SpvClient = service.tip_client().pack();
+
+    let height = 20u32;
+    let header = blocks[height as usize].header;
+    let header_proof = service.generate_header_proof(height).unwrap().unwrap();
+    let build_proof = |height: u32, header: packed::HeaderOrHash| {
+        packed::HeaderProof::new_builder()
+            .height(height.pack())
+            .header(header)
+            .header_proof(header_proof.clone().pack())
+            .build()
+    };
+    let hash_with_target = |block_hash: core::Hash, bits: core::CompactTarget| {
+        let hash_with_target = packed::BlockHashWithTarget::new_builder()
+            .block_hash(block_hash.pack())
+            .compact_target(bits.to_consensus().pack())
+            .build();
+        packed::HeaderOrHash::new_builder()
+            .set(hash_with_target)
+            .build()
+    };
+
+    // With the full header.
+    {
+        let header_or_hash = packed::HeaderOrHash::new_builder()
+            .set(header.pack())
+            .build();
+        let proof = build_proof(height, header_or_hash);
+        let verified = tip_client
+            .verify_header(proof.as_reader(), 10)
+            .map_err(|err| err as i8)
+            .unwrap();
+        assert_eq!(verified, Some(header));
+        let result = tip_client
+            .verify_header(proof.as_reader(), 11)
+            .map(|_| ())
+            .map_err(|err| err as i8);
+        assert_eq!(result, Err(VerifyTxError::TransactionUnconfirmed as i8));
+
+        let other_header = blocks[height as usize + 1].header;
+        let header_or_hash = packed::HeaderOrHash::new_builder()
+            .set(other_header.pack())
+            .build();
+        let proof = build_proof(height, header_or_hash);
+        let result = tip_client
+            .verify_header(proof.as_reader(), 0)
+            .map(|_| ())
+            .map_err(|err| err as i8);
+        assert_eq!(result, Err(VerifyTxError::HeaderMmrProof as i8));
+    }
+    // With the block hash.
+    {
+        let block_hash = header.block_hash().to_raw_hash();
+        let proof = build_proof(height, hash_with_target(block_hash, header.bits));
+        let verified = tip_client
+            .verify_header(proof.as_reader(), 0)
+            .map_err(|err| err as i8)
+            .unwrap();
+        assert_eq!(verified, None);
+
+        let proof = build_proof(height + 1, hash_with_target(block_hash, header.bits));
+        let result = tip_client
+            .verify_header(proof.as_reader(), 0)
+            .map(|_| ())
+            .map_err(|err| err as i8);
+        assert_eq!(result, Err(VerifyTxError::HeaderMmrProof as i8));
+
+        let bits = core::CompactTarget::from_consensus(0x1d00ffff);
+        let proof = build_proof(height, hash_with_target(block_hash, bits));
+        let result = tip_client
+            .verify_header(proof.as_reader(), 0)
+            .map(|_| ())
+            .map_err(|err| err as i8);
+        assert_eq!(result, Err(VerifyTxError::HeaderMmrProof as i8));
+
+        let proof = build_proof(31, hash_with_target(block_hash, header.bits));
+        let result = tip_client
+            .verify_header(proof.as_reader(), 0)
+            .map(|_| ())
+            .map_err(|err| err as i8);
+        assert_eq!(result, Err(VerifyTxError::TransactionTooNew as i8));
+    }
+}
+
+#[test]
+fn verify_transactions() {
+    tests::setup();
+
+    let (service, blocks) = regtest_service(30);
+    let tip_client: packed::SpvClient = service.tip_client().pack();
+
+    let height = 19u32;
+    let block = &blocks[height as usize];
+    assert_eq!(block.txdata.len(), 10);
+    let bpg = BlockProofGenerator::new(block.clone());
+    let header_proof = service.generate_header_proof(height).unwrap().unwrap();
+    let build_proof = |tx_indexes: Vec<u32>, proved_indexes: &[u32]| {
+        let transaction_proof = bpg.generate_txoutproof_via_indexes(proved_indexes).unwrap();
+        packed::TransactionsProof::new_builder()
+            .tx_indexes(tx_indexes.pack())
+            .height(height.pack())
+            .transaction_proof(core::Bytes::from(transaction_proof).pack())
+            .header_proof(header_proof.clone().pack())
+            .build()
+    };
+    let txids_of = |tx_indexes: &[u32]| {
+        tx_indexes
+            .iter()
+            .map(|i| *block.txdata[*i as usize].txid().as_ref())
+            .collect::<Vec<[u8; 32]>>()
+    };
+
+    let tx_indexes = vec![1, 4, 9];
+    let proof = build_proof(tx_indexes.clone(), &tx_indexes);
+    let txids = txids_of(&tx_indexes);
+    let verified = tip_client
+        .verify_transactions(&txids, proof.as_reader(), 11)
+        .map_err(|err| err as i8)
+        .unwrap();
+    assert_eq!(verified, block.header);
+    let result = tip_client
+        .verify_transactions(&txids, proof.as_reader(), 12)
+        .map(|_| ())
+        .map_err(|err| err as i8);
+    assert_eq!(result, Err(VerifyTxError::TransactionUnconfirmed as i8));
+
+    // Only a part of the proved transactions are checked.
+    let proof = build_proof(vec![9, 1], &tx_indexes);
+    let result = tip_client
+        .verify_transactions(&txids_of(&[9, 1]), proof.as_reader(), 0)
+        .map(|_| ())
+        .map_err(|err| err as i8);
+    assert_eq!(result, Ok(()));
+
+    // Transaction IDs are not matched.
+    let result = tip_client
+        .verify_transactions(&txids_of(&[1, 9]), proof.as_reader(), 0)
+        .map(|_| ())
+        .map_err(|err| err as i8);
+    assert_eq!(result, Err(VerifyTxError::TxOutProofInvalidTxId as i8));
+
+    // Transaction IDs are not enough.
+    let result = tip_client
+        .verify_transactions(&txids_of(&[9]), proof.as_reader(), 0)
+        .map(|_| ())
+        .map_err(|err| err as i8);
+    assert_eq!(result, Err(VerifyTxError::TxOutProofInvalidTxIndex as i8));
+
+    // A transaction is not in the TxOut proof.
+    let proof = build_proof(vec![1, 5], &[1, 4]);
+    let result = tip_client
+        .verify_transactions(&txids_of(&[1, 5]), proof.as_reader(), 0)
+        .map(|_| ())
+        .map_err(|err| err as i8);
+    assert_eq!(result, Err(VerifyTxError::TxOutProofInvalidTxIndex as i8));
+
+    // No transactions.
+    let proof = build_proof(vec![], &[1, 4]);
+    let result = tip_client
+        .verify_transactions(&[], proof.as_reader(), 0)
+        .map(|_| ())
+        .map_err(|err| err as i8);
+    assert_eq!(result, Err(VerifyTxError::TxOutProofInvalidTxIndex as i8));
+}
+
+#[test]
+fn verify_headers() {
+    tests::setup();
+
+    let (service, blocks) = regtest_service(40);
+    let tip_client: packed::SpvClient = service.tip_client().pack();
+
+    let build_proof = |heights: &[u32], proved_heights: &[u32]| {
+        let headers = heights
+            .iter()
+            .map(|h| blocks[*h as usize].header)
+            .collect::<Vec<_>>();
+        let headers_proof = service
+            .generate_headers_proof(proved_heights)
+            .unwrap()
+            .unwrap();
+        packed::HeadersProof::new_builder()
+            .heights(heights.to_vec().pack())
+            .headers(headers.pack())
+            .headers_proof(headers_proof.pack())
+            .build()
+    };
+
+    let heights = [3, 17, 18, 31];
+    let proof = build_proof(&heights, &heights);
+    let verified = tip_client
+        .verify_headers(proof.as_reader(), 9)
+        .map_err(|err| err as i8)
+        .unwrap();
+    let expected = heights
+        .iter()
+        .map(|h| blocks[*h as usize].header)
+        .collect::<Vec<_>>();
+    assert_eq!(verified, expected);
+    let result = tip_client
+        .verify_headers(proof.as_reader(), 10)
+        .map(|_| ())
+        .map_err(|err| err as i8);
+    assert_eq!(result, Err(VerifyTxError::TransactionUnconfirmed as i8));
+
+    // The multi-proof is smaller than the separate proofs.
+    let separate_size: usize = heights
+        .iter()
+        .map(|h| service.generate_header_proof(*h).unwrap().unwrap().len())
+        .sum();
+    assert!(proof.headers_proof().len() < separate_size);
+
+    // A single header.
+    let proof = build_proof(&[40], &[40]);
+    assert!(tip_client.verify_headers(proof.as_reader(), 0).is_ok());
+
+    // The proof is not for the headers.
+    let proof = build_proof(&[3, 17], &[3, 18]);
+    let result = tip_client
+        .verify_headers(proof.as_reader(), 0)
+        .map(|_| ())
+        .map_err(|err| err as i8);
+    assert_eq!(result, Err(VerifyTxError::HeaderMmrProof as i8));
+
+    // The heights are not in ascending order.
+    let proof = build_proof(&[17, 3], &[3, 17]);
+    let result = tip_client
+        .verify_headers(proof.as_reader(), 0)
+        .map(|_| ())
+        .map_err(|err| err as i8);
+    assert_eq!(result, Err(VerifyTxError::HeadersProofInvalidHeights as i8));
+
+    // The count of heights is not matched.
+    let proof = build_proof(&[3, 17], &[3, 17])
+        .as_builder()
+        .heights(vec![3u32].pack())
+        .build();
+    let result = tip_client
+        .verify_headers(proof.as_reader(), 0)
+        .map(|_| ())
+        .map_err(|err| err as i8);
+    assert_eq!(result, Err(VerifyTxError::HeadersProofInvalidHeights as i8));
+
+    assert!(service.generate_headers_proof(&[3, 41]).unwrap().is_none());
+}
+
+#[test]
+fn verify_transaction_witness() {
+    tests::setup();
+
+    let (service, blocks) = regtest_service_with(20, |height| {
+        if height % 2 == 0 {
+            build_segwit_block(1 + (height % 10) as u32)
+        } else {
+            build_block(1 + (height % 10) as u32)
+        }
+    });
+    let tip_client: packed::SpvClient = service.tip_client().pack();
+
+    let build_proof = |height: u32, tx_index: u32, block: &Block| {
+        let bpg = BlockProofGenerator::new(block.clone());
+        let coinbase_proof = bpg.generate_coinbase_proof().unwrap();
+        let witness_merkle_branch = bpg
+            .generate_witness_merkle_branch(tx_index as usize)
+            .unwrap();
+        let header_proof = service.generate_header_proof(height).unwrap().unwrap();
+        packed::TransactionWitnessProof::new_builder()
+            .tx_index(tx_index.pack())
+            .height(height.pack())
+            .header(block.header.pack())
+            .coinbase_proof(coinbase_proof)
+            .witness_merkle_branch(witness_merkle_branch.pack())
+            .header_proof(header_proof.pack())
+            .build()
+    };
+    let verify = |tx: &[u8], proof: &packed::TransactionWitnessProof| {
+        tip_client
+            .verify_transaction_witness(tx, proof.as_reader(), 0)
+            .map(|_| ())
+            .map_err(|err| err as i8)
+    };
+
+    let height = 18u32;
+    let block = &blocks[height as usize];
+    assert_eq!(block.txdata.len(), 9);
+    for tx_index in 1..9 {
+        let tx = &block.txdata[tx_index as usize];
+        let proof = build_proof(height, tx_index, block);
+        let (header, verified_tx) = tip_client
+            .verify_transaction_witness(&serialize(tx), proof.as_reader(), 2)
+            .map_err(|err| err as i8)
+            .unwrap();
+        assert_eq!(header, block.header);
+        assert_eq!(&verified_tx, tx);
+
+        // The witness is changed.
+        let mut fake_tx = tx.clone();
+        fake_tx.input[0].witness = Witness::from_slice(&[vec![0xff; 64], vec![0x51]]);
+        assert_eq!(fake_tx.txid(), tx.txid());
+        assert_eq!(
+            verify(&serialize(&fake_tx), &proof),
+            Err(VerifyTxError::WitnessCommitment as i8)
+        );
+    }
+
+    let tx = serialize(&block.txdata[3]);
+    let proof = build_proof(height, 3, block);
+
+    // The coinbase transaction is not supported.
+    let coinbase_proof = build_proof(height, 0, block);
+    assert_eq!(
+        verify(&serialize(&block.txdata[0]), &coinbase_proof),
+        Err(VerifyTxError::TxOutProofInvalidTxIndex as i8)
+    );
+
+    // The witness reserved value is changed.
+    {
+        let mut block = block.clone();
+        block.txdata[0].input[0].witness = Witness::from_slice(&[[1u8; 32]]);
+        let proof = build_proof(height, 3, &block);
+        assert_eq!(
+            verify(&tx, &proof),
+            Err(VerifyTxError::WitnessCommitment as i8)
+        );
+        block.txdata[0].input[0].witness = Witness::new();
+        let proof = build_proof(height, 3, &block);
+        assert_eq!(
+            verify(&tx, &proof),
+            Err(VerifyTxError::WitnessReservedValue as i8)
+        );
+    }
+
+    // The coinbase proof is not for the header.
+    let other_proof = build_proof(16, 3, &blocks[16]);
+    let mixed_proof = proof
+        .clone()
+        .as_builder()
+        .coinbase_proof(other_proof.coinbase_proof())
+        .build();
+    assert_eq!(
+        verify(&tx, &mixed_proof),
+        Err(VerifyTxError::CoinbaseProof as i8)
+    );
+
+    // The depth of the witness merkle branch is incorrect.
+    let mut branch: Vec<core::Hash> = proof.witness_merkle_branch().unpack();
+    branch.pop();
+    let short_proof = proof
+        .clone()
+        .as_builder()
+        .witness_merkle_branch(branch.pack())
+        .build();
+    assert_eq!(
+        verify(&tx, &short_proof),
+        Err(VerifyTxError::WitnessMerkleBranchDepth as i8)
+    );
+
+    // Transactions which are 64 bytes without witnesses are rejected.
+    let ambiguous_tx = &build_block_with_ambiguous_tx(7).txdata[1];
+    assert_eq!(
+        verify(&serialize(ambiguous_tx), &proof),
+        Err(VerifyTxError::TxOutProofAmbiguousTx as i8)
+    );
+
+    // The block has no witness commitment.
+    let height = 17u32;
+    let block = &blocks[height as usize];
+    let proof = build_proof(height, 3, block);
+    assert_eq!(
+        verify(&serialize(&block.txdata[3]), &proof),
+        Err(VerifyTxError::WitnessCommitmentNotFound as i8)
+    );
+}
+
+#[test]
+fn verify_payment() {
+    tests::setup();
+
+    let script_a = ScriptBuf::from(vec![0x51, 0x01, 0xaa]);
+    let script_b = ScriptBuf::from(vec![0x51, 0x01, 0xbb]);
+    let outputs = vec![
+        TxOut {
+            value: Amount::from_sat(1_000),
+            script_pubkey: script_a.clone(),
+        },
+        TxOut {
+            value: Amount::from_sat(5_000),
+            script_pubkey: script_b.clone(),
+        },
+        TxOut {
+            value: Amount::from_sat(10_000),
+            script_pubkey: script_a.clone(),
+        },
+    ];
+    let (service, blocks) = regtest_service_with(10, |height| {
+        let mut block = build_block(4);
+        if height == 6 {
+            block.txdata[2].output = outputs.clone();
+            block.header.merkle_root = block.compute_merkle_root().unwrap();
+        }
+        block
+    });
+    let tip_client: packed::SpvClient = service.tip_client().pack();
+
+    let height = 6u32;
+    let tx_index = 2u32;
+    let block = &blocks[height as usize];
+    let tx = serialize(&block.txdata[tx_index as usize]);
+    let tx_proof = build_transaction_proof(&service, block, height, tx_index);
+
+    let script_bytes = |script: &ScriptBuf| {
+        packed::ScriptCondition::new_builder()
+            .set(core::Bytes::from(script.to_bytes()).pack())
+            .build()
+    };
+    let script_hash = |script: &ScriptBuf| {
+        let hash =
+            core::Hash::from_byte_array(sha256::Hash::hash(script.as_bytes()).to_byte_array());
+        packed::ScriptCondition::new_builder()
+            .set(hash.pack())
+            .build()
+    };
+    let condition = |output_index: Option<u32>,
+                     script: packed::ScriptCondition,
+                     min_value: u64,
+                     min_confirmations: u32| {
+        packed::PaymentCondition::new_builder()
+            .output_index(output_index.pack())
+            .script(script)
+            .min_value(min_value.pack())
+            .min_confirmations(min_confirmations.pack())
+            .build()
+    };
+    let verify = |condition: packed::PaymentCondition| {
+        tip_client
+            .verify_payment(&tx, tx_proof.as_reader(), condition.as_reader())
+            .map(|(_, _, index)| index)
+            .map_err(|err| err as i8)
+    };
+
+    // Any output.
+    assert_eq!(
+        verify(condition(None, script_bytes(&script_a), 0, 0)),
+        Ok(0)
+    );
+    assert_eq!(
+        verify(condition(None, script_bytes(&script_a), 1_001, 0)),
+        Ok(2)
+    );
+    assert_eq!(
+        verify(condition(None, script_hash(&script_b), 5_000, 4)),
+        Ok(1)
+    );
+    assert_eq!(
+        verify(condition(None, script_hash(&script_b), 5_001, 0)),
+        Err(VerifyTxError::PaymentUnmatched as i8)
+    );
+    assert_eq!(
+        verify(condition(None, script_bytes(&script_a), 10_001, 0)),
+        Err(VerifyTxError::PaymentUnmatched as i8)
+    );
+    // The specified output.
+    assert_eq!(
+        verify(condition(Some(2), script_hash(&script_a), 10_000, 0)),
+        Ok(2)
+    );
+    assert_eq!(
+        verify(condition(Some(1), script_hash(&script_a), 0, 0)),
+        Err(VerifyTxError::PaymentUnmatched as i8)
+    );
+    assert_eq!(
+        verify(condition(Some(3), script_bytes(&script_a), 0, 0)),
+        Err(VerifyTxError::PaymentOutputIndex as i8)
+    );
+    // The confirmations.
+    assert_eq!(
+        verify(condition(None, script_bytes(&script_a), 0, 5)),
+        Err(VerifyTxError::TransactionUnconfirmed as i8)
+    );
+}
+
+#[test]
+fn verify_spend() {
+    tests::setup();
+
+    let outpoint_a = OutPoint::new(Txid::from_byte_array([0xaa; 32]), 1);
+    let outpoint_b = OutPoint::new(Txid::from_byte_array([0xbb; 32]), 0);
+    let (service, blocks) = regtest_service_with(10, |height| {
+        let mut block = build_block(4);
+        if height == 4 {
+            let mut input = block.txdata[1].input[0].clone();
+            input.previous_output = outpoint_a;
+            block.txdata[1].input[0] = input.clone();
+            input.previous_output = outpoint_b;
+            block.txdata[1].input.push(input);
+            block.header.merkle_root = block.compute_merkle_root().unwrap();
+        }
+        block
+    });
+    let tip_client: packed::SpvClient = service.tip_client().pack();
+
+    let height = 4u32;
+    let tx_index = 1u32;
+    let block = &blocks[height as usize];
+    let tx = serialize(&block.txdata[tx_index as usize]);
+    let tx_proof = build_transaction_proof(&service, block, height, tx_index);
+    let verify = |outpoint: &OutPoint, confirmations: u32| {
+        tip_client
+            .verify_spend(outpoint, &tx, tx_proof.as_reader(), confirmations)
+            .map(|(_, _, index)| index)
+            .map_err(|err| err as i8)
+    };
+
+    assert_eq!(verify(&outpoint_a, 6), Ok(0));
+    assert_eq!(verify(&outpoint_b, 0), Ok(1));
+    assert_eq!(
+        verify(&outpoint_b, 7),
+        Err(VerifyTxError::TransactionUnconfirmed as i8)
+    );
+    let outpoint_c = OutPoint::new(outpoint_b.txid, 1);
+    assert_eq!(
+        verify(&outpoint_c, 0),
+        Err(VerifyTxError::OutPointNotSpent as i8)
+    );
+}
+
+#[test]
+fn verify_coinbase_transaction() {
+    tests::setup();
+
+    let flags = FLAG_CHAIN_TYPE_REGTEST;
+    let (service, blocks) = regtest_service_with(120, |height| {
+        let mut block = build_block(3);
+        // The coinbase transaction at height 5 has an incorrect height.
+        let encoded_height = if height == 5 { 6 } else { height as i64 };
+        block.txdata[0].input[0].script_sig = script::Builder::new()
+            .push_int(encoded_height)
+            .push_slice([0xff; 4])
+            .into_script();
+        block.header.merkle_root = block.compute_merkle_root().unwrap();
+        block
+    });
+    let tip_client: packed::SpvClient = service.tip_client().pack();
+    let verify = |height: u32, tx_index: u32, flags: u8| {
+        let block = &blocks[height as usize];
+        let tx = serialize(&block.txdata[tx_index as usize]);
+        let tx_proof = build_transaction_proof(&service, block, height, tx_index);
+        tip_client
+            .verify_coinbase_transaction(&tx, tx_proof.as_reader(), flags)
+            .map(|(_, tx)| tx)
+            .map_err(|err| err as i8)
+    };
+
+    let coinbase = verify(20, 0, flags).unwrap();
+    assert_eq!(coinbase.output, blocks[20].txdata[0].output);
+    assert!(verify(21, 0, flags).is_ok());
+    assert_eq!(
+        verify(22, 0, flags),
+        Err(VerifyTxError::CoinbaseImmature as i8)
+    );
+    assert_eq!(
+        verify(120, 0, flags),
+        Err(VerifyTxError::CoinbaseImmature as i8)
+    );
+    assert_eq!(
+        verify(20, 1, flags),
+        Err(VerifyTxError::TxOutProofInvalidTxIndex as i8)
+    );
+    assert_eq!(
+        verify(5, 0, flags),
+        Err(VerifyTxError::CoinbaseHeight as i8)
+    );
+    // BIP34 is not activated at height 5 on mainnet.
+    assert!(verify(5, 0, FLAG_CHAIN_TYPE_MAINNET).is_ok());
+}
+
+#[test]
+fn verify_transaction_with_branch() {
+    tests::setup();
+
+    let (service, blocks) = regtest_service(20);
+    let tip_client: packed::SpvClient = service.tip_client().pack();
+
+    let height = 9u32;
+    let block = &blocks[height as usize];
+    assert_eq!(block.txdata.len(), 10);
+    let bpg = BlockProofGenerator::new(block.clone());
+    let header_proof = service.generate_header_proof(height).unwrap().unwrap();
+    let verify = |txid: &[u8; 32], proof: &packed::TransactionBranchProof, confirmations| {
+        tip_client
+            .verify_transaction_with_branch(txid, proof.as_reader(), confirmations)
+            .map_err(|err| err as i8)
+    };
+
+    for tx_index in 0..10 {
+        let txid = *block.txdata[tx_index].txid().as_ref();
+        let proof = bpg
+            .generate_transaction_branch_proof(tx_index, height, header_proof.clone())
+            .unwrap();
+        assert_eq!(verify(&txid, &proof, 11), Ok(block.header));
+        assert_eq!(
+            verify(&txid, &proof, 12),
+            Err(VerifyTxError::TransactionUnconfirmed as i8)
+        );
+
+        let other_txid = *block.txdata[(tx_index + 1) % 10].txid().as_ref();
+        assert_eq!(
+            verify(&other_txid, &proof, 0),
+            Err(VerifyTxError::TxOutProofIsInvalid as i8)
+        );
+    }
+
+    let txid = *block.txdata[3].txid().as_ref();
+    let proof = bpg
+        .generate_transaction_branch_proof(3, height, header_proof.clone())
+        .unwrap();
+    // The index is out of the range of the merkle branch.
+    let invalid_proof = proof.clone().as_builder().tx_index(16u32.pack()).build();
+    assert_eq!(
+        verify(&txid, &invalid_proof, 0),
+        Err(VerifyTxError::TxOutProofInvalidTxIndex as i8)
+    );
+    // The header is not in the chain.
+    let other_header = blocks[height as usize + 1].header;
+    let invalid_proof = proof.as_builder().header(other_header.pack()).build();
+    assert_eq!(
+        verify(&txid, &invalid_proof, 0),
+        Err(VerifyTxError::TxOutProofIsInvalid as i8)
+    );
+    assert!(bpg
+        .generate_transaction_branch_proof(10, height, header_proof)
+        .is_err());
+}
+
+// Builds a block with 5 transactions, the transaction-1 at height 7 is
+// 64 bytes without witnesses.
+fn build_block_with_ambiguous_tx(height: usize) -> Block {
+    let mut block = build_block(5);
+    for tx in block.txdata.iter_mut().skip(1) {
+        tx.input[0].previous_output = OutPoint::new(Txid::all_zeros(), 0);
+    }
+    if height == 7 {
+        block.txdata[1].output[0].script_pubkey = ScriptBuf::from(vec![0x6a, 0x02, 0xab, 0xcd]);
+        assert_eq!(block.txdata[1].base_size(), 64);
+    }
+    block.header.merkle_root = block.compute_merkle_root().unwrap();
+    block
+}
+
+#[test]
+fn verify_transaction_data_hardened() {
+    tests::setup();
+
+    let (service, blocks) = regtest_service_with(20, build_block_with_ambiguous_tx);
+    let tip_client: packed::SpvClient = service.tip_client().pack();
+    let coinbase_proof = |height: usize| {
+        BlockProofGenerator::new(blocks[height].clone())
+            .generate_coinbase_proof()
+            .unwrap()
+    };
+    let verify = |height: u32, tx_index: u32, coinbase_proof: &packed::CoinbaseProof| {
+        let block = &blocks[height as usize];
+        let tx = serialize(&block.txdata[tx_index as usize]);
+        let tx_proof = build_transaction_proof(&service, block, height, tx_index);
+        let result = tip_client
+            .verify_transaction_data(&tx, tx_proof.as_reader(), 0)
+            .map(|(header, _)| header)
+            .map_err(|err| err as i8);
+        let hardened_result = tip_client
+            .verify_transaction_data_hardened(
+                &tx,
+                tx_proof.as_reader(),
+                coinbase_proof.as_reader(),
+                0,
+            )
+            .map(|(header, _)| header)
+            .map_err(|err| err as i8);
+        (result, hardened_result)
+    };
+
+    let (result, hardened_result) = verify(5, 3, &coinbase_proof(5));
+    assert_eq!(result, Ok(blocks[5].header));
+    assert_eq!(hardened_result, Ok(blocks[5].header));
+
+    let (result, hardened_result) = verify(7, 1, &coinbase_proof(7));
+    assert_eq!(result, Err(VerifyTxError::TxOutProofAmbiguousTx as i8));
+    assert_eq!(
+        hardened_result,
+        Err(VerifyTxError::TxOutProofAmbiguousTx as i8)
+    );
+    assert!(verify(7, 2, &coinbase_proof(7)).1.is_ok());
+
+    // The coinbase proof of another block.
+    let (_, hardened_result) = verify(5, 3, &coinbase_proof(7));
+    assert_eq!(hardened_result, Err(VerifyTxError::CoinbaseProof as i8));
+
+    // The depth of the coinbase merkle branch is not matched.
+    let proof = coinbase_proof(5);
+    let mut merkle_branch: Vec<core::Hash> = proof.merkle_branch().unpack();
+    merkle_branch.pop();
+    let short_proof = proof
+        .clone()
+        .as_builder()
+        .merkle_branch(merkle_branch.pack())
+        .build();
+    let (_, hardened_result) = verify(5, 3, &short_proof);
+    assert_eq!(hardened_result, Err(VerifyTxError::CoinbaseProof as i8));
+
+    // The transaction in the coinbase proof is not a coinbase transaction.
+    let fake_proof = proof
+        .as_builder()
+        .transaction(core::Bytes::from(serialize(&blocks[5].txdata[1])).pack())
+        .build();
+    let (_, hardened_result) = verify(5, 3, &fake_proof);
+    assert_eq!(hardened_result, Err(VerifyTxError::CoinbaseProof as i8));
+}
+
+#[test]
+fn verify_transaction_with_branch_hardened() {
+    tests::setup();
+
+    let (service, blocks) = regtest_service_with(20, build_block_with_ambiguous_tx);
+    let tip_client: packed::SpvClient = service.tip_client().pack();
+    let coinbase_proof = |height: usize| {
+        BlockProofGenerator::new(blocks[height].clone())
+            .generate_coinbase_proof()
+            .unwrap()
+    };
+    let branch_proof = |height: u32, tx_index: usize| {
+        let header_proof = service.generate_header_proof(height).unwrap().unwrap();
+        BlockProofGenerator::new(blocks[height as usize].clone())
+            .generate_transaction_branch_proof(tx_index, height, header_proof)
+            .unwrap()
+    };
+    let verify = |tx: &[u8],
+                  tx_proof: &packed::TransactionBranchProof,
+                  coinbase_proof: &packed::CoinbaseProof| {
+        tip_client
+            .verify_transaction_with_branch_hardened(
+                tx,
+                tx_proof.as_reader(),
+                coinbase_proof.as_reader(),
+                0,
+            )
+            .map(|(header, _)| header)
+            .map_err(|err| err as i8)
+    };
+
+    let tx = serialize(&blocks[5].txdata[3]);
+    let tx_proof = branch_proof(5, 3);
+    assert_eq!(
+        verify(&tx, &tx_proof, &coinbase_proof(5)),
+        Ok(blocks[5].header)
+    );
+
+    let tx = serialize(&blocks[7].txdata[1]);
+    assert_eq!(
+        verify(&tx, &branch_proof(7, 1), &coinbase_proof(7)),
+        Err(VerifyTxError::TxOutProofAmbiguousTx as i8)
+    );
+    let tx = serialize(&blocks[7].txdata[2]);
+    assert!(verify(&tx, &branch_proof(7, 2), &coinbase_proof(7)).is_ok());
+
+    // The coinbase proof of another block.
+    let tx = serialize(&blocks[5].txdata[3]);
+    assert_eq!(
+        verify(&tx, &tx_proof, &coinbase_proof(7)),
+        Err(VerifyTxError::CoinbaseProof as i8)
+    );
+
+    // An inner node of the Merkle tree is accepted by a plain Merkle branch,
+    // but the coinbase Merkle branch is deeper than its Merkle branch.
+    let proof = branch_proof(5, 2);
+    let merkle_branch: Vec<core::Hash> = proof.merkle_branch().unpack();
+    let mut inner_node = serialize(&blocks[5].txdata[2].txid());
+    inner_node.extend_from_slice(merkle_branch[0].as_ref());
+    let inner_proof = proof
+        .as_builder()
+        .tx_index(1u32.pack())
+        .merkle_branch(merkle_branch[1..].to_vec().pack())
+        .build();
+    let inner_txid = sha256d::Hash::hash(&inner_node).to_byte_array();
+    assert_eq!(
+        tip_client
+            .verify_transaction_with_branch(&inner_txid, inner_proof.as_reader(), 0)
+            .map_err(|err| err as i8),
+        Ok(blocks[5].header)
+    );
+    let short_coinbase_proof = {
+        let proof = coinbase_proof(5);
+        let merkle_branch: Vec<core::Hash> = proof.merkle_branch().unpack();
+        proof
+            .as_builder()
+            .merkle_branch(merkle_branch[1..].to_vec().pack())
+            .build()
+    };
+    assert!(verify(&inner_node, &inner_proof, &coinbase_proof(5)).is_err());
+    assert!(verify(&inner_node, &inner_proof, &short_coinbase_proof).is_err());
+}
@@ -0,0 +1,552 @@
+use bitcoin::{
+    blockdata::constants::{genesis_block, DIFFCHANGE_INTERVAL},
+    hashes::Hash as _,
+    BlockHash, Network, TxMerkleNode,
+};
+use ckb_bitcoin_spv_verifier::{
+    checkpoints,
+    constants::*,
+    error::{BootstrapError, UpdateError},
+    types::{core, packed, prelude::*},
+};
+
+use crate::{tests, DummyService};
+
+pub(crate) fn mine_header(
+    prev_blockhash: BlockHash,
+    time: u32,
+    bits: core::CompactTarget,
+) -> core::Header {
+    let mut header = core::Header {
+        version: bitcoin::block::Version::TWO,
+        prev_blockhash,
+        merkle_root: TxMerkleNode::all_zeros(),
+        time,
+        bits,
+        nonce: 0,
+    };
+    while header.validate_pow(header.target()).is_err() {
+        header.nonce += 1;
+    }
+    header
+}
+
+// Mines `count` headers after the previous header, each header is
+// `600 + time_offset` seconds after its previous header.
+pub(crate) fn mine_headers(prev: &core::Header, count: u32, time_offset: u32) -> Vec<core::Header> {
+    let mut prev = *prev;
+    (0..count)
+        .map(|_| {
+            prev = mine_header(prev.block_hash(), prev.time + 600 + time_offset, prev.bits);
+            prev
+        })
+        .collect()
+}
+
+#[test]
+fn regtest_bootstrap_and_update() {
+    tests::setup();
+
+    let flags = FLAG_CHAIN_TYPE_REGTEST;
+    let genesis = genesis_block(Network::Regtest).header;
+
+    let bootstrap = packed::SpvBootstrap::new_builder()
+        .height(0u32.pack())
+        .header(genesis.pack())
+        .build();
+    let expected_client = bootstrap
+        .initialize_spv_client_with_flags(flags)
+        .map_err(|err| err as i8)
+        .unwrap()
+        .pack();
+    let mut service = DummyService::bootstrap(0, genesis, flags).unwrap();
+    let actual_client: packed::SpvClient = service.tip_client().pack();
+    assert_eq!(expected_client.as_slice(), actual_client.as_slice());
+
+    // Across a difficulty adjustment, the target should be never changed.
+    let mut old_client = actual_client;
+    let mut prev_blockhash = genesis.block_hash();
+    let mut time = genesis.time;
+    let mut height = 0;
+    while height < DIFFCHANGE_INTERVAL + 50 {
+        let mut headers = Vec::new();
+        for _ in 0..100 {
+            time += 600;
+            let header = mine_header(prev_blockhash, time, genesis.bits);
+            prev_blockhash = header.block_hash();
+            headers.push(header);
+        }
+        height += 100;
+        let update = service.update(headers).unwrap();
+        let new_client: packed::SpvClient = service.tip_client().pack();
+        old_client
+            .verify_new_client(&new_client, update, flags)
+            .map_err(|err| err as i8)
+            .unwrap();
+        old_client = new_client;
+    }
+    assert_eq!(service.max_height(), height);
+    let (_, next_bits) = service.tip_client().target_adjust_info.decode().unwrap();
+    assert_eq!(next_bits, genesis.bits);
+}
+
+#[test]
+fn regtest_bootstrap_with_mainnet_flags() {
+    tests::setup();
+
+    let genesis = genesis_block(Network::Regtest).header;
+    let bootstrap = packed::SpvBootstrap::new_builder()
+        .height(0u32.pack())
+        .header(genesis.pack())
+        .build();
+    assert!(bootstrap.initialize_spv_client().is_err());
+    assert!(bootstrap
+        .initialize_spv_client_with_flags(FLAG_CHAIN_TYPE_MAINNET)
+        .is_err());
+    assert!(DummyService::bootstrap(0, genesis, FLAG_CHAIN_TYPE_MAINNET).is_err());
+}
+
+#[test]
+fn regtest_median_time_past() {
+    tests::setup();
+
+    let flags = FLAG_CHAIN_TYPE_REGTEST;
+    let genesis = genesis_block(Network::Regtest).header;
+    let mut service = DummyService::bootstrap(0, genesis, flags).unwrap();
+
+    let mut headers = Vec::new();
+    let mut prev_blockhash = genesis.block_hash();
+    for i in 1..=MEDIAN_TIME_SPAN as u32 {
+        let header = mine_header(prev_blockhash, genesis.time + i * 600, genesis.bits);
+        prev_blockhash = header.block_hash();
+        headers.push(header);
+    }
+    service.update(headers).unwrap();
+    let old_client: packed::SpvClient = service.tip_client().pack();
+    let median_time_past = genesis.time + 6 * 600;
+
+    // A header could be earlier than its previous header, but it should be later than the MTP.
+    {
+        let header = mine_header(prev_blockhash, median_time_past + 1, genesis.bits);
+        let update = service.update(vec![header]).unwrap();
+        let new_client: packed::SpvClient = service.tip_client().pack();
+        old_client
+            .verify_new_client(&new_client, update, flags)
+            .map_err(|err| err as i8)
+            .unwrap();
+        service.rollback_to(old_client.unpack()).unwrap();
+    }
+    {
+        let header = mine_header(prev_blockhash, median_time_past, genesis.bits);
+        let update = service.update(vec![header]).unwrap();
+        let new_client: packed::SpvClient = service.tip_client().pack();
+        let result = old_client
+            .verify_new_client(&new_client, update, flags)
+            .map_err(|err| err as i8);
+        assert_eq!(result, Err(UpdateError::MedianTimePast as i8));
+    }
+}
+
+#[test]
+fn regtest_bootstrap_at_arbitrary_height() {
+    tests::setup();
+
+    let flags = FLAG_CHAIN_TYPE_REGTEST;
+    let genesis = genesis_block(Network::Regtest).header;
+
+    let mut chain = vec![genesis];
+    for _ in 0..(DIFFCHANGE_INTERVAL * 2 + 50) {
+        let prev = chain.last().unwrap();
+        let header = mine_header(prev.block_hash(), prev.time + 600, genesis.bits);
+        chain.push(header);
+    }
+
+    let start_height = DIFFCHANGE_INTERVAL + 100;
+    let period_start = DIFFCHANGE_INTERVAL as usize;
+    let header = chain[start_height as usize];
+    let period_headers = chain[period_start..start_height as usize].to_vec();
+
+    // The period headers are required.
+    {
+        let bootstrap = packed::SpvBootstrap::new_builder()
+            .height(start_height.pack())
+            .header(header.pack())
+            .build();
+        let result = bootstrap
+            .initialize_spv_client_with_flags(flags)
+            .map(|_| ())
+            .map_err(|err| err as i8);
+        assert_eq!(result, Err(BootstrapError::Height as i8));
+        assert!(DummyService::bootstrap(start_height, header, flags).is_err());
+    }
+    // The period headers should be continuous.
+    {
+        let mut period_headers = period_headers.clone();
+        period_headers[50] = chain[period_start - 1];
+        let bootstrap = packed::SpvBootstrap::new_builder()
+            .height(start_height.pack())
+            .header(header.pack())
+            .period_headers(period_headers.clone().pack())
+            .build();
+        let result = bootstrap
+            .initialize_spv_client_with_flags(flags)
+            .map(|_| ())
+            .map_err(|err| err as i8);
+        assert_eq!(result, Err(BootstrapError::UncontinuousHeaders as i8));
+        assert!(DummyService::bootstrap_with_period_headers(
+            start_height,
+            header,
+            period_headers,
+            flags
+        )
+        .is_err());
+    }
+
+    let bootstrap = packed::SpvBootstrap::new_builder()
+        .height(start_height.pack())
+        .header(header.pack())
+        .period_headers(period_headers.clone().pack())
+        .build();
+    let expected_client = bootstrap
+        .initialize_spv_client_with_flags(flags)
+        .map_err(|err| err as i8)
+        .unwrap()
+        .pack();
+    let mut service =
+        DummyService::bootstrap_with_period_headers(start_height, header, period_headers, flags)
+            .unwrap();
+    let actual_client: packed::SpvClient = service.tip_client().pack();
+    assert_eq!(expected_client.as_slice(), actual_client.as_slice());
+
+    let (start_time, _) = service.tip_client().target_adjust_info.decode().unwrap();
+    assert_eq!(start_time, chain[period_start].time);
+    let recent_timestamps = service.tip_client().recent_timestamps;
+    assert_eq!(
+        recent_timestamps[MEDIAN_TIME_SPAN - 1],
+        chain[start_height as usize].time
+    );
+    assert_eq!(
+        recent_timestamps[0],
+        chain[start_height as usize + 1 - MEDIAN_TIME_SPAN].time
+    );
+
+    // Update across the next difficulty adjustment.
+    let mut old_client = actual_client;
+    for headers in chain[(start_height as usize + 1)..].chunks(100) {
+        let update = service.update(headers.to_vec()).unwrap();
+        let new_client: packed::SpvClient = service.tip_client().pack();
+        old_client
+            .verify_new_client(&new_client, update, flags)
+            .map_err(|err| err as i8)
+            .unwrap();
+        old_client = new_client;
+    }
+    assert_eq!(service.max_height() as usize, chain.len() - 1);
+
+    // Bootstrap at the last header of a period, regtest is never retargeted.
+    let start_height = DIFFCHANGE_INTERVAL * 2 - 1;
+    let header = chain[start_height as usize];
+    let period_headers = chain[period_start..start_height as usize].to_vec();
+    let bootstrap = packed::SpvBootstrap::new_builder()
+        .height(start_height.pack())
+        .header(header.pack())
+        .period_headers(period_headers.clone().pack())
+        .build();
+    let client = bootstrap
+        .initialize_spv_client_with_flags(flags)
+        .map_err(|err| err as i8)
+        .unwrap();
+    let mut service =
+        DummyService::bootstrap_with_period_headers(start_height, header, period_headers, flags)
+            .unwrap();
+    assert_eq!(
+        client.pack().as_slice(),
+        service.tip_client().pack().as_slice()
+    );
+    let (_, bits) = client.target_adjust_info.decode().unwrap();
+    assert_eq!(bits, genesis.bits);
+    let update = service
+        .update(chain[(start_height as usize + 1)..].to_vec())
+        .unwrap();
+    let result = client
+        .pack()
+        .verify_new_client(&service.tip_client().pack(), update, flags)
+        .map_err(|err| err as i8);
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn regtest_bootstrap_with_checkpoints() {
+    tests::setup();
+
+    let flags = FLAG_CHAIN_TYPE_REGTEST;
+    let genesis = genesis_block(Network::Regtest).header;
+
+    let bootstrap = packed::SpvBootstrap::new_builder()
+        .height(0u32.pack())
+        .header(genesis.pack())
+        .build();
+    bootstrap
+        .initialize_spv_client_with_checkpoints(flags, checkpoints::for_chain(flags))
+        .map_err(|err| err as i8)
+        .unwrap();
+
+    let mut chain = vec![genesis];
+    for _ in 0..20 {
+        let prev = chain.last().unwrap();
+        let header = mine_header(prev.block_hash(), prev.time + 600, genesis.bits);
+        chain.push(header);
+    }
+    let fake_header = mine_header(chain[9].block_hash(), chain[9].time + 1200, genesis.bits);
+    let min_block_work = checkpoints::for_chain(flags)[0].min_block_work;
+    let checkpoints = [
+        checkpoints::Checkpoint {
+            height: 0,
+            block_hash: genesis.block_hash().to_byte_array(),
+            min_block_work,
+        },
+        checkpoints::Checkpoint {
+            height: 10,
+            block_hash: chain[10].block_hash().to_byte_array(),
+            min_block_work,
+        },
+        checkpoints::Checkpoint {
+            height: 15,
+            block_hash: chain[15].block_hash().to_byte_array(),
+            min_block_work: min_block_work + 1,
+        },
+    ];
+
+    let bootstrap_at = |height: usize, header: core::Header| {
+        packed::SpvBootstrap::new_builder()
+            .height((height as u32).pack())
+            .header(header.pack())
+            .period_headers(chain[..height].to_vec().pack())
+            .build()
+            .initialize_spv_client_with_checkpoints(flags, &checkpoints)
+            .map(|_| ())
+            .map_err(|err| err as i8)
+    };
+
+    assert_eq!(bootstrap_at(10, chain[10]), Ok(()));
+    assert_eq!(
+        bootstrap_at(10, fake_header),
+        Err(BootstrapError::Checkpoint as i8)
+    );
+    // Only the checkpoints before the first header in the current period are used to
+    // check the minimum work.
+    assert_eq!(bootstrap_at(20, chain[20]), Ok(()));
+    // Without the checkpoints, the fake header is accepted.
+    let bootstrap = packed::SpvBootstrap::new_builder()
+        .height(10u32.pack())
+        .header(fake_header.pack())
+        .period_headers(chain[..10].to_vec().pack())
+        .build();
+    assert!(bootstrap.initialize_spv_client_with_flags(flags).is_ok());
+
+    // The work of the first header in the current period is less than the minimum block work.
+    let checkpoints = [checkpoints::Checkpoint {
+        height: 0,
+        block_hash: genesis.block_hash().to_byte_array(),
+        min_block_work: min_block_work + 1,
+    }];
+    let result = packed::SpvBootstrap::new_builder()
+        .height(10u32.pack())
+        .header(chain[10].pack())
+        .period_headers(chain[..10].to_vec().pack())
+        .build()
+        .initialize_spv_client_with_checkpoints(flags, &checkpoints)
+        .map(|_| ())
+        .map_err(|err| err as i8);
+    assert_eq!(result, Err(BootstrapError::MinimumWork as i8));
+}
+
+#[test]
+fn regtest_reorg() {
+    tests::setup();
+
+    let flags = FLAG_CHAIN_TYPE_REGTEST;
+    let genesis = genesis_block(Network::Regtest).header;
+    // The `time_offset` is used to mine a different branch.
+    let mine_headers = |prev: &core::Header, count: u32, time_offset: u32| {
+        let mut prev = *prev;
+        (0..count)
+            .map(|_| {
+                prev = mine_header(
+                    prev.block_hash(),
+                    prev.time + 600 + time_offset,
+                    genesis.bits,
+                );
+                prev
+            })
+            .collect::<Vec<_>>()
+    };
+    let build_service = |headers: &[core::Header]| {
+        let mut service = DummyService::bootstrap(0, genesis, flags).unwrap();
+        service.update(headers.to_vec()).unwrap();
+        service
+    };
+    let verify =
+        |service: &mut DummyService, fork_height: u32, headers, tamper: &dyn Fn(_) -> _| {
+            let old_client: packed::SpvClient = service.tip_client().pack();
+            let reorg = service.reorg(fork_height, headers).unwrap();
+            let new_client: packed::SpvClient = service.tip_client().pack();
+            old_client
+                .verify_reorg_client(&new_client, tamper(reorg), flags)
+                .map(|_| new_client)
+                .map_err(|err| err as i8)
+        };
+    let no_tamper = |reorg: packed::SpvReorg| reorg;
+
+    let old_headers = mine_headers(&genesis, 30, 0);
+    let fork_height = 25;
+    let fork_header = old_headers[fork_height as usize - 1];
+
+    // The new branch has more work.
+    {
+        let new_headers = mine_headers(&fork_header, 6, 1);
+        let mut service = build_service(&old_headers);
+        let new_client =
+            verify(&mut service, fork_height, new_headers.clone(), &no_tamper).unwrap();
+        let headers = [&old_headers[..fork_height as usize], &new_headers[..]].concat();
+        let expected_client: packed::SpvClient = build_service(&headers).tip_client().pack();
+        assert_eq!(new_client.as_slice(), expected_client.as_slice());
+    }
+    // The new branch has the same work.
+    {
+        let new_headers = mine_headers(&fork_header, 5, 1);
+        let mut service = build_service(&old_headers);
+        let result = verify(&mut service, fork_height, new_headers, &no_tamper);
+        assert_eq!(result.map(|_| ()), Err(UpdateError::ReorgChainWork as i8));
+    }
+    // The new branch is not started from the fork point.
+    {
+        let new_headers = mine_headers(&old_headers[fork_height as usize - 2], 7, 1);
+        let mut service = build_service(&old_headers);
+        let result = verify(&mut service, fork_height, new_headers, &no_tamper);
+        assert_eq!(
+            result.map(|_| ()),
+            Err(UpdateError::UncontinuousHeaders as i8)
+        );
+    }
+
+    let new_headers = mine_headers(&fork_header, 6, 1);
+    let verify_tampered = |tamper: &dyn Fn(packed::SpvReorg) -> packed::SpvReorg| {
+        let mut service = build_service(&old_headers);
+        verify(&mut service, fork_height, new_headers.clone(), tamper).map(|_| ())
+    };
+    // No headers are dropped.
+    let result = verify_tampered(&|reorg| {
+        reorg
+            .as_builder()
+            .dropped_headers(Vec::<core::Header>::new().pack())
+            .build()
+    });
+    assert_eq!(result, Err(UpdateError::ReorgForkPoint as i8));
+    // The fork point is too close to the min height.
+    let result = verify_tampered(&|reorg| {
+        reorg
+            .as_builder()
+            .dropped_headers(old_headers[5..].to_vec().pack())
+            .build()
+    });
+    assert_eq!(result, Err(UpdateError::ReorgForkPoint as i8));
+    // Not enough fork headers.
+    let result = verify_tampered(&|reorg| {
+        let fork_headers = old_headers[(fork_height as usize - 10)..fork_height as usize].to_vec();
+        reorg.as_builder().fork_headers(fork_headers.pack()).build()
+    });
+    assert_eq!(result, Err(UpdateError::ReorgForkHeaders as i8));
+    // The fork headers are not in the client.
+    let result = verify_tampered(&|reorg| {
+        let mut fork_headers =
+            old_headers[(fork_height as usize - 11)..fork_height as usize].to_vec();
+        fork_headers[10] = new_headers[0];
+        reorg.as_builder().fork_headers(fork_headers.pack()).build()
+    });
+    assert_eq!(result, Err(UpdateError::ReorgForkHeaders as i8));
+    // The MMR root of the fork point is not for the fork point.
+    let old_client = build_service(&old_headers).tip_client();
+    let result = verify_tampered(&|reorg| {
+        reorg
+            .as_builder()
+            .fork_headers_mmr_root(old_client.headers_mmr_root.pack())
+            .build()
+    });
+    assert_eq!(result, Err(UpdateError::ReorgMmrProof as i8));
+    // The dropped headers are not in the client.
+    let result = verify_tampered(&|reorg| {
+        let mut dropped_headers = old_headers[fork_height as usize..].to_vec();
+        dropped_headers[0] = new_headers[0];
+        reorg
+            .as_builder()
+            .dropped_headers(dropped_headers.pack())
+            .build()
+    });
+    assert_eq!(result, Err(UpdateError::ReorgMmrProof as i8));
+
+    // The fork point is just before a difficulty adjustment boundary.
+    {
+        let old_headers = mine_headers(&genesis, DIFFCHANGE_INTERVAL + 4, 0);
+        let fork_height = DIFFCHANGE_INTERVAL - 2;
+        let fork_header = old_headers[fork_height as usize - 1];
+        let new_headers = mine_headers(&fork_header, 8, 1);
+        let mut service = build_service(&old_headers);
+        let new_client =
+            verify(&mut service, fork_height, new_headers.clone(), &no_tamper).unwrap();
+        let headers = [&old_headers[..fork_height as usize], &new_headers[..]].concat();
+        let expected_client: packed::SpvClient = build_service(&headers).tip_client().pack();
+        assert_eq!(new_client.as_slice(), expected_client.as_slice());
+
+        // The first header of the period of the fork point is required.
+        let mut service = build_service(&old_headers);
+        let result = verify(&mut service, fork_height, new_headers.clone(), &|reorg| {
+            reorg
+                .as_builder()
+                .fork_period_headers(Vec::<core::Header>::new().pack())
+                .build()
+        });
+        assert_eq!(
+            result.map(|_| ()),
+            Err(UpdateError::ReorgDifficultyPeriod as i8)
+        );
+        // The first header of the period of the fork point is not in the client.
+        let mut service = build_service(&old_headers);
+        let result = verify(&mut service, fork_height, new_headers, &|reorg| {
+            reorg
+                .as_builder()
+                .fork_period_headers(vec![old_headers[0]].pack())
+                .build()
+        });
+        assert_eq!(result.map(|_| ()), Err(UpdateError::ReorgForkHeaders as i8));
+    }
+    // The tip is the last header of a difficulty adjustment period.
+    {
+        let old_headers = mine_headers(&genesis, DIFFCHANGE_INTERVAL - 1, 0);
+        let fork_height = DIFFCHANGE_INTERVAL - 6;
+        let fork_header = old_headers[fork_height as usize - 1];
+        let new_headers = mine_headers(&fork_header, 7, 1);
+        let mut service = build_service(&old_headers);
+        let new_client =
+            verify(&mut service, fork_height, new_headers.clone(), &no_tamper).unwrap();
+        let headers = [&old_headers[..fork_height as usize], &new_headers[..]].concat();
+        let expected_client: packed::SpvClient = build_service(&headers).tip_client().pack();
+        assert_eq!(new_client.as_slice(), expected_client.as_slice());
+    }
+    // The fork point and the tip are in the same period, the first header of
+    // the period is not required.
+    {
+        let new_headers = mine_headers(&fork_header, 6, 1);
+        let mut service = build_service(&old_headers);
+        let result = verify(&mut service, fork_height, new_headers, &|reorg| {
+            reorg
+                .as_builder()
+                .fork_period_headers(vec![genesis].pack())
+                .build()
+        });
+        assert_eq!(
+            result.map(|_| ()),
+            Err(UpdateError::ReorgDifficultyPeriod as i8)
+        );
+    }
+}
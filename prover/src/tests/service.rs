@@ -39,7 +39,7 @@ fn test_spv_client(
             .map_err(|err| err as i8)
             .unwrap()
             .pack();
-        let service = DummyService::bootstrap(height, header).unwrap();
+        let service = DummyService::bootstrap(height, header, 0).unwrap();
         let actual_client: packed::SpvClient = service.tip_client().pack();
         assert_eq!(expected_client.as_slice(), actual_client.as_slice());
         service
@@ -0,0 +1,486 @@
+use bitcoin::{
+    blockdata::{
+        constants::{genesis_block, DIFFCHANGE_INTERVAL},
+        script,
+    },
+    consensus::serialize,
+    hashes::Hash as _,
+    secp256k1::{Message, Secp256k1, SecretKey},
+    sighash::{EcdsaSighashType, SighashCache},
+    Amount, Block, Network, PublicKey, ScriptBuf, TxMerkleNode, Witness,
+};
+use ckb_bitcoin_spv_verifier::{
+    constants::*,
+    error::UpdateError,
+    types::{core, packed, prelude::*},
+    utilities::{
+        bitcoin::calculate_next_compact_target,
+        signet::{create_signet_transactions, SIGNET_HEADER},
+    },
+};
+
+use crate::{
+    tests::{self, proof::build_segwit_block},
+    BlockProofGenerator, DummyService,
+};
+
+// The nonces of the signet blocks after the genesis block, which are built
+// by `build_signet_block(..)`.
+const SIGNET_BLOCK_NONCES: [u32; 3] = [25_363_489, 6_018_968, 1_446_923];
+
+// The nonces of the signet headers from height 1 to 2016, which are built by
+// `build_retarget_headers()`.
+#[rustfmt::skip]
+const RETARGET_HEADER_NONCES: [u32; DIFFCHANGE_INTERVAL as usize] = [
+    8633573, 7683994, 1287599, 4162124, 2912880, 16249302, 652194, 2114671,
+    16614949, 12287251, 4339246, 1003340, 2144345, 10979754, 6590249, 39721877,
+    9161564, 1569876, 2138823, 9655197, 234936, 2697783, 16897687, 1279456,
+    9864054, 5073239, 2024308, 1067757, 2908186, 3572085, 232674, 3721762,
+    1368750, 6644890, 482038, 7695825, 5161072, 5792082, 4597420, 576340,
+    2456702, 106274, 4003183, 679870, 2863250, 6119398, 2285051, 3492472,
+    1729079, 2524176, 8969477, 1371380, 453229, 2747482, 1222469, 2892378,
+    6731495, 3038442, 271912, 71664, 4297514, 324601, 216571, 2448542,
+    8439695, 7803250, 2921008, 2669877, 27229325, 8423748, 17010192, 935580,
+    7055025, 6172775, 10935656, 650395, 3713676, 770466, 1261983, 3546000,
+    2058601, 18395799, 2677367, 416146, 274151, 7678158, 4179325, 2853306,
+    5669221, 2161208, 11201555, 3658102, 10658673, 3508976, 5551606, 7078189,
+    3669544, 4444132, 2862432, 2543355, 12481024, 6242170, 7113573, 9527015,
+    8024286, 6105627, 540722, 1154075, 4816944, 5851508, 578373, 2646958,
+    5807019, 6283646, 4612224, 3660069, 1486635, 879985, 15256700, 1288647,
+    4325999, 7339537, 367886, 9204413, 2704477, 548960, 3757018, 3238587,
+    1707273, 24235475, 2356613, 9444558, 5237559, 5124169, 9672498, 3408523,
+    6192157, 956874, 4849997, 4744987, 7569385, 6875522, 3760019, 3908815,
+    10538288, 7470511, 39017702, 2512751, 576692, 194961, 9160808, 137377,
+    779930, 5694528, 4029736, 3383083, 6466083, 4151800, 11534794, 810889,
+    5971446, 789954, 1361738, 5595845, 9073637, 15759459, 5906630, 3711893,
+    7216043, 2357629, 10067043, 2001331, 770393, 3511959, 11977608, 1182884,
+    4983637, 619420, 201027, 1308589, 219250, 3221280, 19203458, 119515,
+    481997, 2595262, 9264313, 3260947, 6958114, 5260145, 5739573, 572069,
+    5049461, 656345, 4576085, 92021, 16769808, 5868071, 1500597, 981174,
+    2902790, 12280330, 1396177, 1753704, 6154708, 17864055, 2580677, 12173407,
+    9427891, 10321629, 956124, 4802226, 11215366, 1430681, 4610577, 1430312,
+    1137038, 472428, 516124, 2293603, 1827204, 2418834, 911650, 7365963,
+    4825331, 1174523, 9423465, 17043422, 56215, 2189088, 1564532, 12873593,
+    3665657, 1885179, 3486867, 11174622, 3375202, 50484, 13494496, 6373034,
+    1625320, 2230492, 3538613, 4101354, 4014953, 3250644, 2744206, 754489,
+    12021567, 6944964, 1636041, 3312427, 13161257, 2792325, 5266146, 2837051,
+    5037712, 41994, 1470471, 4831343, 1148007, 2023322, 2156408, 1146416,
+    3681705, 247523, 8666197, 2273359, 1572153, 104167, 19582903, 2372484,
+    2747337, 2013267, 4893689, 10356968, 4866944, 2562704, 5022535, 1497581,
+    6071096, 67489, 13380879, 10258357, 9668312, 5632907, 7332274, 5743566,
+    5933323, 2447454, 183507, 3129232, 10139663, 17831758, 4091947, 879357,
+    7305599, 723410, 5721645, 2386210, 7738817, 1129809, 1085049, 438026,
+    1006749, 2604779, 3425420, 106401, 379059, 7495112, 6820168, 673915,
+    929599, 4177835, 5865580, 547583, 8056768, 8087330, 19890900, 3778272,
+    1293776, 1155294, 262332, 8547477, 628427, 5582547, 2505872, 713530,
+    311346, 4520163, 737314, 24924952, 1600804, 8122941, 885624, 631003,
+    4204651, 13130949, 5275003, 5189955, 4068396, 3637082, 521685, 5738372,
+    4682592, 22153976, 6805523, 1135749, 5857153, 15971260, 342801, 1986152,
+    5244928, 1604781, 1119159, 8790640, 5000840, 129559, 2506932, 1904894,
+    3097273, 2140053, 3350297, 3939701, 2224251, 3809263, 6081359, 7898031,
+    11796494, 1875232, 768675, 704688, 122312, 3056281, 9451887, 19455202,
+    3364077, 1914823, 700700, 4574131, 3343356, 16929779, 4963685, 1489439,
+    249969, 8981302, 14343710, 1083628, 8612428, 15360197, 508974, 2569577,
+    4888250, 1132505, 15400966, 1112837, 912588, 10364610, 3124909, 11083385,
+    6820999, 598770, 4758324, 3307869, 2907706, 3656997, 3250326, 316224,
+    10770850, 11939787, 844285, 10527762, 5133769, 6149524, 3959352, 1387347,
+    1699224, 2690982, 2357662, 1586855, 4425438, 4907229, 924518, 3856984,
+    2146599, 806216, 22516054, 1073057, 7667546, 5980017, 424779, 3412441,
+    8503056, 7918876, 1174531, 6872851, 315599, 3756807, 8380150, 9329775,
+    6386976, 12576933, 6179845, 439290, 110892, 3273545, 569481, 70925,
+    1005443, 4260448, 4502975, 1237546, 5154986, 3607566, 774813, 1880460,
+    1809111, 4153025, 273724, 1931512, 5973599, 1357233, 2068837, 19645088,
+    8667450, 651370, 749837, 2081174, 1019241, 2195008, 2307572, 7276542,
+    160236, 3256817, 1071417, 585490, 1588875, 12009666, 2988390, 3469628,
+    2673185, 12105753, 576101, 4623550, 3870987, 8683826, 4032842, 8028027,
+    191502, 1481685, 20076617, 8340684, 2255878, 246877, 5003929, 5415681,
+    2449567, 9569931, 1528555, 10712240, 352392, 1663957, 521488, 1422415,
+    834434, 717675, 2619923, 4440000, 3860313, 2674749, 4305768, 6570018,
+    6926563, 19766971, 5496973, 1607169, 2884286, 3028535, 3353818, 4323302,
+    20083842, 5640042, 4854122, 5357982, 8118286, 3185776, 12526737, 1140441,
+    321910, 1218307, 4658670, 5439919, 172493, 5436610, 1153296, 2079993,
+    2333504, 4987168, 4436980, 2757006, 4717234, 30985228, 1949948, 3235670,
+    5623781, 11348741, 3791293, 6643714, 18248226, 8753653, 6055864, 1493948,
+    3643981, 1067946, 11111780, 245468, 988042, 3026120, 3843693, 14383542,
+    5973446, 4418439, 9034573, 4328394, 2771247, 2088014, 6492247, 344951,
+    340126, 2590435, 16244418, 5575402, 6671448, 2972203, 2176928, 5191912,
+    5669520, 234188, 1581582, 1460454, 334228, 629806, 3689767, 4478784,
+    9359925, 4554654, 1256025, 4420272, 9789412, 4170904, 3249486, 10350163,
+    2666041, 1813644, 5608927, 14431351, 1266689, 977730, 10650584, 3830330,
+    5913310, 1497404, 761776, 2276326, 6629580, 4438605, 10685328, 103905,
+    213210, 14810205, 7492651, 4246738, 2792215, 2996909, 4574799, 1917457,
+    665862, 2751918, 576112, 929925, 589134, 4243265, 6952142, 5045005,
+    2101630, 838015, 17265959, 25178, 6516529, 5123228, 25642171, 3789082,
+    6144718, 446763, 2265918, 2152899, 8428022, 3956746, 8026044, 4982237,
+    2557786, 5648633, 16228156, 5463751, 120827, 4195744, 15866418, 3126650,
+    13426798, 1359827, 6113090, 8366613, 53538, 2592665, 1814980, 5948459,
+    4727479, 155048, 12282055, 1334859, 4154959, 5323789, 2882842, 28357978,
+    80651, 1371604, 12590521, 6874109, 6941129, 2465793, 2289474, 1471266,
+    2689444, 1836086, 12267634, 5510949, 4020724, 67345, 5914539, 4405428,
+    1771204, 5113468, 3379987, 1136016, 7277997, 1580636, 645297, 5740144,
+    8610066, 4916819, 2467261, 4189573, 987733, 4608267, 14506602, 653084,
+    4838333, 944579, 12914149, 2791443, 2057609, 62737, 965391, 11226770,
+    21860624, 495471, 869941, 3945132, 4553427, 366219, 1091658, 396705,
+    6808677, 1991800, 12648451, 1437084, 4644714, 2115860, 1315794, 2495133,
+    8755677, 11281456, 3262163, 2058604, 7264726, 3507069, 9628029, 8813587,
+    1425540, 6411012, 3434146, 2689687, 14440162, 3252, 260572, 6652236,
+    6483180, 3954967, 1080304, 167269, 9329847, 2312050, 923197, 7203964,
+    7528208, 503195, 1323682, 796837, 6979806, 3129290, 677133, 3710239,
+    31550991, 824623, 820942, 2511403, 4670876, 562933, 5458122, 2398031,
+    6810721, 4031294, 9748094, 12422282, 2608188, 2647277, 267624, 4512423,
+    882068, 12526164, 3971816, 2178241, 18512709, 1040016, 980555, 261739,
+    3888496, 2153371, 742001, 9255616, 1960643, 11292612, 11216391, 1548649,
+    5040131, 319294, 3454165, 2008578, 640806, 2467922, 96235, 2938337,
+    2503821, 4303636, 7387426, 1173182, 4742356, 908019, 13888586, 1460170,
+    14196232, 4752532, 3341837, 4051115, 3046704, 3509021, 5477534, 1871070,
+    4597560, 10058745, 10997599, 428783, 5877372, 2241290, 3456081, 1314551,
+    142971, 2477707, 7860075, 1897020, 6876351, 4638946, 627756, 20982104,
+    588468, 505038, 85538, 6947548, 10615714, 2871021, 621735, 17065895,
+    25825745, 2968175, 1259724, 2567728, 2384224, 1210532, 3992177, 2801186,
+    2587476, 50378, 4238587, 18669976, 5820329, 3656637, 2470553, 1804620,
+    1589260, 11236254, 3488027, 10395903, 3266507, 3760137, 32740869, 8820271,
+    14183088, 2838843, 6931357, 10447572, 6804666, 4182880, 11161296, 2669488,
+    1182837, 1747157, 2693562, 441276, 2298271, 2004316, 11341126, 1854125,
+    309905, 1093976, 1016035, 1713035, 2031932, 1454728, 3901114, 3822188,
+    6320542, 22474705, 4681266, 5005820, 10939890, 380944, 1577955, 8368507,
+    3642746, 17057853, 1874710, 730230, 6503268, 1601088, 1983117, 1685816,
+    2160127, 623353, 2315059, 5676938, 673719, 13016303, 7986340, 10183269,
+    1415438, 12380012, 8664550, 4195319, 3251861, 1476087, 3190041, 1716884,
+    3774671, 6312106, 241940, 6316805, 12548055, 7281445, 4196075, 7702145,
+    342713, 464070, 2385807, 514727, 2844835, 7282275, 179854, 3210470,
+    2520377, 7621719, 4070524, 198822, 4477968, 1657906, 12270786, 4128769,
+    23084651, 744869, 1856936, 6105066, 10067891, 7198813, 10316314, 7674361,
+    1484035, 12320014, 494747, 9904636, 11778160, 2949697, 981727, 17798362,
+    6445072, 2543070, 3032808, 10032333, 11154872, 1982411, 18442893, 6188890,
+    3502328, 2066189, 13216650, 8983278, 16290794, 4234491, 4404830, 4782727,
+    1307155, 121666, 1261872, 3560116, 7849564, 2897327, 5193433, 838628,
+    23481, 5743471, 3728976, 3433302, 2175320, 1496858, 5104301, 4860330,
+    550328, 1312458, 1462322, 4920708, 19062201, 515612, 6247567, 2273621,
+    4505297, 2322270, 1853735, 7701278, 2227202, 4500968, 5959474, 8294266,
+    18181946, 847777, 451366, 2691012, 13330945, 10524651, 3723468, 1919622,
+    1812896, 73952, 8955107, 5106110, 2073548, 2912544, 1620292, 4249413,
+    1789177, 7884168, 5215399, 2512778, 3085324, 24060, 1210844, 2134403,
+    5310227, 3846631, 1823867, 734542, 4522696, 1418332, 3757419, 532774,
+    7005519, 6103314, 4240456, 1240035, 766733, 1994150, 8959005, 1779552,
+    383704, 6202941, 1936108, 2977495, 4171582, 5394701, 4478292, 7055528,
+    697176, 3385983, 5775613, 1835073, 3527617, 12097968, 697367, 2947122,
+    1289430, 4823198, 250, 346542, 707107, 3775304, 696970, 8931083,
+    3360520, 6114280, 4890603, 117701, 476979, 902807, 2673545, 1354858,
+    6948881, 1175276, 8205462, 12249808, 4066696, 12649851, 4230298, 963647,
+    1336625, 17001791, 471896, 15528831, 6875454, 1059466, 374831, 3477104,
+    159936, 6975068, 5253053, 10958189, 2196624, 5285002, 3896323, 10063070,
+    5614728, 8301794, 2134297, 6325661, 703194, 2604857, 293924, 999439,
+    3487740, 6842127, 18190264, 2100770, 4571744, 1768583, 15864733, 9892268,
+    745046, 5783348, 2503792, 304630, 2701915, 969794, 2138205, 755088,
+    379053, 11397533, 1595844, 3044339, 10203201, 1537398, 13097213, 8531872,
+    5336392, 298630, 3162105, 6887632, 4546820, 883529, 2781177, 1615980,
+    666066, 3334078, 110741, 1282366, 1655707, 1591513, 3332371, 2497328,
+    10096109, 4737973, 8850841, 159993, 10270448, 5748047, 2933519, 7336591,
+    4611816, 1712886, 2073504, 12546932, 513563, 8251029, 3938689, 3583843,
+    5097393, 13547082, 782762, 1620383, 1201264, 834246, 6358998, 36619211,
+    4166930, 1925975, 724896, 1625965, 5931543, 2794223, 1374008, 74517,
+    4816556, 15389427, 1070768, 1733383, 12915914, 5244805, 1193846, 3237372,
+    8891130, 8493453, 7820416, 887356, 6657780, 17828882, 280126, 663549,
+    4642852, 824423, 934485, 154353, 1949180, 474782, 383536, 4476499,
+    2901363, 1007444, 2716716, 8708317, 3326895, 4527159, 4487682, 6869870,
+    3507184, 25966765, 11098716, 1897069, 56249, 287315, 2559584, 3526434,
+    2275849, 2128337, 221129, 2346834, 13204938, 3404454, 1664885, 11126534,
+    7642379, 1909004, 4051522, 1299887, 9199554, 1017347, 2309286, 4312393,
+    4513129, 2684572, 4522020, 4124456, 1550810, 8666349, 1025945, 348728,
+    238628, 1862205, 9854248, 1372652, 127732, 4736917, 1499193, 1497290,
+    3047218, 8574521, 6555216, 1834730, 566497, 2528213, 20812606, 819581,
+    10466632, 5739253, 1468425, 638659, 2058360, 5393461, 407001, 2297710,
+    4060815, 1127394, 450709, 4239717, 365901, 6974857, 853117, 2394222,
+    6002889, 2481759, 4425910, 7018189, 23356129, 18230376, 26642, 12922006,
+    2796015, 6930600, 1209210, 2524236, 5575430, 4282078, 1386510, 1159176,
+    9081786, 3383022, 37968, 3009802, 3969701, 4997146, 5793299, 2854850,
+    747800, 44071741, 3188455, 21843111, 8822363, 4163976, 3422508, 8400531,
+    335690, 6817416, 243057, 8417615, 6646978, 3642087, 10760012, 4744412,
+    5099800, 10762719, 11702401, 2096397, 8011893, 9949605, 10007014, 2999427,
+    157433, 2055504, 14137179, 301339, 589447, 2468486, 7671281, 9091826,
+    1350720, 2047481, 78120, 15553075, 1977, 149735, 13201067, 540642,
+    5052945, 101241, 18979159, 6196682, 5170970, 661612, 2178310, 13676543,
+    1491320, 9343420, 6518683, 4395280, 6832086, 3118969, 7160603, 2230141,
+    347087, 770882, 17885195, 3019728, 7558855, 714270, 1705543, 104201,
+    654018, 138988, 3572896, 5056305, 7851141, 1891020, 4616067, 2964121,
+    11966992, 642668, 2775948, 1512993, 6802970, 3107077, 1115495, 24694062,
+    4799056, 3142147, 3534863, 4433137, 1778350, 2372448, 604684, 8868774,
+    9860921, 4860765, 42123, 9888124, 4587428, 3914816, 4628587, 11655122,
+    6480000, 2083740, 21440592, 7207250, 7042985, 1241958, 313413, 1296078,
+    5562433, 351462, 11553899, 19988891, 2238544, 584202, 3681921, 1445335,
+    8342902, 6817287, 10946474, 18036352, 7383133, 1018326, 4034324, 14441180,
+    23751222, 9094744, 234453, 8847313, 7874639, 1823419, 12939540, 4826252,
+    7619825, 3909935, 2893733, 5667256, 1028990, 15018432, 2341179, 710720,
+    1424775, 613217, 14774650, 5129554, 4640534, 1769502, 2274759, 7787168,
+    3158710, 2105023, 85709, 7429321, 26346393, 13101744, 3776331, 10443564,
+    9895971, 5764701, 7872575, 3737278, 1918313, 399427, 3866669, 25067185,
+    3893449, 26102635, 219951, 877014, 3694798, 795666, 8249094, 2379907,
+    4217268, 603373, 13164683, 1471373, 3544227, 2043817, 2670066, 1634539,
+    7530695, 5648859, 9215581, 3339834, 2097550, 1323117, 10176150, 3691800,
+    1886428, 5007463, 2915981, 20204104, 13919579, 1950510, 2244250, 2507200,
+    2236554, 7725094, 11663620, 4856501, 17767700, 830077, 2537821, 2102550,
+    8210248, 4190778, 2797491, 6340611, 695966, 1200926, 705887, 3686869,
+    12480740, 6199208, 9222868, 4733785, 7148847, 1160560, 1911170, 1150619,
+    9133832, 5858224, 2512185, 9243239, 18953828, 13631560, 2388483, 2508242,
+    3749966, 2307762, 2556770, 3090003, 5243068, 18452124, 5572692, 2943517,
+    7933555, 2581376, 1819148, 20060795, 7643445, 1721758, 1525646, 5791676,
+    3115271, 2320125, 7323055, 10257131, 8200434, 3565022, 68915, 1122836,
+    4704740, 2830214, 14483301, 9151615, 6537125, 7940931, 863932, 2273019,
+    3752445, 695268, 1318757, 6608669, 153008, 8292031, 639058, 2028159,
+    144205, 1230504, 4082691, 960575, 924521, 458759, 571310, 22234199,
+    1231607, 14380196, 377320, 1448002, 5060, 22565239, 20728717, 2571584,
+    290718, 1969406, 3247787, 6018177, 8368940, 6510081, 326558, 4600192,
+    4373015, 18317498, 530747, 5841808, 17582063, 7719454, 15223272, 4692527,
+    756266, 2217258, 4012585, 1174483, 9624556, 924728, 633208, 9629716,
+    1025597, 7917018, 1204696, 1897361, 14231659, 375605, 5765632, 1603289,
+    4109837, 12740681, 3396640, 1048964, 577666, 5743678, 1995651, 6353092,
+    7069752, 6920908, 3509338, 423838, 2001489, 2545291, 11194386, 10992315,
+    1038269, 4787176, 10519440, 1974417, 66419, 2436512, 3440837, 1769813,
+    1009227, 2541863, 2959835, 6919645, 22187143, 1573270, 39338, 360201,
+    1012771, 2470608, 3784578, 8426665, 1552632, 2034097, 76693, 5363160,
+    9070549, 6354738, 11280528, 5870029, 1171478, 2118952, 6731090, 5877744,
+    1094024, 1415195, 3438084, 6757765, 2406199, 761090, 6915560, 1136676,
+    4607871, 14148943, 16985360, 2395352, 5266777, 10781081, 422235, 1113945,
+    212166, 12181466, 1129954, 4832194, 2300525, 711328, 2579025, 2001176,
+    1442546, 13870294, 10476580, 3389337, 989910, 10124408, 5459632, 310914,
+    1076940, 7721703, 3833551, 375816, 2459232, 1052533, 6667662, 9436979,
+    516217, 1824796, 2656635, 3359018, 13846402, 248842, 2208339, 1765154,
+    6819486, 541553, 1831117, 6684850, 6972881, 15387481, 5560306, 1772679,
+    3398399, 3322819, 6629669, 2724915, 7286096, 912213, 6554489, 175979,
+    9097133, 4638646, 944932, 5272461, 1358983, 5049023, 7088154, 13424688,
+    2462367, 2934827, 395981, 5502969, 1212788, 633790, 3501829, 1847592,
+    1697538, 8811599, 7751255, 15773497, 6999961, 6467395, 2018930, 1778335,
+    2186043, 9797400, 1945749, 4103144, 1584374, 3824524, 1280152, 383448,
+    1513727, 2563467, 2629990, 2890616, 4412770, 3462264, 861320, 4474045,
+    2193259, 6635305, 3693747, 5032433, 771917, 11713008, 18860080, 3047205,
+    2811528, 1612464, 16811635, 27793, 2921799, 3097250, 5039980, 3163694,
+    5055676, 27841, 5457703, 5216346, 1399198, 2289505, 1126487, 699397,
+    1467746, 6560706, 1677375, 879011, 4728015, 13628486, 18286060, 2351556,
+    2373672, 12033723, 199229, 6530810, 3447205, 711883, 13201431, 1104935,
+    2466919, 3377159, 11979704, 781603, 2596576, 8166711, 11270879, 1578428,
+    2644741, 9324148, 1248938, 2294469, 1058751, 7761083, 587980, 4202420,
+    5342393, 10459653, 4183012, 3691489, 3757134, 1684282, 3645816, 2672168,
+    1434484, 379322, 312153, 7068707, 3736337, 17779117, 3111420, 1646685,
+    7149389, 1541237, 1719703, 193577, 5059730, 243888, 91599, 4223992,
+    3730695, 420173, 4098947, 11206338, 1245255, 188114, 1782778, 5951986,
+    9839361, 31661804, 4518184, 427330, 230273, 18193264, 1014200, 8288120,
+    1213948, 2984715, 4752379, 1315869, 2604870, 3591487, 609384, 11608523,
+    6143590, 5707389, 1710865, 13249278, 1732490, 6412035, 7168007, 9687179,
+    10296824, 1460277, 2530227, 269435, 9466861, 12662883, 2482184, 711406,
+    5292611, 18990873, 802260, 12088409, 3288829, 6576340, 2550290, 5852640,
+    2623868, 3263498, 5351650, 13167281, 662004, 5415921, 2401640, 11918140,
+    2693457, 902594, 1778866, 1898977, 2386342, 2906695, 481368, 18061446,
+    26811715, 5792765, 2011612, 1403108, 2108057, 2820721, 2929563, 1598158,
+    336974, 2472708, 9371506, 1006207, 1992907, 4995179, 10870826, 10463989,
+    830917, 3692841, 1904088, 10799236, 5230463, 1682980, 4870688, 1178493,
+    5441691, 3426107, 2529202, 1437956, 1360919, 812987, 6777508, 2494178,
+    4004066, 5768628, 1718263, 11024302, 7969762, 663254, 2121038, 1901301,
+    1249132, 4450741, 8126552, 890316, 2328565, 273945, 14948184, 956910,
+    4099650, 7854409, 807371, 2749601, 6998619, 4594788, 10375912, 978831,
+    11283657, 20255085, 15910420, 4531311, 3930776, 26496, 4568079, 5375639,
+    12266107, 580401, 3396988, 13332438, 6902436, 4151356, 6630634, 3649946,
+    1534504, 3925729, 16814124, 2670659, 191818, 1587555, 1181546, 5025394,
+    141693, 1306849, 11440553, 144101, 2113445, 833913, 506921, 389454,
+    2781184, 372652, 7409923, 281694, 711131, 4904720, 2332164, 3436557,
+    4815775, 11410256, 8469256, 3582962, 1510399, 1973082, 7775123, 18836262,
+    471458, 1657048, 4552381, 3674696, 2061846, 3485911, 547120, 16773460,
+];
+
+// The signer of the signet, its challenge is a pay to witness public key hash script.
+pub(crate) fn signet_signer() -> (SecretKey, PublicKey, ScriptBuf) {
+    let secp = Secp256k1::new();
+    let secret_key = SecretKey::from_slice(&[0x01; 32]).unwrap();
+    let pubkey = PublicKey::new(secret_key.public_key(&secp));
+    let challenge = ScriptBuf::new_p2wpkh(&pubkey.wpubkey_hash().unwrap());
+    (secret_key, pubkey, challenge)
+}
+
+// Builds a block on the previous header, and signs it as the signet signer.
+fn build_signet_block(prev: &core::Header, nonce: u32) -> Block {
+    let (secret_key, pubkey, challenge) = signet_signer();
+    let mut block = build_segwit_block(2);
+    block.header = core::Header {
+        version: bitcoin::block::Version::TWO,
+        prev_blockhash: prev.block_hash(),
+        merkle_root: block.header.merkle_root,
+        time: prev.time + 600,
+        bits: prev.bits,
+        nonce,
+    };
+    // The witness commitment is the last output of the coinbase transaction.
+    let commitment = block.txdata[0].output.last().unwrap().script_pubkey.clone();
+    let with_section = |section: &[u8]| {
+        let mut script = commitment.clone();
+        script.push_slice(<&script::PushBytes>::try_from(section).unwrap());
+        script
+    };
+    block.txdata[0].output.last_mut().unwrap().script_pubkey = with_section(&SIGNET_HEADER);
+    let merkle_branch = BlockProofGenerator::new(block.clone())
+        .generate_merkle_branch(0)
+        .unwrap();
+    let (_, tx_spending) =
+        create_signet_transactions(&block.header, &block.txdata[0], &merkle_branch, &challenge)
+            .unwrap();
+    let sighash = SighashCache::new(&tx_spending)
+        .p2wpkh_signature_hash(0, &challenge, Amount::ZERO, EcdsaSighashType::All)
+        .unwrap();
+    let msg = Message::from_digest(sighash.to_byte_array());
+    let mut sig = Secp256k1::new()
+        .sign_ecdsa(&msg, &secret_key)
+        .serialize_der()
+        .to_vec();
+    sig.push(EcdsaSighashType::All.to_u32() as u8);
+    let witness = Witness::from_slice(&[sig, pubkey.to_bytes()]);
+    let mut section = SIGNET_HEADER.to_vec();
+    section.extend_from_slice(&serialize(&ScriptBuf::new()));
+    section.extend_from_slice(&serialize(&witness));
+    block.txdata[0].output.last_mut().unwrap().script_pubkey = with_section(&section);
+    block.header.merkle_root = block.compute_merkle_root().unwrap();
+    block
+}
+
+// Builds the signet blocks after the genesis block, which are signed by the signet signer.
+pub(crate) fn build_signet_blocks() -> Vec<Block> {
+    let genesis = genesis_block(Network::Signet).header;
+    let mut blocks: Vec<Block> = Vec::new();
+    for nonce in SIGNET_BLOCK_NONCES {
+        let prev = blocks.last().map_or(genesis, |block| block.header);
+        let block = build_signet_block(&prev, nonce);
+        assert!(block.header.validate_pow(block.header.target()).is_ok());
+        blocks.push(block);
+    }
+    blocks
+}
+
+#[test]
+fn signet_update_with_blocks() {
+    tests::setup();
+
+    let flags = FLAG_CHAIN_TYPE_SIGNET;
+    let (_, _, challenge) = signet_signer();
+    let genesis = genesis_block(Network::Signet).header;
+    let blocks = build_signet_blocks();
+
+    let mut service = DummyService::bootstrap(0, genesis, flags).unwrap();
+    let old_client: packed::SpvClient = service.tip_client().pack();
+    let update = service.update_with_blocks(&blocks).unwrap();
+    let new_client: packed::SpvClient = service.tip_client().pack();
+    assert_eq!(update.coinbase_proofs().len(), blocks.len());
+
+    let result = old_client
+        .verify_new_client_with_signet_challenge(
+            &new_client,
+            update.clone(),
+            flags,
+            challenge.as_bytes(),
+        )
+        .map_err(|err| err as i8);
+    assert_eq!(result, Ok(()));
+
+    // Signed by another signer.
+    let other_challenge = ScriptBuf::new_p2wpkh(&bitcoin::WPubkeyHash::all_zeros());
+    let result = old_client
+        .verify_new_client_with_signet_challenge(
+            &new_client,
+            update.clone(),
+            flags,
+            other_challenge.as_bytes(),
+        )
+        .map_err(|err| err as i8);
+    assert_eq!(result, Err(UpdateError::SignetSolution as i8));
+
+    // Without coinbase proofs.
+    let update_without_proofs = update
+        .as_builder()
+        .coinbase_proofs(Default::default())
+        .build();
+    let result = old_client
+        .verify_new_client_with_signet_challenge(
+            &new_client,
+            update_without_proofs.clone(),
+            flags,
+            challenge.as_bytes(),
+        )
+        .map_err(|err| err as i8);
+    assert_eq!(result, Err(UpdateError::CoinbaseProof as i8));
+    // The coinbase proofs are not required, if the block solutions are not checked.
+    let result = old_client
+        .verify_new_client(&new_client, update_without_proofs, flags)
+        .map_err(|err| err as i8);
+    assert_eq!(result, Ok(()));
+
+    // The count of the coinbase proofs should be the same as the blocks.
+    let headers = blocks.iter().map(|block| block.header).collect();
+    assert!(service
+        .update_with_coinbase_proofs(headers, Vec::new())
+        .is_err());
+}
+
+// Builds the signet headers from the genesis block to the first header of
+// the next difficulty adjustment period, each header is 10 minutes after its
+// previous header.
+fn build_retarget_headers() -> Vec<core::Header> {
+    let flags = FLAG_CHAIN_TYPE_SIGNET;
+    let genesis = genesis_block(Network::Signet).header;
+    let mut headers = vec![genesis];
+    for (height, nonce) in (1..).zip(RETARGET_HEADER_NONCES) {
+        let prev = headers.last().unwrap();
+        let bits = if height % DIFFCHANGE_INTERVAL == 0 {
+            calculate_next_compact_target(genesis.bits, genesis.time, prev, flags)
+        } else {
+            genesis.bits
+        };
+        let header = core::Header {
+            version: bitcoin::block::Version::TWO,
+            prev_blockhash: prev.block_hash(),
+            merkle_root: TxMerkleNode::all_zeros(),
+            time: genesis.time + 600 * height,
+            bits,
+            nonce,
+        };
+        headers.push(header);
+    }
+    headers
+}
+
+#[test]
+fn signet_bootstrap_at_last_header_of_period() {
+    tests::setup();
+
+    let flags = FLAG_CHAIN_TYPE_SIGNET;
+    let headers = build_retarget_headers();
+    for header in &headers {
+        assert!(header.validate_pow(header.target()).is_ok());
+    }
+    let height = DIFFCHANGE_INTERVAL - 1;
+    let header = headers[height as usize];
+    let next_header = headers[height as usize + 1];
+    let period_headers = headers[..height as usize].to_vec();
+    assert_ne!(next_header.bits, header.bits);
+
+    let bootstrap = packed::SpvBootstrap::new_builder()
+        .height(height.pack())
+        .header(header.pack())
+        .period_headers(period_headers.clone().pack())
+        .build();
+    let client = bootstrap
+        .initialize_spv_client_with_flags(flags)
+        .map_err(|err| err as i8)
+        .unwrap();
+    let mut service =
+        DummyService::bootstrap_with_period_headers(height, header, period_headers, flags).unwrap();
+    assert_eq!(
+        client.pack().as_slice(),
+        service.tip_client().pack().as_slice()
+    );
+    let (start_time, bits) = client.target_adjust_info.decode().unwrap();
+    assert_eq!(start_time, headers[0].time);
+    assert_eq!(bits, next_header.bits);
+
+    let update = service.update(vec![next_header]).unwrap();
+    let result = client
+        .pack()
+        .verify_new_client(&service.tip_client().pack(), update, flags)
+        .map_err(|err| err as i8);
+    assert_eq!(result, Ok(()));
+}
@@ -0,0 +1,187 @@
+use std::{
+    fs,
+    io::{BufRead as _, BufReader, Read as _, Write as _},
+    net::{TcpListener, TcpStream},
+    str::FromStr as _,
+    sync::Arc,
+    thread,
+};
+
+use bitcoin::{consensus::serialize, hashes::Hash as _, Block, BlockHash, Txid};
+use serde_json::{json, Value};
+
+use crate::{
+    source::{Auth, BitcoindRpcClient, BlockSource, HeaderSource},
+    tests::{self, proof::regtest_service},
+    BlockProofGenerator,
+};
+
+const USER_PASS_AUTH: &str = "Basic dXNlcjpwYXNzd29yZA==";
+const COOKIE_AUTH: &str = "Basic X19jb29raWVfXzpzZWNyZXQ=";
+
+// Starts a mock server of the Bitcoin Core JSON-RPC interface, returns its URL.
+fn start_mock_bitcoind(blocks: Vec<Block>, authorization: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let blocks = Arc::new(blocks);
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let blocks = Arc::clone(&blocks);
+            thread::spawn(move || serve_http(stream.unwrap(), &blocks, authorization));
+        }
+    });
+    url
+}
+
+fn serve_http(stream: TcpStream, blocks: &[Block], authorization: &str) {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut stream = stream;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).unwrap_or(0) == 0 {
+            return;
+        }
+        assert!(line.starts_with("POST / HTTP/1.1"), "{line}");
+        let mut content_length = 0;
+        let mut authorized = false;
+        loop {
+            line.clear();
+            reader.read_line(&mut line).unwrap();
+            if line == "\r\n" {
+                break;
+            }
+            let (name, value) = line.trim_end().split_once(": ").unwrap();
+            match name.to_ascii_lowercase().as_str() {
+                "content-length" => content_length = value.parse().unwrap(),
+                "authorization" => authorized = value == authorization,
+                _ => {}
+            }
+        }
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+        if !authorized {
+            let response = "HTTP/1.1 401 Unauthorized\r\nContent-Length: 0\r\n\r\n";
+            stream.write_all(response.as_bytes()).unwrap();
+            continue;
+        }
+        let request: Value = serde_json::from_slice(&body).unwrap();
+        let (result, error) = match handle_request(&request, blocks) {
+            Ok(result) => (result, Value::Null),
+            Err(message) => (Value::Null, json!({"code": -5, "message": message})),
+        };
+        let response = json!({"result": result, "error": error, "id": request["id"]}).to_string();
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{response}",
+            response.len()
+        )
+        .unwrap();
+    }
+}
+
+fn handle_request(request: &Value, blocks: &[Block]) -> Result<Value, &'static str> {
+    let params = &request["params"];
+    let find_block = |value: &Value| {
+        let block_hash = BlockHash::from_str(value.as_str().unwrap()).unwrap();
+        blocks
+            .iter()
+            .find(|block| block.block_hash() == block_hash)
+            .ok_or("Block not found")
+    };
+    let to_hex = |data: Vec<u8>| json!(bitcoin::hex::DisplayHex::to_lower_hex_string(&data[..]));
+    match request["method"].as_str().unwrap() {
+        "getblockcount" => Ok(json!(blocks.len() - 1)),
+        "getblockhash" => {
+            let height = params[0].as_u64().unwrap() as usize;
+            let block = blocks.get(height).ok_or("Block height out of range")?;
+            Ok(json!(block.block_hash().to_string()))
+        }
+        "getblockheader" => {
+            assert_eq!(params[1], json!(false));
+            find_block(&params[0]).map(|block| to_hex(serialize(&block.header)))
+        }
+        "getblock" => {
+            assert_eq!(params[1], json!(0));
+            find_block(&params[0]).map(|block| to_hex(serialize(block)))
+        }
+        "gettxoutproof" => {
+            let block = find_block(&params[1])?;
+            let indexes = params[0]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|txid| {
+                    let txid = Txid::from_str(txid.as_str().unwrap()).unwrap();
+                    block
+                        .txdata
+                        .iter()
+                        .position(|tx| tx.txid() == txid)
+                        .map(|index| index as u32)
+                        .ok_or("Not all transactions found in specified or retrieved block")
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let proof = BlockProofGenerator::new(block.clone())
+                .generate_txoutproof_via_indexes(&indexes)
+                .unwrap();
+            Ok(to_hex(proof))
+        }
+        _ => Err("Method not found"),
+    }
+}
+
+#[test]
+fn bitcoind_rpc_client() {
+    tests::setup();
+
+    let (_, blocks) = regtest_service(5);
+    let url = start_mock_bitcoind(blocks.clone(), USER_PASS_AUTH);
+    let auth = Auth::UserPass("user".to_owned(), "password".to_owned());
+    let client = BitcoindRpcClient::new(&url, auth).unwrap();
+
+    assert_eq!(client.tip_height().unwrap(), 5);
+    for (height, block) in blocks.iter().enumerate() {
+        let block_hash = client.block_hash(height as u32).unwrap();
+        assert_eq!(block_hash, block.block_hash());
+        assert_eq!(client.header(&block_hash).unwrap(), block.header);
+        assert_eq!(client.block(&block_hash).unwrap(), *block);
+    }
+    assert!(client.block_hash(6).is_err());
+    assert!(client.header(&BlockHash::from_byte_array([0; 32])).is_err());
+
+    let headers = client.headers(2, 10).unwrap();
+    let expected = blocks[2..]
+        .iter()
+        .map(|block| block.header)
+        .collect::<Vec<_>>();
+    assert_eq!(headers, expected);
+
+    let block = &blocks[4];
+    let txids = [block.txdata[1].txid(), block.txdata[3].txid()];
+    let expected = BlockProofGenerator::new(block.clone())
+        .generate_txoutproof_via_indexes(&[1, 3])
+        .unwrap();
+    let proof = client.txout_proof(&txids, &block.block_hash()).unwrap();
+    assert_eq!(proof, expected);
+    assert!(client.txout_proof(&txids, &blocks[1].block_hash()).is_err());
+}
+
+#[test]
+fn bitcoind_rpc_client_auth() {
+    tests::setup();
+
+    let (_, blocks) = regtest_service(1);
+    let url = start_mock_bitcoind(blocks, COOKIE_AUTH);
+
+    let dir = tests::TempDir::new();
+    let cookie_file = dir.path().join(".cookie");
+    fs::write(&cookie_file, "__cookie__:secret").unwrap();
+    let client = BitcoindRpcClient::new(&url, Auth::CookieFile(cookie_file)).unwrap();
+    assert_eq!(client.tip_height().unwrap(), 1);
+
+    let auth = Auth::UserPass("user".to_owned(), "password".to_owned());
+    let client = BitcoindRpcClient::new(&url, auth).unwrap();
+    assert!(client.tip_height().is_err());
+
+    let missing_cookie_file = dir.path().join("missing");
+    assert!(BitcoindRpcClient::new(&url, Auth::CookieFile(missing_cookie_file)).is_err());
+}
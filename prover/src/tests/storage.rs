@@ -0,0 +1,266 @@
+use std::fs::OpenOptions;
+use std::io::Write as _;
+
+use bitcoin::{blockdata::constants::genesis_block, Network};
+use ckb_bitcoin_spv_verifier::{
+    constants::*,
+    types::{core, packed, prelude::*},
+};
+
+use crate::{
+    result::{Error, Result},
+    storage::{FileStorage, MemStorage, SpvState, SpvStorage},
+    tests::{self, regtest::mine_headers},
+    SpvService,
+};
+
+fn assert_same_services<S1: SpvStorage, S2: SpvStorage>(
+    lhs: &SpvService<S1>,
+    rhs: &SpvService<S2>,
+) {
+    assert_eq!(lhs.flags(), rhs.flags());
+    assert_eq!(
+        lhs.tip_client().pack().as_slice(),
+        rhs.tip_client().pack().as_slice()
+    );
+    for height in lhs.min_height()..=lhs.max_height() {
+        let lhs_proof = lhs.generate_header_proof(height).unwrap().unwrap();
+        let rhs_proof = rhs.generate_header_proof(height).unwrap().unwrap();
+        assert_eq!(lhs_proof.pack().as_slice(), rhs_proof.pack().as_slice());
+        let lhs_header = lhs.header(height).unwrap().unwrap();
+        let rhs_header = rhs.header(height).unwrap().unwrap();
+        assert_eq!(lhs_header, rhs_header);
+    }
+}
+
+// A storage which crashes when the state is saved after MMR nodes are written.
+struct CrashingStorage {
+    inner: FileStorage,
+    crash: bool,
+    nodes_written: bool,
+}
+
+impl SpvStorage for CrashingStorage {
+    fn mmr_node(&self, position: u64) -> Result<Option<packed::HeaderDigest>> {
+        self.inner.mmr_node(position)
+    }
+
+    fn append_mmr_nodes(&mut self, position: u64, nodes: Vec<packed::HeaderDigest>) -> Result<()> {
+        self.nodes_written = true;
+        self.inner.append_mmr_nodes(position, nodes)
+    }
+
+    fn header(&self, height: u32) -> Result<Option<core::Header>> {
+        self.inner.header(height)
+    }
+
+    fn append_headers(&mut self, height: u32, headers: &[core::Header]) -> Result<()> {
+        self.inner.append_headers(height, headers)
+    }
+
+    fn state(&self) -> Result<Option<SpvState>> {
+        self.inner.state()
+    }
+
+    fn save_state(&mut self, state: &SpvState) -> Result<()> {
+        if self.crash && self.nodes_written {
+            return Err(Error::other("crashed"));
+        }
+        self.nodes_written = false;
+        self.inner.save_state(state)
+    }
+}
+
+#[test]
+fn file_storage_resume() {
+    tests::setup();
+
+    let dir = tests::TempDir::new();
+    let flags = FLAG_CHAIN_TYPE_REGTEST;
+    let genesis = genesis_block(Network::Regtest).header;
+    let headers = mine_headers(&genesis, 40, 0);
+
+    let mut mem_service = SpvService::bootstrap(MemStorage::default(), 0, genesis, flags).unwrap();
+    {
+        let storage = FileStorage::open(dir.path()).unwrap();
+        SpvService::bootstrap(storage, 0, genesis, flags).unwrap();
+    }
+    for chunk in headers.chunks(7) {
+        let mut file_service = SpvService::resume(FileStorage::open(dir.path()).unwrap()).unwrap();
+        assert_same_services(&mem_service, &file_service);
+        let expected = mem_service.update(chunk.to_vec()).unwrap();
+        let actual = file_service.update(chunk.to_vec()).unwrap();
+        assert_eq!(expected.as_slice(), actual.as_slice());
+    }
+
+    // Reorganize, then rollback after resuming.
+    let fork_height = 35;
+    let new_headers = mine_headers(&headers[fork_height as usize - 1], 8, 1);
+    let mut file_service = SpvService::resume(FileStorage::open(dir.path()).unwrap()).unwrap();
+    let prev_client = file_service.tip_client();
+    let expected = mem_service.reorg(fork_height, new_headers.clone()).unwrap();
+    let actual = file_service.reorg(fork_height, new_headers).unwrap();
+    assert_eq!(expected.as_slice(), actual.as_slice());
+    drop(file_service);
+
+    let mut file_service = SpvService::resume(FileStorage::open(dir.path()).unwrap()).unwrap();
+    assert_same_services(&mem_service, &file_service);
+    // The client before the reorg is not in the current chain.
+    assert!(file_service.rollback_to(prev_client).is_err());
+    let client_30 = {
+        let mut service = SpvService::bootstrap(MemStorage::default(), 0, genesis, flags).unwrap();
+        service.update(headers[..30].to_vec()).unwrap();
+        service.tip_client()
+    };
+    mem_service.rollback_to(client_30.clone()).unwrap();
+    file_service.rollback_to(client_30).unwrap();
+    drop(file_service);
+
+    let mut file_service = SpvService::resume(FileStorage::open(dir.path()).unwrap()).unwrap();
+    assert_same_services(&mem_service, &file_service);
+    let new_headers = mine_headers(&headers[29], 3, 2);
+    let expected = mem_service.update(new_headers.clone()).unwrap();
+    let actual = file_service.update(new_headers).unwrap();
+    assert_eq!(expected.as_slice(), actual.as_slice());
+    assert_same_services(&mem_service, &file_service);
+}
+
+#[test]
+fn file_storage_drops_incomplete_records() {
+    tests::setup();
+
+    let dir = tests::TempDir::new();
+    let flags = FLAG_CHAIN_TYPE_REGTEST;
+    let genesis = genesis_block(Network::Regtest).header;
+    let headers = mine_headers(&genesis, 10, 0);
+
+    let mut mem_service = SpvService::bootstrap(MemStorage::default(), 0, genesis, flags).unwrap();
+    mem_service.update(headers[..5].to_vec()).unwrap();
+    {
+        let storage = FileStorage::open(dir.path()).unwrap();
+        let mut service = SpvService::bootstrap(storage, 0, genesis, flags).unwrap();
+        service.update(headers[..5].to_vec()).unwrap();
+    }
+    // An interrupted write leaves incomplete records.
+    for name in ["mmr.dat", "headers.dat"] {
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(dir.path().join(name))
+            .unwrap();
+        file.write_all(&[0xff; 33]).unwrap();
+    }
+
+    let mut file_service = SpvService::resume(FileStorage::open(dir.path()).unwrap()).unwrap();
+    assert_same_services(&mem_service, &file_service);
+    let expected = mem_service.update(headers[5..].to_vec()).unwrap();
+    let actual = file_service.update(headers[5..].to_vec()).unwrap();
+    assert_eq!(expected.as_slice(), actual.as_slice());
+    assert_same_services(&mem_service, &file_service);
+}
+
+#[test]
+fn file_storage_bootstrap_and_resume() {
+    tests::setup();
+
+    let dir = tests::TempDir::new();
+    let flags = FLAG_CHAIN_TYPE_REGTEST;
+    let genesis = genesis_block(Network::Regtest).header;
+
+    let storage = FileStorage::open(dir.path()).unwrap();
+    assert!(storage.state().unwrap().is_none());
+    assert!(SpvService::resume(storage).is_err());
+
+    let storage = FileStorage::open(dir.path()).unwrap();
+    let service = SpvService::bootstrap(storage, 0, genesis, flags).unwrap();
+    let client: packed::SpvClient = service.tip_client().pack();
+    let storage = service.into_storage();
+    assert!(SpvService::bootstrap(storage, 0, genesis, flags).is_err());
+
+    let service = SpvService::resume(FileStorage::open(dir.path()).unwrap()).unwrap();
+    assert_eq!(service.flags(), flags);
+    assert_eq!(service.tip_client().pack().as_slice(), client.as_slice());
+    assert!(service.header(1).unwrap().is_none());
+    assert!(service.generate_header_proof(1).unwrap().is_none());
+}
+
+#[test]
+fn resume_checks_the_stored_client() {
+    tests::setup();
+
+    let dir = tests::TempDir::new();
+    let flags = FLAG_CHAIN_TYPE_REGTEST;
+    let genesis = genesis_block(Network::Regtest).header;
+    let headers = mine_headers(&genesis, 20, 0);
+    let fork_height = 15;
+    let new_headers = mine_headers(&headers[fork_height as usize - 1], 8, 1);
+
+    let storage = FileStorage::open(dir.path()).unwrap();
+    let mut service = SpvService::bootstrap(storage, 0, genesis, flags).unwrap();
+    service.update(headers.clone()).unwrap();
+    let old_state = service.storage().state().unwrap().unwrap();
+    service.reorg(fork_height, new_headers).unwrap();
+    let new_state = service.storage().state().unwrap().unwrap();
+    drop(service);
+    assert!(SpvService::resume(FileStorage::open(dir.path()).unwrap()).is_ok());
+
+    // A stale client, which doesn't match the stored data.
+    let mut storage = FileStorage::open(dir.path()).unwrap();
+    storage.save_state(&old_state).unwrap();
+    assert!(SpvService::resume(storage).is_err());
+
+    // The tip block hash is not matched.
+    let mut storage = FileStorage::open(dir.path()).unwrap();
+    let mut state = new_state.clone();
+    state.client.tip_block_hash = headers[19].block_hash().into();
+    storage.save_state(&state).unwrap();
+    assert!(SpvService::resume(storage).is_err());
+
+    let mut storage = FileStorage::open(dir.path()).unwrap();
+    storage.save_state(&new_state).unwrap();
+    let service = SpvService::resume(storage).unwrap();
+    assert_eq!(
+        service.tip_client().pack().as_slice(),
+        new_state.client.pack().as_slice()
+    );
+}
+
+#[test]
+fn file_storage_resume_after_interrupted_reorg() {
+    tests::setup();
+
+    let dir = tests::TempDir::new();
+    let flags = FLAG_CHAIN_TYPE_REGTEST;
+    let genesis = genesis_block(Network::Regtest).header;
+    let headers = mine_headers(&genesis, 20, 0);
+    let fork_height = 15;
+    let new_headers = mine_headers(&headers[fork_height as usize - 1], 8, 1);
+
+    let mut mem_service = SpvService::bootstrap(MemStorage::default(), 0, genesis, flags).unwrap();
+    mem_service.update(headers.clone()).unwrap();
+    let storage = CrashingStorage {
+        inner: FileStorage::open(dir.path()).unwrap(),
+        crash: false,
+        nodes_written: false,
+    };
+    let mut service = SpvService::bootstrap(storage, 0, genesis, flags).unwrap();
+    service.update(headers.clone()).unwrap();
+
+    // Crash after the MMR nodes are replaced, but before the new client is saved.
+    let mut storage = service.into_storage();
+    storage.crash = true;
+    let mut service = SpvService::resume(storage).unwrap();
+    assert!(service.reorg(fork_height, new_headers.clone()).is_err());
+    drop(service);
+
+    // The service is resumed at the fork point.
+    let mut file_service = SpvService::resume(FileStorage::open(dir.path()).unwrap()).unwrap();
+    assert_eq!(file_service.max_height(), fork_height);
+    mem_service.rollback_to(file_service.tip_client()).unwrap();
+    assert_same_services(&mem_service, &file_service);
+
+    // Then the new headers could be appended again.
+    let expected = mem_service.update(new_headers.clone()).unwrap();
+    let actual = file_service.update(new_headers).unwrap();
+    assert_eq!(expected.as_slice(), actual.as_slice());
+    assert_same_services(&mem_service, &file_service);
+}
@@ -0,0 +1,354 @@
+use bitcoin::{blockdata::constants::genesis_block, Network};
+use ckb_bitcoin_spv_verifier::{
+    constants::*,
+    error::TransitionError,
+    transition::{self, Transition},
+    types::{core, packed, prelude::*},
+};
+
+use crate::{
+    tests::{
+        self,
+        regtest::mine_headers,
+        signet::{build_signet_blocks, signet_signer},
+    },
+    DummyService,
+};
+
+fn type_args(clients_count: u8, flags: u8) -> Vec<u8> {
+    core::SpvTypeArgs {
+        type_id: core::Hash::all_zeros(),
+        clients_count,
+        flags,
+    }
+    .pack()
+    .as_slice()
+    .to_vec()
+}
+
+fn info_cell(tip_client_id: u8) -> Vec<u8> {
+    core::SpvInfo { tip_client_id }.pack().as_slice().to_vec()
+}
+
+fn client_cell(client: &core::SpvClient, id: u8) -> Vec<u8> {
+    let mut client = client.clone();
+    client.id = id;
+    client.pack().as_slice().to_vec()
+}
+
+fn verify(
+    args: &[u8],
+    inputs: &[Vec<u8>],
+    outputs: &[Vec<u8>],
+    cell_deps: &[Vec<u8>],
+    witness: &[u8],
+) -> Result<Transition, i8> {
+    fn to_slices(cells: &[Vec<u8>]) -> Vec<&[u8]> {
+        cells.iter().map(Vec::as_slice).collect()
+    }
+    transition::verify_transition(
+        args,
+        &to_slices(inputs),
+        &to_slices(outputs),
+        &to_slices(cell_deps),
+        witness,
+    )
+    .map_err(|err| err as i8)
+}
+
+#[test]
+fn parse_args() {
+    tests::setup();
+
+    let parse = |args: &[u8]| {
+        transition::parse_args(args)
+            .map(|_| ())
+            .map_err(|err| err as i8)
+    };
+
+    assert!(parse(&type_args(MIN_CLIENTS_COUNT, FLAG_CHAIN_TYPE_MAINNET)).is_ok());
+    assert!(parse(&type_args(MAX_CLIENTS_COUNT, FLAG_CHAIN_TYPE_TESTNET4)).is_ok());
+    assert_eq!(
+        parse(&type_args(3, FLAG_CHAIN_TYPE_REGTEST)[1..]),
+        Err(TransitionError::DecodeArgs as i8)
+    );
+    for clients_count in [0, MIN_CLIENTS_COUNT - 1, MAX_CLIENTS_COUNT + 1] {
+        assert_eq!(
+            parse(&type_args(clients_count, FLAG_CHAIN_TYPE_REGTEST)),
+            Err(TransitionError::ClientsCount as i8)
+        );
+    }
+    for flags in [
+        0b1010_0000,
+        0b1100_0000,
+        0b1111_0000,
+        FLAG_CHAIN_TYPE_REGTEST | 0b1,
+    ] {
+        assert_eq!(
+            parse(&type_args(3, flags)),
+            Err(TransitionError::Flags as i8)
+        );
+    }
+
+    let challenge = [0x51];
+    let args = [type_args(3, FLAG_CHAIN_TYPE_SIGNET), challenge.to_vec()].concat();
+    let spv_args = transition::parse_args(&args)
+        .map_err(|err| err as i8)
+        .unwrap();
+    assert_eq!(spv_args.signet_challenge, Some(&challenge[..]));
+    // Without the challenge, the signet block solutions are not checked.
+    let args = type_args(3, FLAG_CHAIN_TYPE_SIGNET);
+    let spv_args = transition::parse_args(&args)
+        .map_err(|err| err as i8)
+        .unwrap();
+    assert_eq!(spv_args.signet_challenge, None);
+    let args = [type_args(3, FLAG_CHAIN_TYPE_REGTEST), challenge.to_vec()].concat();
+    assert_eq!(parse(&args), Err(TransitionError::SignetChallenge as i8));
+}
+
+#[test]
+fn bootstrap_and_destroy() {
+    tests::setup();
+
+    let flags = FLAG_CHAIN_TYPE_REGTEST;
+    let args = type_args(3, flags);
+    let genesis = genesis_block(Network::Regtest).header;
+    let client = DummyService::bootstrap(0, genesis, flags)
+        .unwrap()
+        .tip_client();
+    let witness = packed::SpvBootstrap::new_builder()
+        .height(0u32.pack())
+        .header(genesis.pack())
+        .build();
+    let cells = vec![
+        info_cell(0),
+        client_cell(&client, 2),
+        client_cell(&client, 0),
+        client_cell(&client, 1),
+    ];
+
+    let bootstrap = |outputs: &[Vec<u8>]| verify(&args, &[], outputs, &[], witness.as_slice());
+    assert_eq!(bootstrap(&cells), Ok(Transition::Bootstrap));
+    let mut outputs = cells.clone();
+    outputs[0] = info_cell(1);
+    assert_eq!(bootstrap(&outputs), Err(TransitionError::TipClientId as i8));
+    let mut outputs = cells.clone();
+    outputs[1] = client_cell(&client, 1);
+    assert_eq!(bootstrap(&outputs), Err(TransitionError::ClientId as i8));
+    assert_eq!(
+        bootstrap(&cells[..3]),
+        Err(TransitionError::ClientCells as i8)
+    );
+    assert_eq!(bootstrap(&cells[1..]), Err(TransitionError::InfoCell as i8));
+    let mut outputs = cells.clone();
+    outputs[1] = {
+        let mut other_client = client.clone();
+        other_client.tip_block_hash = core::Hash::all_zeros();
+        client_cell(&other_client, 2)
+    };
+    assert_eq!(bootstrap(&outputs), Err(TransitionError::Bootstrap as i8));
+    outputs.push(vec![0; 3]);
+    assert_eq!(
+        bootstrap(&outputs),
+        Err(TransitionError::DecodeCellData as i8)
+    );
+
+    let destroy = |inputs: &[Vec<u8>]| verify(&args, inputs, &[], &[], &[]);
+    assert_eq!(destroy(&cells), Ok(Transition::Destroy));
+    assert_eq!(
+        destroy(&cells[..3]),
+        Err(TransitionError::ClientCells as i8)
+    );
+    assert_eq!(destroy(&cells[1..]), Err(TransitionError::InfoCell as i8));
+}
+
+#[test]
+fn update() {
+    tests::setup();
+
+    let flags = FLAG_CHAIN_TYPE_REGTEST;
+    let args = type_args(3, flags);
+    let genesis = genesis_block(Network::Regtest).header;
+    let mut service = DummyService::bootstrap(0, genesis, flags).unwrap();
+    let old_client = service.tip_client();
+    let update = service.update(mine_headers(&genesis, 10, 0)).unwrap();
+    let new_client = service.tip_client();
+
+    // All clients are the same, the tip client is client-1, so client-2 is updated.
+    let inputs = vec![info_cell(1), client_cell(&old_client, 2)];
+    let outputs = vec![info_cell(2), client_cell(&new_client, 2)];
+    let cell_deps = vec![client_cell(&old_client, 0), client_cell(&old_client, 1)];
+    let verify_update = |inputs: &[Vec<u8>], outputs: &[Vec<u8>], cell_deps: &[Vec<u8>]| {
+        verify(&args, inputs, outputs, cell_deps, update.as_slice())
+    };
+
+    assert_eq!(
+        verify_update(&inputs, &outputs, &cell_deps),
+        Ok(Transition::Update)
+    );
+    // The tip client is rotated.
+    {
+        let inputs = vec![info_cell(2), client_cell(&old_client, 0)];
+        let outputs = vec![info_cell(0), client_cell(&new_client, 0)];
+        let cell_deps = vec![client_cell(&old_client, 2)];
+        assert_eq!(
+            verify_update(&inputs, &outputs, &cell_deps),
+            Ok(Transition::Update)
+        );
+    }
+    assert_eq!(
+        verify_update(&inputs, &[info_cell(0), outputs[1].clone()], &cell_deps),
+        Err(TransitionError::TipClientId as i8)
+    );
+    assert_eq!(
+        verify_update(&[info_cell(3), inputs[1].clone()], &outputs, &cell_deps),
+        Err(TransitionError::TipClientId as i8)
+    );
+    assert_eq!(
+        verify_update(
+            &[info_cell(1), client_cell(&old_client, 0)],
+            &outputs,
+            &cell_deps
+        ),
+        Err(TransitionError::ClientId as i8)
+    );
+    assert_eq!(
+        verify_update(
+            &inputs,
+            &[info_cell(2), client_cell(&new_client, 0)],
+            &cell_deps
+        ),
+        Err(TransitionError::ClientId as i8)
+    );
+    assert_eq!(
+        verify_update(&inputs, &outputs[..1], &cell_deps),
+        Err(TransitionError::ClientCells as i8)
+    );
+    assert_eq!(
+        verify_update(&inputs, &outputs, &cell_deps[..1]),
+        Err(TransitionError::TipClientNotFound as i8)
+    );
+    // The new client is not updated from the tip client.
+    assert_eq!(
+        verify_update(
+            &inputs,
+            &[info_cell(2), client_cell(&old_client, 2)],
+            &cell_deps
+        ),
+        Err(TransitionError::Update as i8)
+    );
+    assert_eq!(
+        verify(&args, &inputs, &outputs, &cell_deps, &[0; 4]),
+        Err(TransitionError::DecodeWitness as i8)
+    );
+}
+
+#[test]
+fn update_signet() {
+    tests::setup();
+
+    let flags = FLAG_CHAIN_TYPE_SIGNET;
+    let (_, _, challenge) = signet_signer();
+    let genesis = genesis_block(Network::Signet).header;
+    let blocks = build_signet_blocks();
+    let mut service = DummyService::bootstrap(0, genesis, flags).unwrap();
+    let old_client = service.tip_client();
+    let update = service.update_with_blocks(&blocks).unwrap();
+    let new_client = service.tip_client();
+    let update_without_proofs = update
+        .clone()
+        .as_builder()
+        .coinbase_proofs(Default::default())
+        .build();
+
+    let inputs = vec![info_cell(0), client_cell(&old_client, 1)];
+    let outputs = vec![info_cell(1), client_cell(&new_client, 1)];
+    let cell_deps = vec![client_cell(&old_client, 0)];
+    let verify_update = |args: &[u8], update: &packed::SpvUpdate| {
+        verify(args, &inputs, &outputs, &cell_deps, update.as_slice())
+    };
+
+    let args = [type_args(2, flags), challenge.to_bytes()].concat();
+    assert_eq!(verify_update(&args, &update), Ok(Transition::Update));
+    assert_eq!(
+        verify_update(&args, &update_without_proofs),
+        Err(TransitionError::Update as i8)
+    );
+    // Without the challenge, the signet block solutions are not checked.
+    let args = type_args(2, flags);
+    assert_eq!(verify_update(&args, &update), Ok(Transition::Update));
+    assert_eq!(
+        verify_update(&args, &update_without_proofs),
+        Ok(Transition::Update)
+    );
+}
+
+#[test]
+fn reorg() {
+    tests::setup();
+
+    let flags = FLAG_CHAIN_TYPE_REGTEST;
+    let args = type_args(3, flags);
+    let genesis = genesis_block(Network::Regtest).header;
+    let old_headers = mine_headers(&genesis, 30, 0);
+    let mut service = DummyService::bootstrap(0, genesis, flags).unwrap();
+    let client_0 = service.tip_client();
+    service.update(old_headers[..20].to_vec()).unwrap();
+    let client_1 = service.tip_client();
+    service.update(old_headers[20..].to_vec()).unwrap();
+    let client_2 = service.tip_client();
+
+    // The tip client is client-2, and the fork point is at height 25.
+    let fork_height = 25;
+    let new_headers = mine_headers(&old_headers[fork_height as usize - 1], 6, 1);
+    let reorg = service.reorg(fork_height, new_headers).unwrap();
+    let new_client = service.tip_client();
+
+    let inputs = vec![
+        info_cell(2),
+        client_cell(&client_0, 0),
+        client_cell(&client_1, 1),
+        client_cell(&client_2, 2),
+    ];
+    // Client-1 (height 20) is not changed, client-2 (height 30) is replaced.
+    let outputs = vec![
+        info_cell(0),
+        client_cell(&new_client, 0),
+        client_cell(&client_1, 1),
+        client_cell(&new_client, 2),
+    ];
+    let verify_reorg = |inputs: &[Vec<u8>], outputs: &[Vec<u8>]| {
+        verify(&args, inputs, outputs, &[], reorg.as_slice())
+    };
+
+    assert_eq!(verify_reorg(&inputs, &outputs), Ok(Transition::Reorg));
+    let mut invalid_outputs = outputs.clone();
+    invalid_outputs[3] = client_cell(&client_2, 2);
+    assert_eq!(
+        verify_reorg(&inputs, &invalid_outputs),
+        Err(TransitionError::StaleClient as i8)
+    );
+    let mut invalid_outputs = outputs.clone();
+    invalid_outputs[2] = client_cell(&new_client, 1);
+    assert_eq!(
+        verify_reorg(&inputs, &invalid_outputs),
+        Err(TransitionError::StaleClient as i8)
+    );
+    let mut invalid_outputs = outputs.clone();
+    invalid_outputs[0] = info_cell(2);
+    assert_eq!(
+        verify_reorg(&inputs, &invalid_outputs),
+        Err(TransitionError::TipClientId as i8)
+    );
+    assert_eq!(
+        verify_reorg(&inputs[..3], &outputs[..3]),
+        Err(TransitionError::ClientCells as i8)
+    );
+    // The new tip client is not reorganized from the tip client.
+    let mut invalid_outputs = outputs.clone();
+    invalid_outputs[1] = client_cell(&client_1, 0);
+    assert_eq!(
+        verify_reorg(&inputs, &invalid_outputs),
+        Err(TransitionError::Reorg as i8)
+    );
+}
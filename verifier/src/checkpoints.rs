@@ -0,0 +1,216 @@
+//! Checkpoints of bitcoin chains.
+//!
+//! The checkpoints are used to validate the headers when bootstrapping,
+//! to avoid bootstrapping onto a fake low-difficulty chain.
+
+use primitive_types::U256;
+
+use crate::types::core::BitcoinChainType;
+
+/// A known block of a chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// The height of the block.
+    pub height: u32,
+    /// The hash of the block, in the internal byte order.
+    pub block_hash: [u8; 32],
+    /// A lower bound of the work of each single block at or after this checkpoint,
+    /// excluding the minimum-difficulty blocks on testnet.
+    ///
+    /// It's not a bound of the cumulative chain work, only the work of the first
+    /// block in the bootstrap period is checked against it.
+    pub min_block_work: U256,
+}
+
+impl Checkpoint {
+    const fn new(height: u32, block_hash: &str, min_block_work: U256) -> Self {
+        Self {
+            height,
+            block_hash: block_hash_from_hex(block_hash),
+            min_block_work,
+        }
+    }
+}
+
+/// The work of a difficulty-1 block, it's also the work of the genesis blocks
+/// of mainnet, testnet and testnet4.
+pub const DIFFICULTY_1_WORK: U256 = U256([0x1_0001_0001, 0, 0, 0]);
+
+/// Checkpoints of mainnet.
+pub const MAINNET: &[Checkpoint] = &[
+    Checkpoint::new(
+        0,
+        "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f",
+        DIFFICULTY_1_WORK,
+    ),
+    Checkpoint::new(
+        11111,
+        "0000000069e244f73d78e8fd29ba2fd2ed618bd6fa2ee92559f542fdb26e7c1d",
+        DIFFICULTY_1_WORK,
+    ),
+    Checkpoint::new(
+        33333,
+        "000000002dd5588a74784eaa7ab0507a18ad16a236e7b1ce69f00d7ddfb5d0a6",
+        DIFFICULTY_1_WORK,
+    ),
+    Checkpoint::new(
+        74000,
+        "0000000000573993a3c9e41ce34471c079dcf5f52a0e824a81e7f953b8661a20",
+        DIFFICULTY_1_WORK,
+    ),
+    Checkpoint::new(
+        105000,
+        "00000000000291ce28027faea320c8d2b054b2e0fe44a773f3eefb151d6bdc97",
+        DIFFICULTY_1_WORK,
+    ),
+    Checkpoint::new(
+        134444,
+        "00000000000005b12ffd4cd315cd34ffd4a594f430ac814c91184a0d42d2b0fe",
+        DIFFICULTY_1_WORK,
+    ),
+    Checkpoint::new(
+        168000,
+        "000000000000099e61ea72015e79632f216fe6cb33d7899acb35b75c8303b763",
+        DIFFICULTY_1_WORK,
+    ),
+    Checkpoint::new(
+        193000,
+        "000000000000059f452a5f7340de6682a977387c17010ff6e6c3bd83ca8b1317",
+        DIFFICULTY_1_WORK,
+    ),
+    Checkpoint::new(
+        210000,
+        "000000000000048b95347e83192f69cf0366076336c639f9b7228e9ba171342e",
+        work_of_difficulty(1_000_000),
+    ),
+    Checkpoint::new(
+        216116,
+        "00000000000001b4f4b433e81ee46494af945cf96014816a4e2370f11b23df4e",
+        work_of_difficulty(1_000_000),
+    ),
+    Checkpoint::new(
+        225430,
+        "00000000000001c108384350f74090433e7fcf79a606b8e797f065b130575932",
+        work_of_difficulty(1_000_000),
+    ),
+    Checkpoint::new(
+        250000,
+        "000000000000003887df1f29024b06fc2200b55f8af8f35453d7be294df2d214",
+        work_of_difficulty(10_000_000),
+    ),
+    Checkpoint::new(
+        279000,
+        "0000000000000001ae8c72a0b0c301f67e3afca10e819efa9041e458e9bd7e40",
+        work_of_difficulty(10_000_000),
+    ),
+    Checkpoint::new(
+        295000,
+        "00000000000000004d9b4ef50f0f9d686fd69db2e03af35a100370c64632a983",
+        work_of_difficulty(1_000_000_000),
+    ),
+    Checkpoint::new(
+        420000,
+        "000000000000000002cce816c0ab2c5c269cb081896b7dcb34b8422d6b74ffa1",
+        work_of_difficulty(100_000_000_000),
+    ),
+    Checkpoint::new(
+        630000,
+        "000000000000000000024bead8df69990852c202db0e0097c1a12ea637d7e96d",
+        work_of_difficulty(10_000_000_000_000),
+    ),
+    Checkpoint::new(
+        840000,
+        "0000000000000000000320283a032748cef8227873ff4872689bf23f1cda83a5",
+        work_of_difficulty(50_000_000_000_000),
+    ),
+];
+
+/// Checkpoints of testnet (testnet3).
+pub const TESTNET: &[Checkpoint] = &[
+    Checkpoint::new(
+        0,
+        "000000000933ea01ad0ee984209779baaec3ced90fa3f408719526f8d77f4943",
+        DIFFICULTY_1_WORK,
+    ),
+    Checkpoint::new(
+        546,
+        "000000002a936ca763904c3c35fce2f3556c559c0214345d31b1bcebf76acb70",
+        DIFFICULTY_1_WORK,
+    ),
+];
+
+/// Checkpoints of testnet4.
+pub const TESTNET4: &[Checkpoint] = &[Checkpoint::new(
+    0,
+    "00000000da84f2bafbbc53dee25a72ae507ff4914b867c565be350b0da8bf043",
+    DIFFICULTY_1_WORK,
+)];
+
+/// Checkpoints of the default signet.
+pub const SIGNET: &[Checkpoint] = &[Checkpoint::new(
+    0,
+    "00000008819873e925422c1ff0f99f7cc9bbb232af63a077a480a3633bee1ef6",
+    // The work of the proof of work limit of signet.
+    U256([0x49_d414, 0, 0, 0]),
+)];
+
+/// Checkpoints of regtest.
+pub const REGTEST: &[Checkpoint] = &[Checkpoint::new(
+    0,
+    "0f9188f13cb7b2c71f2a335e3a4fc328bf5beb436012afca590b1a11466e2206",
+    // The work of the proof of work limit of regtest.
+    U256([0x2, 0, 0, 0]),
+)];
+
+/// Returns the built-in checkpoints of the chain, which is determined by the `flags`.
+///
+/// The checkpoints are sorted by height.
+pub fn for_chain(flags: u8) -> &'static [Checkpoint] {
+    match flags.into() {
+        BitcoinChainType::Mainnet => MAINNET,
+        BitcoinChainType::Testnet => TESTNET,
+        BitcoinChainType::Signet => SIGNET,
+        BitcoinChainType::Testnet4 => TESTNET4,
+        BitcoinChainType::Regtest => REGTEST,
+        BitcoinChainType::Other => &[],
+    }
+}
+
+/// Finds the checkpoint at the height.
+pub fn find(checkpoints: &[Checkpoint], height: u32) -> Option<&Checkpoint> {
+    checkpoints
+        .binary_search_by_key(&height, |cp| cp.height)
+        .ok()
+        .map(|index| &checkpoints[index])
+}
+
+/// Finds the last checkpoint which height is not greater than the height.
+pub fn find_last_before(checkpoints: &[Checkpoint], height: u32) -> Option<&Checkpoint> {
+    let count = checkpoints.partition_point(|cp| cp.height <= height);
+    count.checked_sub(1).map(|index| &checkpoints[index])
+}
+
+/// A lower bound of the work of a block with the difficulty.
+const fn work_of_difficulty(difficulty: u64) -> U256 {
+    U256([difficulty << 32, difficulty >> 32, 0, 0])
+}
+
+const fn block_hash_from_hex(hex: &str) -> [u8; 32] {
+    const fn digit(c: u8) -> u8 {
+        match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            b'A'..=b'F' => c - b'A' + 10,
+            _ => panic!("invalid hex digit"),
+        }
+    }
+    let bytes = hex.as_bytes();
+    assert!(bytes.len() == 64, "invalid block hash length");
+    let mut hash = [0u8; 32];
+    let mut i = 0;
+    while i < 32 {
+        hash[31 - i] = (digit(bytes[2 * i]) << 4) | digit(bytes[2 * i + 1]);
+        i += 1;
+    }
+    hash
+}
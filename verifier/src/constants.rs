@@ -2,6 +2,26 @@
 
 // Constants for the chain type flag
 // Specifically utilizing the two highest bits for chain type identification
+pub const FLAG_CHAIN_TYPE_MASK: u8 = 0b1100_0000;
 pub const FLAG_CHAIN_TYPE_MAINNET: u8 = 0b0000_0000; // for mainnet
 pub const FLAG_CHAIN_TYPE_TESTNET: u8 = 0b1000_0000; // for testnet
 pub const FLAG_CHAIN_TYPE_SIGNET: u8 = 0b0100_0000; // for signet
+
+// If the two highest bits are both set, the next two bits identify an extended chain type
+pub const FLAG_CHAIN_TYPE_EXTENDED_MASK: u8 = 0b1111_0000;
+pub const FLAG_CHAIN_TYPE_TESTNET4: u8 = 0b1101_0000; // for testnet4
+pub const FLAG_CHAIN_TYPE_REGTEST: u8 = 0b1110_0000; // for regtest
+
+// Constants for the consensus rules
+// The max seconds that the first block of a new difficulty could be earlier than its previous block (BIP94)
+pub const MAX_TIMEWARP: u32 = 600;
+// The count of headers to calculate the median time past
+pub const MEDIAN_TIME_SPAN: usize = 11;
+// The count of blocks, since the block of a coinbase transaction, to spend its outputs
+pub const COINBASE_MATURITY: u32 = 100;
+
+// Constants for the SPV type script
+// The min count of SPV client cells, so the tip client is never consumed by an update
+pub const MIN_CLIENTS_COUNT: u8 = 2;
+// The max count of SPV client cells, since all of them are consumed by a reorg
+pub const MAX_CLIENTS_COUNT: u8 = 32;
@@ -0,0 +1,214 @@
+//! The helpers for CKB contracts, through CKB syscalls.
+//!
+//! Requires the feature `ckb-std`.
+
+use alloc::vec::Vec;
+
+use ckb_std::{
+    ckb_types::core::ScriptHashType,
+    error::SysError,
+    high_level::{load_cell_data, load_cell_type, load_cell_type_hash, load_witness_args},
+};
+
+pub use ckb_std::ckb_constants::Source;
+
+use crate::{
+    core::result::Result,
+    error::VerifyTxError,
+    types::{core, packed, prelude::*},
+};
+
+/// The errors of the helpers.
+///
+/// Could be converted into an exit code of a contract:
+/// - The exit codes of [`VerifyTxError`] are kept, which are less than 32.
+/// - Other exit codes start from `0x41`.
+#[derive(Debug)]
+pub enum Error {
+    Syscall(SysError),
+    DecodeCellData,
+    SpvInfoNotFound,
+    SpvClientNotFound,
+    WitnessFieldMissing,
+    DecodeWitness,
+    VerifyTx(VerifyTxError),
+}
+
+/// The fields of `WitnessArgs`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum WitnessField {
+    Lock,
+    InputType,
+    OutputType,
+}
+
+/// The location of a field in witnesses.
+#[derive(Clone, Copy)]
+pub struct WitnessLocation {
+    /// The index of the witness.
+    pub index: usize,
+    /// The source of the witness, for example, `Source::GroupInput`.
+    pub source: Source,
+    /// The field of the witness, which is `WitnessArgs`.
+    pub field: WitnessField,
+}
+
+impl From<SysError> for Error {
+    fn from(err: SysError) -> Self {
+        Self::Syscall(err)
+    }
+}
+
+impl From<VerifyTxError> for Error {
+    fn from(err: VerifyTxError) -> Self {
+        Self::VerifyTx(err)
+    }
+}
+
+impl From<Error> for i8 {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::Syscall(_) => 0x41,
+            Error::DecodeCellData => 0x42,
+            Error::SpvInfoNotFound => 0x43,
+            Error::SpvClientNotFound => 0x44,
+            Error::WitnessFieldMissing => 0x45,
+            Error::DecodeWitness => 0x46,
+            Error::VerifyTx(err) => err as i8,
+        }
+    }
+}
+
+/// Loads the tip SPV client from cell deps, the SPV cells are matched by their
+/// type script hash.
+///
+/// Both the SPV info cell and the tip SPV client cell should be in cell deps.
+pub fn load_tip_spv_client(type_hash: &[u8; 32]) -> Result<packed::SpvClient, Error> {
+    load_tip_spv_client_with(
+        |index| {
+            load_cell_type_hash(index, Source::CellDep)
+                .map(|hash_opt| hash_opt.as_ref() == Some(type_hash))
+        },
+        load_cell_dep_data,
+    )
+}
+
+/// Loads the tip SPV client from cell deps, the SPV cells are matched by their
+/// type script, which args should be the same as `args`.
+///
+/// The `args` is the packed `SpvTypeArgs`, with the signet challenge if it exists.
+pub fn load_tip_spv_client_by_script(
+    code_hash: &[u8; 32],
+    hash_type: ScriptHashType,
+    args: &[u8],
+) -> Result<packed::SpvClient, Error> {
+    let hash_type: u8 = hash_type.into();
+    load_tip_spv_client_with(
+        |index| {
+            load_cell_type(index, Source::CellDep).map(|script_opt| {
+                script_opt.is_some_and(|script| {
+                    script.code_hash().as_slice() == code_hash
+                        && script.hash_type().as_slice() == [hash_type]
+                        && script.args().raw_data()[..] == args[..]
+                })
+            })
+        },
+        load_cell_dep_data,
+    )
+}
+
+fn load_cell_dep_data(index: usize) -> Result<Vec<u8>, SysError> {
+    load_cell_data(index, Source::CellDep)
+}
+
+// Loads the tip SPV client, the SPV cells are matched by `is_matched`, and
+// their data are loaded by `load_data`.
+//
+// The SPV cells are distinguished by the size of their data.
+pub(crate) fn load_tip_spv_client_with<F, L>(
+    is_matched: F,
+    load_data: L,
+) -> Result<packed::SpvClient, Error>
+where
+    F: Fn(usize) -> Result<bool, SysError>,
+    L: Fn(usize) -> Result<Vec<u8>, SysError>,
+{
+    let mut tip_client_id = None;
+    let mut clients = Vec::new();
+    for index in 0.. {
+        match is_matched(index) {
+            Ok(true) => {}
+            Ok(false) => continue,
+            Err(SysError::IndexOutOfBound) => break,
+            Err(err) => return Err(err.into()),
+        }
+        let data = load_data(index)?;
+        match data.len() {
+            packed::SpvInfo::TOTAL_SIZE => {
+                let info =
+                    packed::SpvInfoReader::from_slice(&data).map_err(|_| Error::DecodeCellData)?;
+                tip_client_id = Some(info.tip_client_id().into());
+            }
+            packed::SpvClient::TOTAL_SIZE => {
+                packed::SpvClientReader::verify(&data, false).map_err(|_| Error::DecodeCellData)?;
+                clients.push(packed::SpvClient::new_unchecked(data.into()));
+            }
+            _ => return Err(Error::DecodeCellData),
+        }
+    }
+    let tip_client_id: u8 = tip_client_id.ok_or(Error::SpvInfoNotFound)?;
+    clients
+        .into_iter()
+        .find(|client| u8::from(client.id()) == tip_client_id)
+        .ok_or(Error::SpvClientNotFound)
+}
+
+/// Loads a field of a witness, which is `WitnessArgs`.
+pub fn load_witness_field(location: WitnessLocation) -> Result<Vec<u8>, Error> {
+    let witness_args = load_witness_args(location.index, location.source)?;
+    let field = match location.field {
+        WitnessField::Lock => witness_args.lock(),
+        WitnessField::InputType => witness_args.input_type(),
+        WitnessField::OutputType => witness_args.output_type(),
+    };
+    field
+        .to_opt()
+        .map(|bytes| bytes.raw_data().to_vec())
+        .ok_or(Error::WitnessFieldMissing)
+}
+
+/// Verifies whether a transaction is in the chain or not, with the
+/// `TransactionProof` in the witness.
+///
+/// See `packed::SpvClient::verify_transaction(..)` for the checks.
+pub fn verify_transaction(
+    client: &packed::SpvClient,
+    txid: &[u8; 32],
+    location: WitnessLocation,
+    confirmations: u32,
+) -> Result<core::Header, Error> {
+    let witness = load_witness_field(location)?;
+    let tx_proof =
+        packed::TransactionProofReader::from_slice(&witness).map_err(|_| Error::DecodeWitness)?;
+    client
+        .verify_transaction(txid, tx_proof, confirmations)
+        .map_err(Into::into)
+}
+
+/// Verifies whether a transaction is in the chain or not, with the
+/// `TransactionProof` in the witness.
+///
+/// See `packed::SpvClient::verify_transaction_data(..)` for the checks.
+pub fn verify_transaction_data(
+    client: &packed::SpvClient,
+    tx: &[u8],
+    location: WitnessLocation,
+    confirmations: u32,
+) -> Result<(core::Header, core::Transaction), Error> {
+    let witness = load_witness_field(location)?;
+    let tx_proof =
+        packed::TransactionProofReader::from_slice(&witness).map_err(|_| Error::DecodeWitness)?;
+    client
+        .verify_transaction_data(tx, tx_proof, confirmations)
+        .map_err(Into::into)
+}
@@ -5,6 +5,10 @@ pub enum BootstrapError {
     // Check data.
     Height = 0x09,
     Pow,
+    Difficulty,
+    UncontinuousHeaders,
+    Checkpoint,
+    MinimumWork,
     // This is not an error, just make sure the error code is less than 32.
     Unreachable = 0x20,
 }
@@ -14,39 +18,93 @@ pub enum UpdateError {
     // Basic errors.
     DecodeHeader = 0x01,
     DecodeTargetAdjustInfo,
+    DecodeCoinbaseTransaction,
     // Check headers.
     EmptyHeaders = 0x09,
     UncontinuousHeaders,
     Difficulty,
     Pow,
+    Timewarp,
+    CoinbaseProof,
+    SignetSolution,
+    MedianTimePast,
     // Check MMR proof.
     Mmr = 0x11,
     HeadersMmrProof,
+    // Check reorg.
+    ReorgForkPoint,
+    ReorgForkHeaders,
+    ReorgMmrProof,
+    ReorgDifficultyPeriod,
+    ReorgChainWork,
     // Check new client.
     ClientId = 0x19,
     ClientTipBlockHash,
     ClientMinimalHeight,
     ClientMaximalHeight,
     ClientTargetAdjustInfo,
+    ClientRecentTimestamps,
     // This is not an error, just make sure the error code is less than 32.
     Unreachable = 0x20,
 }
 
 #[repr(i8)]
+#[derive(Debug)]
 pub enum VerifyTxError {
     // Basic errors.
     DecodeTransaction = 0x01,
     DecodeTxOutProof,
+    DecodeHeader,
+    DecodeCoinbaseTransaction,
     // Transaction related errors.
     TransactionUnconfirmed = 0x09,
     TransactionTooOld,
     TransactionTooNew,
+    PaymentOutputIndex,
+    PaymentUnmatched,
+    OutPointNotSpent,
+    CoinbaseImmature,
+    CoinbaseHeight,
     // Check txout proof.
     TxOutProofIsInvalid = 0x11,
     TxOutProofInvalidTxIndex,
     TxOutProofInvalidTxId,
+    CoinbaseProof,
+    WitnessCommitmentNotFound,
+    WitnessReservedValue,
+    WitnessCommitment,
+    TxOutProofAmbiguousTx,
     // Check header mmr proof.
     HeaderMmrProof = 0x19,
+    HeadersProofInvalidHeights,
+    // Check witness proof.
+    WitnessMerkleBranchDepth,
+    // This is not an error, just make sure the error code is less than 32.
+    Unreachable = 0x20,
+}
+
+#[repr(i8)]
+pub enum TransitionError {
+    // Basic errors.
+    DecodeArgs = 0x01,
+    DecodeCellData,
+    DecodeWitness,
+    // Check args.
+    ClientsCount = 0x09,
+    Flags,
+    SignetChallenge,
+    // Check cells.
+    UnknownTransition = 0x11,
+    InfoCell,
+    ClientCells,
+    ClientId,
+    TipClientId,
+    TipClientNotFound,
+    StaleClient,
+    // Check clients.
+    Bootstrap = 0x19,
+    Update,
+    Reorg,
     // This is not an error, just make sure the error code is less than 32.
     Unreachable = 0x20,
 }
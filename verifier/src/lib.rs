@@ -11,8 +11,12 @@ extern crate core;
 #[macro_use]
 mod log;
 
+pub mod checkpoints;
 pub mod constants;
+#[cfg(feature = "ckb-std")]
+pub mod contract;
 pub mod error;
+pub mod transition;
 pub mod types;
 pub mod utilities;
 
@@ -1,4 +1,4 @@
-use std::format;
+use std::{format, vec};
 
 use bitcoin::{
     blockdata::constants::DIFFCHANGE_INTERVAL,
@@ -6,7 +6,14 @@ use bitcoin::{
 };
 use primitive_types::U256;
 
-use crate::{tests, types::core, utilities::bitcoin::calculate_next_target};
+use crate::{
+    constants::MEDIAN_TIME_SPAN,
+    tests,
+    types::core,
+    utilities::bitcoin::{
+        append_timestamp, calculate_median_time_past, calculate_next_target, verify_coinbase_height,
+    },
+};
 
 const DIFF_CHANGE_HEADERS_DIR: &str = "main-chain/headers/diff-change";
 const CHAINWORK_BY_HEIGHTS: &str = "main-chain/chainwork";
@@ -88,3 +95,58 @@ fn main_chain_targets_and_chainwork() {
         assert_eq!(expected_chainwork, actual_chainwork);
     }
 }
+
+#[test]
+fn median_time_past() {
+    let mut timestamps = [0; MEDIAN_TIME_SPAN];
+    append_timestamp(&mut timestamps, 100);
+    assert_eq!(timestamps[MEDIAN_TIME_SPAN - 1], 100);
+    assert_eq!(calculate_median_time_past(&timestamps), 0);
+
+    for time in [160, 130, 120, 150, 110, 170, 140, 190, 180, 200] {
+        append_timestamp(&mut timestamps, time);
+    }
+    assert_eq!(
+        timestamps,
+        [100, 160, 130, 120, 150, 110, 170, 140, 190, 180, 200]
+    );
+    assert_eq!(calculate_median_time_past(&timestamps), 150);
+
+    append_timestamp(&mut timestamps, 105);
+    assert_eq!(timestamps[0], 160);
+    assert_eq!(timestamps[MEDIAN_TIME_SPAN - 1], 105);
+    assert_eq!(calculate_median_time_past(&timestamps), 150);
+}
+
+#[test]
+fn coinbase_height() {
+    let coinbase_with = |script_sig: &[u8]| core::Transaction {
+        version: bitcoin::transaction::Version::ONE,
+        lock_time: bitcoin::absolute::LockTime::ZERO,
+        input: vec![bitcoin::TxIn {
+            script_sig: bitcoin::ScriptBuf::from(script_sig.to_vec()),
+            ..Default::default()
+        }],
+        output: vec![],
+    };
+
+    assert!(verify_coinbase_height(&coinbase_with(&[0x00, 0xff]), 0));
+    assert!(verify_coinbase_height(&coinbase_with(&[0x60]), 16));
+    assert!(verify_coinbase_height(&coinbase_with(&[0x01, 0x11]), 17));
+    assert!(verify_coinbase_height(
+        &coinbase_with(&[0x02, 0x80, 0x00]),
+        128
+    ));
+    assert!(verify_coinbase_height(
+        &coinbase_with(&[0x03, 0x5b, 0x7a, 0x03, 0xaa]),
+        227931
+    ));
+
+    assert!(!verify_coinbase_height(&coinbase_with(&[0x01, 0x10]), 16));
+    assert!(!verify_coinbase_height(&coinbase_with(&[0x01, 0x80]), 128));
+    assert!(!verify_coinbase_height(
+        &coinbase_with(&[0x03, 0x5b, 0x7a]),
+        227931
+    ));
+    assert!(!verify_coinbase_height(&coinbase_with(&[]), 1));
+}
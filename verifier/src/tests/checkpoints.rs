@@ -0,0 +1,109 @@
+use std::str::FromStr as _;
+
+use bitcoin::{
+    block::Version, blockdata::constants::genesis_block, hashes::Hash as _, BlockHash, Network,
+    TxMerkleNode,
+};
+use primitive_types::U256;
+
+use crate::{
+    checkpoints::{self, Checkpoint},
+    constants::*,
+    tests,
+    types::core,
+};
+
+#[test]
+fn genesis_checkpoints() {
+    tests::setup();
+
+    for (flags, network) in [
+        (FLAG_CHAIN_TYPE_MAINNET, Network::Bitcoin),
+        (FLAG_CHAIN_TYPE_TESTNET, Network::Testnet),
+        (FLAG_CHAIN_TYPE_SIGNET, Network::Signet),
+        (FLAG_CHAIN_TYPE_REGTEST, Network::Regtest),
+    ] {
+        let genesis = genesis_block(network).header;
+        let checkpoint = checkpoints::find(checkpoints::for_chain(flags), 0).unwrap();
+        assert_eq!(
+            checkpoint.block_hash,
+            genesis.block_hash().to_byte_array(),
+            "{network}"
+        );
+        let work = U256::from_little_endian(&genesis.work().to_le_bytes());
+        assert_eq!(checkpoint.min_block_work, work, "{network}");
+    }
+}
+
+#[test]
+fn testnet4_genesis_checkpoint() {
+    tests::setup();
+
+    // The `bitcoin` crate doesn't know testnet4, so build its genesis header here.
+    let genesis = core::Header {
+        version: Version::ONE,
+        prev_blockhash: BlockHash::all_zeros(),
+        merkle_root: TxMerkleNode::from_str(
+            "7aa0a7ae1e223414cb807e40cd57e667b718e42aaf9306db9102fe28912b7b4e",
+        )
+        .unwrap(),
+        time: 1714777860,
+        bits: core::CompactTarget::from_consensus(0x1d00ffff),
+        nonce: 393743547,
+    };
+    let checkpoint =
+        checkpoints::find(checkpoints::for_chain(FLAG_CHAIN_TYPE_TESTNET4), 0).unwrap();
+    assert_eq!(checkpoint.block_hash, genesis.block_hash().to_byte_array());
+    let work = U256::from_little_endian(&genesis.work().to_le_bytes());
+    assert_eq!(checkpoint.min_block_work, work);
+}
+
+#[test]
+fn sorted_checkpoints() {
+    tests::setup();
+
+    for checkpoints in [
+        checkpoints::MAINNET,
+        checkpoints::TESTNET,
+        checkpoints::TESTNET4,
+        checkpoints::SIGNET,
+        checkpoints::REGTEST,
+    ] {
+        assert!(checkpoints
+            .windows(2)
+            .all(|pair| pair[0].height < pair[1].height
+                && pair[0].min_block_work <= pair[1].min_block_work));
+    }
+}
+
+#[test]
+fn find_checkpoints() {
+    tests::setup();
+
+    let checkpoints = checkpoints::MAINNET;
+    let height_of = |checkpoint: Option<&Checkpoint>| checkpoint.map(|cp| cp.height);
+
+    assert_eq!(
+        height_of(checkpoints::find(checkpoints, 11111)),
+        Some(11111)
+    );
+    assert_eq!(height_of(checkpoints::find(checkpoints, 11112)), None);
+
+    assert_eq!(
+        height_of(checkpoints::find_last_before(checkpoints, 0)),
+        Some(0)
+    );
+    assert_eq!(
+        height_of(checkpoints::find_last_before(checkpoints, 210000)),
+        Some(210000)
+    );
+    assert_eq!(
+        height_of(checkpoints::find_last_before(checkpoints, 215000)),
+        Some(210000)
+    );
+    assert_eq!(
+        height_of(checkpoints::find_last_before(checkpoints, u32::MAX)),
+        Some(840000)
+    );
+    assert_eq!(height_of(checkpoints::find_last_before(&[], 1)), None);
+}
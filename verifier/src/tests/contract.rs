@@ -0,0 +1,105 @@
+use std::{vec, vec::Vec};
+
+use ckb_std::error::SysError;
+
+use crate::{
+    contract::{load_tip_spv_client_with, Error},
+    error::VerifyTxError,
+    molecule::prelude::*,
+    tests,
+    types::{core, packed, prelude::*},
+};
+
+fn info_cell(tip_client_id: u8) -> Vec<u8> {
+    core::SpvInfo { tip_client_id }.pack().as_slice().to_vec()
+}
+
+fn client_cell(id: u8) -> Vec<u8> {
+    packed::SpvClient::new_builder()
+        .id(id.into())
+        .build()
+        .as_slice()
+        .to_vec()
+}
+
+// Loads the tip SPV client from the cells, `None` is a cell which is not matched.
+fn load_tip_spv_client_from(cells: &[Option<Vec<u8>>]) -> Result<packed::SpvClient, i8> {
+    load_tip_spv_client_with(
+        |index| match cells.get(index) {
+            Some(cell) => Ok(cell.is_some()),
+            None => Err(SysError::IndexOutOfBound),
+        },
+        |index| Ok(cells[index].clone().unwrap()),
+    )
+    .map_err(i8::from)
+}
+
+#[test]
+fn error_codes() {
+    tests::setup();
+
+    let errors = vec![
+        (Error::Syscall(SysError::ItemMissing), 0x41),
+        (Error::DecodeCellData, 0x42),
+        (Error::SpvInfoNotFound, 0x43),
+        (Error::SpvClientNotFound, 0x44),
+        (Error::WitnessFieldMissing, 0x45),
+        (Error::DecodeWitness, 0x46),
+        (Error::VerifyTx(VerifyTxError::DecodeTransaction), 0x01),
+        (Error::VerifyTx(VerifyTxError::HeaderMmrProof), 0x19),
+        (
+            Error::VerifyTx(VerifyTxError::WitnessMerkleBranchDepth),
+            0x1b,
+        ),
+    ];
+    for (error, code) in errors {
+        assert_eq!(i8::from(error), code);
+    }
+    // The exit codes of other errors are greater than the exit codes of `VerifyTxError`.
+    assert!(VerifyTxError::Unreachable as i8 <= i8::from(Error::Syscall(SysError::Unknown(1))));
+}
+
+#[test]
+fn load_tip_spv_client_by_data_size() {
+    tests::setup();
+
+    let cells = vec![
+        Some(client_cell(0)),
+        None,
+        Some(info_cell(1)),
+        Some(client_cell(1)),
+        Some(client_cell(2)),
+    ];
+    let client = load_tip_spv_client_from(&cells).unwrap();
+    assert_eq!(client.as_slice(), &client_cell(1)[..]);
+    // The data of cells which are not matched are not loaded.
+    let mut other_cells = cells.clone();
+    other_cells.push(None);
+    assert!(load_tip_spv_client_from(&other_cells).is_ok());
+
+    let mut other_cells = cells.clone();
+    other_cells[2] = None;
+    assert_eq!(
+        load_tip_spv_client_from(&other_cells).map(|_| ()),
+        Err(i8::from(Error::SpvInfoNotFound))
+    );
+
+    let mut other_cells = cells.clone();
+    other_cells[3] = None;
+    assert_eq!(
+        load_tip_spv_client_from(&other_cells).map(|_| ()),
+        Err(i8::from(Error::SpvClientNotFound))
+    );
+
+    let mut other_cells = cells.clone();
+    other_cells.push(Some(vec![0; 3]));
+    assert_eq!(
+        load_tip_spv_client_from(&other_cells).map(|_| ()),
+        Err(i8::from(Error::DecodeCellData))
+    );
+
+    let result = load_tip_spv_client_with(|_| Err(SysError::ItemMissing), |_| unreachable!());
+    assert!(matches!(result, Err(Error::Syscall(SysError::ItemMissing))));
+    let result = load_tip_spv_client_with(|_| Ok(true), |_| Err(SysError::ItemMissing));
+    assert!(matches!(result, Err(Error::Syscall(SysError::ItemMissing))));
+}
@@ -40,6 +40,8 @@ pub(crate) fn find_bin_file(in_dir: &str, filename: &str) -> PathBuf {
 mod check_entry {
     use walkdir::DirEntry;
 
+    // `manual_ignore_case_cmp` is unknown to the toolchain used in CI.
+    #[allow(unknown_lints, clippy::manual_ignore_case_cmp)]
     pub(super) fn is_bin(entry: &DirEntry) -> bool {
         entry
             .path()
@@ -4,10 +4,16 @@ use env_logger::{Builder, Target};
 use log::LevelFilter;
 
 mod bitcoin;
+mod checkpoints;
+#[cfg(feature = "ckb-std")]
+mod contract;
+#[cfg(feature = "serde")]
+mod serde;
 
 pub(crate) mod data;
 pub(crate) mod signet;
 pub(crate) mod testnet;
+pub(crate) mod testnet4;
 pub(crate) mod utilities;
 
 pub(crate) fn setup() {
@@ -0,0 +1,122 @@
+use alloc::{format, vec};
+
+use bitcoin::{blockdata::constants::genesis_block, Network};
+use primitive_types::U256;
+use serde_json::json;
+
+use crate::{
+    constants::MEDIAN_TIME_SPAN,
+    tests,
+    types::{core, packed, prelude::*},
+};
+
+fn genesis_client() -> core::SpvClient {
+    let header = genesis_block(Network::Bitcoin).header;
+    let mut recent_timestamps = [0; MEDIAN_TIME_SPAN];
+    recent_timestamps[MEDIAN_TIME_SPAN - 1] = header.time;
+    core::SpvClient {
+        id: 1,
+        tip_block_hash: header.block_hash().into(),
+        headers_mmr_root: core::HeaderDigest {
+            min_height: 0,
+            max_height: 0,
+            partial_chain_work: U256::from(0x1_0001_0001u64),
+            children_hash: header.block_hash().into(),
+        },
+        target_adjust_info: packed::TargetAdjustInfo::new_builder()
+            .start_time(header.time.pack())
+            .next_compact_target(header.bits.to_consensus().pack())
+            .build(),
+        recent_timestamps,
+    }
+}
+
+#[test]
+fn spv_client() {
+    tests::setup();
+
+    let client = genesis_client();
+    let block_hash = "0x000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f";
+    let expected = json!({
+        "id": 1,
+        "tip_block_hash": block_hash,
+        "headers_mmr_root": {
+            "min_height": 0,
+            "max_height": 0,
+            "partial_chain_work": "0x100010001",
+            "children_hash": block_hash,
+        },
+        "target_adjust_info": {
+            "start_time": 1231006505,
+            "next_compact_target": "0x1d00ffff",
+        },
+        "recent_timestamps": [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1231006505],
+    });
+
+    let value = serde_json::to_value(&client).unwrap();
+    assert_eq!(value, expected);
+    let packed_value = serde_json::to_value(client.pack()).unwrap();
+    assert_eq!(packed_value, expected);
+
+    let decoded: core::SpvClient = serde_json::from_value(expected.clone()).unwrap();
+    assert_eq!(decoded.pack().as_slice(), client.pack().as_slice());
+    let decoded: packed::SpvClient = serde_json::from_value(expected).unwrap();
+    assert_eq!(decoded.as_slice(), client.pack().as_slice());
+}
+
+#[test]
+fn spv_type_args() {
+    tests::setup();
+
+    let args = core::SpvTypeArgs {
+        type_id: core::Hash::from_byte_array([0x11; 32]),
+        clients_count: 3,
+        flags: 0b1000_0000,
+    };
+    let expected = json!({
+        "type_id": format!("0x{}", "11".repeat(32)),
+        "clients_count": 3,
+        "flags": 128,
+    });
+    assert_eq!(serde_json::to_value(&args).unwrap(), expected);
+    let decoded: packed::SpvTypeArgs = serde_json::from_value(expected).unwrap();
+    assert_eq!(decoded.as_slice(), args.pack().as_slice());
+}
+
+#[test]
+fn packed_bytes() {
+    tests::setup();
+
+    let header = genesis_block(Network::Bitcoin).header;
+    let packed_header = header.pack();
+    let json = serde_json::to_string(&packed_header).unwrap();
+    assert_eq!(
+        json,
+        format!(
+            "\"0x{}\"",
+            bitcoin::hex::DisplayHex::as_hex(packed_header.as_slice())
+        )
+    );
+    let decoded: packed::Header = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded.as_slice(), packed_header.as_slice());
+
+    // Without the `0x` prefix.
+    assert!(serde_json::from_str::<packed::Header>(&format!("\"{}\"", &json[3..])).is_err());
+    // Invalid length.
+    assert!(serde_json::from_str::<packed::Header>("\"0x0000\"").is_err());
+    assert!(serde_json::from_str::<packed::Uint32>("\"0x01000000\"").is_ok());
+}
+
+#[test]
+fn invalid_hex_numbers() {
+    tests::setup();
+
+    let mut value = serde_json::to_value(genesis_client()).unwrap();
+    value["headers_mmr_root"]["partial_chain_work"] = json!("100010001");
+    assert!(serde_json::from_value::<core::SpvClient>(value.clone()).is_err());
+    value["headers_mmr_root"]["partial_chain_work"] = json!("0xzz");
+    assert!(serde_json::from_value::<core::SpvClient>(value.clone()).is_err());
+    value["headers_mmr_root"]["partial_chain_work"] = json!("0x1");
+    value["tip_block_hash"] = json!("0x00");
+    assert!(serde_json::from_value::<core::SpvClient>(value).is_err());
+}
@@ -1,19 +1,23 @@
-use std::fs::read_to_string;
+use std::{vec, vec::Vec};
 
-use alloc::format;
-use ckb_jsonrpc_types::TransactionView;
-use ckb_types::packed::WitnessArgs;
-use serde_json::from_str as from_json_str;
+use bitcoin::{
+    absolute::LockTime,
+    blockdata::{opcodes::all::OP_RETURN, script::Builder},
+    consensus::serialize,
+    hashes::Hash as _,
+    secp256k1::{Message, Secp256k1, SecretKey},
+    sighash::{EcdsaSighashType, SighashCache},
+    transaction::Version,
+    Amount, BlockHash, OutPoint, PublicKey, ScriptBuf, Sequence, TxIn, TxMerkleNode, TxOut,
+    Witness,
+};
 
 use crate::{
+    constants::FLAG_CHAIN_TYPE_SIGNET,
     error::UpdateError,
-    molecule::prelude::*,
-    tests,
-    types::{
-        core,
-        packed::{SpvClient, SpvUpdate},
-        prelude::*,
-    },
+    tests::{self, utilities},
+    types::core,
+    utilities::signet::{create_signet_transactions, verify_block_solution, SIGNET_HEADER},
 };
 
 // This case shows that:
@@ -56,28 +60,195 @@ fn signet_verify_new_client_normal() {
 fn verify_new_client_common(tx_file: &str, cell_dep_index: usize) -> Result<(), UpdateError> {
     tests::setup();
 
-    let path = tests::data::find_bin_file("signet", tx_file);
-    let tx = read_to_string(path).unwrap();
-    let tx: TransactionView = from_json_str(&tx).unwrap();
-
-    let witnesses = tx.inner.witnesses;
-    let witness_args = WitnessArgs::from_slice(witnesses[0].as_bytes()).unwrap();
-    let spv_update_bin = witness_args.output_type().to_opt().unwrap().raw_data();
-    let spv_update = SpvUpdate::from_slice(&spv_update_bin).unwrap();
-
-    let client_bin = tx.inner.outputs_data[1].clone();
-    let client = SpvClient::from_slice(client_bin.as_bytes()).unwrap();
-
-    let cell_dep = tx.inner.cell_deps[cell_dep_index].out_point.clone();
-    let path =
-        tests::data::find_bin_file("signet", format!("tx-0x{}.json", cell_dep.tx_hash).as_str());
-    let previous_tx = read_to_string(path).unwrap();
-    let previous_tx: TransactionView = from_json_str(&previous_tx).unwrap();
-    let cell_dep_data_bin = &previous_tx.inner.outputs_data[cell_dep.index.value() as usize];
-    let cell_dep_client = SpvClient::from_slice(cell_dep_data_bin.as_bytes()).unwrap();
-
-    let mut cell_dep_client: core::SpvClient = cell_dep_client.unpack();
-    cell_dep_client.id = client.id().into();
-    let input_client = cell_dep_client.pack();
-    input_client.verify_new_client(&client, spv_update, 64)
+    let (input_client, client, spv_update) =
+        utilities::load_legacy_update("signet", tx_file, cell_dep_index);
+    input_client.verify_new_client(&client, spv_update, FLAG_CHAIN_TYPE_SIGNET)
+}
+
+fn coinbase_with_commitment_section(section: &[u8]) -> core::Transaction {
+    let mut witness_commitment = vec![0xaa, 0x21, 0xa9, 0xed];
+    witness_commitment.extend_from_slice(&[0u8; 32]);
+    let script_pubkey = Builder::new()
+        .push_opcode(OP_RETURN)
+        .push_slice(<&bitcoin::script::PushBytes>::try_from(&witness_commitment[..]).unwrap())
+        .push_slice(<&bitcoin::script::PushBytes>::try_from(section).unwrap())
+        .into_script();
+    core::Transaction {
+        version: Version::TWO,
+        lock_time: LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint::null(),
+            script_sig: Builder::new().push_int(1).into_script(),
+            sequence: Sequence::MAX,
+            witness: Witness::new(),
+        }],
+        output: vec![TxOut {
+            value: Amount::ZERO,
+            script_pubkey,
+        }],
+    }
+}
+
+// Signs a block as a signet signer, returns the header and the coinbase transaction.
+fn sign_signet_block<F>(challenge: &ScriptBuf, sign: F) -> (core::Header, core::Transaction)
+where
+    F: Fn(&core::Transaction) -> (ScriptBuf, Witness),
+{
+    let mut header = core::Header {
+        version: bitcoin::block::Version::TWO,
+        prev_blockhash: BlockHash::all_zeros(),
+        merkle_root: TxMerkleNode::all_zeros(),
+        time: 1_700_000_000,
+        bits: core::Target::MAX_ATTAINABLE_SIGNET.to_compact_lossy(),
+        nonce: 0,
+    };
+    let unsigned_coinbase = coinbase_with_commitment_section(&SIGNET_HEADER);
+    let (_, tx_spending) =
+        create_signet_transactions(&header, &unsigned_coinbase, &[], challenge).unwrap();
+    let (script_sig, witness) = sign(&tx_spending);
+    let mut section = SIGNET_HEADER.to_vec();
+    section.extend_from_slice(&serialize(&script_sig));
+    section.extend_from_slice(&serialize(&witness));
+    let coinbase = coinbase_with_commitment_section(&section);
+    header.merkle_root = coinbase.txid().to_raw_hash().into();
+    (header, coinbase)
+}
+
+fn sign_ecdsa(digest: [u8; 32], secret_key: &SecretKey) -> Vec<u8> {
+    let secp = Secp256k1::new();
+    let msg = Message::from_digest(digest);
+    let mut sig = secp.sign_ecdsa(&msg, secret_key).serialize_der().to_vec();
+    sig.push(EcdsaSighashType::All.to_u32() as u8);
+    sig
+}
+
+#[test]
+fn signet_block_solution_p2wpkh() {
+    tests::setup();
+
+    let secp = Secp256k1::new();
+    let secret_key = SecretKey::from_slice(&[0x01; 32]).unwrap();
+    let pubkey = PublicKey::new(secret_key.public_key(&secp));
+    let challenge = ScriptBuf::new_p2wpkh(&pubkey.wpubkey_hash().unwrap());
+
+    let (mut header, coinbase) = sign_signet_block(&challenge, |tx| {
+        let sighash = SighashCache::new(tx)
+            .p2wpkh_signature_hash(0, &challenge, Amount::ZERO, EcdsaSighashType::All)
+            .unwrap();
+        let sig = sign_ecdsa(sighash.to_byte_array(), &secret_key);
+        (
+            ScriptBuf::new(),
+            Witness::from_slice(&[sig, pubkey.to_bytes()]),
+        )
+    });
+    assert!(verify_block_solution(&header, &coinbase, &[], &challenge));
+
+    let other_challenge = ScriptBuf::new_p2wpkh(&bitcoin::WPubkeyHash::all_zeros());
+    assert!(!verify_block_solution(
+        &header,
+        &coinbase,
+        &[],
+        &other_challenge
+    ));
+
+    header.time += 1;
+    assert!(!verify_block_solution(&header, &coinbase, &[], &challenge));
+}
+
+#[test]
+fn signet_block_solution_bare_multisig() {
+    tests::setup();
+
+    let secp = Secp256k1::new();
+    let secret_key = SecretKey::from_slice(&[0x02; 32]).unwrap();
+    let pubkey = PublicKey::new(secret_key.public_key(&secp));
+    let challenge = Builder::new()
+        .push_int(1)
+        .push_key(&pubkey)
+        .push_int(1)
+        .push_opcode(bitcoin::opcodes::all::OP_CHECKMULTISIG)
+        .into_script();
+
+    let (header, coinbase) = sign_signet_block(&challenge, |tx| {
+        let sighash = SighashCache::new(tx)
+            .legacy_signature_hash(0, &challenge, EcdsaSighashType::All.to_u32())
+            .unwrap();
+        let sig = sign_ecdsa(sighash.to_byte_array(), &secret_key);
+        let script_sig = Builder::new()
+            .push_int(0)
+            .push_slice(<&bitcoin::script::PushBytes>::try_from(&sig[..]).unwrap())
+            .into_script();
+        (script_sig, Witness::new())
+    });
+    assert!(verify_block_solution(&header, &coinbase, &[], &challenge));
+
+    // Without the solution.
+    let unsigned_coinbase = coinbase_with_commitment_section(&SIGNET_HEADER);
+    assert!(!verify_block_solution(
+        &header,
+        &unsigned_coinbase,
+        &[],
+        &challenge
+    ));
+}
+
+// The same cases as `signet_parse_tests` in Bitcoin Core (`src/test/signet_tests.cpp`),
+// the challenge is `OP_TRUE`.
+#[test]
+fn signet_block_solution_bitcoin_core_vectors() {
+    tests::setup();
+
+    let challenge = Builder::new().push_int(1).into_script();
+    let header = core::Header {
+        version: bitcoin::block::Version::from_consensus(0),
+        prev_blockhash: BlockHash::all_zeros(),
+        merkle_root: TxMerkleNode::all_zeros(),
+        time: 0,
+        bits: core::CompactTarget::from_consensus(0),
+        nonce: 0,
+    };
+    let coinbase_with_script = |script_pubkey: ScriptBuf| core::Transaction {
+        version: Version::TWO,
+        lock_time: LockTime::ZERO,
+        input: Vec::new(),
+        output: vec![TxOut {
+            value: Amount::ZERO,
+            script_pubkey,
+        }],
+    };
+    let push = |builder: Builder, data: &[u8]| {
+        builder.push_slice(<&bitcoin::script::PushBytes>::try_from(data).unwrap())
+    };
+    // A dummy transaction, to exercise the merkle root code.
+    let dummy_tx = coinbase_with_script(ScriptBuf::new());
+    let merkle_branch = [dummy_tx.txid().to_raw_hash()];
+    let verify = |script_pubkey: ScriptBuf| {
+        let coinbase = coinbase_with_script(script_pubkey);
+        verify_block_solution(&header, &coinbase, &merkle_branch, &challenge)
+    };
+
+    // No witness commitment.
+    assert!(!verify(ScriptBuf::new()));
+
+    // No signet header is treated valid.
+    let mut witness_commitment = vec![0xaa, 0x21, 0xa9, 0xed];
+    witness_commitment.extend_from_slice(&[0xff; 32]);
+    let commitment = push(Builder::new().push_opcode(OP_RETURN), &witness_commitment);
+    assert!(verify(commitment.clone().into_script()));
+
+    // No data after the signet header, valid.
+    let mut section = SIGNET_HEADER.to_vec();
+    assert!(verify(push(commitment.clone(), &section).into_script()));
+
+    // Premature end of data, invalid.
+    section.extend_from_slice(&[0x01, 0x51]);
+    assert!(!verify(push(commitment.clone(), &section).into_script()));
+
+    // Has data, valid.
+    section.push(0x00);
+    assert!(verify(push(commitment.clone(), &section).into_script()));
+
+    // Extraneous data, invalid.
+    section.push(0x00);
+    assert!(!verify(push(commitment, &section).into_script()));
 }
@@ -1,29 +1,90 @@
-use std::fs::read_to_string;
+use std::vec::Vec;
 
-use alloc::format;
-use ckb_jsonrpc_types::TransactionView;
-use ckb_types::packed::WitnessArgs;
-use serde_json::from_str as from_json_str;
+use bitcoin::{
+    block::Version,
+    blockdata::constants::{genesis_block, DIFFCHANGE_INTERVAL},
+    hashes::Hash as _,
+    Network, TxMerkleNode,
+};
 
 use crate::{
+    constants::*,
+    error::UpdateError,
     molecule::prelude::*,
-    tests,
-    types::{
-        core,
-        packed::{SpvClient, SpvUpdate},
-        prelude::*,
-    },
+    tests::{self, utilities},
+    types::{core, packed, prelude::*},
+    utilities::bitcoin::calculate_required_compact_target,
 };
 
-// This case shows that:
-// - For the main network, `header.bits` should be the same as `new_info.1`.
-// - But for the test network, this may not be the case.
+// The nonce of a header, which is mined with the minimum difficulty, and its
+// time is 1201 seconds after the testnet genesis block.
+const MIN_DIFFICULTY_NONCE: u32 = 619_719_505;
+
+/// Creates a testnet client at the height, which tip is the genesis block,
+/// and a header with the minimum difficulty, which is more than 20 minutes
+/// after the tip.
+///
+/// The cached target of the client is harder than the minimum difficulty.
+pub(crate) fn min_difficulty_client_and_header(
+    height: u32,
+    cached_bits: core::CompactTarget,
+) -> (core::SpvClient, core::Header) {
+    let tip = genesis_block(Network::Testnet).header;
+    let mut recent_timestamps = [0; MEDIAN_TIME_SPAN];
+    for (index, timestamp) in recent_timestamps.iter_mut().enumerate() {
+        *timestamp = tip.time - 600 * (MEDIAN_TIME_SPAN - 1 - index) as u32;
+    }
+    let start_time = tip.time - 600 * (height % DIFFCHANGE_INTERVAL);
+    let target_adjust_info = packed::TargetAdjustInfo::encode(start_time, cached_bits);
+    let client = utilities::new_client(height, &tip, recent_timestamps, target_adjust_info);
+    let header = core::Header {
+        version: Version::TWO,
+        prev_blockhash: tip.block_hash(),
+        merkle_root: TxMerkleNode::all_zeros(),
+        time: tip.time + 1201,
+        bits: core::Target::MAX.to_compact_lossy(),
+        nonce: MIN_DIFFICULTY_NONCE,
+    };
+    (client, header)
+}
+
+// The real header with the minimum difficulty is accepted in
+// `testnet_verify_new_client_with_min_difficulty_header`, these are the cases
+// which the minimum difficulty is not allowed.
+#[test]
+fn testnet_verify_new_client_min_difficulty_errors() {
+    tests::setup();
+
+    let flags = FLAG_CHAIN_TYPE_TESTNET;
+    let cached = core::CompactTarget::from_consensus(0x1a01_aa3d);
+    let (client, header) = min_difficulty_client_and_header(4132, cached);
+    let client = client.pack();
+
+    // Not more than 20 minutes after the tip, the cached target is required.
+    let mut early_header = header;
+    early_header.time -= 1;
+    let (update, new_client) = utilities::append_headers(&client.unpack(), &[early_header]);
+    let result = client
+        .verify_new_client(&new_client.pack(), update, flags)
+        .map_err(|err| err as i8);
+    assert_eq!(result, Err(UpdateError::Difficulty as i8));
+
+    // The minimum difficulty is never allowed on mainnet.
+    let (update, new_client) = utilities::append_headers(&client.unpack(), &[header]);
+    let result = client
+        .verify_new_client(&new_client.pack(), update, FLAG_CHAIN_TYPE_MAINNET)
+        .map_err(|err| err as i8);
+    assert_eq!(result, Err(UpdateError::Difficulty as i8));
+}
+
+// This case shows that, for the test network, `header.bits` may be not the
+// same as the cached target: the header-2588542 is mined with the minimum
+// difficulty (486604799), since it's more than 20 minutes after its previous
+// header, so it's accepted.
 // To run this test, use the following command:
-// `cargo test --package ckb-bitcoin-spv-verifier --lib -- tests::testnet::testnet_verify_new_client_error --exact --show-output`
-// Upon running the test, you should expect to see an ERROR output in the log similar to the following:
-// [2024-04-30T10:43:45Z ERROR ckb_bitcoin_spv_verifier::types::extension::packed] failed: invalid difficulty for header-2588542, expect 422451157 but got 486604799
+// `cargo test --package ckb-bitcoin-spv-verifier --lib -- tests::testnet::testnet_verify_new_client_with_min_difficulty_header --exact --show-output`
 #[test]
-fn testnet_verify_new_client_error() {
+fn testnet_verify_new_client_with_min_difficulty_header() {
     verify_new_client_common(
         "tx-0422-error-check-header-target-adjust-info.json",
         1, // cell_dep_index
@@ -38,35 +99,76 @@ fn testnet_tx_verify_new_client_normal() {
     );
 }
 
-fn verify_new_client_common(tx_file: &str, cell_dep_index: usize) {
+#[test]
+fn testnet_legacy_layout_conversion() {
     tests::setup();
 
-    let path = tests::data::find_bin_file("testnet", tx_file);
-    let tx = read_to_string(path).unwrap();
-    let tx: TransactionView = from_json_str(&tx).unwrap();
+    let (client, header) =
+        min_difficulty_client_and_header(4132, core::CompactTarget::from_consensus(0x1a01_aa3d));
+    let (update, new_client) = utilities::append_headers(&client, &[header]);
 
-    let witnesses = tx.inner.witnesses;
-    let witness_args = WitnessArgs::from_slice(witnesses[0].as_bytes()).unwrap();
-    let spv_update_bin = witness_args.output_type().to_opt().unwrap().raw_data();
-    let spv_update = SpvUpdate::from_slice(&spv_update_bin).unwrap();
+    // The legacy update is a table without the coinbase proofs.
+    let headers = update.headers();
+    let proof = update.new_headers_mmr_proof();
+    let header_size = 4 * 3;
+    let proof_start = header_size + headers.as_slice().len();
+    let total_size = proof_start + proof.as_slice().len();
+    let mut legacy_update = Vec::new();
+    for value in [total_size, header_size, proof_start] {
+        legacy_update.extend_from_slice(&(value as u32).to_le_bytes());
+    }
+    legacy_update.extend_from_slice(headers.as_slice());
+    legacy_update.extend_from_slice(proof.as_slice());
+    let decoded = utilities::decode_legacy_spv_update(&legacy_update);
+    assert_eq!(decoded.as_slice(), update.as_slice());
 
-    let client_bin = tx.inner.outputs_data[1].clone();
-    let client = SpvClient::from_slice(client_bin.as_bytes()).unwrap();
+    // The legacy client is the current client without the recent timestamps.
+    let packed_client = new_client.pack();
+    let legacy_client = &packed_client.as_slice()[..113];
+    let decoded = utilities::decode_legacy_spv_client(legacy_client, new_client.recent_timestamps);
+    assert_eq!(decoded.pack().as_slice(), packed_client.as_slice());
 
-    let cell_dep = tx.inner.cell_deps[cell_dep_index].out_point.clone();
-    let path = tests::data::find_bin_file(
-        "testnet",
-        format!("tx-0x{}.json", cell_dep.tx_hash).as_str(),
-    );
-    let previous_tx = read_to_string(path).unwrap();
-    let previous_tx: TransactionView = from_json_str(&previous_tx).unwrap();
-    let cell_dep_data_bin = &previous_tx.inner.outputs_data[cell_dep.index.value() as usize];
-    let cell_dep_client = SpvClient::from_slice(cell_dep_data_bin.as_bytes()).unwrap();
-
-    let mut cell_dep_client: core::SpvClient = cell_dep_client.unpack();
-    cell_dep_client.id = client.id().into();
-    let input_client = cell_dep_client.pack();
-    let ret = input_client.verify_new_client(&client, spv_update, 128);
+    let result = client
+        .pack()
+        .verify_new_client(&decoded.pack(), update, FLAG_CHAIN_TYPE_TESTNET)
+        .map_err(|err| err as i8);
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn testnet_min_difficulty_rule() {
+    tests::setup();
+
+    let cached = core::CompactTarget::from_consensus(0x1a01_aa3d);
+    let min = core::Target::MAX.to_compact_lossy();
+    let prev_time = 1_700_000_000;
+
+    let testnet = FLAG_CHAIN_TYPE_TESTNET;
+    // Not more than 20 minutes: use the last non-minimum-difficulty target.
+    let bits =
+        calculate_required_compact_target(cached, prev_time, 4033, prev_time + 1200, testnet);
+    assert_eq!(bits, cached);
+    // More than 20 minutes: only the minimum difficulty is allowed.
+    let bits =
+        calculate_required_compact_target(cached, prev_time, 4033, prev_time + 1201, testnet);
+    assert_eq!(bits, min);
+    // The first header of a new difficulty is not affected.
+    let bits =
+        calculate_required_compact_target(cached, prev_time, 4032, prev_time + 1201, testnet);
+    assert_eq!(bits, cached);
+
+    let mainnet = FLAG_CHAIN_TYPE_MAINNET;
+    let bits =
+        calculate_required_compact_target(cached, prev_time, 4033, prev_time + 1201, mainnet);
+    assert_eq!(bits, cached);
+}
+
+fn verify_new_client_common(tx_file: &str, cell_dep_index: usize) {
+    tests::setup();
+
+    let (input_client, client, spv_update) =
+        utilities::load_legacy_update("testnet", tx_file, cell_dep_index);
+    let ret = input_client.verify_new_client(&client, spv_update, FLAG_CHAIN_TYPE_TESTNET);
 
     assert!(ret.is_ok());
 }
@@ -0,0 +1,123 @@
+use crate::{
+    constants::*,
+    error::UpdateError,
+    tests::{self, testnet::min_difficulty_client_and_header, utilities},
+    types::{core, packed, prelude::*},
+    utilities::bitcoin::{
+        calculate_next_compact_target, calculate_next_target, calculate_required_compact_target,
+        verify_timewarp,
+    },
+};
+
+#[test]
+fn testnet4_timewarp_rule() {
+    tests::setup();
+
+    let prev_time = 1_700_000_000;
+    let flags = FLAG_CHAIN_TYPE_TESTNET4;
+
+    // The first header of a new difficulty.
+    assert!(verify_timewarp(
+        prev_time,
+        4032,
+        prev_time - MAX_TIMEWARP,
+        flags
+    ));
+    assert!(!verify_timewarp(
+        prev_time,
+        4032,
+        prev_time - MAX_TIMEWARP - 1,
+        flags
+    ));
+    // Other headers are not affected.
+    assert!(verify_timewarp(
+        prev_time,
+        4033,
+        prev_time - MAX_TIMEWARP - 1,
+        flags
+    ));
+    // Other chains are not affected.
+    let flags = FLAG_CHAIN_TYPE_TESTNET;
+    assert!(verify_timewarp(
+        prev_time,
+        4032,
+        prev_time - MAX_TIMEWARP - 1,
+        flags
+    ));
+}
+
+#[test]
+fn testnet4_min_difficulty_rule() {
+    tests::setup();
+
+    let cached = core::CompactTarget::from_consensus(0x1a01_aa3d);
+    let min = core::Target::MAX.to_compact_lossy();
+    let prev_time = 1_700_000_000;
+    let flags = FLAG_CHAIN_TYPE_TESTNET4;
+
+    let bits = calculate_required_compact_target(cached, prev_time, 4033, prev_time + 1201, flags);
+    assert_eq!(bits, min);
+    assert_eq!(core::BitcoinChainType::Testnet4, flags.into());
+}
+
+#[test]
+fn testnet4_verify_new_client_across_difficulty_adjustment() {
+    tests::setup();
+
+    let flags = FLAG_CHAIN_TYPE_TESTNET4;
+    let cached = core::CompactTarget::from_consensus(0x1a01_aa3d);
+    // The next header is the last header of the difficulty adjustment period,
+    // and it is mined with the minimum difficulty.
+    let (client, header) = min_difficulty_client_and_header(4030, cached);
+    let (update, mut new_client) = utilities::append_headers(&client, &[header]);
+    let (start_time, _) = client.target_adjust_info.decode().unwrap();
+
+    // BIP94: the next target is calculated from the first header of the period.
+    let next_bits =
+        calculate_next_target(cached.into(), start_time, header.time, flags).to_compact_lossy();
+    // Without BIP94, it is calculated from the last header, as testnet does.
+    let legacy_bits = calculate_next_target(header.bits.into(), start_time, header.time, flags)
+        .to_compact_lossy();
+    assert_ne!(next_bits, legacy_bits);
+    assert_eq!(
+        calculate_next_compact_target(cached, start_time, &header, flags),
+        next_bits
+    );
+    assert_eq!(
+        calculate_next_compact_target(cached, start_time, &header, FLAG_CHAIN_TYPE_TESTNET),
+        legacy_bits
+    );
+
+    new_client.target_adjust_info = packed::TargetAdjustInfo::encode(start_time, legacy_bits);
+    let result = client
+        .pack()
+        .verify_new_client(&new_client.pack(), update.clone(), flags)
+        .map_err(|err| err as i8);
+    assert_eq!(result, Err(UpdateError::ClientTargetAdjustInfo as i8));
+
+    new_client.target_adjust_info = packed::TargetAdjustInfo::encode(start_time, next_bits);
+    let result = client
+        .pack()
+        .verify_new_client(&new_client.pack(), update, flags)
+        .map_err(|err| err as i8);
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn chain_type_flags() {
+    // The two highest bits are compatible with the old flags.
+    for (flags, chain_type) in [
+        (0b0000_0000, core::BitcoinChainType::Mainnet),
+        (0b0010_0001, core::BitcoinChainType::Mainnet),
+        (0b1000_0000, core::BitcoinChainType::Testnet),
+        (0b1011_0000, core::BitcoinChainType::Testnet),
+        (0b0100_0000, core::BitcoinChainType::Signet),
+        (0b0110_0000, core::BitcoinChainType::Signet),
+        (0b1100_0000, core::BitcoinChainType::Other),
+        (0b1111_0000, core::BitcoinChainType::Other),
+        (FLAG_CHAIN_TYPE_TESTNET4, core::BitcoinChainType::Testnet4),
+        (FLAG_CHAIN_TYPE_REGTEST, core::BitcoinChainType::Regtest),
+    ] {
+        assert_eq!(core::BitcoinChainType::from(flags), chain_type);
+    }
+}
@@ -1,6 +1,29 @@
-use std::{fs::File, io::Read as _, path::Path, vec::Vec};
+use std::{
+    fs::{read_to_string, File},
+    io::Read as _,
+    path::Path,
+    vec::Vec,
+};
 
+use alloc::format;
 use bitcoin::consensus::{deserialize, Decodable};
+use ckb_jsonrpc_types::TransactionView;
+use ckb_types::packed::WitnessArgs;
+use serde_json::from_str as from_json_str;
+
+use crate::{
+    constants::MEDIAN_TIME_SPAN,
+    molecule::prelude::*,
+    tests,
+    types::{core, packed, prelude::*},
+    utilities::{
+        bitcoin::append_timestamp,
+        mmr::{self, lib::util::MemStore},
+    },
+};
+
+// The size of the SPV client in the legacy layout, which has no recent timestamps.
+const LEGACY_SPV_CLIENT_SIZE: usize = 113;
 
 pub(crate) fn decode_from_slice<T: Decodable>(slice: &[u8]) -> T {
     deserialize(slice).unwrap()
@@ -24,3 +47,205 @@ pub(crate) fn load_from_bin_file<P: AsRef<Path>>(bin_file: P) -> Vec<u8> {
         })
         .unwrap()
 }
+
+/// Creates a client which only contains its tip header.
+pub(crate) fn new_client(
+    height: u32,
+    tip: &core::Header,
+    recent_timestamps: [u32; MEDIAN_TIME_SPAN],
+    target_adjust_info: packed::TargetAdjustInfo,
+) -> core::SpvClient {
+    core::SpvClient {
+        id: 0,
+        tip_block_hash: tip.block_hash().into(),
+        headers_mmr_root: core::HeaderDigest::new_leaf(height, tip),
+        target_adjust_info,
+        recent_timestamps,
+    }
+}
+
+/// Appends headers to a client which only contains its tip header.
+///
+/// Returns the update and the new client, the target adjust info of the new
+/// client is not changed.
+pub(crate) fn append_headers(
+    client: &core::SpvClient,
+    headers: &[core::Header],
+) -> (packed::SpvUpdate, core::SpvClient) {
+    let tip_height = client.headers_mmr_root.max_height;
+    assert_eq!(client.headers_mmr_root.min_height, tip_height);
+    let store = MemStore::default();
+    let mut mmr = mmr::ClientRootMMR::new(0, &store);
+    mmr.push(client.headers_mmr_root.pack()).unwrap();
+    let mut new_client = client.clone();
+    let mut positions = Vec::new();
+    for (height, header) in ((tip_height + 1)..).zip(headers) {
+        let digest = core::HeaderDigest::new_leaf(height, header);
+        positions.push(mmr.push(digest.pack()).unwrap());
+        new_client.tip_block_hash = header.block_hash().into();
+        append_timestamp(&mut new_client.recent_timestamps, header.time);
+    }
+    new_client.headers_mmr_root = mmr.get_root().unwrap().unpack();
+    let proof = mmr.gen_proof(positions).unwrap().proof_items().to_vec();
+    let update = packed::SpvUpdate::new_builder()
+        .headers(headers.to_vec().pack())
+        .new_headers_mmr_proof(packed::MmrProof::new_builder().set(proof).build())
+        .build();
+    (update, new_client)
+}
+
+/// Decodes an SPV update in the legacy layout, which has no coinbase proofs.
+pub(crate) fn decode_legacy_spv_update(slice: &[u8]) -> packed::SpvUpdate {
+    try_decode_legacy_spv_update(slice).unwrap()
+}
+
+fn try_decode_legacy_spv_update(slice: &[u8]) -> Option<packed::SpvUpdate> {
+    let read_u32 = |start: usize| {
+        let mut buf = [0u8; 4];
+        buf.copy_from_slice(slice.get(start..start + 4)?);
+        Some(u32::from_le_bytes(buf) as usize)
+    };
+    // A table with 2 fields: total size, 2 offsets, then the fields.
+    if read_u32(0)? != slice.len() || read_u32(4)? != 4 * 3 {
+        return None;
+    }
+    let proof_start = read_u32(8)?;
+    let headers = packed::HeaderVec::from_slice(slice.get(4 * 3..proof_start)?).ok()?;
+    let proof = packed::MmrProof::from_slice(slice.get(proof_start..)?).ok()?;
+    let update = packed::SpvUpdate::new_builder()
+        .headers(headers)
+        .new_headers_mmr_proof(proof)
+        .build();
+    Some(update)
+}
+
+/// Decodes an SPV client in the legacy layout, and appends the recent timestamps to it.
+pub(crate) fn decode_legacy_spv_client(
+    slice: &[u8],
+    recent_timestamps: [u32; MEDIAN_TIME_SPAN],
+) -> core::SpvClient {
+    assert_eq!(slice.len(), LEGACY_SPV_CLIENT_SIZE);
+    let mut data = slice.to_vec();
+    data.extend_from_slice(recent_timestamps.pack().as_slice());
+    packed::SpvClient::from_slice(&data).unwrap().unpack()
+}
+
+fn load_transaction(in_dir: &str, tx_file: &str) -> TransactionView {
+    let path = tests::data::find_bin_file(in_dir, tx_file);
+    let tx = read_to_string(path).unwrap();
+    from_json_str(&tx).unwrap()
+}
+
+fn legacy_spv_update_of(tx: &TransactionView) -> packed::SpvUpdate {
+    try_legacy_spv_update_of(tx).unwrap()
+}
+
+fn try_legacy_spv_update_of(tx: &TransactionView) -> Option<packed::SpvUpdate> {
+    let witness = tx.inner.witnesses.first()?;
+    let witness_args = WitnessArgs::from_slice(witness.as_bytes()).ok()?;
+    let spv_update_bin = witness_args.output_type().to_opt()?.raw_data();
+    try_decode_legacy_spv_update(&spv_update_bin)
+}
+
+fn headers_of(update: &packed::SpvUpdate) -> Vec<core::Header> {
+    update
+        .headers()
+        .into_iter()
+        .map(|header| decode_from_slice(header.as_slice()))
+        .collect()
+}
+
+// Collects the real headers before the first header of the update in the
+// transaction, from the updates of the previous transactions in the same
+// directory, until `MEDIAN_TIME_SPAN` headers are collected.
+//
+// The previous transactions are found by the cell deps and the inputs, and
+// their headers should be continuous.
+fn load_previous_headers(in_dir: &str, tx: &TransactionView) -> Vec<core::Header> {
+    let mut headers: Vec<core::Header> = Vec::new();
+    let mut next = headers_of(&legacy_spv_update_of(tx))[0];
+    let mut tx = tx.clone();
+    while headers.len() < MEDIAN_TIME_SPAN {
+        let previous = tx
+            .inner
+            .cell_deps
+            .iter()
+            .map(|cell_dep| cell_dep.out_point.tx_hash.clone())
+            .chain(
+                tx.inner
+                    .inputs
+                    .iter()
+                    .map(|input| input.previous_output.tx_hash.clone()),
+            )
+            .filter_map(|tx_hash| {
+                let tx_file = format!("tx-0x{tx_hash}.json");
+                if !tests::data::find_bin_file(in_dir, &tx_file).exists() {
+                    return None;
+                }
+                let previous_tx = load_transaction(in_dir, &tx_file);
+                let previous_headers = headers_of(&try_legacy_spv_update_of(&previous_tx)?);
+                let last = previous_headers.last()?;
+                (next.prev_blockhash == last.block_hash())
+                    .then_some((previous_tx, previous_headers))
+            })
+            .next();
+        let Some((previous_tx, mut previous_headers)) = previous else {
+            break;
+        };
+        next = previous_headers[0];
+        previous_headers.append(&mut headers);
+        headers = previous_headers;
+        tx = previous_tx;
+    }
+    let start = headers.len().saturating_sub(MEDIAN_TIME_SPAN);
+    headers.split_off(start)
+}
+
+/// Loads an update from a CKB transaction, which clients and update are in
+/// the legacy layout, and converts them into the current layout.
+///
+/// Returns the input client, the output client and the update.
+///
+/// The legacy clients have no recent timestamps, so they are recovered from
+/// the real headers before the update, which are in the updates of the
+/// previous transactions in the same directory.
+///
+/// If less than `MEDIAN_TIME_SPAN` previous headers are found, the missing
+/// timestamps are zeros, so the median-time-past rule is not fully checked
+/// with this fixture, a warning is logged. The rule is covered by dedicated
+/// tests, for example, `regtest_median_time_past` in the prover.
+pub(crate) fn load_legacy_update(
+    in_dir: &str,
+    tx_file: &str,
+    cell_dep_index: usize,
+) -> (packed::SpvClient, packed::SpvClient, packed::SpvUpdate) {
+    let tx = load_transaction(in_dir, tx_file);
+    let spv_update = legacy_spv_update_of(&tx);
+
+    let previous_headers = load_previous_headers(in_dir, &tx);
+    if previous_headers.len() < MEDIAN_TIME_SPAN {
+        warn!(
+            "only {} headers before {tx_file} are found, \
+            the median-time-past rule is not fully checked",
+            previous_headers.len()
+        );
+    }
+    let mut recent_timestamps = [0; MEDIAN_TIME_SPAN];
+    for header in &previous_headers {
+        append_timestamp(&mut recent_timestamps, header.time);
+    }
+    let cell_dep = tx.inner.cell_deps[cell_dep_index].out_point.clone();
+    let previous_tx = load_transaction(in_dir, &format!("tx-0x{}.json", cell_dep.tx_hash));
+    let cell_dep_data_bin = &previous_tx.inner.outputs_data[cell_dep.index.value() as usize];
+    let mut input_client =
+        decode_legacy_spv_client(cell_dep_data_bin.as_bytes(), recent_timestamps);
+
+    for header in headers_of(&spv_update) {
+        append_timestamp(&mut recent_timestamps, header.time);
+    }
+    let client_bin = tx.inner.outputs_data[1].clone();
+    let output_client = decode_legacy_spv_client(client_bin.as_bytes(), recent_timestamps);
+    input_client.id = output_client.id;
+
+    (input_client.pack(), output_client.pack(), spv_update)
+}
@@ -0,0 +1,451 @@
+//! The state transitions of the SPV cells.
+//!
+//! An SPV instance is a group of cells which use the same type script:
+//! - One SPV info cell, which records the ID of the tip SPV client cell.
+//! - `clients_count` SPV client cells, which are a ring, their IDs are from `0`
+//!   to `clients_count - 1`.
+//!
+//! The transitions are:
+//! - Bootstrap: create the SPV info cell and all SPV client cells.
+//! - Update: append new headers, the next client of the tip client is replaced,
+//!   and it becomes the new tip client.
+//! - Reorg: replace a suffix of headers, all clients are consumed, the next client
+//!   of the tip client becomes the new tip client, and the clients which contain
+//!   the dropped headers are replaced by the new tip client.
+//! - Destroy: consume the SPV info cell and all SPV client cells.
+//!
+//! N.B. The uniqueness of the type ID in the script args is not checked here,
+//! since the inputs of the transaction are required.
+
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    checkpoints,
+    constants::{
+        FLAG_CHAIN_TYPE_EXTENDED_MASK, FLAG_CHAIN_TYPE_MASK, MAX_CLIENTS_COUNT, MIN_CLIENTS_COUNT,
+    },
+    core::result::Result,
+    error::TransitionError,
+    types::{core, packed, prelude::*},
+};
+
+/// The kinds of the state transitions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transition {
+    Bootstrap,
+    Update,
+    Reorg,
+    Destroy,
+}
+
+/// The parsed script args of the SPV type script.
+pub struct SpvArgs<'a> {
+    /// How many SPV client cells.
+    pub clients_count: u8,
+    /// The flags, only the chain type is used for now.
+    pub flags: u8,
+    /// The challenge of signet, which is the remained bytes after the `SpvTypeArgs`.
+    ///
+    /// If it's `None`, the signet block solutions are not checked.
+    pub signet_challenge: Option<&'a [u8]>,
+}
+
+/// The SPV cells, which use the same type script, in inputs, outputs or cell deps.
+pub struct SpvCells<'a> {
+    /// The SPV info cell, if it exists.
+    pub info: Option<packed::SpvInfoReader<'a>>,
+    /// The SPV client cells.
+    pub clients: Vec<packed::SpvClientReader<'a>>,
+}
+
+/// Parses the script args of the SPV type script.
+///
+/// Checks:
+/// - The `clients_count` should be in `[MIN_CLIENTS_COUNT, MAX_CLIENTS_COUNT]`.
+/// - The chain type in the flags should be known, and other bits should be zeros.
+/// - The remained bytes are the challenge, which is allowed for signet only.
+///   For signet, if there is no challenge, the block solutions are not checked.
+pub fn parse_args(args: &[u8]) -> Result<SpvArgs<'_>, TransitionError> {
+    if args.len() < packed::SpvTypeArgs::TOTAL_SIZE {
+        error!(
+            "failed: the script args is too short ({} bytes)",
+            args.len()
+        );
+        return Err(TransitionError::DecodeArgs);
+    }
+    let (type_args, challenge) = args.split_at(packed::SpvTypeArgs::TOTAL_SIZE);
+    let type_args = packed::SpvTypeArgsReader::from_slice(type_args)
+        .map_err(|_| TransitionError::DecodeArgs)?;
+    let clients_count: u8 = type_args.clients_count().into();
+    if !(MIN_CLIENTS_COUNT..=MAX_CLIENTS_COUNT).contains(&clients_count) {
+        error!(
+            "failed: the clients count ({clients_count}) should be in \
+            [{MIN_CLIENTS_COUNT}, {MAX_CLIENTS_COUNT}]"
+        );
+        return Err(TransitionError::ClientsCount);
+    }
+    let flags: u8 = type_args.flags().into();
+    let chain_type: core::BitcoinChainType = flags.into();
+    let chain_type_mask = if flags & FLAG_CHAIN_TYPE_MASK == FLAG_CHAIN_TYPE_MASK {
+        FLAG_CHAIN_TYPE_EXTENDED_MASK
+    } else {
+        FLAG_CHAIN_TYPE_MASK
+    };
+    if chain_type == core::BitcoinChainType::Other || flags & !chain_type_mask != 0 {
+        error!("failed: the flags ({flags:#010b}) are unknown");
+        return Err(TransitionError::Flags);
+    }
+    let signet_challenge = match (chain_type, challenge.is_empty()) {
+        (_, true) => None,
+        (core::BitcoinChainType::Signet, false) => Some(challenge),
+        (_, false) => {
+            error!("failed: the challenge is allowed for signet only");
+            return Err(TransitionError::SignetChallenge);
+        }
+    };
+    Ok(SpvArgs {
+        clients_count,
+        flags,
+        signet_challenge,
+    })
+}
+
+/// Parses the data of SPV cells, they are distinguished by the size of the data.
+pub fn parse_cells<'a>(cells: &[&'a [u8]]) -> Result<SpvCells<'a>, TransitionError> {
+    let mut info = None;
+    let mut clients = Vec::with_capacity(cells.len());
+    for data in cells {
+        match data.len() {
+            packed::SpvInfo::TOTAL_SIZE => {
+                if info.is_some() {
+                    error!("failed: there are multiple SPV info cells");
+                    return Err(TransitionError::InfoCell);
+                }
+                let reader = packed::SpvInfoReader::from_slice(data)
+                    .map_err(|_| TransitionError::DecodeCellData)?;
+                info = Some(reader);
+            }
+            packed::SpvClient::TOTAL_SIZE => {
+                let reader = packed::SpvClientReader::from_slice(data)
+                    .map_err(|_| TransitionError::DecodeCellData)?;
+                clients.push(reader);
+            }
+            _ => {
+                error!("failed: unknown cell data ({} bytes)", data.len());
+                return Err(TransitionError::DecodeCellData);
+            }
+        }
+    }
+    Ok(SpvCells { info, clients })
+}
+
+/// Verifies a state transition of SPV cells.
+///
+/// The `inputs`, `outputs` and `cell_deps` are the data of the cells which use
+/// the SPV type script, and the `witness` is the bytes of `SpvBootstrap`,
+/// `SpvUpdate` or `SpvReorg`, it's ignored when destroying.
+///
+/// The transition is determined by:
+/// - No inputs: bootstrap.
+/// - No outputs: destroy.
+/// - Otherwise, update or reorg, which is determined by the witness.
+pub fn verify_transition(
+    args: &[u8],
+    inputs: &[&[u8]],
+    outputs: &[&[u8]],
+    cell_deps: &[&[u8]],
+    witness: &[u8],
+) -> Result<Transition, TransitionError> {
+    let args = parse_args(args)?;
+    match (inputs.is_empty(), outputs.is_empty()) {
+        (true, true) => {
+            error!("failed: no SPV cells in inputs and outputs");
+            Err(TransitionError::UnknownTransition)
+        }
+        (true, false) => {
+            let outputs = parse_cells(outputs)?;
+            verify_bootstrap(&args, &outputs, witness).map(|_| Transition::Bootstrap)
+        }
+        (false, true) => {
+            let inputs = parse_cells(inputs)?;
+            verify_destroy(&args, &inputs).map(|_| Transition::Destroy)
+        }
+        (false, false) => {
+            let inputs = parse_cells(inputs)?;
+            let outputs = parse_cells(outputs)?;
+            if let Ok(reorg) = packed::SpvReorgReader::from_slice(witness) {
+                verify_reorg(&args, &inputs, &outputs, reorg).map(|_| Transition::Reorg)
+            } else if let Ok(update) = packed::SpvUpdateReader::from_slice(witness) {
+                let cell_deps = parse_cells(cell_deps)?;
+                verify_update(&args, &inputs, &outputs, &cell_deps, update)
+                    .map(|_| Transition::Update)
+            } else {
+                error!("failed: the witness is neither an update nor a reorg");
+                Err(TransitionError::DecodeWitness)
+            }
+        }
+    }
+}
+
+/// Verifies the bootstrap.
+///
+/// Checks:
+/// - Only one SPV info cell and `clients_count` SPV client cells are created.
+/// - The tip client ID is `0`.
+/// - Each ID of clients is used once.
+/// - All clients are the client initialized from the witness, with the built-in
+///   checkpoints, except their IDs.
+pub fn verify_bootstrap(
+    args: &SpvArgs,
+    outputs: &SpvCells,
+    witness: &[u8],
+) -> Result<(), TransitionError> {
+    let info = outputs.info.ok_or(TransitionError::InfoCell)?;
+    verify_all_clients(args, &outputs.clients)?;
+    let tip_client_id: u8 = info.tip_client_id().into();
+    if tip_client_id != 0 {
+        error!("failed: the tip client ID ({tip_client_id}) should be 0 when bootstrapping");
+        return Err(TransitionError::TipClientId);
+    }
+    let bootstrap = packed::SpvBootstrapReader::from_slice(witness)
+        .map_err(|_| TransitionError::DecodeWitness)?;
+    let client = bootstrap
+        .to_entity()
+        .initialize_spv_client_with_checkpoints(args.flags, checkpoints::for_chain(args.flags))
+        .map_err(|_err| {
+            error!("failed: bootstrap with error {}", _err as i8);
+            TransitionError::Bootstrap
+        })?
+        .pack();
+    for actual in &outputs.clients {
+        let expected = client
+            .clone()
+            .as_builder()
+            .id(actual.id().to_entity())
+            .build();
+        if expected.as_slice() != actual.as_slice() {
+            error!(
+                "failed: client-{} is not the initialized client",
+                u8::from(actual.id())
+            );
+            return Err(TransitionError::Bootstrap);
+        }
+    }
+    Ok(())
+}
+
+/// Verifies the update.
+///
+/// Checks:
+/// - The SPV info cell and one SPV client cell are consumed and created.
+/// - The consumed client is the next client of the tip client, and the created
+///   client has the same ID, and it becomes the new tip client.
+/// - The tip client, which is in cell deps, could be updated to the created
+///   client with the witness, when its ID is changed to the new tip client ID.
+pub fn verify_update(
+    args: &SpvArgs,
+    inputs: &SpvCells,
+    outputs: &SpvCells,
+    cell_deps: &SpvCells,
+    update: packed::SpvUpdateReader,
+) -> Result<(), TransitionError> {
+    let (input_client, output_client) = match (&inputs.clients[..], &outputs.clients[..]) {
+        ([input], [output]) => (input, output),
+        _ => {
+            error!(
+                "failed: update {} clients into {} clients",
+                inputs.clients.len(),
+                outputs.clients.len()
+            );
+            return Err(TransitionError::ClientCells);
+        }
+    };
+    let (tip_client_id, new_tip_client_id) = verify_info_cells(args, inputs, outputs)?;
+    for id in [input_client.id(), output_client.id()] {
+        if u8::from(id) != new_tip_client_id {
+            error!(
+                "failed: client-{} is updated, expect client-{new_tip_client_id}",
+                u8::from(id)
+            );
+            return Err(TransitionError::ClientId);
+        }
+    }
+    let tip_client = cell_deps
+        .clients
+        .iter()
+        .find(|client| u8::from(client.id()) == tip_client_id)
+        .ok_or_else(|| {
+            error!("failed: the tip client-{tip_client_id} is not found in cell deps");
+            TransitionError::TipClientNotFound
+        })?
+        .to_entity()
+        .as_builder()
+        .id(new_tip_client_id.into())
+        .build();
+    let output_client = output_client.to_entity();
+    let update = update.to_entity();
+    let result = if let Some(challenge) = args.signet_challenge {
+        tip_client.verify_new_client_with_signet_challenge(
+            &output_client,
+            update,
+            args.flags,
+            challenge,
+        )
+    } else {
+        tip_client.verify_new_client(&output_client, update, args.flags)
+    };
+    result.map_err(|_err| {
+        error!("failed: update with error {}", _err as i8);
+        TransitionError::Update
+    })
+}
+
+/// Verifies the reorg.
+///
+/// Checks:
+/// - The SPV info cell and all SPV client cells are consumed and created.
+/// - The next client of the tip client becomes the new tip client.
+/// - The tip client could be reorganized to the new tip client with the witness,
+///   when its ID is changed to the new tip client ID.
+/// - Other clients, which contain the dropped headers, are replaced by the new tip
+///   client, except their IDs; others are not changed.
+pub fn verify_reorg(
+    args: &SpvArgs,
+    inputs: &SpvCells,
+    outputs: &SpvCells,
+    reorg: packed::SpvReorgReader,
+) -> Result<(), TransitionError> {
+    verify_all_clients(args, &inputs.clients)?;
+    verify_all_clients(args, &outputs.clients)?;
+    let (tip_client_id, new_tip_client_id) = verify_info_cells(args, inputs, outputs)?;
+    let find_client = |clients: &[packed::SpvClientReader], id: u8| {
+        clients
+            .iter()
+            .find(|client| u8::from(client.id()) == id)
+            .map(|client| client.to_entity())
+            .ok_or(TransitionError::ClientId)
+    };
+    let tip_client = find_client(&inputs.clients, tip_client_id)?;
+    let new_tip_client = find_client(&outputs.clients, new_tip_client_id)?;
+    let expected_client = tip_client
+        .clone()
+        .as_builder()
+        .id(new_tip_client_id.into())
+        .build();
+    let result = if let Some(challenge) = args.signet_challenge {
+        expected_client.verify_reorg_client_with_signet_challenge(
+            &new_tip_client,
+            reorg.to_entity(),
+            args.flags,
+            challenge,
+        )
+    } else {
+        expected_client.verify_reorg_client(&new_tip_client, reorg.to_entity(), args.flags)
+    };
+    result.map_err(|_err| {
+        error!("failed: reorg with error {}", _err as i8);
+        TransitionError::Reorg
+    })?;
+
+    let tip_height: u32 = tip_client.headers_mmr_root().max_height().unpack();
+    let fork_height = tip_height - reorg.dropped_headers().len() as u32;
+    for input in &inputs.clients {
+        let id = input.id();
+        if u8::from(id) == new_tip_client_id {
+            continue;
+        }
+        let output = find_client(&outputs.clients, id.into())?;
+        let max_height: u32 = input.headers_mmr_root().max_height().unpack();
+        let expected = if max_height > fork_height {
+            new_tip_client
+                .clone()
+                .as_builder()
+                .id(id.to_entity())
+                .build()
+        } else {
+            input.to_entity()
+        };
+        if expected.as_slice() != output.as_slice() {
+            error!(
+                "failed: client-{} (max height: {max_height}) is not replaced correctly, \
+                the fork height is {fork_height}",
+                u8::from(id)
+            );
+            return Err(TransitionError::StaleClient);
+        }
+    }
+    Ok(())
+}
+
+/// Verifies the destroy.
+///
+/// Checks:
+/// - The SPV info cell and all SPV client cells are consumed.
+pub fn verify_destroy(args: &SpvArgs, inputs: &SpvCells) -> Result<(), TransitionError> {
+    if inputs.info.is_none() {
+        error!("failed: the SPV info cell is not consumed");
+        return Err(TransitionError::InfoCell);
+    }
+    verify_all_clients(args, &inputs.clients)
+}
+
+// Verifies there are `clients_count` clients, and each ID of clients is used once.
+fn verify_all_clients(
+    args: &SpvArgs,
+    clients: &[packed::SpvClientReader],
+) -> Result<(), TransitionError> {
+    if clients.len() != usize::from(args.clients_count) {
+        error!(
+            "failed: {} clients are required but got {}",
+            args.clients_count,
+            clients.len()
+        );
+        return Err(TransitionError::ClientCells);
+    }
+    let mut used = vec![false; clients.len()];
+    for client in clients {
+        let id: u8 = client.id().into();
+        match used.get_mut(usize::from(id)) {
+            Some(used) if !*used => *used = true,
+            _ => {
+                error!("failed: client ID ({id}) is out of range or duplicated");
+                return Err(TransitionError::ClientId);
+            }
+        }
+    }
+    Ok(())
+}
+
+// Verifies the SPV info cells in inputs and outputs, the next client of the tip
+// client should be the new tip client.
+//
+// Returns the tip client ID and the new tip client ID.
+fn verify_info_cells(
+    args: &SpvArgs,
+    inputs: &SpvCells,
+    outputs: &SpvCells,
+) -> Result<(u8, u8), TransitionError> {
+    let (input_info, output_info) = match (inputs.info, outputs.info) {
+        (Some(input), Some(output)) => (input, output),
+        _ => {
+            error!("failed: the SPV info cell should be in both inputs and outputs");
+            return Err(TransitionError::InfoCell);
+        }
+    };
+    let tip_client_id: u8 = input_info.tip_client_id().into();
+    if tip_client_id >= args.clients_count {
+        error!(
+            "failed: the tip client ID ({tip_client_id}) is out of range, \
+            clients count is {}",
+            args.clients_count
+        );
+        return Err(TransitionError::TipClientId);
+    }
+    let new_tip_client_id = (tip_client_id + 1) % args.clients_count;
+    let actual: u8 = output_info.tip_client_id().into();
+    if actual != new_tip_client_id {
+        error!("failed: the new tip client ID ({actual}) should be {new_tip_client_id}");
+        return Err(TransitionError::TipClientId);
+    }
+    Ok((tip_client_id, new_tip_client_id))
+}
@@ -1,4 +1,6 @@
 //! Implement basic conversion functions, according to the traits in `prelude` module.
 
 mod pack;
+#[cfg(feature = "serde")]
+pub(crate) mod serde;
 mod unpack;
@@ -3,7 +3,10 @@ use alloc::vec::Vec;
 use bitcoin::consensus::serialize;
 use primitive_types::U256;
 
-use crate::types::{bytes::Bytes, core, packed, prelude::*};
+use crate::{
+    constants::MEDIAN_TIME_SPAN,
+    types::{bytes::Bytes, core, packed, prelude::*},
+};
 
 //
 // Baisc
@@ -16,6 +19,29 @@ impl Pack<packed::Uint32> for u32 {
     }
 }
 
+impl Pack<packed::Uint32Vec> for Vec<u32> {
+    fn pack(&self) -> packed::Uint32Vec {
+        packed::Uint32Vec::new_builder()
+            .set(self.iter().map(|v| v.pack()).collect())
+            .build()
+    }
+}
+
+impl Pack<packed::Uint32Opt> for Option<u32> {
+    fn pack(&self) -> packed::Uint32Opt {
+        packed::Uint32Opt::new_builder()
+            .set(self.map(|v| v.pack()))
+            .build()
+    }
+}
+
+impl Pack<packed::Uint64> for u64 {
+    fn pack(&self) -> packed::Uint64 {
+        let le = self.to_le_bytes();
+        packed::Uint64::new_unchecked(Bytes::from(le.to_vec()))
+    }
+}
+
 impl Pack<packed::Uint256> for U256 {
     fn pack(&self) -> packed::Uint256 {
         let mut le = [0u8; 32];
@@ -31,6 +57,14 @@ impl Pack<packed::Hash> for core::Hash {
     }
 }
 
+impl Pack<packed::HashVec> for Vec<core::Hash> {
+    fn pack(&self) -> packed::HashVec {
+        packed::HashVec::new_builder()
+            .set(self.iter().map(|v| v.pack()).collect())
+            .build()
+    }
+}
+
 impl Pack<packed::Header> for core::Header {
     fn pack(&self) -> packed::Header {
         let vec = serialize(self);
@@ -94,6 +128,14 @@ impl Pack<packed::SpvInfo> for core::SpvInfo {
     }
 }
 
+impl Pack<packed::RecentTimestamps> for [u32; MEDIAN_TIME_SPAN] {
+    fn pack(&self) -> packed::RecentTimestamps {
+        packed::RecentTimestamps::new_builder()
+            .set(self.map(|v| v.pack()))
+            .build()
+    }
+}
+
 impl Pack<packed::SpvClient> for core::SpvClient {
     fn pack(&self) -> packed::SpvClient {
         packed::SpvClient::new_builder()
@@ -101,6 +143,7 @@ impl Pack<packed::SpvClient> for core::SpvClient {
             .tip_block_hash(self.tip_block_hash.pack())
             .headers_mmr_root(self.headers_mmr_root.pack())
             .target_adjust_info(self.target_adjust_info.clone())
+            .recent_timestamps(self.recent_timestamps.pack())
             .build()
     }
 }
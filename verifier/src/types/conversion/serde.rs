@@ -0,0 +1,211 @@
+//! Implement serde traits for types, requires the feature `serde`.
+//!
+//! The representations are stable and human-readable:
+//! - Heights, timestamps, IDs and flags are numbers.
+//! - Block hashes are hex strings with `0x` prefix, in the same byte order as
+//!   Bitcoin RPCs (the reversed byte order).
+//! - Chain work and compact targets are hex numbers with `0x` prefix.
+//! - Other packed types, such as headers and proofs, are hex strings of their
+//!   molecule bytes, with `0x` prefix.
+
+use alloc::{string::String, vec::Vec};
+
+use bitcoin::hex::{DisplayHex as _, FromHex as _};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::types::{core, packed, prelude::*};
+
+fn strip_hex_prefix<E: serde::de::Error>(s: &str) -> Result<&str, E> {
+    s.strip_prefix("0x")
+        .ok_or_else(|| E::custom("hex string should start with \"0x\""))
+}
+
+fn deserialize_hex_bytes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    Vec::from_hex(strip_hex_prefix(&s)?).map_err(D::Error::custom)
+}
+
+/// Block hashes, in the reversed byte order.
+pub(crate) mod hash {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        hash: &core::Hash,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("0x{hash:x}"))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<core::Hash, D::Error> {
+        let mut bytes: [u8; 32] = deserialize_hex_bytes(deserializer)?
+            .try_into()
+            .map_err(|_| D::Error::custom("a hash should be 32 bytes"))?;
+        bytes.reverse();
+        Ok(core::Hash::from_byte_array(bytes))
+    }
+}
+
+/// Hashes which are not block hashes, in the natural byte order.
+pub(crate) mod bytes32 {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        hash: &core::Hash,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("0x{}", hash.as_byte_array().as_hex()))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<core::Hash, D::Error> {
+        let bytes: [u8; 32] = deserialize_hex_bytes(deserializer)?
+            .try_into()
+            .map_err(|_| D::Error::custom("a hash should be 32 bytes"))?;
+        Ok(core::Hash::from_byte_array(bytes))
+    }
+}
+
+/// 256-bit unsigned integers, as hex numbers.
+pub(crate) mod u256 {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &core::U256,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{value:#x}"))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<core::U256, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        core::U256::from_str_radix(strip_hex_prefix(&s)?, 16).map_err(D::Error::custom)
+    }
+}
+
+/// Compact targets, as hex numbers.
+pub(crate) mod compact_target {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(value: &u32, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{value:#010x}"))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        u32::from_str_radix(strip_hex_prefix(&s)?, 16).map_err(D::Error::custom)
+    }
+}
+
+//
+// Packed types which have core types.
+//
+
+macro_rules! impl_serde_via_core {
+    ($name:ident) => {
+        impl Serialize for packed::$name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                Unpack::<core::$name>::unpack(self).serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for packed::$name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                core::$name::deserialize(deserializer).map(|value| value.pack())
+            }
+        }
+    };
+}
+
+impl_serde_via_core!(HeaderDigest);
+impl_serde_via_core!(SpvInfo);
+impl_serde_via_core!(SpvClient);
+impl_serde_via_core!(SpvTypeArgs);
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "TargetAdjustInfo")]
+struct TargetAdjustInfoFields {
+    start_time: u32,
+    #[serde(with = "compact_target")]
+    next_compact_target: u32,
+}
+
+impl Serialize for packed::TargetAdjustInfo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        TargetAdjustInfoFields {
+            start_time: self.start_time().unpack(),
+            next_compact_target: self.next_compact_target().unpack(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for packed::TargetAdjustInfo {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = TargetAdjustInfoFields::deserialize(deserializer)?;
+        let info = Self::new_builder()
+            .start_time(fields.start_time.pack())
+            .next_compact_target(fields.next_compact_target.pack())
+            .build();
+        Ok(info)
+    }
+}
+
+//
+// Other packed types, as hex strings of molecule bytes.
+//
+
+macro_rules! impl_serde_via_hex {
+    ($($name:ident),* $(,)?) => {
+        $(
+            impl Serialize for packed::$name {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(&format_args!("0x{}", self.as_slice().as_hex()))
+                }
+            }
+
+            impl<'de> Deserialize<'de> for packed::$name {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let bytes = deserialize_hex_bytes(deserializer)?;
+                    Self::from_slice(&bytes).map_err(|_| {
+                        D::Error::custom(concat!("invalid ", stringify!($name)))
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_via_hex!(
+    Uint32,
+    Uint32Vec,
+    Uint32Opt,
+    Uint64,
+    Uint256,
+    Hash,
+    HashVec,
+    Header,
+    HeaderVec,
+    Bytes,
+    MmrProof,
+    CoinbaseProof,
+    CoinbaseProofVec,
+    RecentTimestamps,
+    SpvBootstrap,
+    SpvUpdate,
+    SpvReorg,
+    TransactionProof,
+    TransactionBranchProof,
+    TransactionsProof,
+    BlockHashWithTarget,
+    HeaderOrHash,
+    HeaderProof,
+    TransactionWitnessProof,
+    HeadersProof,
+    ScriptCondition,
+    PaymentCondition,
+);
@@ -1,8 +1,11 @@
-use alloc::borrow::ToOwned;
+use alloc::{borrow::ToOwned, vec::Vec};
 
 use primitive_types::U256;
 
-use crate::types::{core, packed, prelude::*};
+use crate::{
+    constants::MEDIAN_TIME_SPAN,
+    types::{core, packed, prelude::*},
+};
 
 macro_rules! impl_conversion_for_entity_unpack {
     ($name:ident) => {
@@ -34,6 +37,29 @@ impl<'r> Unpack<u32> for packed::Uint32Reader<'r> {
 }
 impl_conversion_for_entity_unpack!(Uint32, u32);
 
+impl<'r> Unpack<Vec<u32>> for packed::Uint32VecReader<'r> {
+    fn unpack(&self) -> Vec<u32> {
+        self.iter().map(|v| v.unpack()).collect()
+    }
+}
+impl_conversion_for_entity_unpack!(Uint32Vec, Vec<u32>);
+
+impl<'r> Unpack<Option<u32>> for packed::Uint32OptReader<'r> {
+    fn unpack(&self) -> Option<u32> {
+        self.to_opt().map(|v| v.unpack())
+    }
+}
+impl_conversion_for_entity_unpack!(Uint32Opt, Option<u32>);
+
+impl<'r> Unpack<u64> for packed::Uint64Reader<'r> {
+    fn unpack(&self) -> u64 {
+        let mut b = [0u8; 8];
+        b.copy_from_slice(self.as_slice());
+        u64::from_le_bytes(b)
+    }
+}
+impl_conversion_for_entity_unpack!(Uint64, u64);
+
 impl<'r> Unpack<U256> for packed::Uint256Reader<'r> {
     fn unpack(&self) -> U256 {
         let mut b = [0u8; 32];
@@ -52,6 +78,13 @@ impl<'r> Unpack<core::Hash> for packed::HashReader<'r> {
 }
 impl_conversion_for_entity_unpack!(Hash);
 
+impl<'r> Unpack<Vec<core::Hash>> for packed::HashVecReader<'r> {
+    fn unpack(&self) -> Vec<core::Hash> {
+        self.iter().map(|v| v.unpack()).collect()
+    }
+}
+impl_conversion_for_entity_unpack!(HashVec, Vec<core::Hash>);
+
 impl<'r> Unpack<core::Bytes> for packed::BytesReader<'r> {
     fn unpack(&self) -> core::Bytes {
         self.raw_data().to_owned().into()
@@ -95,6 +128,19 @@ impl<'r> Unpack<core::SpvInfo> for packed::SpvInfoReader<'r> {
 }
 impl_conversion_for_entity_unpack!(SpvInfo);
 
+impl<'r> Unpack<[u32; MEDIAN_TIME_SPAN]> for packed::RecentTimestampsReader<'r> {
+    fn unpack(&self) -> [u32; MEDIAN_TIME_SPAN] {
+        let mut timestamps = [0u32; MEDIAN_TIME_SPAN];
+        for (timestamp, b) in timestamps.iter_mut().zip(self.as_slice().chunks_exact(4)) {
+            let mut le = [0u8; 4];
+            le.copy_from_slice(b);
+            *timestamp = u32::from_le_bytes(le);
+        }
+        timestamps
+    }
+}
+impl_conversion_for_entity_unpack!(RecentTimestamps, [u32; MEDIAN_TIME_SPAN]);
+
 impl<'r> Unpack<core::SpvClient> for packed::SpvClientReader<'r> {
     fn unpack(&self) -> core::SpvClient {
         core::SpvClient {
@@ -102,6 +148,7 @@ impl<'r> Unpack<core::SpvClient> for packed::SpvClientReader<'r> {
             tip_block_hash: self.tip_block_hash().unpack(),
             headers_mmr_root: self.headers_mmr_root().unpack(),
             target_adjust_info: self.target_adjust_info().to_entity(),
+            recent_timestamps: self.recent_timestamps().unpack(),
         }
     }
 }
@@ -14,7 +14,10 @@ use alloc::fmt;
 use alloc::vec::Vec;
 
 pub use bitcoin::{
-    blockdata::{block::Header, transaction::Transaction},
+    blockdata::{
+        block::Header,
+        transaction::{OutPoint, Transaction, TxOut},
+    },
     hash_types::Txid,
     merkle_tree::MerkleBlock,
     pow::{CompactTarget, Target},
@@ -33,14 +36,23 @@ use crate::{constants::*, types::packed};
 ///
 /// Ref: [`MmrProof`]
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HeaderDigest {
     /// The min height of the headers in MMR.
     pub min_height: u32,
     /// The max height of the headers in MMR.
     pub max_height: u32,
     /// Chain work between min height and max height.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::types::conversion::serde::u256")
+    )]
     pub partial_chain_work: U256,
     /// The block hash for leaves; otherwise, the hash of children nodes.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::types::conversion::serde::hash")
+    )]
     pub children_hash: Hash,
 }
 
@@ -57,6 +69,7 @@ pub type MmrProof = Vec<HeaderDigest>;
 
 /// The SPV info cell.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpvInfo {
     /// The ID of the latest SPV client cell.
     pub tip_client_id: u8,
@@ -64,15 +77,25 @@ pub struct SpvInfo {
 
 /// The SPV client cell.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpvClient {
     /// An unique ID of the SPV client cell.
     pub id: u8,
     /// The root of the latest header.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::types::conversion::serde::hash")
+    )]
     pub tip_block_hash: Hash,
     /// The MMR root of headers between height `min_height` and height `max_height`.
     pub headers_mmr_root: HeaderDigest,
     /// The target adjusts on every 2016th block, SpvClient stores the latest one.
     pub target_adjust_info: packed::TargetAdjustInfo,
+    /// The time of the latest 11 headers, from old to new.
+    ///
+    /// The last one is the time of the tip header.
+    /// Zeros are used for unknown headers, which are before the bootstrap header.
+    pub recent_timestamps: [u32; MEDIAN_TIME_SPAN],
 }
 
 //
@@ -81,7 +104,12 @@ pub struct SpvClient {
 
 /// The args for the type script of the SPV info cell and SPV client cells.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpvTypeArgs {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::types::conversion::serde::bytes32")
+    )]
     pub type_id: Hash,
     /// How many SPV client cells that use current type script.
     ///
@@ -94,6 +122,9 @@ pub struct SpvTypeArgs {
     ///   - 0b00 for mainnet
     ///   - 0b10 for testnet
     ///   - 0b01 for signet
+    ///   - 0b11 for extended chain types, which are identified by the next two bits:
+    ///     - 0b1101 for testnet4
+    ///     - 0b1110 for regtest
     /// - Other bits are reserved for future use.
     pub flags: u8,
 }
@@ -125,16 +156,22 @@ pub enum BitcoinChainType {
     Mainnet,
     Testnet,
     Signet,
+    Testnet4,
+    Regtest,
     Other, // For future use.
 }
 
 impl From<u8> for BitcoinChainType {
     fn from(flags: u8) -> Self {
-        match flags & 0b1100_0000 {
+        match flags & FLAG_CHAIN_TYPE_MASK {
             FLAG_CHAIN_TYPE_MAINNET => BitcoinChainType::Mainnet,
             FLAG_CHAIN_TYPE_TESTNET => BitcoinChainType::Testnet,
             FLAG_CHAIN_TYPE_SIGNET => BitcoinChainType::Signet,
-            _ => BitcoinChainType::Other,
+            _ => match flags & FLAG_CHAIN_TYPE_EXTENDED_MASK {
+                FLAG_CHAIN_TYPE_TESTNET4 => BitcoinChainType::Testnet4,
+                FLAG_CHAIN_TYPE_REGTEST => BitcoinChainType::Regtest,
+                _ => BitcoinChainType::Other,
+            },
         }
     }
 }
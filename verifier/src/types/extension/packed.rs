@@ -3,21 +3,30 @@
 use alloc::{vec, vec::Vec};
 
 use bitcoin::{
-    blockdata::constants::DIFFCHANGE_INTERVAL,
+    blockdata::{constants::DIFFCHANGE_INTERVAL, script::Script},
     consensus::{deserialize, encode::Error as EncodeError, serialize},
+    hashes::{sha256, Hash as _},
 };
 use molecule::bytes::Bytes;
+use primitive_types::U256;
 
 use crate::{
+    checkpoints::{self, Checkpoint},
+    constants::{COINBASE_MATURITY, FLAG_CHAIN_TYPE_MAINNET, MEDIAN_TIME_SPAN},
     core::result::Result,
     error::{BootstrapError, UpdateError, VerifyTxError},
     types::{core, packed, prelude::*},
     utilities::{
-        bitcoin::calculate_next_target,
+        bitcoin::{
+            append_timestamp, bip34_height, calculate_median_time_past, calculate_merkle_root,
+            calculate_next_compact_target, calculate_required_compact_target, pow_limit,
+            verify_coinbase_height, verify_timewarp, witness_commitment_index,
+        },
         mmr::{
             self,
             lib::{leaf_index_to_mmr_size, leaf_index_to_pos},
         },
+        signet::verify_block_solution,
     },
 };
 
@@ -47,38 +56,189 @@ impl packed::TargetAdjustInfo {
     }
 }
 
+impl packed::CoinbaseProofReader<'_> {
+    /// Decodes the coinbase transaction.
+    pub fn decode_transaction(&self) -> Result<core::Transaction, EncodeError> {
+        deserialize(self.transaction().raw_data())
+    }
+
+    /// Calculates the merkle root with the coinbase transaction and the merkle branch.
+    pub fn calc_merkle_root(&self, coinbase: &core::Transaction) -> core::Hash {
+        let leaf = coinbase.txid().to_raw_hash();
+        let merkle_branch = self.merkle_branch();
+        calculate_merkle_root(leaf, 0, merkle_branch.iter().map(|v| v.unpack()))
+            .expect("index 0 is always in the range")
+    }
+}
+
+impl packed::PaymentConditionReader<'_> {
+    /// Checks whether an output matches the script and the minimum value.
+    ///
+    /// The output index and the minimum confirmations are not checked here.
+    pub fn matches(&self, output: &core::TxOut) -> bool {
+        let min_value: u64 = self.min_value().unpack();
+        if output.value.to_sat() < min_value {
+            return false;
+        }
+        let script = output.script_pubkey.as_bytes();
+        match self.script().to_enum() {
+            packed::ScriptConditionUnionReader::Bytes(bytes) => bytes.raw_data() == script,
+            packed::ScriptConditionUnionReader::Hash(hash) => {
+                sha256::Hash::hash(script).as_byte_array()[..] == hash.raw_data()[..]
+            }
+        }
+    }
+}
+
+impl packed::PaymentCondition {
+    /// Checks whether an output matches the script and the minimum value.
+    ///
+    /// The output index and the minimum confirmations are not checked here.
+    pub fn matches(&self, output: &core::TxOut) -> bool {
+        self.as_reader().matches(output)
+    }
+}
+
 impl packed::SpvBootstrap {
+    /// Initializes a new SPV client for mainnet.
+    ///
+    /// Do the same checks as `self.initialize_spv_client_with_flags(..)`, with
+    /// the flags of mainnet.
+    ///
+    /// For other chains, use `self.initialize_spv_client_with_flags(..)`, since
+    /// the proof of work limit depends on the chain type.
+    pub fn initialize_spv_client(&self) -> Result<core::SpvClient, BootstrapError> {
+        self.initialize_spv_client_with_flags(FLAG_CHAIN_TYPE_MAINNET)
+    }
+
     /// Initializes a new SPV client.
     ///
-    /// The height of the input header should be multiples of [`DIFFCHANGE_INTERVAL`].
+    /// If the height of the input header is not multiples of [`DIFFCHANGE_INTERVAL`],
+    /// the period headers, which are from the first header of the current difficulty
+    /// adjustment period to the previous header of the input header, are required to
+    /// initialize the target adjust info.
+    /// If the input header is the last header of the current period, the target
+    /// adjust info contains the target of the next period.
     ///
     /// The client ID, which constructs from this method, is always be `0`.
     ///
+    /// The target of the first header in the current period should not be easier
+    /// than the proof of work limit of the chain, which is determined by the `flags`.
+    ///
     /// Ref:
     /// - [How often does the network difficulty change?](https://en.bitcoin.it/wiki/Difficulty#How_often_does_the_network_difficulty_change.3F)
     ///
     /// [`DIFFCHANGE_INTERVAL`]: https://docs.rs/bitcoin/latest/bitcoin/blockdata/constants/constant.DIFFCHANGE_INTERVAL.html
-    pub fn initialize_spv_client(&self) -> Result<core::SpvClient, BootstrapError> {
+    pub fn initialize_spv_client_with_flags(
+        &self,
+        flags: u8,
+    ) -> Result<core::SpvClient, BootstrapError> {
+        self.initialize_spv_client_with_checkpoints(flags, &[])
+    }
+
+    /// Initializes a new SPV client, and validates the headers with checkpoints.
+    ///
+    /// Do the same checks as `self.initialize_spv_client_with_flags(..)`, and also:
+    /// - Each header, which height is the same as a checkpoint, should have the same
+    ///   block hash as that checkpoint.
+    /// - The work of the first header in the current period should not be less than
+    ///   the minimum block work of the last checkpoint before it.
+    ///   Only the work of that single block is checked, not the cumulative chain work.
+    ///
+    /// The built-in checkpoints could be found by [`checkpoints::for_chain`].
+    pub fn initialize_spv_client_with_checkpoints(
+        &self,
+        flags: u8,
+        checkpoints: &[Checkpoint],
+    ) -> Result<core::SpvClient, BootstrapError> {
         let height: u32 = self.height().unpack();
-        if height % DIFFCHANGE_INTERVAL != 0 {
-            error!("the started height {height} should be multiples of {DIFFCHANGE_INTERVAL}");
+        let period_headers = self.period_headers();
+        let offset = height % DIFFCHANGE_INTERVAL;
+        if period_headers.len() != offset as usize {
+            error!(
+                "the started height {height} requires {offset} period headers, but got {}",
+                period_headers.len()
+            );
             return Err(BootstrapError::Height);
         }
         let header: core::Header =
             deserialize(&self.header().raw_data()).map_err(|_| BootstrapError::DecodeHeader)?;
-        // Verify POW: just trust the input header.
+        let mut headers = Vec::with_capacity(period_headers.len() + 1);
+        for period_header in period_headers.as_reader().iter() {
+            let period_header: core::Header =
+                deserialize(period_header.raw_data()).map_err(|_| BootstrapError::DecodeHeader)?;
+            headers.push(period_header);
+        }
+        headers.push(header);
+        // Verify POW: just trust the first header in the current period.
         // TODO Check constants::FLAG_DISABLE_DIFFICULTY_CHECK before return errors.
-        let block_hash = header
-            .validate_pow(header.target())
-            .map_err(|_| BootstrapError::Pow)?
-            .into();
-        let target_adjust_info = packed::TargetAdjustInfo::encode(header.time, header.bits);
+        let first_header = &headers[0];
+        if first_header.target() > pow_limit(flags) {
+            error!("the target of the started header is easier than the proof of work limit");
+            return Err(BootstrapError::Difficulty);
+        }
+        let period_start = height - offset;
+        if let Some(checkpoint) = checkpoints::find_last_before(checkpoints, period_start) {
+            let work = U256::from_little_endian(&first_header.work().to_le_bytes());
+            if work < checkpoint.min_block_work {
+                error!(
+                    "the work of header-{period_start} ({work:#x}) is less than the minimum block work \
+                    ({:#x}) of checkpoint-{}",
+                    checkpoint.min_block_work, checkpoint.height
+                );
+                return Err(BootstrapError::MinimumWork);
+            }
+        }
+        let mut block_hash = core::Hash::all_zeros();
+        let mut recent_timestamps = [0; MEDIAN_TIME_SPAN];
+        for (index, header) in headers.iter().enumerate() {
+            if index > 0 {
+                if block_hash != header.prev_blockhash.into() {
+                    error!("failed: period headers are uncontinuous");
+                    return Err(BootstrapError::UncontinuousHeaders);
+                }
+                let expected_bits = calculate_required_compact_target(
+                    first_header.bits,
+                    recent_timestamps[MEDIAN_TIME_SPAN - 1],
+                    period_start + index as u32,
+                    header.time,
+                    flags,
+                );
+                if expected_bits != header.bits {
+                    error!("failed: invalid difficulty for period header-{index}");
+                    return Err(BootstrapError::Difficulty);
+                }
+            }
+            block_hash = header
+                .validate_pow(header.target())
+                .map_err(|_| BootstrapError::Pow)?
+                .into();
+            append_timestamp(&mut recent_timestamps, header.time);
+            let header_height = period_start + index as u32;
+            if let Some(checkpoint) = checkpoints::find(checkpoints, header_height) {
+                if checkpoint.block_hash != block_hash.to_byte_array() {
+                    error!(
+                        "the hash of header-{header_height} ({block_hash:#x}) conflicts \
+                        with the checkpoint"
+                    );
+                    return Err(BootstrapError::Checkpoint);
+                }
+            }
+        }
+        // The input header is the last block for current difficulty.
+        let next_bits = if (height + 1) % DIFFCHANGE_INTERVAL == 0 {
+            calculate_next_compact_target(first_header.bits, first_header.time, &header, flags)
+        } else {
+            first_header.bits
+        };
+        let target_adjust_info = packed::TargetAdjustInfo::encode(first_header.time, next_bits);
         let digest = core::HeaderDigest::new_leaf(height, &header);
         let client = core::SpvClient {
             id: 0,
             tip_block_hash: block_hash,
             headers_mmr_root: digest,
             target_adjust_info,
+            recent_timestamps,
         };
         Ok(client)
     }
@@ -91,6 +251,10 @@ impl packed::SpvClient {
     /// - Check headers:
     ///     - Check previous block hashes.
     ///     - Check the target adjust info.
+    ///       - For testnet and testnet4, minimum-difficulty headers are allowed only
+    ///         when the header time is more than 20 minutes after the previous header.
+    ///     - Check the median time past.
+    ///     - Check the timewarp protection (BIP94) for testnet4.
     ///     - Check POW.
     /// - Check MMR root:
     ///     - All appeneded headers are included in the new MMR root.
@@ -103,11 +267,42 @@ impl packed::SpvClient {
     ///     - The min height should be the same.
     ///     - Check the new max height.
     ///     - Check the target adjust info.
+    ///     - Check the recent timestamps.
     pub fn verify_new_client(
         &self,
         packed_new_client: &Self,
         update: packed::SpvUpdate,
         flags: u8,
+    ) -> Result<(), UpdateError> {
+        self.verify_new_client_internal(packed_new_client, update, flags, None)
+    }
+
+    /// Verifies a new client, and the signet block solutions of new headers (BIP325).
+    ///
+    /// Do the same checks as `self.verify_new_client(..)`, and checks:
+    /// - The count of coinbase proofs should be the same as the count of headers.
+    /// - Each coinbase transaction is included in its header.
+    /// - Each signet block solution satisfies the challenge.
+    ///
+    /// Where to commit the challenge is decided by the caller, for example,
+    /// the remained bytes of the script args after the `SpvTypeArgs`.
+    pub fn verify_new_client_with_signet_challenge(
+        &self,
+        packed_new_client: &Self,
+        update: packed::SpvUpdate,
+        flags: u8,
+        challenge: &[u8],
+    ) -> Result<(), UpdateError> {
+        let challenge = Script::from_bytes(challenge);
+        self.verify_new_client_internal(packed_new_client, update, flags, Some(challenge))
+    }
+
+    fn verify_new_client_internal(
+        &self,
+        packed_new_client: &Self,
+        update: packed::SpvUpdate,
+        flags: u8,
+        signet_challenge: Option<&Script>,
     ) -> Result<(), UpdateError> {
         let old_client = self.unpack();
         let new_client = packed_new_client.unpack();
@@ -121,6 +316,15 @@ impl packed::SpvClient {
             return Err(UpdateError::EmptyHeaders);
         }
         debug!("update has {} headers", headers.len());
+        let coinbase_proofs = update.coinbase_proofs();
+        if signet_challenge.is_some() && coinbase_proofs.len() != headers.len() {
+            error!(
+                "failed: update has {} headers but {} coinbase proofs",
+                headers.len(),
+                coinbase_proofs.len()
+            );
+            return Err(UpdateError::CoinbaseProof);
+        }
         let mut digests = Vec::with_capacity(headers.len());
         let mut new_tip_block_hash = old_client.tip_block_hash;
         let mut new_max_height = old_client.headers_mmr_root.max_height;
@@ -128,8 +332,9 @@ impl packed::SpvClient {
             .target_adjust_info
             .decode()
             .map_err(|_| UpdateError::DecodeTargetAdjustInfo)?;
+        let mut new_timestamps = old_client.recent_timestamps;
         trace!("tip block hash: {new_tip_block_hash:#x}, max height: {new_max_height}");
-        for header in update.headers().as_reader().iter() {
+        for (index, header) in update.headers().as_reader().iter().enumerate() {
             new_max_height += 1;
             let header: core::Header =
                 deserialize(header.raw_data()).map_err(|_| UpdateError::DecodeHeader)?;
@@ -139,27 +344,65 @@ impl packed::SpvClient {
                 return Err(UpdateError::UncontinuousHeaders);
             }
             // Check the target adjust info.
-            if new_info.1 != header.bits {
+            let expected_bits = calculate_required_compact_target(
+                new_info.1,
+                new_timestamps[MEDIAN_TIME_SPAN - 1],
+                new_max_height,
+                header.time,
+                flags,
+            );
+            if expected_bits != header.bits {
                 log_if_enabled!(|Error| {
-                    let expected = new_info.1.to_consensus();
+                    let expected = expected_bits.to_consensus();
                     let actual = header.bits.to_consensus();
                     error!(
                         "failed: invalid difficulty for header-{new_max_height}, \
                         expect {expected} but got {actual}"
                     );
                 });
-
-                // For mainnet and signet, `header.bits` should be as the same as `new_info.1`.
-                // But for testnet, it could be not.
-                if core::BitcoinChainType::Testnet != flags.into() {
-                    return Err(UpdateError::Difficulty);
-                }
+                return Err(UpdateError::Difficulty);
+            }
+            // Check the median time past.
+            let median_time_past = calculate_median_time_past(&new_timestamps);
+            if header.time <= median_time_past {
+                error!(
+                    "failed: the time of header-{new_max_height} ({}) should be greater than \
+                    the median time past ({median_time_past})",
+                    header.time
+                );
+                return Err(UpdateError::MedianTimePast);
+            }
+            // Check the timewarp protection.
+            let prev_time = new_timestamps[MEDIAN_TIME_SPAN - 1];
+            if !verify_timewarp(prev_time, new_max_height, header.time, flags) {
+                error!(
+                    "failed: the time of header-{new_max_height} ({}) is too early, \
+                    the time of its previous header is {prev_time}",
+                    header.time
+                );
+                return Err(UpdateError::Timewarp);
             }
             // Check POW.
             new_tip_block_hash = header
                 .validate_pow(header.bits.into())
                 .map_err(|_| UpdateError::Pow)?
                 .into();
+            // Check the signet block solution.
+            if let Some(challenge) = signet_challenge {
+                let coinbase_proof = coinbase_proofs.as_reader().get_unchecked(index);
+                let coinbase = coinbase_proof
+                    .decode_transaction()
+                    .map_err(|_| UpdateError::DecodeCoinbaseTransaction)?;
+                if coinbase_proof.calc_merkle_root(&coinbase) != header.merkle_root.to_raw_hash() {
+                    error!("failed: coinbase of header-{new_max_height} is not in the header");
+                    return Err(UpdateError::CoinbaseProof);
+                }
+                let merkle_branch: Vec<core::Hash> = coinbase_proof.merkle_branch().unpack();
+                if !verify_block_solution(&header, &coinbase, &merkle_branch, challenge) {
+                    error!("failed: invalid signet block solution for header-{new_max_height}");
+                    return Err(UpdateError::SignetSolution);
+                }
+            }
 
             // Update the target adjust info.
             {
@@ -168,11 +411,11 @@ impl packed::SpvClient {
                     0 => {
                         // See the above check:
                         // - For mainnet, `header.bits` should be as the same as `new_info.1`.
-                        // - But for testnet, it could be not.
-                        let prev_target = header.bits.into();
-                        let next_target =
-                            calculate_next_target(prev_target, new_info.0, header.time, flags);
-                        new_info.1 = next_target.to_compact_lossy();
+                        // - But for testnet, it could be the minimum difficulty.
+                        // - For testnet4 (BIP94), use the `nbits` of the first block for
+                        //   current difficulty, which is `new_info.1`.
+                        new_info.1 =
+                            calculate_next_compact_target(new_info.1, new_info.0, &header, flags);
                     }
                     // Current block is the first block for a new difficulty.
                     1 => {
@@ -180,6 +423,7 @@ impl packed::SpvClient {
                     }
                     _ => {}
                 }
+                append_timestamp(&mut new_timestamps, header.time);
             }
             let digest = core::HeaderDigest::new_leaf(new_max_height, &header);
             trace!(
@@ -258,6 +502,224 @@ impl packed::SpvClient {
             );
             return Err(UpdateError::ClientTargetAdjustInfo);
         }
+        if new_client.recent_timestamps != new_timestamps {
+            error!(
+                "failed: new client's recent timestamps are incorrect, \
+                expect {new_timestamps:?} but got {:?}",
+                new_client.recent_timestamps
+            );
+            return Err(UpdateError::ClientRecentTimestamps);
+        }
+
+        Ok(())
+    }
+
+    /// Verifies a new client, which replaces a suffix of headers in the current client.
+    ///
+    /// Checks:
+    /// - Check the fork point:
+    ///     - At least one header should be dropped.
+    ///     - The latest 11 headers until the fork point should be in the current client.
+    /// - Check the target adjust info of the fork point:
+    ///     - If the fork point and the next header of the current tip are in the
+    ///       same difficulty adjustment period, it's the same as the current one.
+    ///     - Otherwise, it's calculated from the first header of the period of
+    ///       the fork point, which should be in the current client.
+    /// - Check the MMR root of the fork point:
+    ///     - The max height should be the height of the fork point.
+    ///     - The current MMR root is extended from it with the dropped headers.
+    /// - Check the new client, from the client at the fork point, with the same
+    ///   checks as `self.verify_new_client(..)`.
+    /// - The new client should have strictly more chain work than the current one.
+    pub fn verify_reorg_client(
+        &self,
+        packed_new_client: &Self,
+        reorg: packed::SpvReorg,
+        flags: u8,
+    ) -> Result<(), UpdateError> {
+        self.verify_reorg_client_internal(packed_new_client, reorg, flags, None)
+    }
+
+    /// Verifies a new client, which replaces a suffix of headers in the current client,
+    /// and the signet block solutions of new headers (BIP325).
+    ///
+    /// Do the same checks as `self.verify_reorg_client(..)`, and checks the new
+    /// headers as `self.verify_new_client_with_signet_challenge(..)`.
+    pub fn verify_reorg_client_with_signet_challenge(
+        &self,
+        packed_new_client: &Self,
+        reorg: packed::SpvReorg,
+        flags: u8,
+        challenge: &[u8],
+    ) -> Result<(), UpdateError> {
+        let challenge = Script::from_bytes(challenge);
+        self.verify_reorg_client_internal(packed_new_client, reorg, flags, Some(challenge))
+    }
+
+    fn verify_reorg_client_internal(
+        &self,
+        packed_new_client: &Self,
+        reorg: packed::SpvReorg,
+        flags: u8,
+        signet_challenge: Option<&Script>,
+    ) -> Result<(), UpdateError> {
+        let old_client = self.unpack();
+        let min_height = old_client.headers_mmr_root.min_height;
+        let max_height = old_client.headers_mmr_root.max_height;
+
+        // Check the fork point
+        let dropped_headers = reorg.dropped_headers();
+        let dropped_count = dropped_headers.len() as u32;
+        if dropped_count == 0 || max_height - min_height < dropped_count {
+            error!(
+                "failed: drop {dropped_count} headers from the client \
+                with headers [{min_height}, {max_height}]"
+            );
+            return Err(UpdateError::ReorgForkPoint);
+        }
+        let fork_height = max_height - dropped_count;
+        if fork_height - min_height + 1 < MEDIAN_TIME_SPAN as u32 {
+            error!(
+                "failed: the fork point ({fork_height}) is too close to \
+                the min height ({min_height})"
+            );
+            return Err(UpdateError::ReorgForkPoint);
+        }
+        debug!("reorg from header-{fork_height}, drop {dropped_count} headers");
+
+        // Check the fork headers
+        let fork_headers = reorg.fork_headers();
+        if fork_headers.len() != MEDIAN_TIME_SPAN {
+            error!(
+                "failed: {MEDIAN_TIME_SPAN} fork headers are required but got {}",
+                fork_headers.len()
+            );
+            return Err(UpdateError::ReorgForkHeaders);
+        }
+        let mut fork_digests = Vec::with_capacity(MEDIAN_TIME_SPAN + 1);
+        let mut fork_timestamps = [0; MEDIAN_TIME_SPAN];
+        let mut fork_header_list = Vec::with_capacity(MEDIAN_TIME_SPAN);
+        let first_height = fork_height + 1 - MEDIAN_TIME_SPAN as u32;
+        for (index, header) in fork_headers.as_reader().iter().enumerate() {
+            let header: core::Header =
+                deserialize(header.raw_data()).map_err(|_| UpdateError::DecodeHeader)?;
+            let height = first_height + index as u32;
+            fork_timestamps[index] = header.time;
+            fork_digests.push(core::HeaderDigest::new_leaf(height, &header));
+            fork_header_list.push(header);
+        }
+        let fork_header = fork_header_list[MEDIAN_TIME_SPAN - 1];
+
+        // Check the target adjust info of the fork point
+        let fork_period_headers = reorg.fork_period_headers();
+        let fork_target_adjust_info = if fork_height / DIFFCHANGE_INTERVAL
+            == max_height / DIFFCHANGE_INTERVAL
+            && (max_height + 1) % DIFFCHANGE_INTERVAL != 0
+        {
+            // The fork point and the next header of the tip are in the same period.
+            if !fork_period_headers.is_empty() {
+                error!("failed: the fork period header is not required");
+                return Err(UpdateError::ReorgDifficultyPeriod);
+            }
+            old_client.target_adjust_info
+        } else {
+            let period_height = fork_height - fork_height % DIFFCHANGE_INTERVAL;
+            if fork_period_headers.len() != 1 || period_height < min_height {
+                error!(
+                    "failed: the first header (height: {period_height}) of the difficulty \
+                    adjustment period of the fork point ({fork_height}) is required \
+                    and it should be in the client with headers [{min_height}, {max_height}]"
+                );
+                return Err(UpdateError::ReorgDifficultyPeriod);
+            }
+            let period_header: core::Header =
+                deserialize(fork_period_headers.as_reader().get_unchecked(0).raw_data())
+                    .map_err(|_| UpdateError::DecodeHeader)?;
+            if period_height < first_height {
+                let digest = core::HeaderDigest::new_leaf(period_height, &period_header);
+                fork_digests.insert(0, digest);
+            } else if fork_header_list[(period_height - first_height) as usize] != period_header {
+                error!("failed: the fork period header is not in the fork headers");
+                return Err(UpdateError::ReorgForkHeaders);
+            }
+            let next_bits = if (fork_height + 1) % DIFFCHANGE_INTERVAL == 0 {
+                calculate_next_compact_target(
+                    period_header.bits,
+                    period_header.time,
+                    &fork_header,
+                    flags,
+                )
+            } else {
+                period_header.bits
+            };
+            packed::TargetAdjustInfo::encode(period_header.time, next_bits)
+        };
+        self.verify_header_digests(fork_digests, reorg.fork_headers_proof().as_reader())
+            .map_err(|_| {
+                error!("failed: fork headers are not in the client");
+                UpdateError::ReorgForkHeaders
+            })?;
+
+        // Check the MMR root of the fork point
+        let fork_headers_mmr_root = reorg.fork_headers_mmr_root();
+        {
+            let fork_root = fork_headers_mmr_root.unpack();
+            if fork_root.min_height != min_height || fork_root.max_height != fork_height {
+                error!(
+                    "failed: the MMR root of the fork point is for headers [{}, {}], \
+                    expect [{min_height}, {fork_height}]",
+                    fork_root.min_height, fork_root.max_height
+                );
+                return Err(UpdateError::ReorgMmrProof);
+            }
+            let mut dropped_digests = Vec::with_capacity(dropped_headers.len());
+            for (index, header) in dropped_headers.as_reader().iter().enumerate() {
+                let header: core::Header =
+                    deserialize(header.raw_data()).map_err(|_| UpdateError::DecodeHeader)?;
+                let height = fork_height + 1 + index as u32;
+                dropped_digests.push(core::HeaderDigest::new_leaf(height, &header).pack());
+            }
+            let proof: mmr::MMRProof = {
+                let max_index = max_height - min_height;
+                let mmr_size = leaf_index_to_mmr_size(u64::from(max_index));
+                debug!("check MMR root of the fork point with size: {mmr_size}");
+                let proof = reorg.old_headers_mmr_proof().into_iter().collect();
+                mmr::MMRProof::new(mmr_size, proof)
+            };
+            let result = proof
+                .verify_incremental(
+                    self.headers_mmr_root(),
+                    fork_headers_mmr_root.clone(),
+                    dropped_digests,
+                )
+                .map_err(|_| UpdateError::Mmr)?;
+            if !result {
+                warn!("failed: verify MMR proof for the fork point ({fork_height})");
+                return Err(UpdateError::ReorgMmrProof);
+            }
+        }
+
+        // Check the new client from the fork point
+        let fork_client = core::SpvClient {
+            id: old_client.id,
+            tip_block_hash: fork_header.block_hash().into(),
+            headers_mmr_root: fork_headers_mmr_root.unpack(),
+            target_adjust_info: fork_target_adjust_info,
+            recent_timestamps: fork_timestamps,
+        }
+        .pack();
+        fork_client.verify_new_client_internal(
+            packed_new_client,
+            reorg.update(),
+            flags,
+            signet_challenge,
+        )?;
+
+        // Check the chain work
+        if !packed_new_client.is_better_than(self) {
+            error!("failed: the new branch doesn't have more chain work than the dropped one");
+            return Err(UpdateError::ReorgChainWork);
+        }
 
         Ok(())
     }
@@ -267,6 +729,9 @@ impl packed::SpvClient {
     /// Do the same checks as `self.verify_transaction(..)`,
     /// but require the transaction data as an input argument rather than `Txid`.
     ///
+    /// Transactions which are 64 bytes without witnesses are rejected, since they
+    /// could not be distinguished from the inner nodes of the Merkle tree.
+    ///
     /// Since the header and the transaction has been recovered from bytes,
     /// so this function return them in order to any possible future usages.
     /// If you don't need them, just ignore them.
@@ -276,18 +741,281 @@ impl packed::SpvClient {
         tx_proof: packed::TransactionProofReader,
         confirmations: u32,
     ) -> Result<(core::Header, core::Transaction), VerifyTxError> {
-        let tx: core::Transaction =
-            deserialize(tx).map_err(|_| VerifyTxError::DecodeTransaction)?;
+        let tx = decode_transaction_data(tx)?;
         let txid = tx.txid();
         let header = self.verify_transaction(txid.as_ref(), tx_proof, confirmations)?;
         Ok((header, tx))
     }
 
+    /// Verifies whether a transaction is in the chain or not, in the hardened mode.
+    ///
+    /// Do the same checks as `self.verify_transaction_data(..)`, and also check
+    /// if the coinbase transaction is contained in the same header, with a Merkle
+    /// branch which has the same depth as the Merkle tree in the transaction proof.
+    ///
+    /// Since the coinbase transaction could not be forged, its Merkle branch
+    /// authenticates the height of the Merkle tree, so the transaction could not
+    /// be forged with a fake Merkle tree, whose leaves are the halves of a 64-byte
+    /// transaction.
+    ///
+    /// Ref:
+    /// - [Leaf-Node weakness in Bitcoin Merkle Tree Design](https://bitslog.com/2018/06/09/leaf-node-weakness-in-bitcoin-merkle-tree-design/)
+    pub fn verify_transaction_data_hardened(
+        &self,
+        tx: &[u8],
+        tx_proof: packed::TransactionProofReader,
+        coinbase_proof: packed::CoinbaseProofReader,
+        confirmations: u32,
+    ) -> Result<(core::Header, core::Transaction), VerifyTxError> {
+        let tx = decode_transaction_data(tx)?;
+        let txid = tx.txid();
+        let height: u32 = tx_proof.height().unpack();
+
+        // Verify Transaction
+        self.verify_height(height, confirmations)?;
+
+        // Verify TxOut proof
+        let tx_index: u32 = tx_proof.tx_index().unpack();
+        let merkle_block = verify_txout_proof(
+            tx_proof.transaction_proof().raw_data(),
+            [(tx_index, txid.as_ref())],
+        )?;
+        let header = merkle_block.header;
+
+        // Verify coinbase proof
+        let tree_depth = merkle_tree_depth(merkle_block.txn.num_transactions());
+        verify_coinbase_proof(coinbase_proof, &header, tree_depth)?;
+
+        // Verify Header MMR proof
+        let digest = core::HeaderDigest::new_leaf(height, &header);
+        self.verify_header_digest(digest, tx_proof.header_proof())?;
+
+        Ok((header, tx))
+    }
+
+    /// Verifies whether a transaction is in the chain, and pays to an output
+    /// which matches the payment condition.
+    ///
+    /// Do the same checks as `self.verify_transaction_data(..)`, with the minimum
+    /// confirmations in the condition, and also:
+    /// - If the output index is set in the condition, the output at that index
+    ///   should match the condition; otherwise, any output could match it.
+    /// - The `script_pubkey` of the output should be the same as the script in the
+    ///   condition, or its SHA-256 hash should be the same as the hash in the condition.
+    /// - The value of the output should not be less than the minimum value.
+    ///
+    /// Returns the header, the transaction and the index of the first matched output.
+    pub fn verify_payment(
+        &self,
+        tx: &[u8],
+        tx_proof: packed::TransactionProofReader,
+        condition: packed::PaymentConditionReader,
+    ) -> Result<(core::Header, core::Transaction, u32), VerifyTxError> {
+        let min_confirmations: u32 = condition.min_confirmations().unpack();
+        let (header, tx) = self.verify_transaction_data(tx, tx_proof, min_confirmations)?;
+        let output_index = if let Some(index) = condition.output_index().unpack() {
+            let output = tx.output.get(index as usize).ok_or_else(|| {
+                error!(
+                    "failed: the transaction has {} outputs, no output-{index}",
+                    tx.output.len()
+                );
+                VerifyTxError::PaymentOutputIndex
+            })?;
+            if !condition.matches(output) {
+                error!("failed: output-{index} doesn't match the payment condition");
+                return Err(VerifyTxError::PaymentUnmatched);
+            }
+            index
+        } else {
+            tx.output
+                .iter()
+                .position(|output| condition.matches(output))
+                .ok_or_else(|| {
+                    error!("failed: no outputs match the payment condition");
+                    VerifyTxError::PaymentUnmatched
+                })? as u32
+        };
+        Ok((header, tx, output_index))
+    }
+
+    /// Verifies whether a transaction is in the chain, and spends the outpoint.
+    ///
+    /// Do the same checks as `self.verify_transaction_data(..)`, and also check
+    /// if any input of the transaction spends the outpoint.
+    ///
+    /// Returns the header, the transaction and the index of the input which
+    /// spends the outpoint.
+    pub fn verify_spend(
+        &self,
+        outpoint: &core::OutPoint,
+        tx: &[u8],
+        tx_proof: packed::TransactionProofReader,
+        confirmations: u32,
+    ) -> Result<(core::Header, core::Transaction, u32), VerifyTxError> {
+        let (header, tx) = self.verify_transaction_data(tx, tx_proof, confirmations)?;
+        let input_index = tx
+            .input
+            .iter()
+            .position(|input| input.previous_output == *outpoint)
+            .ok_or_else(|| {
+                error!("failed: the transaction doesn't spend the outpoint {outpoint}");
+                VerifyTxError::OutPointNotSpent
+            })? as u32;
+        Ok((header, tx, input_index))
+    }
+
+    /// Verifies whether a coinbase transaction is in the chain or not.
+    ///
+    /// Do the same checks as `self.verify_transaction_data(..)`, and also:
+    /// - The transaction should be the first transaction in the block.
+    /// - The coinbase maturity: the outputs of the coinbase transaction could be
+    ///   spent in the next block of the tip header in current SPV client.
+    /// - Since BIP34 is activated, the `scriptSig` of the coinbase transaction
+    ///   should start with the height of its block.
+    ///
+    /// The coinbase outputs could be accessed via the returned transaction.
+    ///
+    /// Ref:
+    /// - [BIP34: Block v2, Height in Coinbase](https://github.com/bitcoin/bips/blob/master/bip-0034.mediawiki)
+    pub fn verify_coinbase_transaction(
+        &self,
+        tx: &[u8],
+        tx_proof: packed::TransactionProofReader,
+        flags: u8,
+    ) -> Result<(core::Header, core::Transaction), VerifyTxError> {
+        let tx_index: u32 = tx_proof.tx_index().unpack();
+        if tx_index != 0 {
+            error!("failed: transaction-{tx_index} is not a coinbase transaction");
+            return Err(VerifyTxError::TxOutProofInvalidTxIndex);
+        }
+        let height: u32 = tx_proof.height().unpack();
+        let max_height: u32 = self.headers_mmr_root().max_height().unpack();
+        if height <= max_height && max_height - height + 1 < COINBASE_MATURITY {
+            error!(
+                "failed: the coinbase transaction at height {height} is immature, \
+                the tip height is {max_height}"
+            );
+            return Err(VerifyTxError::CoinbaseImmature);
+        }
+        let (header, tx) = self.verify_transaction_data(tx, tx_proof, 0)?;
+        if !tx.is_coinbase() {
+            error!("failed: the transaction is not a coinbase transaction");
+            return Err(VerifyTxError::CoinbaseProof);
+        }
+        if height >= bip34_height(flags) && !verify_coinbase_height(&tx, height) {
+            error!("failed: the coinbase transaction doesn't contain the height {height}");
+            return Err(VerifyTxError::CoinbaseHeight);
+        }
+        Ok((header, tx))
+    }
+
+    /// Verifies whether a transaction, including its witness, is in the chain or not.
+    ///
+    /// Checks:
+    /// - Check if the coinbase transaction is contained in the provided header
+    ///   (via Merkle branch).
+    /// - Check if the `wtxid` of the transaction is committed by the witness commitment
+    ///   in the coinbase transaction (BIP141, via witness Merkle branch).
+    /// - Check if the header is contained in the Bitcoin chain (via MMR proof).
+    /// - Check the confirmation blocks based on the tip header in current SPV client.
+    ///   - `0` means skip the check of the confirmation blocks.
+    ///
+    /// Since the `wtxid` is calculated from the full serialized transaction, the
+    /// witness data is also authenticated.
+    ///
+    /// Transactions which are 64 bytes without witnesses are rejected, and the
+    /// witness Merkle branch should be as deep as the coinbase Merkle branch.
+    ///
+    /// The coinbase transaction is not supported, since its `wtxid` is always
+    /// zeros in the witness Merkle tree.
+    ///
+    /// Ref:
+    /// - [BIP141: Commitment structure](https://github.com/bitcoin/bips/blob/master/bip-0141.mediawiki#commitment-structure)
+    pub fn verify_transaction_witness(
+        &self,
+        tx: &[u8],
+        tx_proof: packed::TransactionWitnessProofReader,
+        confirmations: u32,
+    ) -> Result<(core::Header, core::Transaction), VerifyTxError> {
+        let height: u32 = tx_proof.height().unpack();
+
+        // Verify Transaction
+        self.verify_height(height, confirmations)?;
+        let tx = decode_transaction_data(tx)?;
+        let header: core::Header =
+            deserialize(tx_proof.header().raw_data()).map_err(|_| VerifyTxError::DecodeHeader)?;
+
+        // Verify Coinbase proof
+        let coinbase_proof = tx_proof.coinbase_proof();
+        let coinbase = coinbase_proof
+            .decode_transaction()
+            .map_err(|_| VerifyTxError::DecodeCoinbaseTransaction)?;
+        if !coinbase.is_coinbase()
+            || coinbase_proof.calc_merkle_root(&coinbase) != header.merkle_root.to_raw_hash()
+        {
+            error!("failed: the coinbase proof is not matched the header");
+            return Err(VerifyTxError::CoinbaseProof);
+        }
+
+        // Verify Witness commitment
+        let tx_index: u32 = tx_proof.tx_index().unpack();
+        let witness_merkle_branch = tx_proof.witness_merkle_branch();
+        if tx_index == 0 {
+            error!("failed: the coinbase transaction has no witness commitment for itself");
+            return Err(VerifyTxError::TxOutProofInvalidTxIndex);
+        }
+        // Both Merkle trees have the same count of leaves, so they have the same depth.
+        if witness_merkle_branch.len() != coinbase_proof.merkle_branch().len() {
+            error!(
+                "failed: the depth of the witness merkle branch is {}, \
+                but the depth of the coinbase merkle branch is {}",
+                witness_merkle_branch.len(),
+                coinbase_proof.merkle_branch().len()
+            );
+            return Err(VerifyTxError::WitnessMerkleBranchDepth);
+        }
+        let commitment_index =
+            witness_commitment_index(&coinbase).ok_or(VerifyTxError::WitnessCommitmentNotFound)?;
+        let reserved_value = {
+            let witness = &coinbase.input[0].witness;
+            match witness.nth(0) {
+                Some(value) if witness.len() == 1 && value.len() == 32 => {
+                    let mut array = [0u8; 32];
+                    array.copy_from_slice(value);
+                    core::Hash::from_byte_array(array)
+                }
+                _ => {
+                    error!("failed: the witness reserved value is invalid");
+                    return Err(VerifyTxError::WitnessReservedValue);
+                }
+            }
+        };
+        let witness_root = calculate_merkle_root(
+            tx.wtxid().to_raw_hash(),
+            tx_index,
+            witness_merkle_branch.iter().map(|v| v.unpack()),
+        )
+        .ok_or(VerifyTxError::TxOutProofInvalidTxIndex)?;
+        let commitment = mmr::hash_concat(&witness_root, &reserved_value);
+        let script = coinbase.output[commitment_index].script_pubkey.as_bytes();
+        if commitment.as_byte_array()[..] != script[6..38] {
+            error!("failed: the witness commitment is not matched");
+            return Err(VerifyTxError::WitnessCommitment);
+        }
+
+        // Verify Header MMR proof
+        let digest = core::HeaderDigest::new_leaf(height, &header);
+        self.verify_header_digest(digest, tx_proof.header_proof())?;
+
+        Ok((header, tx))
+    }
+
     /// Verifies whether a transaction is in the chain or not.
     ///
     /// Checks:
     /// - Check if the transaction is contained in the provided header (via Merkle proof).
-    ///   - In current version, only one transaction could be included in the Merkle proof.
+    ///   - Only one transaction is checked, for multiple transactions in the same block,
+    ///     use `self.verify_transactions(..)` instead.
     /// - Check if the header is contained in the Bitcoin chain (via MMR proof).
     /// - Check the confirmation blocks based on the tip header in current SPV client.
     ///   - `0` means skip the check of the confirmation blocks.
@@ -302,10 +1030,246 @@ impl packed::SpvClient {
         confirmations: u32,
     ) -> Result<core::Header, VerifyTxError> {
         let height: u32 = tx_proof.height().unpack();
-        let min_height = self.headers_mmr_root().min_height().unpack();
-        let max_height = self.headers_mmr_root().max_height().unpack();
 
         // Verify Transaction
+        self.verify_height(height, confirmations)?;
+
+        // Verify TxOut proof
+        let tx_index: u32 = tx_proof.tx_index().unpack();
+        let header =
+            verify_txout_proof(tx_proof.transaction_proof().raw_data(), [(tx_index, txid)])?.header;
+
+        // Verify Header MMR proof
+        let digest = core::HeaderDigest::new_leaf(height, &header);
+        self.verify_header_digest(digest, tx_proof.header_proof())?;
+
+        Ok(header)
+    }
+
+    /// Verifies whether a transaction is in the chain or not, with a Merkle branch.
+    ///
+    /// Do the same checks as `self.verify_transaction(..)`, but the transaction is
+    /// checked with a plain Merkle branch rather than a serialized `MerkleBlock`,
+    /// and the Merkle root is recomputed without any allocations.
+    ///
+    /// **Warning:** a plain Merkle branch doesn't authenticate the depth of the
+    /// Merkle tree, so a fake "transaction" could be proved with a shorter branch,
+    /// from the halves of a 64-byte transaction or from an inner node of the tree.
+    /// Use `self.verify_transaction_with_branch_hardened(..)` unless the `Txid` is
+    /// known to be a real transaction.
+    ///
+    /// Ref:
+    /// - [Leaf-Node weakness in Bitcoin Merkle Tree Design](https://bitslog.com/2018/06/09/leaf-node-weakness-in-bitcoin-merkle-tree-design/)
+    ///
+    /// Since the header has been recovered from bytes, so this function return it
+    /// in order to any possible future usages.
+    /// If you don't need it, just ignore it.
+    pub fn verify_transaction_with_branch(
+        &self,
+        txid: &[u8; 32],
+        tx_proof: packed::TransactionBranchProofReader,
+        confirmations: u32,
+    ) -> Result<core::Header, VerifyTxError> {
+        let height: u32 = tx_proof.height().unpack();
+
+        // Verify Transaction
+        self.verify_height(height, confirmations)?;
+
+        // Verify Merkle branch
+        let header: core::Header =
+            deserialize(tx_proof.header().raw_data()).map_err(|_| VerifyTxError::DecodeHeader)?;
+        let tx_index: u32 = tx_proof.tx_index().unpack();
+        let merkle_root = calculate_merkle_root(
+            core::Hash::from_byte_array(*txid),
+            tx_index,
+            tx_proof.merkle_branch().iter().map(|v| v.unpack()),
+        )
+        .ok_or(VerifyTxError::TxOutProofInvalidTxIndex)?;
+        if merkle_root != header.merkle_root.to_raw_hash() {
+            error!("failed: the merkle branch of transaction-{tx_index} is not matched the header");
+            return Err(VerifyTxError::TxOutProofIsInvalid);
+        }
+
+        // Verify Header MMR proof
+        let digest = core::HeaderDigest::new_leaf(height, &header);
+        self.verify_header_digest(digest, tx_proof.header_proof())?;
+
+        Ok(header)
+    }
+
+    /// Verifies whether a transaction is in the chain or not, with a Merkle branch,
+    /// in the hardened mode.
+    ///
+    /// Do the same checks as `self.verify_transaction_with_branch(..)`, but require
+    /// the transaction data rather than `Txid`, and also:
+    /// - Transactions which are 64 bytes without witnesses are rejected.
+    /// - The coinbase transaction should be contained in the same header, with a
+    ///   Merkle branch which has the same depth as the Merkle branch of the transaction.
+    ///
+    /// A plain Merkle branch doesn't contain the count of transactions, so the depth
+    /// of the Merkle tree is authenticated by the coinbase Merkle branch, as
+    /// `self.verify_transaction_data_hardened(..)` does.
+    pub fn verify_transaction_with_branch_hardened(
+        &self,
+        tx: &[u8],
+        tx_proof: packed::TransactionBranchProofReader,
+        coinbase_proof: packed::CoinbaseProofReader,
+        confirmations: u32,
+    ) -> Result<(core::Header, core::Transaction), VerifyTxError> {
+        let tx = decode_transaction_data(tx)?;
+        let txid = tx.txid();
+        let header = self.verify_transaction_with_branch(txid.as_ref(), tx_proof, confirmations)?;
+
+        // Verify coinbase proof
+        let tree_depth = tx_proof.merkle_branch().len();
+        verify_coinbase_proof(coinbase_proof, &header, tree_depth)?;
+
+        Ok((header, tx))
+    }
+
+    /// Verifies whether multiple transactions, which are in the same block,
+    /// are in the chain or not.
+    ///
+    /// The `txids` should be in the same order as the `tx_indexes` in the proof.
+    ///
+    /// Do the same checks as `self.verify_transaction(..)`, but all transactions
+    /// are checked with one Merkle proof and one MMR proof.
+    ///
+    /// Since the header has been recovered from bytes, so this function return it
+    /// in order to any possible future usages.
+    /// If you don't need it, just ignore it.
+    pub fn verify_transactions(
+        &self,
+        txids: &[[u8; 32]],
+        txs_proof: packed::TransactionsProofReader,
+        confirmations: u32,
+    ) -> Result<core::Header, VerifyTxError> {
+        let height: u32 = txs_proof.height().unpack();
+
+        // Verify Transactions
+        self.verify_height(height, confirmations)?;
+
+        // Verify TxOut proof
+        let tx_indexes = txs_proof.tx_indexes();
+        if tx_indexes.is_empty() || tx_indexes.len() != txids.len() {
+            error!(
+                "failed: the proof has {} transaction indexes, but {} transactions are provided",
+                tx_indexes.len(),
+                txids.len()
+            );
+            return Err(VerifyTxError::TxOutProofInvalidTxIndex);
+        }
+        let header = verify_txout_proof(
+            txs_proof.transaction_proof().raw_data(),
+            tx_indexes.iter().map(|v| v.unpack()).zip(txids),
+        )?
+        .header;
+
+        // Verify Header MMR proof
+        let digest = core::HeaderDigest::new_leaf(height, &header);
+        self.verify_header_digest(digest, txs_proof.header_proof())?;
+
+        Ok(header)
+    }
+
+    /// Verifies whether a header is in the chain or not.
+    ///
+    /// Checks:
+    /// - Check if the header is contained in the Bitcoin chain (via MMR proof).
+    /// - Check the confirmation blocks based on the tip header in current SPV client.
+    ///   - `0` means skip the check of the confirmation blocks.
+    ///
+    /// The header could be provided as the full header, or as its block hash
+    /// with its compact target.
+    /// If the full header is provided, since it has been recovered from bytes,
+    /// so this function return it in order to any possible future usages.
+    pub fn verify_header(
+        &self,
+        header_proof: packed::HeaderProofReader,
+        confirmations: u32,
+    ) -> Result<Option<core::Header>, VerifyTxError> {
+        let height: u32 = header_proof.height().unpack();
+
+        self.verify_height(height, confirmations)?;
+
+        let (header_opt, digest) = match header_proof.header().to_enum() {
+            packed::HeaderOrHashUnionReader::Header(header) => {
+                let header: core::Header =
+                    deserialize(header.raw_data()).map_err(|_| VerifyTxError::DecodeHeader)?;
+                let digest = core::HeaderDigest::new_leaf(height, &header);
+                (Some(header), digest)
+            }
+            packed::HeaderOrHashUnionReader::BlockHashWithTarget(hash_with_target) => {
+                let block_hash = hash_with_target.block_hash().unpack();
+                let bits: u32 = hash_with_target.compact_target().unpack();
+                let digest = core::HeaderDigest::new_leaf_with_block_hash(
+                    height,
+                    block_hash,
+                    core::CompactTarget::from_consensus(bits),
+                );
+                (None, digest)
+            }
+        };
+        self.verify_header_digest(digest, header_proof.header_proof())?;
+
+        Ok(header_opt)
+    }
+
+    /// Verifies whether multiple headers, which are at different heights,
+    /// are in the chain or not.
+    ///
+    /// Checks:
+    /// - The heights should be in ascending order, and the count of heights
+    ///   should be the same as the count of headers.
+    /// - Check if all headers are contained in the Bitcoin chain (via one MMR proof).
+    /// - Check the confirmation blocks of each header based on the tip header
+    ///   in current SPV client.
+    ///   - `0` means skip the check of the confirmation blocks.
+    ///
+    /// Since the headers have been recovered from bytes, so this function return
+    /// them in order to any possible future usages.
+    pub fn verify_headers(
+        &self,
+        headers_proof: packed::HeadersProofReader,
+        confirmations: u32,
+    ) -> Result<Vec<core::Header>, VerifyTxError> {
+        let heights = headers_proof.heights();
+        let packed_headers = headers_proof.headers();
+        if heights.is_empty() || heights.len() != packed_headers.len() {
+            error!(
+                "failed: the proof has {} heights but {} headers",
+                heights.len(),
+                packed_headers.len()
+            );
+            return Err(VerifyTxError::HeadersProofInvalidHeights);
+        }
+
+        let mut prev_height = None;
+        let mut headers = Vec::with_capacity(heights.len());
+        let mut digests = Vec::with_capacity(heights.len());
+        for (height, header) in heights.iter().zip(packed_headers.iter()) {
+            let height: u32 = height.unpack();
+            if prev_height.is_some_and(|prev| prev >= height) {
+                error!("failed: the heights of headers are not in ascending order");
+                return Err(VerifyTxError::HeadersProofInvalidHeights);
+            }
+            prev_height = Some(height);
+            self.verify_height(height, confirmations)?;
+            let header: core::Header =
+                deserialize(header.raw_data()).map_err(|_| VerifyTxError::DecodeHeader)?;
+            digests.push(core::HeaderDigest::new_leaf(height, &header));
+            headers.push(header);
+        }
+        self.verify_header_digests(digests, headers_proof.headers_proof())?;
+
+        Ok(headers)
+    }
+
+    // Checks whether the height is in the range of current SPV client,
+    // and has enough confirmation blocks.
+    fn verify_height(&self, height: u32, confirmations: u32) -> Result<(), VerifyTxError> {
+        let min_height = self.headers_mmr_root().min_height().unpack();
+        let max_height = self.headers_mmr_root().max_height().unpack();
         if min_height > height {
             return Err(VerifyTxError::TransactionTooOld);
         }
@@ -315,76 +1279,65 @@ impl packed::SpvClient {
         if confirmations > 0 && max_height - height < confirmations {
             return Err(VerifyTxError::TransactionUnconfirmed);
         }
+        Ok(())
+    }
 
-        // Verify TxOut proof
-        let header = {
-            let merkle_block: core::MerkleBlock =
-                deserialize(tx_proof.transaction_proof().raw_data())
-                    .map_err(|_| VerifyTxError::DecodeTxOutProof)?;
-
-            let mut matches: Vec<core::Txid> = vec![];
-            let mut indexes: Vec<u32> = vec![];
-
-            merkle_block
-                .extract_matches(&mut matches, &mut indexes)
-                .map_err(|_| VerifyTxError::TxOutProofIsInvalid)?;
-
-            if matches.len() != indexes.len() {
-                return Err(VerifyTxError::TxOutProofIsInvalid);
-            }
+    // Verifies the MMR proof of a leaf header digest.
+    // The height of the header should be checked by `verify_height(..)` before.
+    fn verify_header_digest(
+        &self,
+        digest: core::HeaderDigest,
+        header_proof: packed::MmrProofReader,
+    ) -> Result<(), VerifyTxError> {
+        self.verify_header_digests(vec![digest], header_proof)
+    }
 
-            let tx_index: u32 = tx_proof.tx_index().unpack();
-            indexes
-                .into_iter()
-                .position(|v| v == tx_index)
-                .map(|i| matches[i])
-                .ok_or(VerifyTxError::TxOutProofInvalidTxIndex)
-                .and_then(|id| {
-                    let id_bytes: &[u8; 32] = id.as_ref();
-                    if id_bytes == txid {
-                        Ok(())
-                    } else {
-                        Err(VerifyTxError::TxOutProofInvalidTxId)
-                    }
-                })?;
+    // Verifies the MMR proof of leaf header digests.
+    // The heights of the headers should be checked by `verify_height(..)` before.
+    fn verify_header_digests(
+        &self,
+        digests: Vec<core::HeaderDigest>,
+        headers_proof: packed::MmrProofReader,
+    ) -> Result<(), VerifyTxError> {
+        let min_height = self.headers_mmr_root().min_height().unpack();
+        let max_height = self.headers_mmr_root().max_height().unpack();
 
-            merkle_block.header
+        let proof: mmr::MMRProof = {
+            let max_index = max_height - min_height;
+            let mmr_size = leaf_index_to_mmr_size(u64::from(max_index));
+            trace!(
+                "verify MMR proof for {} headers with \
+                MMR {{ size: {mmr_size}, max-index: {max_index} }}",
+                digests.len()
+            );
+            let proof = headers_proof
+                .iter()
+                .map(|r| r.to_entity())
+                .collect::<Vec<_>>();
+            mmr::MMRProof::new(mmr_size, proof)
         };
-
-        // Verify Header MMR proof
-        {
-            let block_hash = header.block_hash();
-
-            let proof: mmr::MMRProof = {
-                let max_index = max_height - min_height;
-                let mmr_size = leaf_index_to_mmr_size(u64::from(max_index));
-                trace!(
-                    "verify MMR proof for header-{height} with \
-                    MMR {{ size: {mmr_size}, max-index: {max_index} }}, root: {block_hash:#x}",
-                );
-                let proof = tx_proof
-                    .header_proof()
-                    .iter()
-                    .map(|r| r.to_entity())
-                    .collect::<Vec<_>>();
-                mmr::MMRProof::new(mmr_size, proof)
-            };
-            let digests_with_positions = {
+        let digests_with_positions = digests
+            .into_iter()
+            .map(|digest| {
+                let height = digest.min_height;
                 let index = height - min_height;
                 let position = leaf_index_to_pos(u64::from(index));
                 trace!(
                     "verify MMR proof for header-{height} with \
-                    index: {index}, position: {position}, root: {block_hash:#x}"
+                    index: {index}, position: {position}, root: {:#x}",
+                    digest.children_hash
                 );
-                let digest = core::HeaderDigest::new_leaf(height, &header).pack();
-                vec![(position, digest)]
-            };
-            proof
-                .verify(self.headers_mmr_root(), digests_with_positions)
-                .map_err(|_| VerifyTxError::HeaderMmrProof)?;
+                (position, digest.pack())
+            })
+            .collect::<Vec<_>>();
+        let result = proof
+            .verify(self.headers_mmr_root(), digests_with_positions)
+            .map_err(|_| VerifyTxError::HeaderMmrProof)?;
+        if !result {
+            warn!("failed: verify MMR proof for headers");
+            return Err(VerifyTxError::HeaderMmrProof);
         }
-
-        Ok(header)
+        Ok(())
     }
 
     /// Compare two chains, which is better.
@@ -394,3 +1347,91 @@ impl packed::SpvClient {
         self_work > other_work
     }
 }
+
+// Verifies the transactions, which are indexes and IDs, are included in the
+// TxOut proof (the merkle block), then returns the merkle block.
+fn verify_txout_proof<'a, I>(
+    transaction_proof: &[u8],
+    txs: I,
+) -> Result<core::MerkleBlock, VerifyTxError>
+where
+    I: IntoIterator<Item = (u32, &'a [u8; 32])>,
+{
+    let merkle_block: core::MerkleBlock =
+        deserialize(transaction_proof).map_err(|_| VerifyTxError::DecodeTxOutProof)?;
+
+    let mut matches: Vec<core::Txid> = vec![];
+    let mut indexes: Vec<u32> = vec![];
+
+    merkle_block
+        .extract_matches(&mut matches, &mut indexes)
+        .map_err(|_| VerifyTxError::TxOutProofIsInvalid)?;
+
+    if matches.len() != indexes.len() {
+        return Err(VerifyTxError::TxOutProofIsInvalid);
+    }
+
+    for (tx_index, txid) in txs {
+        indexes
+            .iter()
+            .position(|v| *v == tx_index)
+            .map(|i| matches[i])
+            .ok_or(VerifyTxError::TxOutProofInvalidTxIndex)
+            .and_then(|id| {
+                let id_bytes: &[u8; 32] = id.as_ref();
+                if id_bytes == txid {
+                    Ok(())
+                } else {
+                    Err(VerifyTxError::TxOutProofInvalidTxId)
+                }
+            })?;
+    }
+
+    Ok(merkle_block)
+}
+
+// Decodes a transaction, and rejects it if it's 64 bytes without witnesses.
+fn decode_transaction_data(tx: &[u8]) -> Result<core::Transaction, VerifyTxError> {
+    let tx: core::Transaction = deserialize(tx).map_err(|_| VerifyTxError::DecodeTransaction)?;
+    if tx.base_size() == 64 {
+        error!("failed: the transaction is 64 bytes without witnesses");
+        return Err(VerifyTxError::TxOutProofAmbiguousTx);
+    }
+    Ok(tx)
+}
+
+// Verifies the coinbase proof, its Merkle branch should be as deep as the Merkle tree.
+fn verify_coinbase_proof(
+    coinbase_proof: packed::CoinbaseProofReader,
+    header: &core::Header,
+    tree_depth: usize,
+) -> Result<(), VerifyTxError> {
+    let coinbase = decode_transaction_data(coinbase_proof.transaction().raw_data()).map_err(
+        |err| match err {
+            VerifyTxError::DecodeTransaction => VerifyTxError::DecodeCoinbaseTransaction,
+            _ => err,
+        },
+    )?;
+    if !coinbase.is_coinbase() {
+        error!("failed: the transaction in coinbase proof is not a coinbase transaction");
+        return Err(VerifyTxError::CoinbaseProof);
+    }
+    let branch_depth = coinbase_proof.merkle_branch().len();
+    if branch_depth != tree_depth {
+        error!(
+            "failed: the depth of the coinbase merkle branch is {branch_depth}, \
+            but the depth of the merkle tree is {tree_depth}"
+        );
+        return Err(VerifyTxError::CoinbaseProof);
+    }
+    if coinbase_proof.calc_merkle_root(&coinbase) != header.merkle_root.to_raw_hash() {
+        error!("failed: the coinbase merkle branch is not matched the header");
+        return Err(VerifyTxError::CoinbaseProof);
+    }
+    Ok(())
+}
+
+// Calculates the depth of the Merkle tree which has `count` leaves.
+fn merkle_tree_depth(count: u32) -> usize {
+    (u32::BITS - count.saturating_sub(1).leading_zeros()) as usize
+}
@@ -205,8 +205,8 @@ impl molecule::prelude::Builder for Uint32Builder {
     }
 }
 #[derive(Clone)]
-pub struct Uint256(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Uint256 {
+pub struct Uint32Vec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Uint32Vec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -215,140 +215,66 @@ impl ::core::fmt::LowerHex for Uint256 {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Uint256 {
+impl ::core::fmt::Debug for Uint32Vec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for Uint256 {
+impl ::core::fmt::Display for Uint32Vec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        let raw_data = hex_string(&self.raw_data());
-        write!(f, "{}(0x{})", Self::NAME, raw_data)
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
     }
 }
-impl ::core::default::Default for Uint256 {
+impl ::core::default::Default for Uint32Vec {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        Uint256::new_unchecked(v)
+        Uint32Vec::new_unchecked(v)
     }
 }
-impl Uint256 {
-    const DEFAULT_VALUE: [u8; 32] = [
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0,
-    ];
-    pub const TOTAL_SIZE: usize = 32;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 32;
-    pub fn nth0(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(0..1))
-    }
-    pub fn nth1(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(1..2))
-    }
-    pub fn nth2(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(2..3))
-    }
-    pub fn nth3(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(3..4))
-    }
-    pub fn nth4(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(4..5))
-    }
-    pub fn nth5(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(5..6))
-    }
-    pub fn nth6(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(6..7))
-    }
-    pub fn nth7(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(7..8))
-    }
-    pub fn nth8(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(8..9))
-    }
-    pub fn nth9(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(9..10))
-    }
-    pub fn nth10(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(10..11))
-    }
-    pub fn nth11(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(11..12))
-    }
-    pub fn nth12(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(12..13))
-    }
-    pub fn nth13(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(13..14))
-    }
-    pub fn nth14(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(14..15))
-    }
-    pub fn nth15(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(15..16))
-    }
-    pub fn nth16(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(16..17))
-    }
-    pub fn nth17(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(17..18))
-    }
-    pub fn nth18(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(18..19))
-    }
-    pub fn nth19(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(19..20))
-    }
-    pub fn nth20(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(20..21))
-    }
-    pub fn nth21(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(21..22))
-    }
-    pub fn nth22(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(22..23))
-    }
-    pub fn nth23(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(23..24))
-    }
-    pub fn nth24(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(24..25))
-    }
-    pub fn nth25(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(25..26))
-    }
-    pub fn nth26(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(26..27))
-    }
-    pub fn nth27(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(27..28))
+impl Uint32Vec {
+    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
+    pub const ITEM_SIZE: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
     }
-    pub fn nth28(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(28..29))
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn nth29(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(29..30))
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-    pub fn nth30(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(30..31))
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-    pub fn nth31(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(31..32))
+    pub fn get(&self, idx: usize) -> Option<Uint32> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
     }
-    pub fn raw_data(&self) -> molecule::bytes::Bytes {
-        self.as_bytes()
+    pub fn get_unchecked(&self, idx: usize) -> Uint32 {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        Uint32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn as_reader<'r>(&'r self) -> Uint256Reader<'r> {
-        Uint256Reader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> Uint32VecReader<'r> {
+        Uint32VecReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Uint256 {
-    type Builder = Uint256Builder;
-    const NAME: &'static str = "Uint256";
+impl molecule::prelude::Entity for Uint32Vec {
+    type Builder = Uint32VecBuilder;
+    const NAME: &'static str = "Uint32Vec";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Uint256(data)
+        Uint32Vec(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -357,54 +283,21 @@ impl molecule::prelude::Entity for Uint256 {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        Uint256Reader::from_slice(slice).map(|reader| reader.to_entity())
+        Uint32VecReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        Uint256Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        Uint32VecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set([
-            self.nth0(),
-            self.nth1(),
-            self.nth2(),
-            self.nth3(),
-            self.nth4(),
-            self.nth5(),
-            self.nth6(),
-            self.nth7(),
-            self.nth8(),
-            self.nth9(),
-            self.nth10(),
-            self.nth11(),
-            self.nth12(),
-            self.nth13(),
-            self.nth14(),
-            self.nth15(),
-            self.nth16(),
-            self.nth17(),
-            self.nth18(),
-            self.nth19(),
-            self.nth20(),
-            self.nth21(),
-            self.nth22(),
-            self.nth23(),
-            self.nth24(),
-            self.nth25(),
-            self.nth26(),
-            self.nth27(),
-            self.nth28(),
-            self.nth29(),
-            self.nth30(),
-            self.nth31(),
-        ])
+        Self::new_builder().extend(self.into_iter())
     }
 }
 #[derive(Clone, Copy)]
-pub struct Uint256Reader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for Uint256Reader<'r> {
+pub struct Uint32VecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for Uint32VecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -413,375 +306,343 @@ impl<'r> ::core::fmt::LowerHex for Uint256Reader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for Uint256Reader<'r> {
+impl<'r> ::core::fmt::Debug for Uint32VecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for Uint256Reader<'r> {
+impl<'r> ::core::fmt::Display for Uint32VecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        let raw_data = hex_string(&self.raw_data());
-        write!(f, "{}(0x{})", Self::NAME, raw_data)
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
     }
 }
-impl<'r> Uint256Reader<'r> {
-    pub const TOTAL_SIZE: usize = 32;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 32;
-    pub fn nth0(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[0..1])
-    }
-    pub fn nth1(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[1..2])
-    }
-    pub fn nth2(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[2..3])
-    }
-    pub fn nth3(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[3..4])
-    }
-    pub fn nth4(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[4..5])
-    }
-    pub fn nth5(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[5..6])
+impl<'r> Uint32VecReader<'r> {
+    pub const ITEM_SIZE: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
     }
-    pub fn nth6(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[6..7])
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn nth7(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[7..8])
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-    pub fn nth8(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[8..9])
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-    pub fn nth9(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[9..10])
+    pub fn get(&self, idx: usize) -> Option<Uint32Reader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
     }
-    pub fn nth10(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[10..11])
+    pub fn get_unchecked(&self, idx: usize) -> Uint32Reader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn nth11(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[11..12])
+}
+impl<'r> molecule::prelude::Reader<'r> for Uint32VecReader<'r> {
+    type Entity = Uint32Vec;
+    const NAME: &'static str = "Uint32VecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
-    pub fn nth12(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[12..13])
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        Uint32VecReader(slice)
     }
-    pub fn nth13(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[13..14])
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
     }
-    pub fn nth14(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[14..15])
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        Ok(())
     }
-    pub fn nth15(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[15..16])
+}
+#[derive(Debug, Default)]
+pub struct Uint32VecBuilder(pub(crate) Vec<Uint32>);
+impl Uint32VecBuilder {
+    pub const ITEM_SIZE: usize = 4;
+    pub fn set(mut self, v: Vec<Uint32>) -> Self {
+        self.0 = v;
+        self
     }
-    pub fn nth16(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[16..17])
+    pub fn push(mut self, v: Uint32) -> Self {
+        self.0.push(v);
+        self
     }
-    pub fn nth17(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[17..18])
+    pub fn extend<T: ::core::iter::IntoIterator<Item = Uint32>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
     }
-    pub fn nth18(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[18..19])
+    pub fn replace(&mut self, index: usize, v: Uint32) -> Option<Uint32> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
     }
-    pub fn nth19(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[19..20])
+}
+impl molecule::prelude::Builder for Uint32VecBuilder {
+    type Entity = Uint32Vec;
+    const NAME: &'static str = "Uint32VecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
     }
-    pub fn nth20(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[20..21])
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
+        Ok(())
     }
-    pub fn nth21(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[21..22])
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Uint32Vec::new_unchecked(inner.into())
     }
-    pub fn nth22(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[22..23])
+}
+pub struct Uint32VecIterator(Uint32Vec, usize, usize);
+impl ::core::iter::Iterator for Uint32VecIterator {
+    type Item = Uint32;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
     }
-    pub fn nth23(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[23..24])
+}
+impl ::core::iter::ExactSizeIterator for Uint32VecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
     }
-    pub fn nth24(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[24..25])
+}
+impl ::core::iter::IntoIterator for Uint32Vec {
+    type Item = Uint32;
+    type IntoIter = Uint32VecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        Uint32VecIterator(self, 0, len)
     }
-    pub fn nth25(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[25..26])
+}
+impl<'r> Uint32VecReader<'r> {
+    pub fn iter<'t>(&'t self) -> Uint32VecReaderIterator<'t, 'r> {
+        Uint32VecReaderIterator(&self, 0, self.len())
     }
-    pub fn nth26(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[26..27])
+}
+pub struct Uint32VecReaderIterator<'t, 'r>(&'t Uint32VecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for Uint32VecReaderIterator<'t, 'r> {
+    type Item = Uint32Reader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
     }
-    pub fn nth27(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[27..28])
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for Uint32VecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
     }
-    pub fn nth28(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[28..29])
+}
+#[derive(Clone)]
+pub struct Uint32Opt(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Uint32Opt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
     }
-    pub fn nth29(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[29..30])
+}
+impl ::core::fmt::Debug for Uint32Opt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
     }
-    pub fn nth30(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[30..31])
+}
+impl ::core::fmt::Display for Uint32Opt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
     }
-    pub fn nth31(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[31..32])
+}
+impl ::core::default::Default for Uint32Opt {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        Uint32Opt::new_unchecked(v)
     }
-    pub fn raw_data(&self) -> &'r [u8] {
-        self.as_slice()
+}
+impl Uint32Opt {
+    const DEFAULT_VALUE: [u8; 0] = [];
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<Uint32> {
+        if self.is_none() {
+            None
+        } else {
+            Some(Uint32::new_unchecked(self.0.clone()))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> Uint32OptReader<'r> {
+        Uint32OptReader::new_unchecked(self.as_slice())
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for Uint256Reader<'r> {
-    type Entity = Uint256;
-    const NAME: &'static str = "Uint256Reader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+impl molecule::prelude::Entity for Uint32Opt {
+    type Builder = Uint32OptBuilder;
+    const NAME: &'static str = "Uint32Opt";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Uint32Opt(data)
     }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
-        Uint256Reader(slice)
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
     }
-    fn as_slice(&self) -> &'r [u8] {
-        self.0
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        Uint32OptReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        Uint32OptReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_opt())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct Uint32OptReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for Uint32OptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
         }
-        Ok(())
+        write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-pub struct Uint256Builder(pub(crate) [Byte; 32]);
-impl ::core::fmt::Debug for Uint256Builder {
+impl<'r> ::core::fmt::Debug for Uint32OptReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+        write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::default::Default for Uint256Builder {
-    fn default() -> Self {
-        Uint256Builder([
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-        ])
+impl<'r> ::core::fmt::Display for Uint32OptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
     }
 }
-impl Uint256Builder {
-    pub const TOTAL_SIZE: usize = 32;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 32;
-    pub fn set(mut self, v: [Byte; 32]) -> Self {
-        self.0 = v;
-        self
+impl<'r> Uint32OptReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
     }
-    pub fn nth0(mut self, v: Byte) -> Self {
-        self.0[0] = v;
-        self
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
     }
-    pub fn nth1(mut self, v: Byte) -> Self {
-        self.0[1] = v;
-        self
+    pub fn to_opt(&self) -> Option<Uint32Reader<'r>> {
+        if self.is_none() {
+            None
+        } else {
+            Some(Uint32Reader::new_unchecked(self.as_slice()))
+        }
     }
-    pub fn nth2(mut self, v: Byte) -> Self {
-        self.0[2] = v;
-        self
+}
+impl<'r> molecule::prelude::Reader<'r> for Uint32OptReader<'r> {
+    type Entity = Uint32Opt;
+    const NAME: &'static str = "Uint32OptReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
-    pub fn nth3(mut self, v: Byte) -> Self {
-        self.0[3] = v;
-        self
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        Uint32OptReader(slice)
     }
-    pub fn nth4(mut self, v: Byte) -> Self {
-        self.0[4] = v;
-        self
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
     }
-    pub fn nth5(mut self, v: Byte) -> Self {
-        self.0[5] = v;
-        self
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        if !slice.is_empty() {
+            Uint32Reader::verify(&slice[..], compatible)?;
+        }
+        Ok(())
     }
-    pub fn nth6(mut self, v: Byte) -> Self {
-        self.0[6] = v;
-        self
-    }
-    pub fn nth7(mut self, v: Byte) -> Self {
-        self.0[7] = v;
-        self
-    }
-    pub fn nth8(mut self, v: Byte) -> Self {
-        self.0[8] = v;
-        self
-    }
-    pub fn nth9(mut self, v: Byte) -> Self {
-        self.0[9] = v;
-        self
-    }
-    pub fn nth10(mut self, v: Byte) -> Self {
-        self.0[10] = v;
-        self
-    }
-    pub fn nth11(mut self, v: Byte) -> Self {
-        self.0[11] = v;
-        self
-    }
-    pub fn nth12(mut self, v: Byte) -> Self {
-        self.0[12] = v;
-        self
-    }
-    pub fn nth13(mut self, v: Byte) -> Self {
-        self.0[13] = v;
-        self
-    }
-    pub fn nth14(mut self, v: Byte) -> Self {
-        self.0[14] = v;
-        self
-    }
-    pub fn nth15(mut self, v: Byte) -> Self {
-        self.0[15] = v;
-        self
-    }
-    pub fn nth16(mut self, v: Byte) -> Self {
-        self.0[16] = v;
-        self
-    }
-    pub fn nth17(mut self, v: Byte) -> Self {
-        self.0[17] = v;
-        self
-    }
-    pub fn nth18(mut self, v: Byte) -> Self {
-        self.0[18] = v;
-        self
-    }
-    pub fn nth19(mut self, v: Byte) -> Self {
-        self.0[19] = v;
-        self
-    }
-    pub fn nth20(mut self, v: Byte) -> Self {
-        self.0[20] = v;
-        self
-    }
-    pub fn nth21(mut self, v: Byte) -> Self {
-        self.0[21] = v;
-        self
-    }
-    pub fn nth22(mut self, v: Byte) -> Self {
-        self.0[22] = v;
-        self
-    }
-    pub fn nth23(mut self, v: Byte) -> Self {
-        self.0[23] = v;
-        self
-    }
-    pub fn nth24(mut self, v: Byte) -> Self {
-        self.0[24] = v;
-        self
-    }
-    pub fn nth25(mut self, v: Byte) -> Self {
-        self.0[25] = v;
-        self
-    }
-    pub fn nth26(mut self, v: Byte) -> Self {
-        self.0[26] = v;
-        self
-    }
-    pub fn nth27(mut self, v: Byte) -> Self {
-        self.0[27] = v;
-        self
-    }
-    pub fn nth28(mut self, v: Byte) -> Self {
-        self.0[28] = v;
-        self
-    }
-    pub fn nth29(mut self, v: Byte) -> Self {
-        self.0[29] = v;
-        self
-    }
-    pub fn nth30(mut self, v: Byte) -> Self {
-        self.0[30] = v;
-        self
-    }
-    pub fn nth31(mut self, v: Byte) -> Self {
-        self.0[31] = v;
+}
+#[derive(Debug, Default)]
+pub struct Uint32OptBuilder(pub(crate) Option<Uint32>);
+impl Uint32OptBuilder {
+    pub fn set(mut self, v: Option<Uint32>) -> Self {
+        self.0 = v;
         self
     }
 }
-impl molecule::prelude::Builder for Uint256Builder {
-    type Entity = Uint256;
-    const NAME: &'static str = "Uint256Builder";
+impl molecule::prelude::Builder for Uint32OptBuilder {
+    type Entity = Uint32Opt;
+    const NAME: &'static str = "Uint32OptBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        self.0
+            .as_ref()
+            .map(|ref inner| inner.as_slice().len())
+            .unwrap_or(0)
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.0[0].as_slice())?;
-        writer.write_all(self.0[1].as_slice())?;
-        writer.write_all(self.0[2].as_slice())?;
-        writer.write_all(self.0[3].as_slice())?;
-        writer.write_all(self.0[4].as_slice())?;
-        writer.write_all(self.0[5].as_slice())?;
-        writer.write_all(self.0[6].as_slice())?;
-        writer.write_all(self.0[7].as_slice())?;
-        writer.write_all(self.0[8].as_slice())?;
-        writer.write_all(self.0[9].as_slice())?;
-        writer.write_all(self.0[10].as_slice())?;
-        writer.write_all(self.0[11].as_slice())?;
-        writer.write_all(self.0[12].as_slice())?;
-        writer.write_all(self.0[13].as_slice())?;
-        writer.write_all(self.0[14].as_slice())?;
-        writer.write_all(self.0[15].as_slice())?;
-        writer.write_all(self.0[16].as_slice())?;
-        writer.write_all(self.0[17].as_slice())?;
-        writer.write_all(self.0[18].as_slice())?;
-        writer.write_all(self.0[19].as_slice())?;
-        writer.write_all(self.0[20].as_slice())?;
-        writer.write_all(self.0[21].as_slice())?;
-        writer.write_all(self.0[22].as_slice())?;
-        writer.write_all(self.0[23].as_slice())?;
-        writer.write_all(self.0[24].as_slice())?;
-        writer.write_all(self.0[25].as_slice())?;
-        writer.write_all(self.0[26].as_slice())?;
-        writer.write_all(self.0[27].as_slice())?;
-        writer.write_all(self.0[28].as_slice())?;
-        writer.write_all(self.0[29].as_slice())?;
-        writer.write_all(self.0[30].as_slice())?;
-        writer.write_all(self.0[31].as_slice())?;
-        Ok(())
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Uint256::new_unchecked(inner.into())
+        Uint32Opt::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct Hash(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Hash {
+pub struct Uint64(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Uint64 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -790,32 +651,29 @@ impl ::core::fmt::LowerHex for Hash {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Hash {
+impl ::core::fmt::Debug for Uint64 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for Hash {
+impl ::core::fmt::Display for Uint64 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         let raw_data = hex_string(&self.raw_data());
         write!(f, "{}(0x{})", Self::NAME, raw_data)
     }
 }
-impl ::core::default::Default for Hash {
+impl ::core::default::Default for Uint64 {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        Hash::new_unchecked(v)
+        Uint64::new_unchecked(v)
     }
 }
-impl Hash {
-    const DEFAULT_VALUE: [u8; 32] = [
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0,
-    ];
-    pub const TOTAL_SIZE: usize = 32;
+impl Uint64 {
+    const DEFAULT_VALUE: [u8; 8] = [0, 0, 0, 0, 0, 0, 0, 0];
+    pub const TOTAL_SIZE: usize = 8;
     pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 32;
+    pub const ITEM_COUNT: usize = 8;
     pub fn nth0(&self) -> Byte {
         Byte::new_unchecked(self.0.slice(0..1))
     }
@@ -840,90 +698,18 @@ impl Hash {
     pub fn nth7(&self) -> Byte {
         Byte::new_unchecked(self.0.slice(7..8))
     }
-    pub fn nth8(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(8..9))
-    }
-    pub fn nth9(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(9..10))
-    }
-    pub fn nth10(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(10..11))
-    }
-    pub fn nth11(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(11..12))
-    }
-    pub fn nth12(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(12..13))
-    }
-    pub fn nth13(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(13..14))
-    }
-    pub fn nth14(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(14..15))
-    }
-    pub fn nth15(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(15..16))
-    }
-    pub fn nth16(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(16..17))
-    }
-    pub fn nth17(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(17..18))
-    }
-    pub fn nth18(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(18..19))
-    }
-    pub fn nth19(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(19..20))
-    }
-    pub fn nth20(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(20..21))
-    }
-    pub fn nth21(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(21..22))
-    }
-    pub fn nth22(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(22..23))
-    }
-    pub fn nth23(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(23..24))
-    }
-    pub fn nth24(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(24..25))
-    }
-    pub fn nth25(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(25..26))
-    }
-    pub fn nth26(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(26..27))
-    }
-    pub fn nth27(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(27..28))
-    }
-    pub fn nth28(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(28..29))
-    }
-    pub fn nth29(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(29..30))
-    }
-    pub fn nth30(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(30..31))
-    }
-    pub fn nth31(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(31..32))
-    }
     pub fn raw_data(&self) -> molecule::bytes::Bytes {
         self.as_bytes()
     }
-    pub fn as_reader<'r>(&'r self) -> HashReader<'r> {
-        HashReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Hash {
-    type Builder = HashBuilder;
-    const NAME: &'static str = "Hash";
+impl molecule::prelude::Entity for Uint64 {
+    type Builder = Uint64Builder;
+    const NAME: &'static str = "Uint64";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Hash(data)
+        Uint64(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -932,10 +718,10 @@ impl molecule::prelude::Entity for Hash {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        HashReader::from_slice(slice).map(|reader| reader.to_entity())
+        Uint64Reader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        HashReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        Uint64Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
@@ -950,36 +736,12 @@ impl molecule::prelude::Entity for Hash {
             self.nth5(),
             self.nth6(),
             self.nth7(),
-            self.nth8(),
-            self.nth9(),
-            self.nth10(),
-            self.nth11(),
-            self.nth12(),
-            self.nth13(),
-            self.nth14(),
-            self.nth15(),
-            self.nth16(),
-            self.nth17(),
-            self.nth18(),
-            self.nth19(),
-            self.nth20(),
-            self.nth21(),
-            self.nth22(),
-            self.nth23(),
-            self.nth24(),
-            self.nth25(),
-            self.nth26(),
-            self.nth27(),
-            self.nth28(),
-            self.nth29(),
-            self.nth30(),
-            self.nth31(),
         ])
     }
 }
 #[derive(Clone, Copy)]
-pub struct HashReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for HashReader<'r> {
+pub struct Uint64Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for Uint64Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -988,22 +750,22 @@ impl<'r> ::core::fmt::LowerHex for HashReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for HashReader<'r> {
+impl<'r> ::core::fmt::Debug for Uint64Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for HashReader<'r> {
+impl<'r> ::core::fmt::Display for Uint64Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         let raw_data = hex_string(&self.raw_data());
         write!(f, "{}(0x{})", Self::NAME, raw_data)
     }
 }
-impl<'r> HashReader<'r> {
-    pub const TOTAL_SIZE: usize = 32;
+impl<'r> Uint64Reader<'r> {
+    pub const TOTAL_SIZE: usize = 8;
     pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 32;
+    pub const ITEM_COUNT: usize = 8;
     pub fn nth0(&self) -> ByteReader<'r> {
         ByteReader::new_unchecked(&self.as_slice()[0..1])
     }
@@ -1028,90 +790,18 @@ impl<'r> HashReader<'r> {
     pub fn nth7(&self) -> ByteReader<'r> {
         ByteReader::new_unchecked(&self.as_slice()[7..8])
     }
-    pub fn nth8(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[8..9])
-    }
-    pub fn nth9(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[9..10])
-    }
-    pub fn nth10(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[10..11])
-    }
-    pub fn nth11(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[11..12])
-    }
-    pub fn nth12(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[12..13])
-    }
-    pub fn nth13(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[13..14])
-    }
-    pub fn nth14(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[14..15])
-    }
-    pub fn nth15(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[15..16])
-    }
-    pub fn nth16(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[16..17])
-    }
-    pub fn nth17(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[17..18])
-    }
-    pub fn nth18(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[18..19])
-    }
-    pub fn nth19(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[19..20])
-    }
-    pub fn nth20(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[20..21])
-    }
-    pub fn nth21(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[21..22])
-    }
-    pub fn nth22(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[22..23])
-    }
-    pub fn nth23(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[23..24])
-    }
-    pub fn nth24(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[24..25])
-    }
-    pub fn nth25(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[25..26])
-    }
-    pub fn nth26(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[26..27])
-    }
-    pub fn nth27(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[27..28])
-    }
-    pub fn nth28(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[28..29])
-    }
-    pub fn nth29(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[29..30])
-    }
-    pub fn nth30(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[30..31])
-    }
-    pub fn nth31(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[31..32])
-    }
     pub fn raw_data(&self) -> &'r [u8] {
         self.as_slice()
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for HashReader<'r> {
-    type Entity = Hash;
-    const NAME: &'static str = "HashReader";
+impl<'r> molecule::prelude::Reader<'r> for Uint64Reader<'r> {
+    type Entity = Uint64;
+    const NAME: &'static str = "Uint64Reader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        HashReader(slice)
+        Uint64Reader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -1125,39 +815,15 @@ impl<'r> molecule::prelude::Reader<'r> for HashReader<'r> {
         Ok(())
     }
 }
-pub struct HashBuilder(pub(crate) [Byte; 32]);
-impl ::core::fmt::Debug for HashBuilder {
+pub struct Uint64Builder(pub(crate) [Byte; 8]);
+impl ::core::fmt::Debug for Uint64Builder {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:?})", Self::NAME, &self.0[..])
     }
 }
-impl ::core::default::Default for HashBuilder {
+impl ::core::default::Default for Uint64Builder {
     fn default() -> Self {
-        HashBuilder([
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
+        Uint64Builder([
             Byte::default(),
             Byte::default(),
             Byte::default(),
@@ -1169,11 +835,11 @@ impl ::core::default::Default for HashBuilder {
         ])
     }
 }
-impl HashBuilder {
-    pub const TOTAL_SIZE: usize = 32;
+impl Uint64Builder {
+    pub const TOTAL_SIZE: usize = 8;
     pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 32;
-    pub fn set(mut self, v: [Byte; 32]) -> Self {
+    pub const ITEM_COUNT: usize = 8;
+    pub fn set(mut self, v: [Byte; 8]) -> Self {
         self.0 = v;
         self
     }
@@ -1209,106 +875,10 @@ impl HashBuilder {
         self.0[7] = v;
         self
     }
-    pub fn nth8(mut self, v: Byte) -> Self {
-        self.0[8] = v;
-        self
-    }
-    pub fn nth9(mut self, v: Byte) -> Self {
-        self.0[9] = v;
-        self
-    }
-    pub fn nth10(mut self, v: Byte) -> Self {
-        self.0[10] = v;
-        self
-    }
-    pub fn nth11(mut self, v: Byte) -> Self {
-        self.0[11] = v;
-        self
-    }
-    pub fn nth12(mut self, v: Byte) -> Self {
-        self.0[12] = v;
-        self
-    }
-    pub fn nth13(mut self, v: Byte) -> Self {
-        self.0[13] = v;
-        self
-    }
-    pub fn nth14(mut self, v: Byte) -> Self {
-        self.0[14] = v;
-        self
-    }
-    pub fn nth15(mut self, v: Byte) -> Self {
-        self.0[15] = v;
-        self
-    }
-    pub fn nth16(mut self, v: Byte) -> Self {
-        self.0[16] = v;
-        self
-    }
-    pub fn nth17(mut self, v: Byte) -> Self {
-        self.0[17] = v;
-        self
-    }
-    pub fn nth18(mut self, v: Byte) -> Self {
-        self.0[18] = v;
-        self
-    }
-    pub fn nth19(mut self, v: Byte) -> Self {
-        self.0[19] = v;
-        self
-    }
-    pub fn nth20(mut self, v: Byte) -> Self {
-        self.0[20] = v;
-        self
-    }
-    pub fn nth21(mut self, v: Byte) -> Self {
-        self.0[21] = v;
-        self
-    }
-    pub fn nth22(mut self, v: Byte) -> Self {
-        self.0[22] = v;
-        self
-    }
-    pub fn nth23(mut self, v: Byte) -> Self {
-        self.0[23] = v;
-        self
-    }
-    pub fn nth24(mut self, v: Byte) -> Self {
-        self.0[24] = v;
-        self
-    }
-    pub fn nth25(mut self, v: Byte) -> Self {
-        self.0[25] = v;
-        self
-    }
-    pub fn nth26(mut self, v: Byte) -> Self {
-        self.0[26] = v;
-        self
-    }
-    pub fn nth27(mut self, v: Byte) -> Self {
-        self.0[27] = v;
-        self
-    }
-    pub fn nth28(mut self, v: Byte) -> Self {
-        self.0[28] = v;
-        self
-    }
-    pub fn nth29(mut self, v: Byte) -> Self {
-        self.0[29] = v;
-        self
-    }
-    pub fn nth30(mut self, v: Byte) -> Self {
-        self.0[30] = v;
-        self
-    }
-    pub fn nth31(mut self, v: Byte) -> Self {
-        self.0[31] = v;
-        self
-    }
 }
-impl molecule::prelude::Builder for HashBuilder {
-    type Entity = Hash;
-    const NAME: &'static str = "HashBuilder";
+impl molecule::prelude::Builder for Uint64Builder {
+    type Entity = Uint64;
+    const NAME: &'static str = "Uint64Builder";
     fn expected_length(&self) -> usize {
         Self::TOTAL_SIZE
     }
@@ -1321,42 +891,18 @@ impl molecule::prelude::Builder for HashBuilder {
         writer.write_all(self.0[5].as_slice())?;
         writer.write_all(self.0[6].as_slice())?;
         writer.write_all(self.0[7].as_slice())?;
-        writer.write_all(self.0[8].as_slice())?;
-        writer.write_all(self.0[9].as_slice())?;
-        writer.write_all(self.0[10].as_slice())?;
-        writer.write_all(self.0[11].as_slice())?;
-        writer.write_all(self.0[12].as_slice())?;
-        writer.write_all(self.0[13].as_slice())?;
-        writer.write_all(self.0[14].as_slice())?;
-        writer.write_all(self.0[15].as_slice())?;
-        writer.write_all(self.0[16].as_slice())?;
-        writer.write_all(self.0[17].as_slice())?;
-        writer.write_all(self.0[18].as_slice())?;
-        writer.write_all(self.0[19].as_slice())?;
-        writer.write_all(self.0[20].as_slice())?;
-        writer.write_all(self.0[21].as_slice())?;
-        writer.write_all(self.0[22].as_slice())?;
-        writer.write_all(self.0[23].as_slice())?;
-        writer.write_all(self.0[24].as_slice())?;
-        writer.write_all(self.0[25].as_slice())?;
-        writer.write_all(self.0[26].as_slice())?;
-        writer.write_all(self.0[27].as_slice())?;
-        writer.write_all(self.0[28].as_slice())?;
-        writer.write_all(self.0[29].as_slice())?;
-        writer.write_all(self.0[30].as_slice())?;
-        writer.write_all(self.0[31].as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Hash::new_unchecked(inner.into())
+        Uint64::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct Header(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Header {
+pub struct Uint256(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Uint256 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -1365,33 +911,32 @@ impl ::core::fmt::LowerHex for Header {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Header {
+impl ::core::fmt::Debug for Uint256 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for Header {
+impl ::core::fmt::Display for Uint256 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         let raw_data = hex_string(&self.raw_data());
         write!(f, "{}(0x{})", Self::NAME, raw_data)
     }
 }
-impl ::core::default::Default for Header {
+impl ::core::default::Default for Uint256 {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        Header::new_unchecked(v)
+        Uint256::new_unchecked(v)
     }
 }
-impl Header {
-    const DEFAULT_VALUE: [u8; 80] = [
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl Uint256 {
+    const DEFAULT_VALUE: [u8; 32] = [
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0,
     ];
-    pub const TOTAL_SIZE: usize = 80;
+    pub const TOTAL_SIZE: usize = 32;
     pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 80;
+    pub const ITEM_COUNT: usize = 32;
     pub fn nth0(&self) -> Byte {
         Byte::new_unchecked(self.0.slice(0..1))
     }
@@ -1488,162 +1033,18 @@ impl Header {
     pub fn nth31(&self) -> Byte {
         Byte::new_unchecked(self.0.slice(31..32))
     }
-    pub fn nth32(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(32..33))
-    }
-    pub fn nth33(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(33..34))
-    }
-    pub fn nth34(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(34..35))
-    }
-    pub fn nth35(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(35..36))
-    }
-    pub fn nth36(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(36..37))
-    }
-    pub fn nth37(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(37..38))
-    }
-    pub fn nth38(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(38..39))
-    }
-    pub fn nth39(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(39..40))
-    }
-    pub fn nth40(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(40..41))
-    }
-    pub fn nth41(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(41..42))
-    }
-    pub fn nth42(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(42..43))
-    }
-    pub fn nth43(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(43..44))
-    }
-    pub fn nth44(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(44..45))
-    }
-    pub fn nth45(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(45..46))
-    }
-    pub fn nth46(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(46..47))
-    }
-    pub fn nth47(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(47..48))
-    }
-    pub fn nth48(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(48..49))
-    }
-    pub fn nth49(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(49..50))
-    }
-    pub fn nth50(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(50..51))
-    }
-    pub fn nth51(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(51..52))
-    }
-    pub fn nth52(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(52..53))
-    }
-    pub fn nth53(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(53..54))
-    }
-    pub fn nth54(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(54..55))
-    }
-    pub fn nth55(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(55..56))
-    }
-    pub fn nth56(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(56..57))
-    }
-    pub fn nth57(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(57..58))
-    }
-    pub fn nth58(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(58..59))
-    }
-    pub fn nth59(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(59..60))
-    }
-    pub fn nth60(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(60..61))
-    }
-    pub fn nth61(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(61..62))
-    }
-    pub fn nth62(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(62..63))
-    }
-    pub fn nth63(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(63..64))
-    }
-    pub fn nth64(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(64..65))
-    }
-    pub fn nth65(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(65..66))
-    }
-    pub fn nth66(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(66..67))
-    }
-    pub fn nth67(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(67..68))
-    }
-    pub fn nth68(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(68..69))
-    }
-    pub fn nth69(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(69..70))
-    }
-    pub fn nth70(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(70..71))
-    }
-    pub fn nth71(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(71..72))
-    }
-    pub fn nth72(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(72..73))
-    }
-    pub fn nth73(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(73..74))
-    }
-    pub fn nth74(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(74..75))
-    }
-    pub fn nth75(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(75..76))
-    }
-    pub fn nth76(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(76..77))
-    }
-    pub fn nth77(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(77..78))
-    }
-    pub fn nth78(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(78..79))
-    }
-    pub fn nth79(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(79..80))
-    }
     pub fn raw_data(&self) -> molecule::bytes::Bytes {
         self.as_bytes()
     }
-    pub fn as_reader<'r>(&'r self) -> HeaderReader<'r> {
-        HeaderReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> Uint256Reader<'r> {
+        Uint256Reader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Header {
-    type Builder = HeaderBuilder;
-    const NAME: &'static str = "Header";
+impl molecule::prelude::Entity for Uint256 {
+    type Builder = Uint256Builder;
+    const NAME: &'static str = "Uint256";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Header(data)
+        Uint256(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -1652,10 +1053,10 @@ impl molecule::prelude::Entity for Header {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        HeaderReader::from_slice(slice).map(|reader| reader.to_entity())
+        Uint256Reader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        HeaderReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        Uint256Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
@@ -1694,60 +1095,12 @@ impl molecule::prelude::Entity for Header {
             self.nth29(),
             self.nth30(),
             self.nth31(),
-            self.nth32(),
-            self.nth33(),
-            self.nth34(),
-            self.nth35(),
-            self.nth36(),
-            self.nth37(),
-            self.nth38(),
-            self.nth39(),
-            self.nth40(),
-            self.nth41(),
-            self.nth42(),
-            self.nth43(),
-            self.nth44(),
-            self.nth45(),
-            self.nth46(),
-            self.nth47(),
-            self.nth48(),
-            self.nth49(),
-            self.nth50(),
-            self.nth51(),
-            self.nth52(),
-            self.nth53(),
-            self.nth54(),
-            self.nth55(),
-            self.nth56(),
-            self.nth57(),
-            self.nth58(),
-            self.nth59(),
-            self.nth60(),
-            self.nth61(),
-            self.nth62(),
-            self.nth63(),
-            self.nth64(),
-            self.nth65(),
-            self.nth66(),
-            self.nth67(),
-            self.nth68(),
-            self.nth69(),
-            self.nth70(),
-            self.nth71(),
-            self.nth72(),
-            self.nth73(),
-            self.nth74(),
-            self.nth75(),
-            self.nth76(),
-            self.nth77(),
-            self.nth78(),
-            self.nth79(),
         ])
     }
 }
 #[derive(Clone, Copy)]
-pub struct HeaderReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for HeaderReader<'r> {
+pub struct Uint256Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for Uint256Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -1756,22 +1109,22 @@ impl<'r> ::core::fmt::LowerHex for HeaderReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for HeaderReader<'r> {
+impl<'r> ::core::fmt::Debug for Uint256Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for HeaderReader<'r> {
+impl<'r> ::core::fmt::Display for Uint256Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         let raw_data = hex_string(&self.raw_data());
         write!(f, "{}(0x{})", Self::NAME, raw_data)
     }
 }
-impl<'r> HeaderReader<'r> {
-    pub const TOTAL_SIZE: usize = 80;
+impl<'r> Uint256Reader<'r> {
+    pub const TOTAL_SIZE: usize = 32;
     pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 80;
+    pub const ITEM_COUNT: usize = 32;
     pub fn nth0(&self) -> ByteReader<'r> {
         ByteReader::new_unchecked(&self.as_slice()[0..1])
     }
@@ -1868,165 +1221,21 @@ impl<'r> HeaderReader<'r> {
     pub fn nth31(&self) -> ByteReader<'r> {
         ByteReader::new_unchecked(&self.as_slice()[31..32])
     }
-    pub fn nth32(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[32..33])
-    }
-    pub fn nth33(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[33..34])
+    pub fn raw_data(&self) -> &'r [u8] {
+        self.as_slice()
     }
-    pub fn nth34(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[34..35])
+}
+impl<'r> molecule::prelude::Reader<'r> for Uint256Reader<'r> {
+    type Entity = Uint256;
+    const NAME: &'static str = "Uint256Reader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
-    pub fn nth35(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[35..36])
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        Uint256Reader(slice)
     }
-    pub fn nth36(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[36..37])
-    }
-    pub fn nth37(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[37..38])
-    }
-    pub fn nth38(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[38..39])
-    }
-    pub fn nth39(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[39..40])
-    }
-    pub fn nth40(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[40..41])
-    }
-    pub fn nth41(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[41..42])
-    }
-    pub fn nth42(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[42..43])
-    }
-    pub fn nth43(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[43..44])
-    }
-    pub fn nth44(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[44..45])
-    }
-    pub fn nth45(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[45..46])
-    }
-    pub fn nth46(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[46..47])
-    }
-    pub fn nth47(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[47..48])
-    }
-    pub fn nth48(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[48..49])
-    }
-    pub fn nth49(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[49..50])
-    }
-    pub fn nth50(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[50..51])
-    }
-    pub fn nth51(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[51..52])
-    }
-    pub fn nth52(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[52..53])
-    }
-    pub fn nth53(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[53..54])
-    }
-    pub fn nth54(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[54..55])
-    }
-    pub fn nth55(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[55..56])
-    }
-    pub fn nth56(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[56..57])
-    }
-    pub fn nth57(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[57..58])
-    }
-    pub fn nth58(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[58..59])
-    }
-    pub fn nth59(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[59..60])
-    }
-    pub fn nth60(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[60..61])
-    }
-    pub fn nth61(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[61..62])
-    }
-    pub fn nth62(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[62..63])
-    }
-    pub fn nth63(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[63..64])
-    }
-    pub fn nth64(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[64..65])
-    }
-    pub fn nth65(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[65..66])
-    }
-    pub fn nth66(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[66..67])
-    }
-    pub fn nth67(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[67..68])
-    }
-    pub fn nth68(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[68..69])
-    }
-    pub fn nth69(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[69..70])
-    }
-    pub fn nth70(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[70..71])
-    }
-    pub fn nth71(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[71..72])
-    }
-    pub fn nth72(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[72..73])
-    }
-    pub fn nth73(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[73..74])
-    }
-    pub fn nth74(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[74..75])
-    }
-    pub fn nth75(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[75..76])
-    }
-    pub fn nth76(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[76..77])
-    }
-    pub fn nth77(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[77..78])
-    }
-    pub fn nth78(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[78..79])
-    }
-    pub fn nth79(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[79..80])
-    }
-    pub fn raw_data(&self) -> &'r [u8] {
-        self.as_slice()
-    }
-}
-impl<'r> molecule::prelude::Reader<'r> for HeaderReader<'r> {
-    type Entity = Header;
-    const NAME: &'static str = "HeaderReader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
-    }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
-        HeaderReader(slice)
-    }
-    fn as_slice(&self) -> &'r [u8] {
-        self.0
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
     }
     fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
@@ -2037,63 +1246,15 @@ impl<'r> molecule::prelude::Reader<'r> for HeaderReader<'r> {
         Ok(())
     }
 }
-pub struct HeaderBuilder(pub(crate) [Byte; 80]);
-impl ::core::fmt::Debug for HeaderBuilder {
+pub struct Uint256Builder(pub(crate) [Byte; 32]);
+impl ::core::fmt::Debug for Uint256Builder {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:?})", Self::NAME, &self.0[..])
     }
 }
-impl ::core::default::Default for HeaderBuilder {
+impl ::core::default::Default for Uint256Builder {
     fn default() -> Self {
-        HeaderBuilder([
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
+        Uint256Builder([
             Byte::default(),
             Byte::default(),
             Byte::default(),
@@ -2129,11 +1290,11 @@ impl ::core::default::Default for HeaderBuilder {
         ])
     }
 }
-impl HeaderBuilder {
-    pub const TOTAL_SIZE: usize = 80;
+impl Uint256Builder {
+    pub const TOTAL_SIZE: usize = 32;
     pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 80;
-    pub fn set(mut self, v: [Byte; 80]) -> Self {
+    pub const ITEM_COUNT: usize = 32;
+    pub fn set(mut self, v: [Byte; 32]) -> Self {
         self.0 = v;
         self
     }
@@ -2265,298 +1426,5038 @@ impl HeaderBuilder {
         self.0[31] = v;
         self
     }
-    pub fn nth32(mut self, v: Byte) -> Self {
-        self.0[32] = v;
-        self
+}
+impl molecule::prelude::Builder for Uint256Builder {
+    type Entity = Uint256;
+    const NAME: &'static str = "Uint256Builder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
     }
-    pub fn nth33(mut self, v: Byte) -> Self {
-        self.0[33] = v;
-        self
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.0[0].as_slice())?;
+        writer.write_all(self.0[1].as_slice())?;
+        writer.write_all(self.0[2].as_slice())?;
+        writer.write_all(self.0[3].as_slice())?;
+        writer.write_all(self.0[4].as_slice())?;
+        writer.write_all(self.0[5].as_slice())?;
+        writer.write_all(self.0[6].as_slice())?;
+        writer.write_all(self.0[7].as_slice())?;
+        writer.write_all(self.0[8].as_slice())?;
+        writer.write_all(self.0[9].as_slice())?;
+        writer.write_all(self.0[10].as_slice())?;
+        writer.write_all(self.0[11].as_slice())?;
+        writer.write_all(self.0[12].as_slice())?;
+        writer.write_all(self.0[13].as_slice())?;
+        writer.write_all(self.0[14].as_slice())?;
+        writer.write_all(self.0[15].as_slice())?;
+        writer.write_all(self.0[16].as_slice())?;
+        writer.write_all(self.0[17].as_slice())?;
+        writer.write_all(self.0[18].as_slice())?;
+        writer.write_all(self.0[19].as_slice())?;
+        writer.write_all(self.0[20].as_slice())?;
+        writer.write_all(self.0[21].as_slice())?;
+        writer.write_all(self.0[22].as_slice())?;
+        writer.write_all(self.0[23].as_slice())?;
+        writer.write_all(self.0[24].as_slice())?;
+        writer.write_all(self.0[25].as_slice())?;
+        writer.write_all(self.0[26].as_slice())?;
+        writer.write_all(self.0[27].as_slice())?;
+        writer.write_all(self.0[28].as_slice())?;
+        writer.write_all(self.0[29].as_slice())?;
+        writer.write_all(self.0[30].as_slice())?;
+        writer.write_all(self.0[31].as_slice())?;
+        Ok(())
     }
-    pub fn nth34(mut self, v: Byte) -> Self {
-        self.0[34] = v;
-        self
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Uint256::new_unchecked(inner.into())
     }
-    pub fn nth35(mut self, v: Byte) -> Self {
-        self.0[35] = v;
-        self
+}
+#[derive(Clone)]
+pub struct Hash(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Hash {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
     }
-    pub fn nth36(mut self, v: Byte) -> Self {
-        self.0[36] = v;
-        self
+}
+impl ::core::fmt::Debug for Hash {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
     }
-    pub fn nth37(mut self, v: Byte) -> Self {
-        self.0[37] = v;
-        self
+}
+impl ::core::fmt::Display for Hash {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
     }
-    pub fn nth38(mut self, v: Byte) -> Self {
-        self.0[38] = v;
-        self
+}
+impl ::core::default::Default for Hash {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        Hash::new_unchecked(v)
     }
-    pub fn nth39(mut self, v: Byte) -> Self {
-        self.0[39] = v;
-        self
+}
+impl Hash {
+    const DEFAULT_VALUE: [u8; 32] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 32;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 32;
+    pub fn nth0(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
     }
-    pub fn nth40(mut self, v: Byte) -> Self {
-        self.0[40] = v;
-        self
+    pub fn nth1(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(1..2))
     }
-    pub fn nth41(mut self, v: Byte) -> Self {
-        self.0[41] = v;
-        self
+    pub fn nth2(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(2..3))
     }
-    pub fn nth42(mut self, v: Byte) -> Self {
-        self.0[42] = v;
-        self
+    pub fn nth3(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(3..4))
     }
-    pub fn nth43(mut self, v: Byte) -> Self {
-        self.0[43] = v;
-        self
+    pub fn nth4(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(4..5))
     }
-    pub fn nth44(mut self, v: Byte) -> Self {
-        self.0[44] = v;
-        self
+    pub fn nth5(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(5..6))
     }
-    pub fn nth45(mut self, v: Byte) -> Self {
-        self.0[45] = v;
-        self
+    pub fn nth6(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(6..7))
     }
-    pub fn nth46(mut self, v: Byte) -> Self {
-        self.0[46] = v;
-        self
+    pub fn nth7(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(7..8))
     }
-    pub fn nth47(mut self, v: Byte) -> Self {
-        self.0[47] = v;
-        self
+    pub fn nth8(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(8..9))
     }
-    pub fn nth48(mut self, v: Byte) -> Self {
-        self.0[48] = v;
-        self
+    pub fn nth9(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(9..10))
     }
-    pub fn nth49(mut self, v: Byte) -> Self {
-        self.0[49] = v;
-        self
+    pub fn nth10(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(10..11))
     }
-    pub fn nth50(mut self, v: Byte) -> Self {
-        self.0[50] = v;
-        self
+    pub fn nth11(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(11..12))
     }
-    pub fn nth51(mut self, v: Byte) -> Self {
-        self.0[51] = v;
-        self
+    pub fn nth12(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(12..13))
     }
-    pub fn nth52(mut self, v: Byte) -> Self {
-        self.0[52] = v;
-        self
+    pub fn nth13(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(13..14))
     }
-    pub fn nth53(mut self, v: Byte) -> Self {
-        self.0[53] = v;
-        self
+    pub fn nth14(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(14..15))
     }
-    pub fn nth54(mut self, v: Byte) -> Self {
-        self.0[54] = v;
-        self
+    pub fn nth15(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(15..16))
     }
-    pub fn nth55(mut self, v: Byte) -> Self {
-        self.0[55] = v;
-        self
+    pub fn nth16(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(16..17))
     }
-    pub fn nth56(mut self, v: Byte) -> Self {
-        self.0[56] = v;
-        self
+    pub fn nth17(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(17..18))
     }
-    pub fn nth57(mut self, v: Byte) -> Self {
-        self.0[57] = v;
-        self
+    pub fn nth18(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(18..19))
     }
-    pub fn nth58(mut self, v: Byte) -> Self {
-        self.0[58] = v;
-        self
+    pub fn nth19(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(19..20))
     }
-    pub fn nth59(mut self, v: Byte) -> Self {
-        self.0[59] = v;
-        self
+    pub fn nth20(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(20..21))
     }
-    pub fn nth60(mut self, v: Byte) -> Self {
-        self.0[60] = v;
-        self
+    pub fn nth21(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(21..22))
     }
-    pub fn nth61(mut self, v: Byte) -> Self {
-        self.0[61] = v;
-        self
+    pub fn nth22(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(22..23))
     }
-    pub fn nth62(mut self, v: Byte) -> Self {
-        self.0[62] = v;
-        self
+    pub fn nth23(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(23..24))
     }
-    pub fn nth63(mut self, v: Byte) -> Self {
-        self.0[63] = v;
-        self
+    pub fn nth24(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(24..25))
     }
-    pub fn nth64(mut self, v: Byte) -> Self {
-        self.0[64] = v;
-        self
+    pub fn nth25(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(25..26))
     }
-    pub fn nth65(mut self, v: Byte) -> Self {
-        self.0[65] = v;
-        self
+    pub fn nth26(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(26..27))
     }
-    pub fn nth66(mut self, v: Byte) -> Self {
-        self.0[66] = v;
-        self
+    pub fn nth27(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(27..28))
     }
-    pub fn nth67(mut self, v: Byte) -> Self {
-        self.0[67] = v;
-        self
+    pub fn nth28(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(28..29))
     }
-    pub fn nth68(mut self, v: Byte) -> Self {
-        self.0[68] = v;
-        self
+    pub fn nth29(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(29..30))
     }
-    pub fn nth69(mut self, v: Byte) -> Self {
-        self.0[69] = v;
-        self
+    pub fn nth30(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(30..31))
     }
-    pub fn nth70(mut self, v: Byte) -> Self {
-        self.0[70] = v;
-        self
+    pub fn nth31(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(31..32))
     }
-    pub fn nth71(mut self, v: Byte) -> Self {
-        self.0[71] = v;
-        self
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.as_bytes()
     }
-    pub fn nth72(mut self, v: Byte) -> Self {
-        self.0[72] = v;
-        self
+    pub fn as_reader<'r>(&'r self) -> HashReader<'r> {
+        HashReader::new_unchecked(self.as_slice())
     }
-    pub fn nth73(mut self, v: Byte) -> Self {
-        self.0[73] = v;
-        self
+}
+impl molecule::prelude::Entity for Hash {
+    type Builder = HashBuilder;
+    const NAME: &'static str = "Hash";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Hash(data)
     }
-    pub fn nth74(mut self, v: Byte) -> Self {
-        self.0[74] = v;
-        self
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
     }
-    pub fn nth75(mut self, v: Byte) -> Self {
-        self.0[75] = v;
-        self
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
     }
-    pub fn nth76(mut self, v: Byte) -> Self {
-        self.0[76] = v;
-        self
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        HashReader::from_slice(slice).map(|reader| reader.to_entity())
     }
-    pub fn nth77(mut self, v: Byte) -> Self {
-        self.0[77] = v;
-        self
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        HashReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
-    pub fn nth78(mut self, v: Byte) -> Self {
-        self.0[78] = v;
-        self
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
     }
-    pub fn nth79(mut self, v: Byte) -> Self {
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set([
+            self.nth0(),
+            self.nth1(),
+            self.nth2(),
+            self.nth3(),
+            self.nth4(),
+            self.nth5(),
+            self.nth6(),
+            self.nth7(),
+            self.nth8(),
+            self.nth9(),
+            self.nth10(),
+            self.nth11(),
+            self.nth12(),
+            self.nth13(),
+            self.nth14(),
+            self.nth15(),
+            self.nth16(),
+            self.nth17(),
+            self.nth18(),
+            self.nth19(),
+            self.nth20(),
+            self.nth21(),
+            self.nth22(),
+            self.nth23(),
+            self.nth24(),
+            self.nth25(),
+            self.nth26(),
+            self.nth27(),
+            self.nth28(),
+            self.nth29(),
+            self.nth30(),
+            self.nth31(),
+        ])
+    }
+}
+#[derive(Clone, Copy)]
+pub struct HashReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for HashReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for HashReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for HashReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl<'r> HashReader<'r> {
+    pub const TOTAL_SIZE: usize = 32;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 32;
+    pub fn nth0(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+    pub fn nth1(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[1..2])
+    }
+    pub fn nth2(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[2..3])
+    }
+    pub fn nth3(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[3..4])
+    }
+    pub fn nth4(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[4..5])
+    }
+    pub fn nth5(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[5..6])
+    }
+    pub fn nth6(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[6..7])
+    }
+    pub fn nth7(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[7..8])
+    }
+    pub fn nth8(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[8..9])
+    }
+    pub fn nth9(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[9..10])
+    }
+    pub fn nth10(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[10..11])
+    }
+    pub fn nth11(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[11..12])
+    }
+    pub fn nth12(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[12..13])
+    }
+    pub fn nth13(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[13..14])
+    }
+    pub fn nth14(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[14..15])
+    }
+    pub fn nth15(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[15..16])
+    }
+    pub fn nth16(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[16..17])
+    }
+    pub fn nth17(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[17..18])
+    }
+    pub fn nth18(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[18..19])
+    }
+    pub fn nth19(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[19..20])
+    }
+    pub fn nth20(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[20..21])
+    }
+    pub fn nth21(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[21..22])
+    }
+    pub fn nth22(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[22..23])
+    }
+    pub fn nth23(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[23..24])
+    }
+    pub fn nth24(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[24..25])
+    }
+    pub fn nth25(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[25..26])
+    }
+    pub fn nth26(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[26..27])
+    }
+    pub fn nth27(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[27..28])
+    }
+    pub fn nth28(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[28..29])
+    }
+    pub fn nth29(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[29..30])
+    }
+    pub fn nth30(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[30..31])
+    }
+    pub fn nth31(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[31..32])
+    }
+    pub fn raw_data(&self) -> &'r [u8] {
+        self.as_slice()
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for HashReader<'r> {
+    type Entity = Hash;
+    const NAME: &'static str = "HashReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        HashReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+pub struct HashBuilder(pub(crate) [Byte; 32]);
+impl ::core::fmt::Debug for HashBuilder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+    }
+}
+impl ::core::default::Default for HashBuilder {
+    fn default() -> Self {
+        HashBuilder([
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+        ])
+    }
+}
+impl HashBuilder {
+    pub const TOTAL_SIZE: usize = 32;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 32;
+    pub fn set(mut self, v: [Byte; 32]) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn nth0(mut self, v: Byte) -> Self {
+        self.0[0] = v;
+        self
+    }
+    pub fn nth1(mut self, v: Byte) -> Self {
+        self.0[1] = v;
+        self
+    }
+    pub fn nth2(mut self, v: Byte) -> Self {
+        self.0[2] = v;
+        self
+    }
+    pub fn nth3(mut self, v: Byte) -> Self {
+        self.0[3] = v;
+        self
+    }
+    pub fn nth4(mut self, v: Byte) -> Self {
+        self.0[4] = v;
+        self
+    }
+    pub fn nth5(mut self, v: Byte) -> Self {
+        self.0[5] = v;
+        self
+    }
+    pub fn nth6(mut self, v: Byte) -> Self {
+        self.0[6] = v;
+        self
+    }
+    pub fn nth7(mut self, v: Byte) -> Self {
+        self.0[7] = v;
+        self
+    }
+    pub fn nth8(mut self, v: Byte) -> Self {
+        self.0[8] = v;
+        self
+    }
+    pub fn nth9(mut self, v: Byte) -> Self {
+        self.0[9] = v;
+        self
+    }
+    pub fn nth10(mut self, v: Byte) -> Self {
+        self.0[10] = v;
+        self
+    }
+    pub fn nth11(mut self, v: Byte) -> Self {
+        self.0[11] = v;
+        self
+    }
+    pub fn nth12(mut self, v: Byte) -> Self {
+        self.0[12] = v;
+        self
+    }
+    pub fn nth13(mut self, v: Byte) -> Self {
+        self.0[13] = v;
+        self
+    }
+    pub fn nth14(mut self, v: Byte) -> Self {
+        self.0[14] = v;
+        self
+    }
+    pub fn nth15(mut self, v: Byte) -> Self {
+        self.0[15] = v;
+        self
+    }
+    pub fn nth16(mut self, v: Byte) -> Self {
+        self.0[16] = v;
+        self
+    }
+    pub fn nth17(mut self, v: Byte) -> Self {
+        self.0[17] = v;
+        self
+    }
+    pub fn nth18(mut self, v: Byte) -> Self {
+        self.0[18] = v;
+        self
+    }
+    pub fn nth19(mut self, v: Byte) -> Self {
+        self.0[19] = v;
+        self
+    }
+    pub fn nth20(mut self, v: Byte) -> Self {
+        self.0[20] = v;
+        self
+    }
+    pub fn nth21(mut self, v: Byte) -> Self {
+        self.0[21] = v;
+        self
+    }
+    pub fn nth22(mut self, v: Byte) -> Self {
+        self.0[22] = v;
+        self
+    }
+    pub fn nth23(mut self, v: Byte) -> Self {
+        self.0[23] = v;
+        self
+    }
+    pub fn nth24(mut self, v: Byte) -> Self {
+        self.0[24] = v;
+        self
+    }
+    pub fn nth25(mut self, v: Byte) -> Self {
+        self.0[25] = v;
+        self
+    }
+    pub fn nth26(mut self, v: Byte) -> Self {
+        self.0[26] = v;
+        self
+    }
+    pub fn nth27(mut self, v: Byte) -> Self {
+        self.0[27] = v;
+        self
+    }
+    pub fn nth28(mut self, v: Byte) -> Self {
+        self.0[28] = v;
+        self
+    }
+    pub fn nth29(mut self, v: Byte) -> Self {
+        self.0[29] = v;
+        self
+    }
+    pub fn nth30(mut self, v: Byte) -> Self {
+        self.0[30] = v;
+        self
+    }
+    pub fn nth31(mut self, v: Byte) -> Self {
+        self.0[31] = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for HashBuilder {
+    type Entity = Hash;
+    const NAME: &'static str = "HashBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.0[0].as_slice())?;
+        writer.write_all(self.0[1].as_slice())?;
+        writer.write_all(self.0[2].as_slice())?;
+        writer.write_all(self.0[3].as_slice())?;
+        writer.write_all(self.0[4].as_slice())?;
+        writer.write_all(self.0[5].as_slice())?;
+        writer.write_all(self.0[6].as_slice())?;
+        writer.write_all(self.0[7].as_slice())?;
+        writer.write_all(self.0[8].as_slice())?;
+        writer.write_all(self.0[9].as_slice())?;
+        writer.write_all(self.0[10].as_slice())?;
+        writer.write_all(self.0[11].as_slice())?;
+        writer.write_all(self.0[12].as_slice())?;
+        writer.write_all(self.0[13].as_slice())?;
+        writer.write_all(self.0[14].as_slice())?;
+        writer.write_all(self.0[15].as_slice())?;
+        writer.write_all(self.0[16].as_slice())?;
+        writer.write_all(self.0[17].as_slice())?;
+        writer.write_all(self.0[18].as_slice())?;
+        writer.write_all(self.0[19].as_slice())?;
+        writer.write_all(self.0[20].as_slice())?;
+        writer.write_all(self.0[21].as_slice())?;
+        writer.write_all(self.0[22].as_slice())?;
+        writer.write_all(self.0[23].as_slice())?;
+        writer.write_all(self.0[24].as_slice())?;
+        writer.write_all(self.0[25].as_slice())?;
+        writer.write_all(self.0[26].as_slice())?;
+        writer.write_all(self.0[27].as_slice())?;
+        writer.write_all(self.0[28].as_slice())?;
+        writer.write_all(self.0[29].as_slice())?;
+        writer.write_all(self.0[30].as_slice())?;
+        writer.write_all(self.0[31].as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Hash::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct HashVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for HashVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for HashVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for HashVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for HashVec {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        HashVec::new_unchecked(v)
+    }
+}
+impl HashVec {
+    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
+    pub const ITEM_SIZE: usize = 32;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<Hash> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> Hash {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        Hash::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn as_reader<'r>(&'r self) -> HashVecReader<'r> {
+        HashVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for HashVec {
+    type Builder = HashVecBuilder;
+    const NAME: &'static str = "HashVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        HashVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        HashVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        HashVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct HashVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for HashVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for HashVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for HashVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> HashVecReader<'r> {
+    pub const ITEM_SIZE: usize = 32;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<HashReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> HashReader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        HashReader::new_unchecked(&self.as_slice()[start..end])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for HashVecReader<'r> {
+    type Entity = HashVec;
+    const NAME: &'static str = "HashVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        HashVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct HashVecBuilder(pub(crate) Vec<Hash>);
+impl HashVecBuilder {
+    pub const ITEM_SIZE: usize = 32;
+    pub fn set(mut self, v: Vec<Hash>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: Hash) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = Hash>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: Hash) -> Option<Hash> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for HashVecBuilder {
+    type Entity = HashVec;
+    const NAME: &'static str = "HashVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        HashVec::new_unchecked(inner.into())
+    }
+}
+pub struct HashVecIterator(HashVec, usize, usize);
+impl ::core::iter::Iterator for HashVecIterator {
+    type Item = Hash;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for HashVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for HashVec {
+    type Item = Hash;
+    type IntoIter = HashVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        HashVecIterator(self, 0, len)
+    }
+}
+impl<'r> HashVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> HashVecReaderIterator<'t, 'r> {
+        HashVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct HashVecReaderIterator<'t, 'r>(&'t HashVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for HashVecReaderIterator<'t, 'r> {
+    type Item = HashReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for HashVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct Header(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Header {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Header {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Header {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl ::core::default::Default for Header {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        Header::new_unchecked(v)
+    }
+}
+impl Header {
+    const DEFAULT_VALUE: [u8; 80] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 80;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 80;
+    pub fn nth0(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
+    }
+    pub fn nth1(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(1..2))
+    }
+    pub fn nth2(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(2..3))
+    }
+    pub fn nth3(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(3..4))
+    }
+    pub fn nth4(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(4..5))
+    }
+    pub fn nth5(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(5..6))
+    }
+    pub fn nth6(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(6..7))
+    }
+    pub fn nth7(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(7..8))
+    }
+    pub fn nth8(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(8..9))
+    }
+    pub fn nth9(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(9..10))
+    }
+    pub fn nth10(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(10..11))
+    }
+    pub fn nth11(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(11..12))
+    }
+    pub fn nth12(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(12..13))
+    }
+    pub fn nth13(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(13..14))
+    }
+    pub fn nth14(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(14..15))
+    }
+    pub fn nth15(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(15..16))
+    }
+    pub fn nth16(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(16..17))
+    }
+    pub fn nth17(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(17..18))
+    }
+    pub fn nth18(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(18..19))
+    }
+    pub fn nth19(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(19..20))
+    }
+    pub fn nth20(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(20..21))
+    }
+    pub fn nth21(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(21..22))
+    }
+    pub fn nth22(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(22..23))
+    }
+    pub fn nth23(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(23..24))
+    }
+    pub fn nth24(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(24..25))
+    }
+    pub fn nth25(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(25..26))
+    }
+    pub fn nth26(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(26..27))
+    }
+    pub fn nth27(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(27..28))
+    }
+    pub fn nth28(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(28..29))
+    }
+    pub fn nth29(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(29..30))
+    }
+    pub fn nth30(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(30..31))
+    }
+    pub fn nth31(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(31..32))
+    }
+    pub fn nth32(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(32..33))
+    }
+    pub fn nth33(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(33..34))
+    }
+    pub fn nth34(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(34..35))
+    }
+    pub fn nth35(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(35..36))
+    }
+    pub fn nth36(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(36..37))
+    }
+    pub fn nth37(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(37..38))
+    }
+    pub fn nth38(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(38..39))
+    }
+    pub fn nth39(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(39..40))
+    }
+    pub fn nth40(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(40..41))
+    }
+    pub fn nth41(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(41..42))
+    }
+    pub fn nth42(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(42..43))
+    }
+    pub fn nth43(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(43..44))
+    }
+    pub fn nth44(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(44..45))
+    }
+    pub fn nth45(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(45..46))
+    }
+    pub fn nth46(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(46..47))
+    }
+    pub fn nth47(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(47..48))
+    }
+    pub fn nth48(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(48..49))
+    }
+    pub fn nth49(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(49..50))
+    }
+    pub fn nth50(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(50..51))
+    }
+    pub fn nth51(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(51..52))
+    }
+    pub fn nth52(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(52..53))
+    }
+    pub fn nth53(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(53..54))
+    }
+    pub fn nth54(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(54..55))
+    }
+    pub fn nth55(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(55..56))
+    }
+    pub fn nth56(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(56..57))
+    }
+    pub fn nth57(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(57..58))
+    }
+    pub fn nth58(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(58..59))
+    }
+    pub fn nth59(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(59..60))
+    }
+    pub fn nth60(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(60..61))
+    }
+    pub fn nth61(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(61..62))
+    }
+    pub fn nth62(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(62..63))
+    }
+    pub fn nth63(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(63..64))
+    }
+    pub fn nth64(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(64..65))
+    }
+    pub fn nth65(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(65..66))
+    }
+    pub fn nth66(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(66..67))
+    }
+    pub fn nth67(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(67..68))
+    }
+    pub fn nth68(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(68..69))
+    }
+    pub fn nth69(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(69..70))
+    }
+    pub fn nth70(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(70..71))
+    }
+    pub fn nth71(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(71..72))
+    }
+    pub fn nth72(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(72..73))
+    }
+    pub fn nth73(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(73..74))
+    }
+    pub fn nth74(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(74..75))
+    }
+    pub fn nth75(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(75..76))
+    }
+    pub fn nth76(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(76..77))
+    }
+    pub fn nth77(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(77..78))
+    }
+    pub fn nth78(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(78..79))
+    }
+    pub fn nth79(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(79..80))
+    }
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.as_bytes()
+    }
+    pub fn as_reader<'r>(&'r self) -> HeaderReader<'r> {
+        HeaderReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Header {
+    type Builder = HeaderBuilder;
+    const NAME: &'static str = "Header";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Header(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        HeaderReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        HeaderReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set([
+            self.nth0(),
+            self.nth1(),
+            self.nth2(),
+            self.nth3(),
+            self.nth4(),
+            self.nth5(),
+            self.nth6(),
+            self.nth7(),
+            self.nth8(),
+            self.nth9(),
+            self.nth10(),
+            self.nth11(),
+            self.nth12(),
+            self.nth13(),
+            self.nth14(),
+            self.nth15(),
+            self.nth16(),
+            self.nth17(),
+            self.nth18(),
+            self.nth19(),
+            self.nth20(),
+            self.nth21(),
+            self.nth22(),
+            self.nth23(),
+            self.nth24(),
+            self.nth25(),
+            self.nth26(),
+            self.nth27(),
+            self.nth28(),
+            self.nth29(),
+            self.nth30(),
+            self.nth31(),
+            self.nth32(),
+            self.nth33(),
+            self.nth34(),
+            self.nth35(),
+            self.nth36(),
+            self.nth37(),
+            self.nth38(),
+            self.nth39(),
+            self.nth40(),
+            self.nth41(),
+            self.nth42(),
+            self.nth43(),
+            self.nth44(),
+            self.nth45(),
+            self.nth46(),
+            self.nth47(),
+            self.nth48(),
+            self.nth49(),
+            self.nth50(),
+            self.nth51(),
+            self.nth52(),
+            self.nth53(),
+            self.nth54(),
+            self.nth55(),
+            self.nth56(),
+            self.nth57(),
+            self.nth58(),
+            self.nth59(),
+            self.nth60(),
+            self.nth61(),
+            self.nth62(),
+            self.nth63(),
+            self.nth64(),
+            self.nth65(),
+            self.nth66(),
+            self.nth67(),
+            self.nth68(),
+            self.nth69(),
+            self.nth70(),
+            self.nth71(),
+            self.nth72(),
+            self.nth73(),
+            self.nth74(),
+            self.nth75(),
+            self.nth76(),
+            self.nth77(),
+            self.nth78(),
+            self.nth79(),
+        ])
+    }
+}
+#[derive(Clone, Copy)]
+pub struct HeaderReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for HeaderReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for HeaderReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for HeaderReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl<'r> HeaderReader<'r> {
+    pub const TOTAL_SIZE: usize = 80;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 80;
+    pub fn nth0(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+    pub fn nth1(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[1..2])
+    }
+    pub fn nth2(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[2..3])
+    }
+    pub fn nth3(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[3..4])
+    }
+    pub fn nth4(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[4..5])
+    }
+    pub fn nth5(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[5..6])
+    }
+    pub fn nth6(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[6..7])
+    }
+    pub fn nth7(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[7..8])
+    }
+    pub fn nth8(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[8..9])
+    }
+    pub fn nth9(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[9..10])
+    }
+    pub fn nth10(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[10..11])
+    }
+    pub fn nth11(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[11..12])
+    }
+    pub fn nth12(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[12..13])
+    }
+    pub fn nth13(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[13..14])
+    }
+    pub fn nth14(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[14..15])
+    }
+    pub fn nth15(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[15..16])
+    }
+    pub fn nth16(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[16..17])
+    }
+    pub fn nth17(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[17..18])
+    }
+    pub fn nth18(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[18..19])
+    }
+    pub fn nth19(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[19..20])
+    }
+    pub fn nth20(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[20..21])
+    }
+    pub fn nth21(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[21..22])
+    }
+    pub fn nth22(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[22..23])
+    }
+    pub fn nth23(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[23..24])
+    }
+    pub fn nth24(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[24..25])
+    }
+    pub fn nth25(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[25..26])
+    }
+    pub fn nth26(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[26..27])
+    }
+    pub fn nth27(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[27..28])
+    }
+    pub fn nth28(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[28..29])
+    }
+    pub fn nth29(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[29..30])
+    }
+    pub fn nth30(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[30..31])
+    }
+    pub fn nth31(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[31..32])
+    }
+    pub fn nth32(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[32..33])
+    }
+    pub fn nth33(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[33..34])
+    }
+    pub fn nth34(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[34..35])
+    }
+    pub fn nth35(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[35..36])
+    }
+    pub fn nth36(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[36..37])
+    }
+    pub fn nth37(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[37..38])
+    }
+    pub fn nth38(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[38..39])
+    }
+    pub fn nth39(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[39..40])
+    }
+    pub fn nth40(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[40..41])
+    }
+    pub fn nth41(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[41..42])
+    }
+    pub fn nth42(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[42..43])
+    }
+    pub fn nth43(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[43..44])
+    }
+    pub fn nth44(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[44..45])
+    }
+    pub fn nth45(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[45..46])
+    }
+    pub fn nth46(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[46..47])
+    }
+    pub fn nth47(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[47..48])
+    }
+    pub fn nth48(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[48..49])
+    }
+    pub fn nth49(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[49..50])
+    }
+    pub fn nth50(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[50..51])
+    }
+    pub fn nth51(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[51..52])
+    }
+    pub fn nth52(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[52..53])
+    }
+    pub fn nth53(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[53..54])
+    }
+    pub fn nth54(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[54..55])
+    }
+    pub fn nth55(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[55..56])
+    }
+    pub fn nth56(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[56..57])
+    }
+    pub fn nth57(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[57..58])
+    }
+    pub fn nth58(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[58..59])
+    }
+    pub fn nth59(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[59..60])
+    }
+    pub fn nth60(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[60..61])
+    }
+    pub fn nth61(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[61..62])
+    }
+    pub fn nth62(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[62..63])
+    }
+    pub fn nth63(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[63..64])
+    }
+    pub fn nth64(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[64..65])
+    }
+    pub fn nth65(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[65..66])
+    }
+    pub fn nth66(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[66..67])
+    }
+    pub fn nth67(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[67..68])
+    }
+    pub fn nth68(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[68..69])
+    }
+    pub fn nth69(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[69..70])
+    }
+    pub fn nth70(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[70..71])
+    }
+    pub fn nth71(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[71..72])
+    }
+    pub fn nth72(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[72..73])
+    }
+    pub fn nth73(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[73..74])
+    }
+    pub fn nth74(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[74..75])
+    }
+    pub fn nth75(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[75..76])
+    }
+    pub fn nth76(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[76..77])
+    }
+    pub fn nth77(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[77..78])
+    }
+    pub fn nth78(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[78..79])
+    }
+    pub fn nth79(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[79..80])
+    }
+    pub fn raw_data(&self) -> &'r [u8] {
+        self.as_slice()
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for HeaderReader<'r> {
+    type Entity = Header;
+    const NAME: &'static str = "HeaderReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        HeaderReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+pub struct HeaderBuilder(pub(crate) [Byte; 80]);
+impl ::core::fmt::Debug for HeaderBuilder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+    }
+}
+impl ::core::default::Default for HeaderBuilder {
+    fn default() -> Self {
+        HeaderBuilder([
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+        ])
+    }
+}
+impl HeaderBuilder {
+    pub const TOTAL_SIZE: usize = 80;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 80;
+    pub fn set(mut self, v: [Byte; 80]) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn nth0(mut self, v: Byte) -> Self {
+        self.0[0] = v;
+        self
+    }
+    pub fn nth1(mut self, v: Byte) -> Self {
+        self.0[1] = v;
+        self
+    }
+    pub fn nth2(mut self, v: Byte) -> Self {
+        self.0[2] = v;
+        self
+    }
+    pub fn nth3(mut self, v: Byte) -> Self {
+        self.0[3] = v;
+        self
+    }
+    pub fn nth4(mut self, v: Byte) -> Self {
+        self.0[4] = v;
+        self
+    }
+    pub fn nth5(mut self, v: Byte) -> Self {
+        self.0[5] = v;
+        self
+    }
+    pub fn nth6(mut self, v: Byte) -> Self {
+        self.0[6] = v;
+        self
+    }
+    pub fn nth7(mut self, v: Byte) -> Self {
+        self.0[7] = v;
+        self
+    }
+    pub fn nth8(mut self, v: Byte) -> Self {
+        self.0[8] = v;
+        self
+    }
+    pub fn nth9(mut self, v: Byte) -> Self {
+        self.0[9] = v;
+        self
+    }
+    pub fn nth10(mut self, v: Byte) -> Self {
+        self.0[10] = v;
+        self
+    }
+    pub fn nth11(mut self, v: Byte) -> Self {
+        self.0[11] = v;
+        self
+    }
+    pub fn nth12(mut self, v: Byte) -> Self {
+        self.0[12] = v;
+        self
+    }
+    pub fn nth13(mut self, v: Byte) -> Self {
+        self.0[13] = v;
+        self
+    }
+    pub fn nth14(mut self, v: Byte) -> Self {
+        self.0[14] = v;
+        self
+    }
+    pub fn nth15(mut self, v: Byte) -> Self {
+        self.0[15] = v;
+        self
+    }
+    pub fn nth16(mut self, v: Byte) -> Self {
+        self.0[16] = v;
+        self
+    }
+    pub fn nth17(mut self, v: Byte) -> Self {
+        self.0[17] = v;
+        self
+    }
+    pub fn nth18(mut self, v: Byte) -> Self {
+        self.0[18] = v;
+        self
+    }
+    pub fn nth19(mut self, v: Byte) -> Self {
+        self.0[19] = v;
+        self
+    }
+    pub fn nth20(mut self, v: Byte) -> Self {
+        self.0[20] = v;
+        self
+    }
+    pub fn nth21(mut self, v: Byte) -> Self {
+        self.0[21] = v;
+        self
+    }
+    pub fn nth22(mut self, v: Byte) -> Self {
+        self.0[22] = v;
+        self
+    }
+    pub fn nth23(mut self, v: Byte) -> Self {
+        self.0[23] = v;
+        self
+    }
+    pub fn nth24(mut self, v: Byte) -> Self {
+        self.0[24] = v;
+        self
+    }
+    pub fn nth25(mut self, v: Byte) -> Self {
+        self.0[25] = v;
+        self
+    }
+    pub fn nth26(mut self, v: Byte) -> Self {
+        self.0[26] = v;
+        self
+    }
+    pub fn nth27(mut self, v: Byte) -> Self {
+        self.0[27] = v;
+        self
+    }
+    pub fn nth28(mut self, v: Byte) -> Self {
+        self.0[28] = v;
+        self
+    }
+    pub fn nth29(mut self, v: Byte) -> Self {
+        self.0[29] = v;
+        self
+    }
+    pub fn nth30(mut self, v: Byte) -> Self {
+        self.0[30] = v;
+        self
+    }
+    pub fn nth31(mut self, v: Byte) -> Self {
+        self.0[31] = v;
+        self
+    }
+    pub fn nth32(mut self, v: Byte) -> Self {
+        self.0[32] = v;
+        self
+    }
+    pub fn nth33(mut self, v: Byte) -> Self {
+        self.0[33] = v;
+        self
+    }
+    pub fn nth34(mut self, v: Byte) -> Self {
+        self.0[34] = v;
+        self
+    }
+    pub fn nth35(mut self, v: Byte) -> Self {
+        self.0[35] = v;
+        self
+    }
+    pub fn nth36(mut self, v: Byte) -> Self {
+        self.0[36] = v;
+        self
+    }
+    pub fn nth37(mut self, v: Byte) -> Self {
+        self.0[37] = v;
+        self
+    }
+    pub fn nth38(mut self, v: Byte) -> Self {
+        self.0[38] = v;
+        self
+    }
+    pub fn nth39(mut self, v: Byte) -> Self {
+        self.0[39] = v;
+        self
+    }
+    pub fn nth40(mut self, v: Byte) -> Self {
+        self.0[40] = v;
+        self
+    }
+    pub fn nth41(mut self, v: Byte) -> Self {
+        self.0[41] = v;
+        self
+    }
+    pub fn nth42(mut self, v: Byte) -> Self {
+        self.0[42] = v;
+        self
+    }
+    pub fn nth43(mut self, v: Byte) -> Self {
+        self.0[43] = v;
+        self
+    }
+    pub fn nth44(mut self, v: Byte) -> Self {
+        self.0[44] = v;
+        self
+    }
+    pub fn nth45(mut self, v: Byte) -> Self {
+        self.0[45] = v;
+        self
+    }
+    pub fn nth46(mut self, v: Byte) -> Self {
+        self.0[46] = v;
+        self
+    }
+    pub fn nth47(mut self, v: Byte) -> Self {
+        self.0[47] = v;
+        self
+    }
+    pub fn nth48(mut self, v: Byte) -> Self {
+        self.0[48] = v;
+        self
+    }
+    pub fn nth49(mut self, v: Byte) -> Self {
+        self.0[49] = v;
+        self
+    }
+    pub fn nth50(mut self, v: Byte) -> Self {
+        self.0[50] = v;
+        self
+    }
+    pub fn nth51(mut self, v: Byte) -> Self {
+        self.0[51] = v;
+        self
+    }
+    pub fn nth52(mut self, v: Byte) -> Self {
+        self.0[52] = v;
+        self
+    }
+    pub fn nth53(mut self, v: Byte) -> Self {
+        self.0[53] = v;
+        self
+    }
+    pub fn nth54(mut self, v: Byte) -> Self {
+        self.0[54] = v;
+        self
+    }
+    pub fn nth55(mut self, v: Byte) -> Self {
+        self.0[55] = v;
+        self
+    }
+    pub fn nth56(mut self, v: Byte) -> Self {
+        self.0[56] = v;
+        self
+    }
+    pub fn nth57(mut self, v: Byte) -> Self {
+        self.0[57] = v;
+        self
+    }
+    pub fn nth58(mut self, v: Byte) -> Self {
+        self.0[58] = v;
+        self
+    }
+    pub fn nth59(mut self, v: Byte) -> Self {
+        self.0[59] = v;
+        self
+    }
+    pub fn nth60(mut self, v: Byte) -> Self {
+        self.0[60] = v;
+        self
+    }
+    pub fn nth61(mut self, v: Byte) -> Self {
+        self.0[61] = v;
+        self
+    }
+    pub fn nth62(mut self, v: Byte) -> Self {
+        self.0[62] = v;
+        self
+    }
+    pub fn nth63(mut self, v: Byte) -> Self {
+        self.0[63] = v;
+        self
+    }
+    pub fn nth64(mut self, v: Byte) -> Self {
+        self.0[64] = v;
+        self
+    }
+    pub fn nth65(mut self, v: Byte) -> Self {
+        self.0[65] = v;
+        self
+    }
+    pub fn nth66(mut self, v: Byte) -> Self {
+        self.0[66] = v;
+        self
+    }
+    pub fn nth67(mut self, v: Byte) -> Self {
+        self.0[67] = v;
+        self
+    }
+    pub fn nth68(mut self, v: Byte) -> Self {
+        self.0[68] = v;
+        self
+    }
+    pub fn nth69(mut self, v: Byte) -> Self {
+        self.0[69] = v;
+        self
+    }
+    pub fn nth70(mut self, v: Byte) -> Self {
+        self.0[70] = v;
+        self
+    }
+    pub fn nth71(mut self, v: Byte) -> Self {
+        self.0[71] = v;
+        self
+    }
+    pub fn nth72(mut self, v: Byte) -> Self {
+        self.0[72] = v;
+        self
+    }
+    pub fn nth73(mut self, v: Byte) -> Self {
+        self.0[73] = v;
+        self
+    }
+    pub fn nth74(mut self, v: Byte) -> Self {
+        self.0[74] = v;
+        self
+    }
+    pub fn nth75(mut self, v: Byte) -> Self {
+        self.0[75] = v;
+        self
+    }
+    pub fn nth76(mut self, v: Byte) -> Self {
+        self.0[76] = v;
+        self
+    }
+    pub fn nth77(mut self, v: Byte) -> Self {
+        self.0[77] = v;
+        self
+    }
+    pub fn nth78(mut self, v: Byte) -> Self {
+        self.0[78] = v;
+        self
+    }
+    pub fn nth79(mut self, v: Byte) -> Self {
         self.0[79] = v;
         self
     }
 }
-impl molecule::prelude::Builder for HeaderBuilder {
-    type Entity = Header;
-    const NAME: &'static str = "HeaderBuilder";
-    fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+impl molecule::prelude::Builder for HeaderBuilder {
+    type Entity = Header;
+    const NAME: &'static str = "HeaderBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.0[0].as_slice())?;
+        writer.write_all(self.0[1].as_slice())?;
+        writer.write_all(self.0[2].as_slice())?;
+        writer.write_all(self.0[3].as_slice())?;
+        writer.write_all(self.0[4].as_slice())?;
+        writer.write_all(self.0[5].as_slice())?;
+        writer.write_all(self.0[6].as_slice())?;
+        writer.write_all(self.0[7].as_slice())?;
+        writer.write_all(self.0[8].as_slice())?;
+        writer.write_all(self.0[9].as_slice())?;
+        writer.write_all(self.0[10].as_slice())?;
+        writer.write_all(self.0[11].as_slice())?;
+        writer.write_all(self.0[12].as_slice())?;
+        writer.write_all(self.0[13].as_slice())?;
+        writer.write_all(self.0[14].as_slice())?;
+        writer.write_all(self.0[15].as_slice())?;
+        writer.write_all(self.0[16].as_slice())?;
+        writer.write_all(self.0[17].as_slice())?;
+        writer.write_all(self.0[18].as_slice())?;
+        writer.write_all(self.0[19].as_slice())?;
+        writer.write_all(self.0[20].as_slice())?;
+        writer.write_all(self.0[21].as_slice())?;
+        writer.write_all(self.0[22].as_slice())?;
+        writer.write_all(self.0[23].as_slice())?;
+        writer.write_all(self.0[24].as_slice())?;
+        writer.write_all(self.0[25].as_slice())?;
+        writer.write_all(self.0[26].as_slice())?;
+        writer.write_all(self.0[27].as_slice())?;
+        writer.write_all(self.0[28].as_slice())?;
+        writer.write_all(self.0[29].as_slice())?;
+        writer.write_all(self.0[30].as_slice())?;
+        writer.write_all(self.0[31].as_slice())?;
+        writer.write_all(self.0[32].as_slice())?;
+        writer.write_all(self.0[33].as_slice())?;
+        writer.write_all(self.0[34].as_slice())?;
+        writer.write_all(self.0[35].as_slice())?;
+        writer.write_all(self.0[36].as_slice())?;
+        writer.write_all(self.0[37].as_slice())?;
+        writer.write_all(self.0[38].as_slice())?;
+        writer.write_all(self.0[39].as_slice())?;
+        writer.write_all(self.0[40].as_slice())?;
+        writer.write_all(self.0[41].as_slice())?;
+        writer.write_all(self.0[42].as_slice())?;
+        writer.write_all(self.0[43].as_slice())?;
+        writer.write_all(self.0[44].as_slice())?;
+        writer.write_all(self.0[45].as_slice())?;
+        writer.write_all(self.0[46].as_slice())?;
+        writer.write_all(self.0[47].as_slice())?;
+        writer.write_all(self.0[48].as_slice())?;
+        writer.write_all(self.0[49].as_slice())?;
+        writer.write_all(self.0[50].as_slice())?;
+        writer.write_all(self.0[51].as_slice())?;
+        writer.write_all(self.0[52].as_slice())?;
+        writer.write_all(self.0[53].as_slice())?;
+        writer.write_all(self.0[54].as_slice())?;
+        writer.write_all(self.0[55].as_slice())?;
+        writer.write_all(self.0[56].as_slice())?;
+        writer.write_all(self.0[57].as_slice())?;
+        writer.write_all(self.0[58].as_slice())?;
+        writer.write_all(self.0[59].as_slice())?;
+        writer.write_all(self.0[60].as_slice())?;
+        writer.write_all(self.0[61].as_slice())?;
+        writer.write_all(self.0[62].as_slice())?;
+        writer.write_all(self.0[63].as_slice())?;
+        writer.write_all(self.0[64].as_slice())?;
+        writer.write_all(self.0[65].as_slice())?;
+        writer.write_all(self.0[66].as_slice())?;
+        writer.write_all(self.0[67].as_slice())?;
+        writer.write_all(self.0[68].as_slice())?;
+        writer.write_all(self.0[69].as_slice())?;
+        writer.write_all(self.0[70].as_slice())?;
+        writer.write_all(self.0[71].as_slice())?;
+        writer.write_all(self.0[72].as_slice())?;
+        writer.write_all(self.0[73].as_slice())?;
+        writer.write_all(self.0[74].as_slice())?;
+        writer.write_all(self.0[75].as_slice())?;
+        writer.write_all(self.0[76].as_slice())?;
+        writer.write_all(self.0[77].as_slice())?;
+        writer.write_all(self.0[78].as_slice())?;
+        writer.write_all(self.0[79].as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Header::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct HeaderVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for HeaderVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for HeaderVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for HeaderVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for HeaderVec {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        HeaderVec::new_unchecked(v)
+    }
+}
+impl HeaderVec {
+    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
+    pub const ITEM_SIZE: usize = 80;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<Header> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> Header {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        Header::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn as_reader<'r>(&'r self) -> HeaderVecReader<'r> {
+        HeaderVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for HeaderVec {
+    type Builder = HeaderVecBuilder;
+    const NAME: &'static str = "HeaderVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        HeaderVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        HeaderVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        HeaderVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct HeaderVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for HeaderVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for HeaderVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for HeaderVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> HeaderVecReader<'r> {
+    pub const ITEM_SIZE: usize = 80;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<HeaderReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> HeaderReader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        HeaderReader::new_unchecked(&self.as_slice()[start..end])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for HeaderVecReader<'r> {
+    type Entity = HeaderVec;
+    const NAME: &'static str = "HeaderVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        HeaderVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct HeaderVecBuilder(pub(crate) Vec<Header>);
+impl HeaderVecBuilder {
+    pub const ITEM_SIZE: usize = 80;
+    pub fn set(mut self, v: Vec<Header>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: Header) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = Header>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: Header) -> Option<Header> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for HeaderVecBuilder {
+    type Entity = HeaderVec;
+    const NAME: &'static str = "HeaderVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        HeaderVec::new_unchecked(inner.into())
+    }
+}
+pub struct HeaderVecIterator(HeaderVec, usize, usize);
+impl ::core::iter::Iterator for HeaderVecIterator {
+    type Item = Header;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for HeaderVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for HeaderVec {
+    type Item = Header;
+    type IntoIter = HeaderVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        HeaderVecIterator(self, 0, len)
+    }
+}
+impl<'r> HeaderVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> HeaderVecReaderIterator<'t, 'r> {
+        HeaderVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct HeaderVecReaderIterator<'t, 'r>(&'t HeaderVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for HeaderVecReaderIterator<'t, 'r> {
+    type Item = HeaderReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for HeaderVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct Bytes(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Bytes {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Bytes {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Bytes {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl ::core::default::Default for Bytes {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        Bytes::new_unchecked(v)
+    }
+}
+impl Bytes {
+    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
+    pub const ITEM_SIZE: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<Byte> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> Byte {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.0.slice(molecule::NUMBER_SIZE..)
+    }
+    pub fn as_reader<'r>(&'r self) -> BytesReader<'r> {
+        BytesReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Bytes {
+    type Builder = BytesBuilder;
+    const NAME: &'static str = "Bytes";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Bytes(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BytesReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BytesReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct BytesReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BytesReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for BytesReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for BytesReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl<'r> BytesReader<'r> {
+    pub const ITEM_SIZE: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<ByteReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> ByteReader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn raw_data(&self) -> &'r [u8] {
+        &self.as_slice()[molecule::NUMBER_SIZE..]
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for BytesReader<'r> {
+    type Entity = Bytes;
+    const NAME: &'static str = "BytesReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        BytesReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct BytesBuilder(pub(crate) Vec<Byte>);
+impl BytesBuilder {
+    pub const ITEM_SIZE: usize = 1;
+    pub fn set(mut self, v: Vec<Byte>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: Byte) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = Byte>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: Byte) -> Option<Byte> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for BytesBuilder {
+    type Entity = Bytes;
+    const NAME: &'static str = "BytesBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Bytes::new_unchecked(inner.into())
+    }
+}
+pub struct BytesIterator(Bytes, usize, usize);
+impl ::core::iter::Iterator for BytesIterator {
+    type Item = Byte;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for BytesIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for Bytes {
+    type Item = Byte;
+    type IntoIter = BytesIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        BytesIterator(self, 0, len)
+    }
+}
+#[derive(Clone)]
+pub struct HeaderDigest(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for HeaderDigest {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for HeaderDigest {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for HeaderDigest {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "min_height", self.min_height())?;
+        write!(f, ", {}: {}", "max_height", self.max_height())?;
+        write!(
+            f,
+            ", {}: {}",
+            "partial_chain_work",
+            self.partial_chain_work()
+        )?;
+        write!(f, ", {}: {}", "children_hash", self.children_hash())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for HeaderDigest {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        HeaderDigest::new_unchecked(v)
+    }
+}
+impl HeaderDigest {
+    const DEFAULT_VALUE: [u8; 72] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 72;
+    pub const FIELD_SIZES: [usize; 4] = [4, 4, 32, 32];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn min_height(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(0..4))
+    }
+    pub fn max_height(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(4..8))
+    }
+    pub fn partial_chain_work(&self) -> Uint256 {
+        Uint256::new_unchecked(self.0.slice(8..40))
+    }
+    pub fn children_hash(&self) -> Hash {
+        Hash::new_unchecked(self.0.slice(40..72))
+    }
+    pub fn as_reader<'r>(&'r self) -> HeaderDigestReader<'r> {
+        HeaderDigestReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for HeaderDigest {
+    type Builder = HeaderDigestBuilder;
+    const NAME: &'static str = "HeaderDigest";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        HeaderDigest(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        HeaderDigestReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        HeaderDigestReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .min_height(self.min_height())
+            .max_height(self.max_height())
+            .partial_chain_work(self.partial_chain_work())
+            .children_hash(self.children_hash())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct HeaderDigestReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for HeaderDigestReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for HeaderDigestReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for HeaderDigestReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "min_height", self.min_height())?;
+        write!(f, ", {}: {}", "max_height", self.max_height())?;
+        write!(
+            f,
+            ", {}: {}",
+            "partial_chain_work",
+            self.partial_chain_work()
+        )?;
+        write!(f, ", {}: {}", "children_hash", self.children_hash())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> HeaderDigestReader<'r> {
+    pub const TOTAL_SIZE: usize = 72;
+    pub const FIELD_SIZES: [usize; 4] = [4, 4, 32, 32];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn min_height(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[0..4])
+    }
+    pub fn max_height(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[4..8])
+    }
+    pub fn partial_chain_work(&self) -> Uint256Reader<'r> {
+        Uint256Reader::new_unchecked(&self.as_slice()[8..40])
+    }
+    pub fn children_hash(&self) -> HashReader<'r> {
+        HashReader::new_unchecked(&self.as_slice()[40..72])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for HeaderDigestReader<'r> {
+    type Entity = HeaderDigest;
+    const NAME: &'static str = "HeaderDigestReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        HeaderDigestReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct HeaderDigestBuilder {
+    pub(crate) min_height: Uint32,
+    pub(crate) max_height: Uint32,
+    pub(crate) partial_chain_work: Uint256,
+    pub(crate) children_hash: Hash,
+}
+impl HeaderDigestBuilder {
+    pub const TOTAL_SIZE: usize = 72;
+    pub const FIELD_SIZES: [usize; 4] = [4, 4, 32, 32];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn min_height(mut self, v: Uint32) -> Self {
+        self.min_height = v;
+        self
+    }
+    pub fn max_height(mut self, v: Uint32) -> Self {
+        self.max_height = v;
+        self
+    }
+    pub fn partial_chain_work(mut self, v: Uint256) -> Self {
+        self.partial_chain_work = v;
+        self
+    }
+    pub fn children_hash(mut self, v: Hash) -> Self {
+        self.children_hash = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for HeaderDigestBuilder {
+    type Entity = HeaderDigest;
+    const NAME: &'static str = "HeaderDigestBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.min_height.as_slice())?;
+        writer.write_all(self.max_height.as_slice())?;
+        writer.write_all(self.partial_chain_work.as_slice())?;
+        writer.write_all(self.children_hash.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        HeaderDigest::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct MmrProof(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for MmrProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for MmrProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for MmrProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for MmrProof {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        MmrProof::new_unchecked(v)
+    }
+}
+impl MmrProof {
+    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
+    pub const ITEM_SIZE: usize = 72;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<HeaderDigest> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> HeaderDigest {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        HeaderDigest::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn as_reader<'r>(&'r self) -> MmrProofReader<'r> {
+        MmrProofReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for MmrProof {
+    type Builder = MmrProofBuilder;
+    const NAME: &'static str = "MmrProof";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        MmrProof(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        MmrProofReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        MmrProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct MmrProofReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for MmrProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for MmrProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for MmrProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> MmrProofReader<'r> {
+    pub const ITEM_SIZE: usize = 72;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<HeaderDigestReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> HeaderDigestReader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        HeaderDigestReader::new_unchecked(&self.as_slice()[start..end])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for MmrProofReader<'r> {
+    type Entity = MmrProof;
+    const NAME: &'static str = "MmrProofReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        MmrProofReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct MmrProofBuilder(pub(crate) Vec<HeaderDigest>);
+impl MmrProofBuilder {
+    pub const ITEM_SIZE: usize = 72;
+    pub fn set(mut self, v: Vec<HeaderDigest>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: HeaderDigest) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = HeaderDigest>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: HeaderDigest) -> Option<HeaderDigest> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for MmrProofBuilder {
+    type Entity = MmrProof;
+    const NAME: &'static str = "MmrProofBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        MmrProof::new_unchecked(inner.into())
+    }
+}
+pub struct MmrProofIterator(MmrProof, usize, usize);
+impl ::core::iter::Iterator for MmrProofIterator {
+    type Item = HeaderDigest;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for MmrProofIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for MmrProof {
+    type Item = HeaderDigest;
+    type IntoIter = MmrProofIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        MmrProofIterator(self, 0, len)
+    }
+}
+impl<'r> MmrProofReader<'r> {
+    pub fn iter<'t>(&'t self) -> MmrProofReaderIterator<'t, 'r> {
+        MmrProofReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct MmrProofReaderIterator<'t, 'r>(&'t MmrProofReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for MmrProofReaderIterator<'t, 'r> {
+    type Item = HeaderDigestReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for MmrProofReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct CoinbaseProof(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for CoinbaseProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for CoinbaseProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for CoinbaseProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "transaction", self.transaction())?;
+        write!(f, ", {}: {}", "merkle_branch", self.merkle_branch())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for CoinbaseProof {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        CoinbaseProof::new_unchecked(v)
+    }
+}
+impl CoinbaseProof {
+    const DEFAULT_VALUE: [u8; 20] = [
+        20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn transaction(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn merkle_branch(&self) -> HashVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            HashVec::new_unchecked(self.0.slice(start..end))
+        } else {
+            HashVec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> CoinbaseProofReader<'r> {
+        CoinbaseProofReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for CoinbaseProof {
+    type Builder = CoinbaseProofBuilder;
+    const NAME: &'static str = "CoinbaseProof";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        CoinbaseProof(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CoinbaseProofReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CoinbaseProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .transaction(self.transaction())
+            .merkle_branch(self.merkle_branch())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct CoinbaseProofReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for CoinbaseProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for CoinbaseProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for CoinbaseProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "transaction", self.transaction())?;
+        write!(f, ", {}: {}", "merkle_branch", self.merkle_branch())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> CoinbaseProofReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn transaction(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn merkle_branch(&self) -> HashVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            HashVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            HashVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for CoinbaseProofReader<'r> {
+    type Entity = CoinbaseProof;
+    const NAME: &'static str = "CoinbaseProofReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        CoinbaseProofReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        BytesReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        HashVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct CoinbaseProofBuilder {
+    pub(crate) transaction: Bytes,
+    pub(crate) merkle_branch: HashVec,
+}
+impl CoinbaseProofBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn transaction(mut self, v: Bytes) -> Self {
+        self.transaction = v;
+        self
+    }
+    pub fn merkle_branch(mut self, v: HashVec) -> Self {
+        self.merkle_branch = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for CoinbaseProofBuilder {
+    type Entity = CoinbaseProof;
+    const NAME: &'static str = "CoinbaseProofBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.transaction.as_slice().len()
+            + self.merkle_branch.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.transaction.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.merkle_branch.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.transaction.as_slice())?;
+        writer.write_all(self.merkle_branch.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        CoinbaseProof::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct CoinbaseProofVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for CoinbaseProofVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for CoinbaseProofVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for CoinbaseProofVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for CoinbaseProofVec {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        CoinbaseProofVec::new_unchecked(v)
+    }
+}
+impl CoinbaseProofVec {
+    const DEFAULT_VALUE: [u8; 4] = [4, 0, 0, 0];
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<CoinbaseProof> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> CoinbaseProof {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            CoinbaseProof::new_unchecked(self.0.slice(start..))
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            CoinbaseProof::new_unchecked(self.0.slice(start..end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> CoinbaseProofVecReader<'r> {
+        CoinbaseProofVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for CoinbaseProofVec {
+    type Builder = CoinbaseProofVecBuilder;
+    const NAME: &'static str = "CoinbaseProofVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        CoinbaseProofVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CoinbaseProofVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CoinbaseProofVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct CoinbaseProofVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for CoinbaseProofVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for CoinbaseProofVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for CoinbaseProofVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> CoinbaseProofVecReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<CoinbaseProofReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> CoinbaseProofReader<'r> {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            CoinbaseProofReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            CoinbaseProofReader::new_unchecked(&self.as_slice()[start..end])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for CoinbaseProofVecReader<'r> {
+    type Entity = CoinbaseProofVec;
+    const NAME: &'static str = "CoinbaseProofVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        CoinbaseProofVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            CoinbaseProofReader::verify(&slice[start..end], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct CoinbaseProofVecBuilder(pub(crate) Vec<CoinbaseProof>);
+impl CoinbaseProofVecBuilder {
+    pub fn set(mut self, v: Vec<CoinbaseProof>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: CoinbaseProof) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = CoinbaseProof>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: CoinbaseProof) -> Option<CoinbaseProof> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for CoinbaseProofVecBuilder {
+    type Entity = CoinbaseProofVec;
+    const NAME: &'static str = "CoinbaseProofVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        CoinbaseProofVec::new_unchecked(inner.into())
+    }
+}
+pub struct CoinbaseProofVecIterator(CoinbaseProofVec, usize, usize);
+impl ::core::iter::Iterator for CoinbaseProofVecIterator {
+    type Item = CoinbaseProof;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for CoinbaseProofVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for CoinbaseProofVec {
+    type Item = CoinbaseProof;
+    type IntoIter = CoinbaseProofVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        CoinbaseProofVecIterator(self, 0, len)
+    }
+}
+impl<'r> CoinbaseProofVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> CoinbaseProofVecReaderIterator<'t, 'r> {
+        CoinbaseProofVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct CoinbaseProofVecReaderIterator<'t, 'r>(&'t CoinbaseProofVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for CoinbaseProofVecReaderIterator<'t, 'r> {
+    type Item = CoinbaseProofReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for CoinbaseProofVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct SpvInfo(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SpvInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SpvInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SpvInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "tip_client_id", self.tip_client_id())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for SpvInfo {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        SpvInfo::new_unchecked(v)
+    }
+}
+impl SpvInfo {
+    const DEFAULT_VALUE: [u8; 1] = [0];
+    pub const TOTAL_SIZE: usize = 1;
+    pub const FIELD_SIZES: [usize; 1] = [1];
+    pub const FIELD_COUNT: usize = 1;
+    pub fn tip_client_id(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
+    }
+    pub fn as_reader<'r>(&'r self) -> SpvInfoReader<'r> {
+        SpvInfoReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SpvInfo {
+    type Builder = SpvInfoBuilder;
+    const NAME: &'static str = "SpvInfo";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SpvInfo(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SpvInfoReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SpvInfoReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().tip_client_id(self.tip_client_id())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SpvInfoReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SpvInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SpvInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SpvInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "tip_client_id", self.tip_client_id())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> SpvInfoReader<'r> {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const FIELD_SIZES: [usize; 1] = [1];
+    pub const FIELD_COUNT: usize = 1;
+    pub fn tip_client_id(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SpvInfoReader<'r> {
+    type Entity = SpvInfo;
+    const NAME: &'static str = "SpvInfoReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SpvInfoReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SpvInfoBuilder {
+    pub(crate) tip_client_id: Byte,
+}
+impl SpvInfoBuilder {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const FIELD_SIZES: [usize; 1] = [1];
+    pub const FIELD_COUNT: usize = 1;
+    pub fn tip_client_id(mut self, v: Byte) -> Self {
+        self.tip_client_id = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SpvInfoBuilder {
+    type Entity = SpvInfo;
+    const NAME: &'static str = "SpvInfoBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.tip_client_id.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SpvInfo::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct TargetAdjustInfo(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for TargetAdjustInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for TargetAdjustInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for TargetAdjustInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "start_time", self.start_time())?;
+        write!(
+            f,
+            ", {}: {}",
+            "next_compact_target",
+            self.next_compact_target()
+        )?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for TargetAdjustInfo {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        TargetAdjustInfo::new_unchecked(v)
+    }
+}
+impl TargetAdjustInfo {
+    const DEFAULT_VALUE: [u8; 8] = [0, 0, 0, 0, 0, 0, 0, 0];
+    pub const TOTAL_SIZE: usize = 8;
+    pub const FIELD_SIZES: [usize; 2] = [4, 4];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn start_time(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(0..4))
+    }
+    pub fn next_compact_target(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(4..8))
+    }
+    pub fn as_reader<'r>(&'r self) -> TargetAdjustInfoReader<'r> {
+        TargetAdjustInfoReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for TargetAdjustInfo {
+    type Builder = TargetAdjustInfoBuilder;
+    const NAME: &'static str = "TargetAdjustInfo";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        TargetAdjustInfo(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TargetAdjustInfoReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TargetAdjustInfoReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .start_time(self.start_time())
+            .next_compact_target(self.next_compact_target())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct TargetAdjustInfoReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TargetAdjustInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for TargetAdjustInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for TargetAdjustInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "start_time", self.start_time())?;
+        write!(
+            f,
+            ", {}: {}",
+            "next_compact_target",
+            self.next_compact_target()
+        )?;
+        write!(f, " }}")
+    }
+}
+impl<'r> TargetAdjustInfoReader<'r> {
+    pub const TOTAL_SIZE: usize = 8;
+    pub const FIELD_SIZES: [usize; 2] = [4, 4];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn start_time(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[0..4])
+    }
+    pub fn next_compact_target(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[4..8])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for TargetAdjustInfoReader<'r> {
+    type Entity = TargetAdjustInfo;
+    const NAME: &'static str = "TargetAdjustInfoReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        TargetAdjustInfoReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct TargetAdjustInfoBuilder {
+    pub(crate) start_time: Uint32,
+    pub(crate) next_compact_target: Uint32,
+}
+impl TargetAdjustInfoBuilder {
+    pub const TOTAL_SIZE: usize = 8;
+    pub const FIELD_SIZES: [usize; 2] = [4, 4];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn start_time(mut self, v: Uint32) -> Self {
+        self.start_time = v;
+        self
+    }
+    pub fn next_compact_target(mut self, v: Uint32) -> Self {
+        self.next_compact_target = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for TargetAdjustInfoBuilder {
+    type Entity = TargetAdjustInfo;
+    const NAME: &'static str = "TargetAdjustInfoBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.start_time.as_slice())?;
+        writer.write_all(self.next_compact_target.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        TargetAdjustInfo::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct RecentTimestamps(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for RecentTimestamps {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for RecentTimestamps {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for RecentTimestamps {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        write!(f, "{}", self.nth0())?;
+        write!(f, ", {}", self.nth1())?;
+        write!(f, ", {}", self.nth2())?;
+        write!(f, ", {}", self.nth3())?;
+        write!(f, ", {}", self.nth4())?;
+        write!(f, ", {}", self.nth5())?;
+        write!(f, ", {}", self.nth6())?;
+        write!(f, ", {}", self.nth7())?;
+        write!(f, ", {}", self.nth8())?;
+        write!(f, ", {}", self.nth9())?;
+        write!(f, ", {}", self.nth10())?;
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for RecentTimestamps {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        RecentTimestamps::new_unchecked(v)
+    }
+}
+impl RecentTimestamps {
+    const DEFAULT_VALUE: [u8; 44] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 44;
+    pub const ITEM_SIZE: usize = 4;
+    pub const ITEM_COUNT: usize = 11;
+    pub fn nth0(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(0..4))
+    }
+    pub fn nth1(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(4..8))
+    }
+    pub fn nth2(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(8..12))
+    }
+    pub fn nth3(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(12..16))
+    }
+    pub fn nth4(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(16..20))
+    }
+    pub fn nth5(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(20..24))
+    }
+    pub fn nth6(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(24..28))
+    }
+    pub fn nth7(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(28..32))
+    }
+    pub fn nth8(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(32..36))
+    }
+    pub fn nth9(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(36..40))
+    }
+    pub fn nth10(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(40..44))
+    }
+    pub fn as_reader<'r>(&'r self) -> RecentTimestampsReader<'r> {
+        RecentTimestampsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for RecentTimestamps {
+    type Builder = RecentTimestampsBuilder;
+    const NAME: &'static str = "RecentTimestamps";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        RecentTimestamps(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RecentTimestampsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RecentTimestampsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set([
+            self.nth0(),
+            self.nth1(),
+            self.nth2(),
+            self.nth3(),
+            self.nth4(),
+            self.nth5(),
+            self.nth6(),
+            self.nth7(),
+            self.nth8(),
+            self.nth9(),
+            self.nth10(),
+        ])
+    }
+}
+#[derive(Clone, Copy)]
+pub struct RecentTimestampsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RecentTimestampsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for RecentTimestampsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for RecentTimestampsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        write!(f, "{}", self.nth0())?;
+        write!(f, ", {}", self.nth1())?;
+        write!(f, ", {}", self.nth2())?;
+        write!(f, ", {}", self.nth3())?;
+        write!(f, ", {}", self.nth4())?;
+        write!(f, ", {}", self.nth5())?;
+        write!(f, ", {}", self.nth6())?;
+        write!(f, ", {}", self.nth7())?;
+        write!(f, ", {}", self.nth8())?;
+        write!(f, ", {}", self.nth9())?;
+        write!(f, ", {}", self.nth10())?;
+        write!(f, "]")
+    }
+}
+impl<'r> RecentTimestampsReader<'r> {
+    pub const TOTAL_SIZE: usize = 44;
+    pub const ITEM_SIZE: usize = 4;
+    pub const ITEM_COUNT: usize = 11;
+    pub fn nth0(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[0..4])
+    }
+    pub fn nth1(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[4..8])
+    }
+    pub fn nth2(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[8..12])
+    }
+    pub fn nth3(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[12..16])
+    }
+    pub fn nth4(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[16..20])
+    }
+    pub fn nth5(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[20..24])
+    }
+    pub fn nth6(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[24..28])
+    }
+    pub fn nth7(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[28..32])
+    }
+    pub fn nth8(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[32..36])
+    }
+    pub fn nth9(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[36..40])
+    }
+    pub fn nth10(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[40..44])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for RecentTimestampsReader<'r> {
+    type Entity = RecentTimestamps;
+    const NAME: &'static str = "RecentTimestampsReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        RecentTimestampsReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+pub struct RecentTimestampsBuilder(pub(crate) [Uint32; 11]);
+impl ::core::fmt::Debug for RecentTimestampsBuilder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+    }
+}
+impl ::core::default::Default for RecentTimestampsBuilder {
+    fn default() -> Self {
+        RecentTimestampsBuilder([
+            Uint32::default(),
+            Uint32::default(),
+            Uint32::default(),
+            Uint32::default(),
+            Uint32::default(),
+            Uint32::default(),
+            Uint32::default(),
+            Uint32::default(),
+            Uint32::default(),
+            Uint32::default(),
+            Uint32::default(),
+        ])
+    }
+}
+impl RecentTimestampsBuilder {
+    pub const TOTAL_SIZE: usize = 44;
+    pub const ITEM_SIZE: usize = 4;
+    pub const ITEM_COUNT: usize = 11;
+    pub fn set(mut self, v: [Uint32; 11]) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn nth0(mut self, v: Uint32) -> Self {
+        self.0[0] = v;
+        self
+    }
+    pub fn nth1(mut self, v: Uint32) -> Self {
+        self.0[1] = v;
+        self
+    }
+    pub fn nth2(mut self, v: Uint32) -> Self {
+        self.0[2] = v;
+        self
+    }
+    pub fn nth3(mut self, v: Uint32) -> Self {
+        self.0[3] = v;
+        self
+    }
+    pub fn nth4(mut self, v: Uint32) -> Self {
+        self.0[4] = v;
+        self
+    }
+    pub fn nth5(mut self, v: Uint32) -> Self {
+        self.0[5] = v;
+        self
+    }
+    pub fn nth6(mut self, v: Uint32) -> Self {
+        self.0[6] = v;
+        self
+    }
+    pub fn nth7(mut self, v: Uint32) -> Self {
+        self.0[7] = v;
+        self
+    }
+    pub fn nth8(mut self, v: Uint32) -> Self {
+        self.0[8] = v;
+        self
+    }
+    pub fn nth9(mut self, v: Uint32) -> Self {
+        self.0[9] = v;
+        self
+    }
+    pub fn nth10(mut self, v: Uint32) -> Self {
+        self.0[10] = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for RecentTimestampsBuilder {
+    type Entity = RecentTimestamps;
+    const NAME: &'static str = "RecentTimestampsBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.0[0].as_slice())?;
+        writer.write_all(self.0[1].as_slice())?;
+        writer.write_all(self.0[2].as_slice())?;
+        writer.write_all(self.0[3].as_slice())?;
+        writer.write_all(self.0[4].as_slice())?;
+        writer.write_all(self.0[5].as_slice())?;
+        writer.write_all(self.0[6].as_slice())?;
+        writer.write_all(self.0[7].as_slice())?;
+        writer.write_all(self.0[8].as_slice())?;
+        writer.write_all(self.0[9].as_slice())?;
+        writer.write_all(self.0[10].as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        RecentTimestamps::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct SpvClient(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SpvClient {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SpvClient {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SpvClient {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "id", self.id())?;
+        write!(f, ", {}: {}", "tip_block_hash", self.tip_block_hash())?;
+        write!(f, ", {}: {}", "headers_mmr_root", self.headers_mmr_root())?;
+        write!(
+            f,
+            ", {}: {}",
+            "target_adjust_info",
+            self.target_adjust_info()
+        )?;
+        write!(f, ", {}: {}", "recent_timestamps", self.recent_timestamps())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for SpvClient {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        SpvClient::new_unchecked(v)
+    }
+}
+impl SpvClient {
+    const DEFAULT_VALUE: [u8; 157] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 157;
+    pub const FIELD_SIZES: [usize; 5] = [1, 32, 72, 8, 44];
+    pub const FIELD_COUNT: usize = 5;
+    pub fn id(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
+    }
+    pub fn tip_block_hash(&self) -> Hash {
+        Hash::new_unchecked(self.0.slice(1..33))
+    }
+    pub fn headers_mmr_root(&self) -> HeaderDigest {
+        HeaderDigest::new_unchecked(self.0.slice(33..105))
+    }
+    pub fn target_adjust_info(&self) -> TargetAdjustInfo {
+        TargetAdjustInfo::new_unchecked(self.0.slice(105..113))
+    }
+    pub fn recent_timestamps(&self) -> RecentTimestamps {
+        RecentTimestamps::new_unchecked(self.0.slice(113..157))
+    }
+    pub fn as_reader<'r>(&'r self) -> SpvClientReader<'r> {
+        SpvClientReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SpvClient {
+    type Builder = SpvClientBuilder;
+    const NAME: &'static str = "SpvClient";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SpvClient(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SpvClientReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SpvClientReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .id(self.id())
+            .tip_block_hash(self.tip_block_hash())
+            .headers_mmr_root(self.headers_mmr_root())
+            .target_adjust_info(self.target_adjust_info())
+            .recent_timestamps(self.recent_timestamps())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SpvClientReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SpvClientReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SpvClientReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SpvClientReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "id", self.id())?;
+        write!(f, ", {}: {}", "tip_block_hash", self.tip_block_hash())?;
+        write!(f, ", {}: {}", "headers_mmr_root", self.headers_mmr_root())?;
+        write!(
+            f,
+            ", {}: {}",
+            "target_adjust_info",
+            self.target_adjust_info()
+        )?;
+        write!(f, ", {}: {}", "recent_timestamps", self.recent_timestamps())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> SpvClientReader<'r> {
+    pub const TOTAL_SIZE: usize = 157;
+    pub const FIELD_SIZES: [usize; 5] = [1, 32, 72, 8, 44];
+    pub const FIELD_COUNT: usize = 5;
+    pub fn id(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+    pub fn tip_block_hash(&self) -> HashReader<'r> {
+        HashReader::new_unchecked(&self.as_slice()[1..33])
+    }
+    pub fn headers_mmr_root(&self) -> HeaderDigestReader<'r> {
+        HeaderDigestReader::new_unchecked(&self.as_slice()[33..105])
+    }
+    pub fn target_adjust_info(&self) -> TargetAdjustInfoReader<'r> {
+        TargetAdjustInfoReader::new_unchecked(&self.as_slice()[105..113])
+    }
+    pub fn recent_timestamps(&self) -> RecentTimestampsReader<'r> {
+        RecentTimestampsReader::new_unchecked(&self.as_slice()[113..157])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SpvClientReader<'r> {
+    type Entity = SpvClient;
+    const NAME: &'static str = "SpvClientReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SpvClientReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SpvClientBuilder {
+    pub(crate) id: Byte,
+    pub(crate) tip_block_hash: Hash,
+    pub(crate) headers_mmr_root: HeaderDigest,
+    pub(crate) target_adjust_info: TargetAdjustInfo,
+    pub(crate) recent_timestamps: RecentTimestamps,
+}
+impl SpvClientBuilder {
+    pub const TOTAL_SIZE: usize = 157;
+    pub const FIELD_SIZES: [usize; 5] = [1, 32, 72, 8, 44];
+    pub const FIELD_COUNT: usize = 5;
+    pub fn id(mut self, v: Byte) -> Self {
+        self.id = v;
+        self
+    }
+    pub fn tip_block_hash(mut self, v: Hash) -> Self {
+        self.tip_block_hash = v;
+        self
+    }
+    pub fn headers_mmr_root(mut self, v: HeaderDigest) -> Self {
+        self.headers_mmr_root = v;
+        self
+    }
+    pub fn target_adjust_info(mut self, v: TargetAdjustInfo) -> Self {
+        self.target_adjust_info = v;
+        self
+    }
+    pub fn recent_timestamps(mut self, v: RecentTimestamps) -> Self {
+        self.recent_timestamps = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SpvClientBuilder {
+    type Entity = SpvClient;
+    const NAME: &'static str = "SpvClientBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.id.as_slice())?;
+        writer.write_all(self.tip_block_hash.as_slice())?;
+        writer.write_all(self.headers_mmr_root.as_slice())?;
+        writer.write_all(self.target_adjust_info.as_slice())?;
+        writer.write_all(self.recent_timestamps.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SpvClient::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct SpvTypeArgs(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SpvTypeArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SpvTypeArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SpvTypeArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "type_id", self.type_id())?;
+        write!(f, ", {}: {}", "clients_count", self.clients_count())?;
+        write!(f, ", {}: {}", "flags", self.flags())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for SpvTypeArgs {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        SpvTypeArgs::new_unchecked(v)
+    }
+}
+impl SpvTypeArgs {
+    const DEFAULT_VALUE: [u8; 34] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 34;
+    pub const FIELD_SIZES: [usize; 3] = [32, 1, 1];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn type_id(&self) -> Hash {
+        Hash::new_unchecked(self.0.slice(0..32))
+    }
+    pub fn clients_count(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(32..33))
+    }
+    pub fn flags(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(33..34))
+    }
+    pub fn as_reader<'r>(&'r self) -> SpvTypeArgsReader<'r> {
+        SpvTypeArgsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SpvTypeArgs {
+    type Builder = SpvTypeArgsBuilder;
+    const NAME: &'static str = "SpvTypeArgs";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SpvTypeArgs(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SpvTypeArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SpvTypeArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .type_id(self.type_id())
+            .clients_count(self.clients_count())
+            .flags(self.flags())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SpvTypeArgsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SpvTypeArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SpvTypeArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SpvTypeArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "type_id", self.type_id())?;
+        write!(f, ", {}: {}", "clients_count", self.clients_count())?;
+        write!(f, ", {}: {}", "flags", self.flags())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> SpvTypeArgsReader<'r> {
+    pub const TOTAL_SIZE: usize = 34;
+    pub const FIELD_SIZES: [usize; 3] = [32, 1, 1];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn type_id(&self) -> HashReader<'r> {
+        HashReader::new_unchecked(&self.as_slice()[0..32])
+    }
+    pub fn clients_count(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[32..33])
+    }
+    pub fn flags(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[33..34])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SpvTypeArgsReader<'r> {
+    type Entity = SpvTypeArgs;
+    const NAME: &'static str = "SpvTypeArgsReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SpvTypeArgsReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SpvTypeArgsBuilder {
+    pub(crate) type_id: Hash,
+    pub(crate) clients_count: Byte,
+    pub(crate) flags: Byte,
+}
+impl SpvTypeArgsBuilder {
+    pub const TOTAL_SIZE: usize = 34;
+    pub const FIELD_SIZES: [usize; 3] = [32, 1, 1];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn type_id(mut self, v: Hash) -> Self {
+        self.type_id = v;
+        self
+    }
+    pub fn clients_count(mut self, v: Byte) -> Self {
+        self.clients_count = v;
+        self
+    }
+    pub fn flags(mut self, v: Byte) -> Self {
+        self.flags = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SpvTypeArgsBuilder {
+    type Entity = SpvTypeArgs;
+    const NAME: &'static str = "SpvTypeArgsBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.type_id.as_slice())?;
+        writer.write_all(self.clients_count.as_slice())?;
+        writer.write_all(self.flags.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SpvTypeArgs::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct SpvBootstrap(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SpvBootstrap {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SpvBootstrap {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SpvBootstrap {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "height", self.height())?;
+        write!(f, ", {}: {}", "header", self.header())?;
+        write!(f, ", {}: {}", "period_headers", self.period_headers())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for SpvBootstrap {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        SpvBootstrap::new_unchecked(v)
+    }
+}
+impl SpvBootstrap {
+    const DEFAULT_VALUE: [u8; 104] = [
+        104, 0, 0, 0, 16, 0, 0, 0, 20, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn height(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn header(&self) -> Header {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Header::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn period_headers(&self) -> HeaderVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            HeaderVec::new_unchecked(self.0.slice(start..end))
+        } else {
+            HeaderVec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SpvBootstrapReader<'r> {
+        SpvBootstrapReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SpvBootstrap {
+    type Builder = SpvBootstrapBuilder;
+    const NAME: &'static str = "SpvBootstrap";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SpvBootstrap(data)
     }
-    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.0[0].as_slice())?;
-        writer.write_all(self.0[1].as_slice())?;
-        writer.write_all(self.0[2].as_slice())?;
-        writer.write_all(self.0[3].as_slice())?;
-        writer.write_all(self.0[4].as_slice())?;
-        writer.write_all(self.0[5].as_slice())?;
-        writer.write_all(self.0[6].as_slice())?;
-        writer.write_all(self.0[7].as_slice())?;
-        writer.write_all(self.0[8].as_slice())?;
-        writer.write_all(self.0[9].as_slice())?;
-        writer.write_all(self.0[10].as_slice())?;
-        writer.write_all(self.0[11].as_slice())?;
-        writer.write_all(self.0[12].as_slice())?;
-        writer.write_all(self.0[13].as_slice())?;
-        writer.write_all(self.0[14].as_slice())?;
-        writer.write_all(self.0[15].as_slice())?;
-        writer.write_all(self.0[16].as_slice())?;
-        writer.write_all(self.0[17].as_slice())?;
-        writer.write_all(self.0[18].as_slice())?;
-        writer.write_all(self.0[19].as_slice())?;
-        writer.write_all(self.0[20].as_slice())?;
-        writer.write_all(self.0[21].as_slice())?;
-        writer.write_all(self.0[22].as_slice())?;
-        writer.write_all(self.0[23].as_slice())?;
-        writer.write_all(self.0[24].as_slice())?;
-        writer.write_all(self.0[25].as_slice())?;
-        writer.write_all(self.0[26].as_slice())?;
-        writer.write_all(self.0[27].as_slice())?;
-        writer.write_all(self.0[28].as_slice())?;
-        writer.write_all(self.0[29].as_slice())?;
-        writer.write_all(self.0[30].as_slice())?;
-        writer.write_all(self.0[31].as_slice())?;
-        writer.write_all(self.0[32].as_slice())?;
-        writer.write_all(self.0[33].as_slice())?;
-        writer.write_all(self.0[34].as_slice())?;
-        writer.write_all(self.0[35].as_slice())?;
-        writer.write_all(self.0[36].as_slice())?;
-        writer.write_all(self.0[37].as_slice())?;
-        writer.write_all(self.0[38].as_slice())?;
-        writer.write_all(self.0[39].as_slice())?;
-        writer.write_all(self.0[40].as_slice())?;
-        writer.write_all(self.0[41].as_slice())?;
-        writer.write_all(self.0[42].as_slice())?;
-        writer.write_all(self.0[43].as_slice())?;
-        writer.write_all(self.0[44].as_slice())?;
-        writer.write_all(self.0[45].as_slice())?;
-        writer.write_all(self.0[46].as_slice())?;
-        writer.write_all(self.0[47].as_slice())?;
-        writer.write_all(self.0[48].as_slice())?;
-        writer.write_all(self.0[49].as_slice())?;
-        writer.write_all(self.0[50].as_slice())?;
-        writer.write_all(self.0[51].as_slice())?;
-        writer.write_all(self.0[52].as_slice())?;
-        writer.write_all(self.0[53].as_slice())?;
-        writer.write_all(self.0[54].as_slice())?;
-        writer.write_all(self.0[55].as_slice())?;
-        writer.write_all(self.0[56].as_slice())?;
-        writer.write_all(self.0[57].as_slice())?;
-        writer.write_all(self.0[58].as_slice())?;
-        writer.write_all(self.0[59].as_slice())?;
-        writer.write_all(self.0[60].as_slice())?;
-        writer.write_all(self.0[61].as_slice())?;
-        writer.write_all(self.0[62].as_slice())?;
-        writer.write_all(self.0[63].as_slice())?;
-        writer.write_all(self.0[64].as_slice())?;
-        writer.write_all(self.0[65].as_slice())?;
-        writer.write_all(self.0[66].as_slice())?;
-        writer.write_all(self.0[67].as_slice())?;
-        writer.write_all(self.0[68].as_slice())?;
-        writer.write_all(self.0[69].as_slice())?;
-        writer.write_all(self.0[70].as_slice())?;
-        writer.write_all(self.0[71].as_slice())?;
-        writer.write_all(self.0[72].as_slice())?;
-        writer.write_all(self.0[73].as_slice())?;
-        writer.write_all(self.0[74].as_slice())?;
-        writer.write_all(self.0[75].as_slice())?;
-        writer.write_all(self.0[76].as_slice())?;
-        writer.write_all(self.0[77].as_slice())?;
-        writer.write_all(self.0[78].as_slice())?;
-        writer.write_all(self.0[79].as_slice())?;
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SpvBootstrapReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SpvBootstrapReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .height(self.height())
+            .header(self.header())
+            .period_headers(self.period_headers())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SpvBootstrapReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SpvBootstrapReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SpvBootstrapReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SpvBootstrapReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "height", self.height())?;
+        write!(f, ", {}: {}", "header", self.header())?;
+        write!(f, ", {}: {}", "period_headers", self.period_headers())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> SpvBootstrapReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn height(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn header(&self) -> HeaderReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        HeaderReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn period_headers(&self) -> HeaderVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            HeaderVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            HeaderVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SpvBootstrapReader<'r> {
+    type Entity = SpvBootstrap;
+    const NAME: &'static str = "SpvBootstrapReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SpvBootstrapReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        HeaderReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        HeaderVecReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SpvBootstrapBuilder {
+    pub(crate) height: Uint32,
+    pub(crate) header: Header,
+    pub(crate) period_headers: HeaderVec,
+}
+impl SpvBootstrapBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn height(mut self, v: Uint32) -> Self {
+        self.height = v;
+        self
+    }
+    pub fn header(mut self, v: Header) -> Self {
+        self.header = v;
+        self
+    }
+    pub fn period_headers(mut self, v: HeaderVec) -> Self {
+        self.period_headers = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SpvBootstrapBuilder {
+    type Entity = SpvBootstrap;
+    const NAME: &'static str = "SpvBootstrapBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.height.as_slice().len()
+            + self.header.as_slice().len()
+            + self.period_headers.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.height.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.header.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.period_headers.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.height.as_slice())?;
+        writer.write_all(self.header.as_slice())?;
+        writer.write_all(self.period_headers.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Header::new_unchecked(inner.into())
+        SpvBootstrap::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct HeaderVec(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for HeaderVec {
+pub struct SpvUpdate(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SpvUpdate {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -2565,66 +6466,87 @@ impl ::core::fmt::LowerHex for HeaderVec {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for HeaderVec {
+impl ::core::fmt::Debug for SpvUpdate {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for HeaderVec {
+impl ::core::fmt::Display for SpvUpdate {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "headers", self.headers())?;
+        write!(
+            f,
+            ", {}: {}",
+            "new_headers_mmr_proof",
+            self.new_headers_mmr_proof()
+        )?;
+        write!(f, ", {}: {}", "coinbase_proofs", self.coinbase_proofs())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
         }
-        write!(f, "]")
+        write!(f, " }}")
     }
 }
-impl ::core::default::Default for HeaderVec {
+impl ::core::default::Default for SpvUpdate {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        HeaderVec::new_unchecked(v)
+        SpvUpdate::new_unchecked(v)
     }
 }
-impl HeaderVec {
-    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
-    pub const ITEM_SIZE: usize = 80;
+impl SpvUpdate {
+    const DEFAULT_VALUE: [u8; 28] = [
+        28, 0, 0, 0, 16, 0, 0, 0, 20, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
-    }
-    pub fn item_count(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn get(&self, idx: usize) -> Option<Header> {
-        if idx >= self.len() {
-            None
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn headers(&self) -> HeaderVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        HeaderVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn new_headers_mmr_proof(&self) -> MmrProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        MmrProof::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn coinbase_proofs(&self) -> CoinbaseProofVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            CoinbaseProofVec::new_unchecked(self.0.slice(start..end))
         } else {
-            Some(self.get_unchecked(idx))
+            CoinbaseProofVec::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn get_unchecked(&self, idx: usize) -> Header {
-        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
-        let end = start + Self::ITEM_SIZE;
-        Header::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn as_reader<'r>(&'r self) -> HeaderVecReader<'r> {
-        HeaderVecReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> SpvUpdateReader<'r> {
+        SpvUpdateReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for HeaderVec {
-    type Builder = HeaderVecBuilder;
-    const NAME: &'static str = "HeaderVec";
+impl molecule::prelude::Entity for SpvUpdate {
+    type Builder = SpvUpdateBuilder;
+    const NAME: &'static str = "SpvUpdate";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        HeaderVec(data)
+        SpvUpdate(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -2633,21 +6555,24 @@ impl molecule::prelude::Entity for HeaderVec {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        HeaderVecReader::from_slice(slice).map(|reader| reader.to_entity())
+        SpvUpdateReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        HeaderVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        SpvUpdateReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().extend(self.into_iter())
+        Self::new_builder()
+            .headers(self.headers())
+            .new_headers_mmr_proof(self.new_headers_mmr_proof())
+            .coinbase_proofs(self.coinbase_proofs())
     }
 }
 #[derive(Clone, Copy)]
-pub struct HeaderVecReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for HeaderVecReader<'r> {
+pub struct SpvUpdateReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SpvUpdateReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -2656,179 +6581,180 @@ impl<'r> ::core::fmt::LowerHex for HeaderVecReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for HeaderVecReader<'r> {
+impl<'r> ::core::fmt::Debug for SpvUpdateReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for HeaderVecReader<'r> {
+impl<'r> ::core::fmt::Display for SpvUpdateReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "headers", self.headers())?;
+        write!(
+            f,
+            ", {}: {}",
+            "new_headers_mmr_proof",
+            self.new_headers_mmr_proof()
+        )?;
+        write!(f, ", {}: {}", "coinbase_proofs", self.coinbase_proofs())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
         }
-        write!(f, "]")
+        write!(f, " }}")
     }
 }
-impl<'r> HeaderVecReader<'r> {
-    pub const ITEM_SIZE: usize = 80;
+impl<'r> SpvUpdateReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
-    }
-    pub fn item_count(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn get(&self, idx: usize) -> Option<HeaderReader<'r>> {
-        if idx >= self.len() {
-            None
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn headers(&self) -> HeaderVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        HeaderVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn new_headers_mmr_proof(&self) -> MmrProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        MmrProofReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn coinbase_proofs(&self) -> CoinbaseProofVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            CoinbaseProofVecReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Some(self.get_unchecked(idx))
+            CoinbaseProofVecReader::new_unchecked(&self.as_slice()[start..])
         }
     }
-    pub fn get_unchecked(&self, idx: usize) -> HeaderReader<'r> {
-        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
-        let end = start + Self::ITEM_SIZE;
-        HeaderReader::new_unchecked(&self.as_slice()[start..end])
-    }
 }
-impl<'r> molecule::prelude::Reader<'r> for HeaderVecReader<'r> {
-    type Entity = HeaderVec;
-    const NAME: &'static str = "HeaderVecReader";
+impl<'r> molecule::prelude::Reader<'r> for SpvUpdateReader<'r> {
+    type Entity = SpvUpdate;
+    const NAME: &'static str = "SpvUpdateReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        HeaderVecReader(slice)
+        SpvUpdateReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
         if slice_len < molecule::NUMBER_SIZE {
             return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
-        let item_count = molecule::unpack_number(slice) as usize;
-        if item_count == 0 {
-            if slice_len != molecule::NUMBER_SIZE {
-                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
-            }
-            return Ok(());
-        }
-        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        let total_size = molecule::unpack_number(slice) as usize;
         if slice_len != total_size {
             return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        HeaderVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        MmrProofReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        CoinbaseProofVecReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct HeaderVecBuilder(pub(crate) Vec<Header>);
-impl HeaderVecBuilder {
-    pub const ITEM_SIZE: usize = 80;
-    pub fn set(mut self, v: Vec<Header>) -> Self {
-        self.0 = v;
+pub struct SpvUpdateBuilder {
+    pub(crate) headers: HeaderVec,
+    pub(crate) new_headers_mmr_proof: MmrProof,
+    pub(crate) coinbase_proofs: CoinbaseProofVec,
+}
+impl SpvUpdateBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn headers(mut self, v: HeaderVec) -> Self {
+        self.headers = v;
         self
     }
-    pub fn push(mut self, v: Header) -> Self {
-        self.0.push(v);
+    pub fn new_headers_mmr_proof(mut self, v: MmrProof) -> Self {
+        self.new_headers_mmr_proof = v;
         self
     }
-    pub fn extend<T: ::core::iter::IntoIterator<Item = Header>>(mut self, iter: T) -> Self {
-        for elem in iter {
-            self.0.push(elem);
-        }
+    pub fn coinbase_proofs(mut self, v: CoinbaseProofVec) -> Self {
+        self.coinbase_proofs = v;
         self
     }
-    pub fn replace(&mut self, index: usize, v: Header) -> Option<Header> {
-        self.0
-            .get_mut(index)
-            .map(|item| ::core::mem::replace(item, v))
-    }
 }
-impl molecule::prelude::Builder for HeaderVecBuilder {
-    type Entity = HeaderVec;
-    const NAME: &'static str = "HeaderVecBuilder";
+impl molecule::prelude::Builder for SpvUpdateBuilder {
+    type Entity = SpvUpdate;
+    const NAME: &'static str = "SpvUpdateBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.headers.as_slice().len()
+            + self.new_headers_mmr_proof.as_slice().len()
+            + self.coinbase_proofs.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
-        for inner in &self.0[..] {
-            writer.write_all(inner.as_slice())?;
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.headers.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.new_headers_mmr_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.coinbase_proofs.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
+        writer.write_all(self.headers.as_slice())?;
+        writer.write_all(self.new_headers_mmr_proof.as_slice())?;
+        writer.write_all(self.coinbase_proofs.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        HeaderVec::new_unchecked(inner.into())
-    }
-}
-pub struct HeaderVecIterator(HeaderVec, usize, usize);
-impl ::core::iter::Iterator for HeaderVecIterator {
-    type Item = Header;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
-    }
-}
-impl ::core::iter::ExactSizeIterator for HeaderVecIterator {
-    fn len(&self) -> usize {
-        self.2 - self.1
-    }
-}
-impl ::core::iter::IntoIterator for HeaderVec {
-    type Item = Header;
-    type IntoIter = HeaderVecIterator;
-    fn into_iter(self) -> Self::IntoIter {
-        let len = self.len();
-        HeaderVecIterator(self, 0, len)
-    }
-}
-impl<'r> HeaderVecReader<'r> {
-    pub fn iter<'t>(&'t self) -> HeaderVecReaderIterator<'t, 'r> {
-        HeaderVecReaderIterator(&self, 0, self.len())
-    }
-}
-pub struct HeaderVecReaderIterator<'t, 'r>(&'t HeaderVecReader<'r>, usize, usize);
-impl<'t: 'r, 'r> ::core::iter::Iterator for HeaderVecReaderIterator<'t, 'r> {
-    type Item = HeaderReader<'t>;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
-    }
-}
-impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for HeaderVecReaderIterator<'t, 'r> {
-    fn len(&self) -> usize {
-        self.2 - self.1
+        SpvUpdate::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct Bytes(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Bytes {
+pub struct SpvReorg(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SpvReorg {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -2837,63 +6763,135 @@ impl ::core::fmt::LowerHex for Bytes {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Bytes {
+impl ::core::fmt::Debug for SpvReorg {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for Bytes {
+impl ::core::fmt::Display for SpvReorg {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        let raw_data = hex_string(&self.raw_data());
-        write!(f, "{}(0x{})", Self::NAME, raw_data)
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "dropped_headers", self.dropped_headers())?;
+        write!(
+            f,
+            ", {}: {}",
+            "fork_headers_mmr_root",
+            self.fork_headers_mmr_root()
+        )?;
+        write!(f, ", {}: {}", "fork_headers", self.fork_headers())?;
+        write!(
+            f,
+            ", {}: {}",
+            "fork_period_headers",
+            self.fork_period_headers()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "fork_headers_proof",
+            self.fork_headers_proof()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "old_headers_mmr_proof",
+            self.old_headers_mmr_proof()
+        )?;
+        write!(f, ", {}: {}", "update", self.update())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
 }
-impl ::core::default::Default for Bytes {
+impl ::core::default::Default for SpvReorg {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        Bytes::new_unchecked(v)
+        SpvReorg::new_unchecked(v)
     }
 }
-impl Bytes {
-    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
-    pub const ITEM_SIZE: usize = 1;
+impl SpvReorg {
+    const DEFAULT_VALUE: [u8; 152] = [
+        152, 0, 0, 0, 32, 0, 0, 0, 36, 0, 0, 0, 108, 0, 0, 0, 112, 0, 0, 0, 116, 0, 0, 0, 120, 0,
+        0, 0, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 16, 0, 0, 0, 20, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 4, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 7;
     pub fn total_size(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
-    }
-    pub fn item_count(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
-    }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
-    }
-    pub fn get(&self, idx: usize) -> Option<Byte> {
-        if idx >= self.len() {
-            None
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
         } else {
-            Some(self.get_unchecked(idx))
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-    pub fn get_unchecked(&self, idx: usize) -> Byte {
-        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
-        let end = start + Self::ITEM_SIZE;
-        Byte::new_unchecked(self.0.slice(start..end))
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn raw_data(&self) -> molecule::bytes::Bytes {
-        self.0.slice(molecule::NUMBER_SIZE..)
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn as_reader<'r>(&'r self) -> BytesReader<'r> {
-        BytesReader::new_unchecked(self.as_slice())
+    pub fn dropped_headers(&self) -> HeaderVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        HeaderVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn fork_headers_mmr_root(&self) -> HeaderDigest {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        HeaderDigest::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn fork_headers(&self) -> HeaderVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        HeaderVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn fork_period_headers(&self) -> HeaderVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        HeaderVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn fork_headers_proof(&self) -> MmrProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        MmrProof::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn old_headers_mmr_proof(&self) -> MmrProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        MmrProof::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn update(&self) -> SpvUpdate {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[32..]) as usize;
+            SpvUpdate::new_unchecked(self.0.slice(start..end))
+        } else {
+            SpvUpdate::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SpvReorgReader<'r> {
+        SpvReorgReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Bytes {
-    type Builder = BytesBuilder;
-    const NAME: &'static str = "Bytes";
+impl molecule::prelude::Entity for SpvReorg {
+    type Builder = SpvReorgBuilder;
+    const NAME: &'static str = "SpvReorg";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Bytes(data)
+        SpvReorg(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -2902,176 +6900,293 @@ impl molecule::prelude::Entity for Bytes {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BytesReader::from_slice(slice).map(|reader| reader.to_entity())
+        SpvReorgReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BytesReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        SpvReorgReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().extend(self.into_iter())
+        Self::new_builder()
+            .dropped_headers(self.dropped_headers())
+            .fork_headers_mmr_root(self.fork_headers_mmr_root())
+            .fork_headers(self.fork_headers())
+            .fork_period_headers(self.fork_period_headers())
+            .fork_headers_proof(self.fork_headers_proof())
+            .old_headers_mmr_proof(self.old_headers_mmr_proof())
+            .update(self.update())
     }
 }
 #[derive(Clone, Copy)]
-pub struct BytesReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for BytesReader<'r> {
+pub struct SpvReorgReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SpvReorgReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
             write!(f, "0x")?;
         }
-        write!(f, "{}", hex_string(self.as_slice()))
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SpvReorgReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SpvReorgReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "dropped_headers", self.dropped_headers())?;
+        write!(
+            f,
+            ", {}: {}",
+            "fork_headers_mmr_root",
+            self.fork_headers_mmr_root()
+        )?;
+        write!(f, ", {}: {}", "fork_headers", self.fork_headers())?;
+        write!(
+            f,
+            ", {}: {}",
+            "fork_period_headers",
+            self.fork_period_headers()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "fork_headers_proof",
+            self.fork_headers_proof()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "old_headers_mmr_proof",
+            self.old_headers_mmr_proof()
+        )?;
+        write!(f, ", {}: {}", "update", self.update())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> SpvReorgReader<'r> {
+    pub const FIELD_COUNT: usize = 7;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-}
-impl<'r> ::core::fmt::Debug for BytesReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-}
-impl<'r> ::core::fmt::Display for BytesReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        let raw_data = hex_string(&self.raw_data());
-        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-}
-impl<'r> BytesReader<'r> {
-    pub const ITEM_SIZE: usize = 1;
-    pub fn total_size(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    pub fn dropped_headers(&self) -> HeaderVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        HeaderVecReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn item_count(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+    pub fn fork_headers_mmr_root(&self) -> HeaderDigestReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        HeaderDigestReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn fork_headers(&self) -> HeaderVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        HeaderVecReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn fork_period_headers(&self) -> HeaderVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        HeaderVecReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn get(&self, idx: usize) -> Option<ByteReader<'r>> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
+    pub fn fork_headers_proof(&self) -> MmrProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        MmrProofReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn get_unchecked(&self, idx: usize) -> ByteReader<'r> {
-        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
-        let end = start + Self::ITEM_SIZE;
-        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn old_headers_mmr_proof(&self) -> MmrProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        MmrProofReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn raw_data(&self) -> &'r [u8] {
-        &self.as_slice()[molecule::NUMBER_SIZE..]
+    pub fn update(&self) -> SpvUpdateReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[32..]) as usize;
+            SpvUpdateReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            SpvUpdateReader::new_unchecked(&self.as_slice()[start..])
+        }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for BytesReader<'r> {
-    type Entity = Bytes;
-    const NAME: &'static str = "BytesReader";
+impl<'r> molecule::prelude::Reader<'r> for SpvReorgReader<'r> {
+    type Entity = SpvReorg;
+    const NAME: &'static str = "SpvReorgReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        BytesReader(slice)
+        SpvReorgReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
         if slice_len < molecule::NUMBER_SIZE {
             return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
-        let item_count = molecule::unpack_number(slice) as usize;
-        if item_count == 0 {
-            if slice_len != molecule::NUMBER_SIZE {
-                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
-            }
-            return Ok(());
-        }
-        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        let total_size = molecule::unpack_number(slice) as usize;
         if slice_len != total_size {
             return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        HeaderVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        HeaderDigestReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        HeaderVecReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        HeaderVecReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        MmrProofReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        MmrProofReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        SpvUpdateReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct BytesBuilder(pub(crate) Vec<Byte>);
-impl BytesBuilder {
-    pub const ITEM_SIZE: usize = 1;
-    pub fn set(mut self, v: Vec<Byte>) -> Self {
-        self.0 = v;
+pub struct SpvReorgBuilder {
+    pub(crate) dropped_headers: HeaderVec,
+    pub(crate) fork_headers_mmr_root: HeaderDigest,
+    pub(crate) fork_headers: HeaderVec,
+    pub(crate) fork_period_headers: HeaderVec,
+    pub(crate) fork_headers_proof: MmrProof,
+    pub(crate) old_headers_mmr_proof: MmrProof,
+    pub(crate) update: SpvUpdate,
+}
+impl SpvReorgBuilder {
+    pub const FIELD_COUNT: usize = 7;
+    pub fn dropped_headers(mut self, v: HeaderVec) -> Self {
+        self.dropped_headers = v;
         self
     }
-    pub fn push(mut self, v: Byte) -> Self {
-        self.0.push(v);
+    pub fn fork_headers_mmr_root(mut self, v: HeaderDigest) -> Self {
+        self.fork_headers_mmr_root = v;
         self
     }
-    pub fn extend<T: ::core::iter::IntoIterator<Item = Byte>>(mut self, iter: T) -> Self {
-        for elem in iter {
-            self.0.push(elem);
-        }
+    pub fn fork_headers(mut self, v: HeaderVec) -> Self {
+        self.fork_headers = v;
         self
     }
-    pub fn replace(&mut self, index: usize, v: Byte) -> Option<Byte> {
-        self.0
-            .get_mut(index)
-            .map(|item| ::core::mem::replace(item, v))
+    pub fn fork_period_headers(mut self, v: HeaderVec) -> Self {
+        self.fork_period_headers = v;
+        self
+    }
+    pub fn fork_headers_proof(mut self, v: MmrProof) -> Self {
+        self.fork_headers_proof = v;
+        self
+    }
+    pub fn old_headers_mmr_proof(mut self, v: MmrProof) -> Self {
+        self.old_headers_mmr_proof = v;
+        self
+    }
+    pub fn update(mut self, v: SpvUpdate) -> Self {
+        self.update = v;
+        self
     }
 }
-impl molecule::prelude::Builder for BytesBuilder {
-    type Entity = Bytes;
-    const NAME: &'static str = "BytesBuilder";
+impl molecule::prelude::Builder for SpvReorgBuilder {
+    type Entity = SpvReorg;
+    const NAME: &'static str = "SpvReorgBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.dropped_headers.as_slice().len()
+            + self.fork_headers_mmr_root.as_slice().len()
+            + self.fork_headers.as_slice().len()
+            + self.fork_period_headers.as_slice().len()
+            + self.fork_headers_proof.as_slice().len()
+            + self.old_headers_mmr_proof.as_slice().len()
+            + self.update.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
-        for inner in &self.0[..] {
-            writer.write_all(inner.as_slice())?;
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.dropped_headers.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.fork_headers_mmr_root.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.fork_headers.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.fork_period_headers.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.fork_headers_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.old_headers_mmr_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.update.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
+        writer.write_all(self.dropped_headers.as_slice())?;
+        writer.write_all(self.fork_headers_mmr_root.as_slice())?;
+        writer.write_all(self.fork_headers.as_slice())?;
+        writer.write_all(self.fork_period_headers.as_slice())?;
+        writer.write_all(self.fork_headers_proof.as_slice())?;
+        writer.write_all(self.old_headers_mmr_proof.as_slice())?;
+        writer.write_all(self.update.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Bytes::new_unchecked(inner.into())
-    }
-}
-pub struct BytesIterator(Bytes, usize, usize);
-impl ::core::iter::Iterator for BytesIterator {
-    type Item = Byte;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
-    }
-}
-impl ::core::iter::ExactSizeIterator for BytesIterator {
-    fn len(&self) -> usize {
-        self.2 - self.1
-    }
-}
-impl ::core::iter::IntoIterator for Bytes {
-    type Item = Byte;
-    type IntoIter = BytesIterator;
-    fn into_iter(self) -> Self::IntoIter {
-        let len = self.len();
-        BytesIterator(self, 0, len)
+        SpvReorg::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct HeaderDigest(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for HeaderDigest {
+pub struct TransactionProof(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for TransactionProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -3080,62 +7195,90 @@ impl ::core::fmt::LowerHex for HeaderDigest {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for HeaderDigest {
+impl ::core::fmt::Debug for TransactionProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for HeaderDigest {
+impl ::core::fmt::Display for TransactionProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "min_height", self.min_height())?;
-        write!(f, ", {}: {}", "max_height", self.max_height())?;
-        write!(
-            f,
-            ", {}: {}",
-            "partial_chain_work",
-            self.partial_chain_work()
-        )?;
-        write!(f, ", {}: {}", "children_hash", self.children_hash())?;
+        write!(f, "{}: {}", "tx_index", self.tx_index())?;
+        write!(f, ", {}: {}", "height", self.height())?;
+        write!(f, ", {}: {}", "transaction_proof", self.transaction_proof())?;
+        write!(f, ", {}: {}", "header_proof", self.header_proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for HeaderDigest {
+impl ::core::default::Default for TransactionProof {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        HeaderDigest::new_unchecked(v)
+        TransactionProof::new_unchecked(v)
     }
 }
-impl HeaderDigest {
-    const DEFAULT_VALUE: [u8; 72] = [
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl TransactionProof {
+    const DEFAULT_VALUE: [u8; 36] = [
+        36, 0, 0, 0, 20, 0, 0, 0, 24, 0, 0, 0, 28, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const TOTAL_SIZE: usize = 72;
-    pub const FIELD_SIZES: [usize; 4] = [4, 4, 32, 32];
     pub const FIELD_COUNT: usize = 4;
-    pub fn min_height(&self) -> Uint32 {
-        Uint32::new_unchecked(self.0.slice(0..4))
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn max_height(&self) -> Uint32 {
-        Uint32::new_unchecked(self.0.slice(4..8))
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn partial_chain_work(&self) -> Uint256 {
-        Uint256::new_unchecked(self.0.slice(8..40))
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn children_hash(&self) -> Hash {
-        Hash::new_unchecked(self.0.slice(40..72))
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn tx_index(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn height(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn transaction_proof(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn header_proof(&self) -> MmrProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            MmrProof::new_unchecked(self.0.slice(start..end))
+        } else {
+            MmrProof::new_unchecked(self.0.slice(start..))
+        }
     }
-    pub fn as_reader<'r>(&'r self) -> HeaderDigestReader<'r> {
-        HeaderDigestReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> TransactionProofReader<'r> {
+        TransactionProofReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for HeaderDigest {
-    type Builder = HeaderDigestBuilder;
-    const NAME: &'static str = "HeaderDigest";
+impl molecule::prelude::Entity for TransactionProof {
+    type Builder = TransactionProofBuilder;
+    const NAME: &'static str = "TransactionProof";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        HeaderDigest(data)
+        TransactionProof(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -3144,25 +7287,25 @@ impl molecule::prelude::Entity for HeaderDigest {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        HeaderDigestReader::from_slice(slice).map(|reader| reader.to_entity())
+        TransactionProofReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        HeaderDigestReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        TransactionProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .min_height(self.min_height())
-            .max_height(self.max_height())
-            .partial_chain_work(self.partial_chain_work())
-            .children_hash(self.children_hash())
+            .tx_index(self.tx_index())
+            .height(self.height())
+            .transaction_proof(self.transaction_proof())
+            .header_proof(self.header_proof())
     }
 }
 #[derive(Clone, Copy)]
-pub struct HeaderDigestReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for HeaderDigestReader<'r> {
+pub struct TransactionProofReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TransactionProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -3171,115 +7314,192 @@ impl<'r> ::core::fmt::LowerHex for HeaderDigestReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for HeaderDigestReader<'r> {
+impl<'r> ::core::fmt::Debug for TransactionProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for HeaderDigestReader<'r> {
+impl<'r> ::core::fmt::Display for TransactionProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "min_height", self.min_height())?;
-        write!(f, ", {}: {}", "max_height", self.max_height())?;
-        write!(
-            f,
-            ", {}: {}",
-            "partial_chain_work",
-            self.partial_chain_work()
-        )?;
-        write!(f, ", {}: {}", "children_hash", self.children_hash())?;
+        write!(f, "{}: {}", "tx_index", self.tx_index())?;
+        write!(f, ", {}: {}", "height", self.height())?;
+        write!(f, ", {}: {}", "transaction_proof", self.transaction_proof())?;
+        write!(f, ", {}: {}", "header_proof", self.header_proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
         write!(f, " }}")
     }
 }
-impl<'r> HeaderDigestReader<'r> {
-    pub const TOTAL_SIZE: usize = 72;
-    pub const FIELD_SIZES: [usize; 4] = [4, 4, 32, 32];
+impl<'r> TransactionProofReader<'r> {
     pub const FIELD_COUNT: usize = 4;
-    pub fn min_height(&self) -> Uint32Reader<'r> {
-        Uint32Reader::new_unchecked(&self.as_slice()[0..4])
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn max_height(&self) -> Uint32Reader<'r> {
-        Uint32Reader::new_unchecked(&self.as_slice()[4..8])
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn partial_chain_work(&self) -> Uint256Reader<'r> {
-        Uint256Reader::new_unchecked(&self.as_slice()[8..40])
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn children_hash(&self) -> HashReader<'r> {
-        HashReader::new_unchecked(&self.as_slice()[40..72])
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn tx_index(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn height(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn transaction_proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn header_proof(&self) -> MmrProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            MmrProofReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            MmrProofReader::new_unchecked(&self.as_slice()[start..])
+        }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for HeaderDigestReader<'r> {
-    type Entity = HeaderDigest;
-    const NAME: &'static str = "HeaderDigestReader";
+impl<'r> molecule::prelude::Reader<'r> for TransactionProofReader<'r> {
+    type Entity = TransactionProof;
+    const NAME: &'static str = "TransactionProofReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        HeaderDigestReader(slice)
+        TransactionProofReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
         }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        BytesReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        MmrProofReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct HeaderDigestBuilder {
-    pub(crate) min_height: Uint32,
-    pub(crate) max_height: Uint32,
-    pub(crate) partial_chain_work: Uint256,
-    pub(crate) children_hash: Hash,
+pub struct TransactionProofBuilder {
+    pub(crate) tx_index: Uint32,
+    pub(crate) height: Uint32,
+    pub(crate) transaction_proof: Bytes,
+    pub(crate) header_proof: MmrProof,
 }
-impl HeaderDigestBuilder {
-    pub const TOTAL_SIZE: usize = 72;
-    pub const FIELD_SIZES: [usize; 4] = [4, 4, 32, 32];
+impl TransactionProofBuilder {
     pub const FIELD_COUNT: usize = 4;
-    pub fn min_height(mut self, v: Uint32) -> Self {
-        self.min_height = v;
+    pub fn tx_index(mut self, v: Uint32) -> Self {
+        self.tx_index = v;
         self
     }
-    pub fn max_height(mut self, v: Uint32) -> Self {
-        self.max_height = v;
+    pub fn height(mut self, v: Uint32) -> Self {
+        self.height = v;
         self
     }
-    pub fn partial_chain_work(mut self, v: Uint256) -> Self {
-        self.partial_chain_work = v;
+    pub fn transaction_proof(mut self, v: Bytes) -> Self {
+        self.transaction_proof = v;
         self
     }
-    pub fn children_hash(mut self, v: Hash) -> Self {
-        self.children_hash = v;
+    pub fn header_proof(mut self, v: MmrProof) -> Self {
+        self.header_proof = v;
         self
     }
 }
-impl molecule::prelude::Builder for HeaderDigestBuilder {
-    type Entity = HeaderDigest;
-    const NAME: &'static str = "HeaderDigestBuilder";
+impl molecule::prelude::Builder for TransactionProofBuilder {
+    type Entity = TransactionProof;
+    const NAME: &'static str = "TransactionProofBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.tx_index.as_slice().len()
+            + self.height.as_slice().len()
+            + self.transaction_proof.as_slice().len()
+            + self.header_proof.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.min_height.as_slice())?;
-        writer.write_all(self.max_height.as_slice())?;
-        writer.write_all(self.partial_chain_work.as_slice())?;
-        writer.write_all(self.children_hash.as_slice())?;
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.tx_index.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.height.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.transaction_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.header_proof.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.tx_index.as_slice())?;
+        writer.write_all(self.height.as_slice())?;
+        writer.write_all(self.transaction_proof.as_slice())?;
+        writer.write_all(self.header_proof.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        HeaderDigest::new_unchecked(inner.into())
+        TransactionProof::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct MmrProof(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for MmrProof {
+pub struct TransactionBranchProof(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for TransactionBranchProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -3288,66 +7508,100 @@ impl ::core::fmt::LowerHex for MmrProof {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for MmrProof {
+impl ::core::fmt::Debug for TransactionBranchProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for MmrProof {
+impl ::core::fmt::Display for TransactionBranchProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "tx_index", self.tx_index())?;
+        write!(f, ", {}: {}", "height", self.height())?;
+        write!(f, ", {}: {}", "header", self.header())?;
+        write!(f, ", {}: {}", "merkle_branch", self.merkle_branch())?;
+        write!(f, ", {}: {}", "header_proof", self.header_proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
         }
-        write!(f, "]")
+        write!(f, " }}")
     }
 }
-impl ::core::default::Default for MmrProof {
+impl ::core::default::Default for TransactionBranchProof {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        MmrProof::new_unchecked(v)
+        TransactionBranchProof::new_unchecked(v)
+    }
+}
+impl TransactionBranchProof {
+    const DEFAULT_VALUE: [u8; 120] = [
+        120, 0, 0, 0, 24, 0, 0, 0, 28, 0, 0, 0, 32, 0, 0, 0, 112, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 5;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-}
-impl MmrProof {
-    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
-    pub const ITEM_SIZE: usize = 72;
-    pub fn total_size(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn item_count(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+    pub fn tx_index(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn height(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn header(&self) -> Header {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Header::new_unchecked(self.0.slice(start..end))
     }
-    pub fn get(&self, idx: usize) -> Option<HeaderDigest> {
-        if idx >= self.len() {
-            None
+    pub fn merkle_branch(&self) -> HashVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        HashVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn header_proof(&self) -> MmrProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[24..]) as usize;
+            MmrProof::new_unchecked(self.0.slice(start..end))
         } else {
-            Some(self.get_unchecked(idx))
+            MmrProof::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn get_unchecked(&self, idx: usize) -> HeaderDigest {
-        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
-        let end = start + Self::ITEM_SIZE;
-        HeaderDigest::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn as_reader<'r>(&'r self) -> MmrProofReader<'r> {
-        MmrProofReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> TransactionBranchProofReader<'r> {
+        TransactionBranchProofReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for MmrProof {
-    type Builder = MmrProofBuilder;
-    const NAME: &'static str = "MmrProof";
+impl molecule::prelude::Entity for TransactionBranchProof {
+    type Builder = TransactionBranchProofBuilder;
+    const NAME: &'static str = "TransactionBranchProof";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        MmrProof(data)
+        TransactionBranchProof(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -3356,21 +7610,26 @@ impl molecule::prelude::Entity for MmrProof {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        MmrProofReader::from_slice(slice).map(|reader| reader.to_entity())
+        TransactionBranchProofReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        MmrProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        TransactionBranchProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().extend(self.into_iter())
+        Self::new_builder()
+            .tx_index(self.tx_index())
+            .height(self.height())
+            .header(self.header())
+            .merkle_branch(self.merkle_branch())
+            .header_proof(self.header_proof())
     }
 }
 #[derive(Clone, Copy)]
-pub struct MmrProofReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for MmrProofReader<'r> {
+pub struct TransactionBranchProofReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TransactionBranchProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -3379,179 +7638,209 @@ impl<'r> ::core::fmt::LowerHex for MmrProofReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for MmrProofReader<'r> {
+impl<'r> ::core::fmt::Debug for TransactionBranchProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for MmrProofReader<'r> {
+impl<'r> ::core::fmt::Display for TransactionBranchProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "tx_index", self.tx_index())?;
+        write!(f, ", {}: {}", "height", self.height())?;
+        write!(f, ", {}: {}", "header", self.header())?;
+        write!(f, ", {}: {}", "merkle_branch", self.merkle_branch())?;
+        write!(f, ", {}: {}", "header_proof", self.header_proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
         }
-        write!(f, "]")
+        write!(f, " }}")
     }
 }
-impl<'r> MmrProofReader<'r> {
-    pub const ITEM_SIZE: usize = 72;
+impl<'r> TransactionBranchProofReader<'r> {
+    pub const FIELD_COUNT: usize = 5;
     pub fn total_size(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
-    }
-    pub fn item_count(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn get(&self, idx: usize) -> Option<HeaderDigestReader<'r>> {
-        if idx >= self.len() {
-            None
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn tx_index(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn height(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn header(&self) -> HeaderReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        HeaderReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn merkle_branch(&self) -> HashVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        HashVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn header_proof(&self) -> MmrProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[24..]) as usize;
+            MmrProofReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Some(self.get_unchecked(idx))
+            MmrProofReader::new_unchecked(&self.as_slice()[start..])
         }
     }
-    pub fn get_unchecked(&self, idx: usize) -> HeaderDigestReader<'r> {
-        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
-        let end = start + Self::ITEM_SIZE;
-        HeaderDigestReader::new_unchecked(&self.as_slice()[start..end])
-    }
 }
-impl<'r> molecule::prelude::Reader<'r> for MmrProofReader<'r> {
-    type Entity = MmrProof;
-    const NAME: &'static str = "MmrProofReader";
+impl<'r> molecule::prelude::Reader<'r> for TransactionBranchProofReader<'r> {
+    type Entity = TransactionBranchProof;
+    const NAME: &'static str = "TransactionBranchProofReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        MmrProofReader(slice)
+        TransactionBranchProofReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
         if slice_len < molecule::NUMBER_SIZE {
             return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
-        let item_count = molecule::unpack_number(slice) as usize;
-        if item_count == 0 {
-            if slice_len != molecule::NUMBER_SIZE {
-                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
-            }
-            return Ok(());
-        }
-        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        let total_size = molecule::unpack_number(slice) as usize;
         if slice_len != total_size {
             return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        HeaderReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        HashVecReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        MmrProofReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct MmrProofBuilder(pub(crate) Vec<HeaderDigest>);
-impl MmrProofBuilder {
-    pub const ITEM_SIZE: usize = 72;
-    pub fn set(mut self, v: Vec<HeaderDigest>) -> Self {
-        self.0 = v;
+pub struct TransactionBranchProofBuilder {
+    pub(crate) tx_index: Uint32,
+    pub(crate) height: Uint32,
+    pub(crate) header: Header,
+    pub(crate) merkle_branch: HashVec,
+    pub(crate) header_proof: MmrProof,
+}
+impl TransactionBranchProofBuilder {
+    pub const FIELD_COUNT: usize = 5;
+    pub fn tx_index(mut self, v: Uint32) -> Self {
+        self.tx_index = v;
         self
     }
-    pub fn push(mut self, v: HeaderDigest) -> Self {
-        self.0.push(v);
+    pub fn height(mut self, v: Uint32) -> Self {
+        self.height = v;
         self
     }
-    pub fn extend<T: ::core::iter::IntoIterator<Item = HeaderDigest>>(mut self, iter: T) -> Self {
-        for elem in iter {
-            self.0.push(elem);
-        }
+    pub fn header(mut self, v: Header) -> Self {
+        self.header = v;
         self
     }
-    pub fn replace(&mut self, index: usize, v: HeaderDigest) -> Option<HeaderDigest> {
-        self.0
-            .get_mut(index)
-            .map(|item| ::core::mem::replace(item, v))
-    }
-}
-impl molecule::prelude::Builder for MmrProofBuilder {
-    type Entity = MmrProof;
-    const NAME: &'static str = "MmrProofBuilder";
-    fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
-    }
-    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
-        for inner in &self.0[..] {
-            writer.write_all(inner.as_slice())?;
-        }
-        Ok(())
-    }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        MmrProof::new_unchecked(inner.into())
-    }
-}
-pub struct MmrProofIterator(MmrProof, usize, usize);
-impl ::core::iter::Iterator for MmrProofIterator {
-    type Item = HeaderDigest;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
-    }
-}
-impl ::core::iter::ExactSizeIterator for MmrProofIterator {
-    fn len(&self) -> usize {
-        self.2 - self.1
+    pub fn merkle_branch(mut self, v: HashVec) -> Self {
+        self.merkle_branch = v;
+        self
     }
-}
-impl ::core::iter::IntoIterator for MmrProof {
-    type Item = HeaderDigest;
-    type IntoIter = MmrProofIterator;
-    fn into_iter(self) -> Self::IntoIter {
-        let len = self.len();
-        MmrProofIterator(self, 0, len)
+    pub fn header_proof(mut self, v: MmrProof) -> Self {
+        self.header_proof = v;
+        self
     }
 }
-impl<'r> MmrProofReader<'r> {
-    pub fn iter<'t>(&'t self) -> MmrProofReaderIterator<'t, 'r> {
-        MmrProofReaderIterator(&self, 0, self.len())
+impl molecule::prelude::Builder for TransactionBranchProofBuilder {
+    type Entity = TransactionBranchProof;
+    const NAME: &'static str = "TransactionBranchProofBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.tx_index.as_slice().len()
+            + self.height.as_slice().len()
+            + self.header.as_slice().len()
+            + self.merkle_branch.as_slice().len()
+            + self.header_proof.as_slice().len()
     }
-}
-pub struct MmrProofReaderIterator<'t, 'r>(&'t MmrProofReader<'r>, usize, usize);
-impl<'t: 'r, 'r> ::core::iter::Iterator for MmrProofReaderIterator<'t, 'r> {
-    type Item = HeaderDigestReader<'t>;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.tx_index.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.height.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.header.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.merkle_branch.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.header_proof.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
+        writer.write_all(self.tx_index.as_slice())?;
+        writer.write_all(self.height.as_slice())?;
+        writer.write_all(self.header.as_slice())?;
+        writer.write_all(self.merkle_branch.as_slice())?;
+        writer.write_all(self.header_proof.as_slice())?;
+        Ok(())
     }
-}
-impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for MmrProofReaderIterator<'t, 'r> {
-    fn len(&self) -> usize {
-        self.2 - self.1
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        TransactionBranchProof::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct SpvInfo(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for SpvInfo {
+pub struct TransactionsProof(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for TransactionsProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -3560,41 +7849,90 @@ impl ::core::fmt::LowerHex for SpvInfo {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for SpvInfo {
+impl ::core::fmt::Debug for TransactionsProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for SpvInfo {
+impl ::core::fmt::Display for TransactionsProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "tip_client_id", self.tip_client_id())?;
+        write!(f, "{}: {}", "tx_indexes", self.tx_indexes())?;
+        write!(f, ", {}: {}", "height", self.height())?;
+        write!(f, ", {}: {}", "transaction_proof", self.transaction_proof())?;
+        write!(f, ", {}: {}", "header_proof", self.header_proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for SpvInfo {
+impl ::core::default::Default for TransactionsProof {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        SpvInfo::new_unchecked(v)
+        TransactionsProof::new_unchecked(v)
     }
 }
-impl SpvInfo {
-    const DEFAULT_VALUE: [u8; 1] = [0];
-    pub const TOTAL_SIZE: usize = 1;
-    pub const FIELD_SIZES: [usize; 1] = [1];
-    pub const FIELD_COUNT: usize = 1;
-    pub fn tip_client_id(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(0..1))
+impl TransactionsProof {
+    const DEFAULT_VALUE: [u8; 36] = [
+        36, 0, 0, 0, 20, 0, 0, 0, 24, 0, 0, 0, 28, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn as_reader<'r>(&'r self) -> SpvInfoReader<'r> {
-        SpvInfoReader::new_unchecked(self.as_slice())
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn tx_indexes(&self) -> Uint32Vec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32Vec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn height(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn transaction_proof(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn header_proof(&self) -> MmrProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            MmrProof::new_unchecked(self.0.slice(start..end))
+        } else {
+            MmrProof::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> TransactionsProofReader<'r> {
+        TransactionsProofReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for SpvInfo {
-    type Builder = SpvInfoBuilder;
-    const NAME: &'static str = "SpvInfo";
+impl molecule::prelude::Entity for TransactionsProof {
+    type Builder = TransactionsProofBuilder;
+    const NAME: &'static str = "TransactionsProof";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        SpvInfo(data)
+        TransactionsProof(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -3603,21 +7941,25 @@ impl molecule::prelude::Entity for SpvInfo {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SpvInfoReader::from_slice(slice).map(|reader| reader.to_entity())
+        TransactionsProofReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SpvInfoReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        TransactionsProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().tip_client_id(self.tip_client_id())
+        Self::new_builder()
+            .tx_indexes(self.tx_indexes())
+            .height(self.height())
+            .transaction_proof(self.transaction_proof())
+            .header_proof(self.header_proof())
     }
 }
 #[derive(Clone, Copy)]
-pub struct SpvInfoReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for SpvInfoReader<'r> {
+pub struct TransactionsProofReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TransactionsProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -3626,80 +7968,192 @@ impl<'r> ::core::fmt::LowerHex for SpvInfoReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for SpvInfoReader<'r> {
+impl<'r> ::core::fmt::Debug for TransactionsProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for SpvInfoReader<'r> {
+impl<'r> ::core::fmt::Display for TransactionsProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "tip_client_id", self.tip_client_id())?;
+        write!(f, "{}: {}", "tx_indexes", self.tx_indexes())?;
+        write!(f, ", {}: {}", "height", self.height())?;
+        write!(f, ", {}: {}", "transaction_proof", self.transaction_proof())?;
+        write!(f, ", {}: {}", "header_proof", self.header_proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
         write!(f, " }}")
     }
 }
-impl<'r> SpvInfoReader<'r> {
-    pub const TOTAL_SIZE: usize = 1;
-    pub const FIELD_SIZES: [usize; 1] = [1];
-    pub const FIELD_COUNT: usize = 1;
-    pub fn tip_client_id(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[0..1])
+impl<'r> TransactionsProofReader<'r> {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn tx_indexes(&self) -> Uint32VecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32VecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn height(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn transaction_proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn header_proof(&self) -> MmrProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            MmrProofReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            MmrProofReader::new_unchecked(&self.as_slice()[start..])
+        }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for SpvInfoReader<'r> {
-    type Entity = SpvInfo;
-    const NAME: &'static str = "SpvInfoReader";
+impl<'r> molecule::prelude::Reader<'r> for TransactionsProofReader<'r> {
+    type Entity = TransactionsProof;
+    const NAME: &'static str = "TransactionsProofReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        SpvInfoReader(slice)
+        TransactionsProofReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint32VecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        BytesReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        MmrProofReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct SpvInfoBuilder {
-    pub(crate) tip_client_id: Byte,
+pub struct TransactionsProofBuilder {
+    pub(crate) tx_indexes: Uint32Vec,
+    pub(crate) height: Uint32,
+    pub(crate) transaction_proof: Bytes,
+    pub(crate) header_proof: MmrProof,
 }
-impl SpvInfoBuilder {
-    pub const TOTAL_SIZE: usize = 1;
-    pub const FIELD_SIZES: [usize; 1] = [1];
-    pub const FIELD_COUNT: usize = 1;
-    pub fn tip_client_id(mut self, v: Byte) -> Self {
-        self.tip_client_id = v;
+impl TransactionsProofBuilder {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn tx_indexes(mut self, v: Uint32Vec) -> Self {
+        self.tx_indexes = v;
+        self
+    }
+    pub fn height(mut self, v: Uint32) -> Self {
+        self.height = v;
+        self
+    }
+    pub fn transaction_proof(mut self, v: Bytes) -> Self {
+        self.transaction_proof = v;
+        self
+    }
+    pub fn header_proof(mut self, v: MmrProof) -> Self {
+        self.header_proof = v;
         self
     }
 }
-impl molecule::prelude::Builder for SpvInfoBuilder {
-    type Entity = SpvInfo;
-    const NAME: &'static str = "SpvInfoBuilder";
+impl molecule::prelude::Builder for TransactionsProofBuilder {
+    type Entity = TransactionsProof;
+    const NAME: &'static str = "TransactionsProofBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.tx_indexes.as_slice().len()
+            + self.height.as_slice().len()
+            + self.transaction_proof.as_slice().len()
+            + self.header_proof.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.tip_client_id.as_slice())?;
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.tx_indexes.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.height.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.transaction_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.header_proof.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.tx_indexes.as_slice())?;
+        writer.write_all(self.height.as_slice())?;
+        writer.write_all(self.transaction_proof.as_slice())?;
+        writer.write_all(self.header_proof.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        SpvInfo::new_unchecked(inner.into())
+        TransactionsProof::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct TargetAdjustInfo(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for TargetAdjustInfo {
+pub struct BlockHashWithTarget(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BlockHashWithTarget {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -3708,50 +8162,48 @@ impl ::core::fmt::LowerHex for TargetAdjustInfo {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for TargetAdjustInfo {
+impl ::core::fmt::Debug for BlockHashWithTarget {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for TargetAdjustInfo {
+impl ::core::fmt::Display for BlockHashWithTarget {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "start_time", self.start_time())?;
-        write!(
-            f,
-            ", {}: {}",
-            "next_compact_target",
-            self.next_compact_target()
-        )?;
+        write!(f, "{}: {}", "block_hash", self.block_hash())?;
+        write!(f, ", {}: {}", "compact_target", self.compact_target())?;
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for TargetAdjustInfo {
+impl ::core::default::Default for BlockHashWithTarget {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        TargetAdjustInfo::new_unchecked(v)
+        BlockHashWithTarget::new_unchecked(v)
     }
 }
-impl TargetAdjustInfo {
-    const DEFAULT_VALUE: [u8; 8] = [0, 0, 0, 0, 0, 0, 0, 0];
-    pub const TOTAL_SIZE: usize = 8;
-    pub const FIELD_SIZES: [usize; 2] = [4, 4];
+impl BlockHashWithTarget {
+    const DEFAULT_VALUE: [u8; 36] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 36;
+    pub const FIELD_SIZES: [usize; 2] = [32, 4];
     pub const FIELD_COUNT: usize = 2;
-    pub fn start_time(&self) -> Uint32 {
-        Uint32::new_unchecked(self.0.slice(0..4))
+    pub fn block_hash(&self) -> Hash {
+        Hash::new_unchecked(self.0.slice(0..32))
     }
-    pub fn next_compact_target(&self) -> Uint32 {
-        Uint32::new_unchecked(self.0.slice(4..8))
+    pub fn compact_target(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(32..36))
     }
-    pub fn as_reader<'r>(&'r self) -> TargetAdjustInfoReader<'r> {
-        TargetAdjustInfoReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> BlockHashWithTargetReader<'r> {
+        BlockHashWithTargetReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for TargetAdjustInfo {
-    type Builder = TargetAdjustInfoBuilder;
-    const NAME: &'static str = "TargetAdjustInfo";
+impl molecule::prelude::Entity for BlockHashWithTarget {
+    type Builder = BlockHashWithTargetBuilder;
+    const NAME: &'static str = "BlockHashWithTarget";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        TargetAdjustInfo(data)
+        BlockHashWithTarget(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -3760,23 +8212,23 @@ impl molecule::prelude::Entity for TargetAdjustInfo {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TargetAdjustInfoReader::from_slice(slice).map(|reader| reader.to_entity())
+        BlockHashWithTargetReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TargetAdjustInfoReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        BlockHashWithTargetReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .start_time(self.start_time())
-            .next_compact_target(self.next_compact_target())
+            .block_hash(self.block_hash())
+            .compact_target(self.compact_target())
     }
 }
 #[derive(Clone, Copy)]
-pub struct TargetAdjustInfoReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for TargetAdjustInfoReader<'r> {
+pub struct BlockHashWithTargetReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BlockHashWithTargetReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -3785,43 +8237,38 @@ impl<'r> ::core::fmt::LowerHex for TargetAdjustInfoReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for TargetAdjustInfoReader<'r> {
+impl<'r> ::core::fmt::Debug for BlockHashWithTargetReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for TargetAdjustInfoReader<'r> {
+impl<'r> ::core::fmt::Display for BlockHashWithTargetReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "start_time", self.start_time())?;
-        write!(
-            f,
-            ", {}: {}",
-            "next_compact_target",
-            self.next_compact_target()
-        )?;
+        write!(f, "{}: {}", "block_hash", self.block_hash())?;
+        write!(f, ", {}: {}", "compact_target", self.compact_target())?;
         write!(f, " }}")
     }
 }
-impl<'r> TargetAdjustInfoReader<'r> {
-    pub const TOTAL_SIZE: usize = 8;
-    pub const FIELD_SIZES: [usize; 2] = [4, 4];
+impl<'r> BlockHashWithTargetReader<'r> {
+    pub const TOTAL_SIZE: usize = 36;
+    pub const FIELD_SIZES: [usize; 2] = [32, 4];
     pub const FIELD_COUNT: usize = 2;
-    pub fn start_time(&self) -> Uint32Reader<'r> {
-        Uint32Reader::new_unchecked(&self.as_slice()[0..4])
+    pub fn block_hash(&self) -> HashReader<'r> {
+        HashReader::new_unchecked(&self.as_slice()[0..32])
     }
-    pub fn next_compact_target(&self) -> Uint32Reader<'r> {
-        Uint32Reader::new_unchecked(&self.as_slice()[4..8])
+    pub fn compact_target(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[32..36])
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for TargetAdjustInfoReader<'r> {
-    type Entity = TargetAdjustInfo;
-    const NAME: &'static str = "TargetAdjustInfoReader";
+impl<'r> molecule::prelude::Reader<'r> for BlockHashWithTargetReader<'r> {
+    type Entity = BlockHashWithTarget;
+    const NAME: &'static str = "BlockHashWithTargetReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        TargetAdjustInfoReader(slice)
+        BlockHashWithTargetReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -3836,44 +8283,44 @@ impl<'r> molecule::prelude::Reader<'r> for TargetAdjustInfoReader<'r> {
     }
 }
 #[derive(Debug, Default)]
-pub struct TargetAdjustInfoBuilder {
-    pub(crate) start_time: Uint32,
-    pub(crate) next_compact_target: Uint32,
+pub struct BlockHashWithTargetBuilder {
+    pub(crate) block_hash: Hash,
+    pub(crate) compact_target: Uint32,
 }
-impl TargetAdjustInfoBuilder {
-    pub const TOTAL_SIZE: usize = 8;
-    pub const FIELD_SIZES: [usize; 2] = [4, 4];
+impl BlockHashWithTargetBuilder {
+    pub const TOTAL_SIZE: usize = 36;
+    pub const FIELD_SIZES: [usize; 2] = [32, 4];
     pub const FIELD_COUNT: usize = 2;
-    pub fn start_time(mut self, v: Uint32) -> Self {
-        self.start_time = v;
+    pub fn block_hash(mut self, v: Hash) -> Self {
+        self.block_hash = v;
         self
     }
-    pub fn next_compact_target(mut self, v: Uint32) -> Self {
-        self.next_compact_target = v;
+    pub fn compact_target(mut self, v: Uint32) -> Self {
+        self.compact_target = v;
         self
     }
 }
-impl molecule::prelude::Builder for TargetAdjustInfoBuilder {
-    type Entity = TargetAdjustInfo;
-    const NAME: &'static str = "TargetAdjustInfoBuilder";
+impl molecule::prelude::Builder for BlockHashWithTargetBuilder {
+    type Entity = BlockHashWithTarget;
+    const NAME: &'static str = "BlockHashWithTargetBuilder";
     fn expected_length(&self) -> usize {
         Self::TOTAL_SIZE
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.start_time.as_slice())?;
-        writer.write_all(self.next_compact_target.as_slice())?;
+        writer.write_all(self.block_hash.as_slice())?;
+        writer.write_all(self.compact_target.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        TargetAdjustInfo::new_unchecked(inner.into())
+        BlockHashWithTarget::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct SpvClient(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for SpvClient {
+pub struct HeaderOrHash(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for HeaderOrHash {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -3882,63 +8329,51 @@ impl ::core::fmt::LowerHex for SpvClient {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for SpvClient {
+impl ::core::fmt::Debug for HeaderOrHash {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for SpvClient {
+impl ::core::fmt::Display for HeaderOrHash {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "id", self.id())?;
-        write!(f, ", {}: {}", "tip_block_hash", self.tip_block_hash())?;
-        write!(f, ", {}: {}", "headers_mmr_root", self.headers_mmr_root())?;
-        write!(
-            f,
-            ", {}: {}",
-            "target_adjust_info",
-            self.target_adjust_info()
-        )?;
-        write!(f, " }}")
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
     }
 }
-impl ::core::default::Default for SpvClient {
+impl ::core::default::Default for HeaderOrHash {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        SpvClient::new_unchecked(v)
+        HeaderOrHash::new_unchecked(v)
     }
 }
-impl SpvClient {
-    const DEFAULT_VALUE: [u8; 113] = [
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl HeaderOrHash {
+    const DEFAULT_VALUE: [u8; 84] = [
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const TOTAL_SIZE: usize = 113;
-    pub const FIELD_SIZES: [usize; 4] = [1, 32, 72, 8];
-    pub const FIELD_COUNT: usize = 4;
-    pub fn id(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(0..1))
-    }
-    pub fn tip_block_hash(&self) -> Hash {
-        Hash::new_unchecked(self.0.slice(1..33))
-    }
-    pub fn headers_mmr_root(&self) -> HeaderDigest {
-        HeaderDigest::new_unchecked(self.0.slice(33..105))
-    }
-    pub fn target_adjust_info(&self) -> TargetAdjustInfo {
-        TargetAdjustInfo::new_unchecked(self.0.slice(105..113))
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> HeaderOrHashUnion {
+        let inner = self.0.slice(molecule::NUMBER_SIZE..);
+        match self.item_id() {
+            0 => Header::new_unchecked(inner).into(),
+            1 => BlockHashWithTarget::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
     }
-    pub fn as_reader<'r>(&'r self) -> SpvClientReader<'r> {
-        SpvClientReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> HeaderOrHashReader<'r> {
+        HeaderOrHashReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for SpvClient {
-    type Builder = SpvClientBuilder;
-    const NAME: &'static str = "SpvClient";
+impl molecule::prelude::Entity for HeaderOrHash {
+    type Builder = HeaderOrHashBuilder;
+    const NAME: &'static str = "HeaderOrHash";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        SpvClient(data)
+        HeaderOrHash(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -3947,25 +8382,21 @@ impl molecule::prelude::Entity for SpvClient {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SpvClientReader::from_slice(slice).map(|reader| reader.to_entity())
+        HeaderOrHashReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SpvClientReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        HeaderOrHashReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .id(self.id())
-            .tip_block_hash(self.tip_block_hash())
-            .headers_mmr_root(self.headers_mmr_root())
-            .target_adjust_info(self.target_adjust_info())
+        Self::new_builder().set(self.to_enum())
     }
 }
 #[derive(Clone, Copy)]
-pub struct SpvClientReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for SpvClientReader<'r> {
+pub struct HeaderOrHashReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for HeaderOrHashReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -3974,115 +8405,221 @@ impl<'r> ::core::fmt::LowerHex for SpvClientReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for SpvClientReader<'r> {
+impl<'r> ::core::fmt::Debug for HeaderOrHashReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for SpvClientReader<'r> {
+impl<'r> ::core::fmt::Display for HeaderOrHashReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "id", self.id())?;
-        write!(f, ", {}: {}", "tip_block_hash", self.tip_block_hash())?;
-        write!(f, ", {}: {}", "headers_mmr_root", self.headers_mmr_root())?;
-        write!(
-            f,
-            ", {}: {}",
-            "target_adjust_info",
-            self.target_adjust_info()
-        )?;
-        write!(f, " }}")
-    }
-}
-impl<'r> SpvClientReader<'r> {
-    pub const TOTAL_SIZE: usize = 113;
-    pub const FIELD_SIZES: [usize; 4] = [1, 32, 72, 8];
-    pub const FIELD_COUNT: usize = 4;
-    pub fn id(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[0..1])
-    }
-    pub fn tip_block_hash(&self) -> HashReader<'r> {
-        HashReader::new_unchecked(&self.as_slice()[1..33])
-    }
-    pub fn headers_mmr_root(&self) -> HeaderDigestReader<'r> {
-        HeaderDigestReader::new_unchecked(&self.as_slice()[33..105])
-    }
-    pub fn target_adjust_info(&self) -> TargetAdjustInfoReader<'r> {
-        TargetAdjustInfoReader::new_unchecked(&self.as_slice()[105..113])
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl<'r> HeaderOrHashReader<'r> {
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> HeaderOrHashUnionReader<'r> {
+        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
+        match self.item_id() {
+            0 => HeaderReader::new_unchecked(inner).into(),
+            1 => BlockHashWithTargetReader::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for SpvClientReader<'r> {
-    type Entity = SpvClient;
-    const NAME: &'static str = "SpvClientReader";
+impl<'r> molecule::prelude::Reader<'r> for HeaderOrHashReader<'r> {
+    type Entity = HeaderOrHash;
+    const NAME: &'static str = "HeaderOrHashReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        SpvClientReader(slice)
+        HeaderOrHashReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
+        let item_id = molecule::unpack_number(slice);
+        let inner_slice = &slice[molecule::NUMBER_SIZE..];
+        match item_id {
+            0 => HeaderReader::verify(inner_slice, compatible),
+            1 => BlockHashWithTargetReader::verify(inner_slice, compatible),
+            _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
+        }?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct SpvClientBuilder {
-    pub(crate) id: Byte,
-    pub(crate) tip_block_hash: Hash,
-    pub(crate) headers_mmr_root: HeaderDigest,
-    pub(crate) target_adjust_info: TargetAdjustInfo,
-}
-impl SpvClientBuilder {
-    pub const TOTAL_SIZE: usize = 113;
-    pub const FIELD_SIZES: [usize; 4] = [1, 32, 72, 8];
-    pub const FIELD_COUNT: usize = 4;
-    pub fn id(mut self, v: Byte) -> Self {
-        self.id = v;
-        self
-    }
-    pub fn tip_block_hash(mut self, v: Hash) -> Self {
-        self.tip_block_hash = v;
-        self
-    }
-    pub fn headers_mmr_root(mut self, v: HeaderDigest) -> Self {
-        self.headers_mmr_root = v;
-        self
-    }
-    pub fn target_adjust_info(mut self, v: TargetAdjustInfo) -> Self {
-        self.target_adjust_info = v;
+pub struct HeaderOrHashBuilder(pub(crate) HeaderOrHashUnion);
+impl HeaderOrHashBuilder {
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn set<I>(mut self, v: I) -> Self
+    where
+        I: ::core::convert::Into<HeaderOrHashUnion>,
+    {
+        self.0 = v.into();
         self
     }
 }
-impl molecule::prelude::Builder for SpvClientBuilder {
-    type Entity = SpvClient;
-    const NAME: &'static str = "SpvClientBuilder";
+impl molecule::prelude::Builder for HeaderOrHashBuilder {
+    type Entity = HeaderOrHash;
+    const NAME: &'static str = "HeaderOrHashBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE + self.0.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.id.as_slice())?;
-        writer.write_all(self.tip_block_hash.as_slice())?;
-        writer.write_all(self.headers_mmr_root.as_slice())?;
-        writer.write_all(self.target_adjust_info.as_slice())?;
-        Ok(())
+        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
+        writer.write_all(self.0.as_slice())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        SpvClient::new_unchecked(inner.into())
+        HeaderOrHash::new_unchecked(inner.into())
+    }
+}
+#[derive(Debug, Clone)]
+pub enum HeaderOrHashUnion {
+    Header(Header),
+    BlockHashWithTarget(BlockHashWithTarget),
+}
+#[derive(Debug, Clone, Copy)]
+pub enum HeaderOrHashUnionReader<'r> {
+    Header(HeaderReader<'r>),
+    BlockHashWithTarget(BlockHashWithTargetReader<'r>),
+}
+impl ::core::default::Default for HeaderOrHashUnion {
+    fn default() -> Self {
+        HeaderOrHashUnion::Header(::core::default::Default::default())
+    }
+}
+impl ::core::fmt::Display for HeaderOrHashUnion {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            HeaderOrHashUnion::Header(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Header::NAME, item)
+            }
+            HeaderOrHashUnion::BlockHashWithTarget(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, BlockHashWithTarget::NAME, item)
+            }
+        }
+    }
+}
+impl<'r> ::core::fmt::Display for HeaderOrHashUnionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            HeaderOrHashUnionReader::Header(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Header::NAME, item)
+            }
+            HeaderOrHashUnionReader::BlockHashWithTarget(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, BlockHashWithTarget::NAME, item)
+            }
+        }
+    }
+}
+impl HeaderOrHashUnion {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            HeaderOrHashUnion::Header(ref item) => write!(f, "{}", item),
+            HeaderOrHashUnion::BlockHashWithTarget(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl<'r> HeaderOrHashUnionReader<'r> {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            HeaderOrHashUnionReader::Header(ref item) => write!(f, "{}", item),
+            HeaderOrHashUnionReader::BlockHashWithTarget(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl ::core::convert::From<Header> for HeaderOrHashUnion {
+    fn from(item: Header) -> Self {
+        HeaderOrHashUnion::Header(item)
+    }
+}
+impl ::core::convert::From<BlockHashWithTarget> for HeaderOrHashUnion {
+    fn from(item: BlockHashWithTarget) -> Self {
+        HeaderOrHashUnion::BlockHashWithTarget(item)
+    }
+}
+impl<'r> ::core::convert::From<HeaderReader<'r>> for HeaderOrHashUnionReader<'r> {
+    fn from(item: HeaderReader<'r>) -> Self {
+        HeaderOrHashUnionReader::Header(item)
+    }
+}
+impl<'r> ::core::convert::From<BlockHashWithTargetReader<'r>> for HeaderOrHashUnionReader<'r> {
+    fn from(item: BlockHashWithTargetReader<'r>) -> Self {
+        HeaderOrHashUnionReader::BlockHashWithTarget(item)
+    }
+}
+impl HeaderOrHashUnion {
+    pub const NAME: &'static str = "HeaderOrHashUnion";
+    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
+        match self {
+            HeaderOrHashUnion::Header(item) => item.as_bytes(),
+            HeaderOrHashUnion::BlockHashWithTarget(item) => item.as_bytes(),
+        }
+    }
+    pub fn as_slice(&self) -> &[u8] {
+        match self {
+            HeaderOrHashUnion::Header(item) => item.as_slice(),
+            HeaderOrHashUnion::BlockHashWithTarget(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            HeaderOrHashUnion::Header(_) => 0,
+            HeaderOrHashUnion::BlockHashWithTarget(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            HeaderOrHashUnion::Header(_) => "Header",
+            HeaderOrHashUnion::BlockHashWithTarget(_) => "BlockHashWithTarget",
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> HeaderOrHashUnionReader<'r> {
+        match self {
+            HeaderOrHashUnion::Header(item) => item.as_reader().into(),
+            HeaderOrHashUnion::BlockHashWithTarget(item) => item.as_reader().into(),
+        }
+    }
+}
+impl<'r> HeaderOrHashUnionReader<'r> {
+    pub const NAME: &'r str = "HeaderOrHashUnionReader";
+    pub fn as_slice(&self) -> &'r [u8] {
+        match self {
+            HeaderOrHashUnionReader::Header(item) => item.as_slice(),
+            HeaderOrHashUnionReader::BlockHashWithTarget(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            HeaderOrHashUnionReader::Header(_) => 0,
+            HeaderOrHashUnionReader::BlockHashWithTarget(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            HeaderOrHashUnionReader::Header(_) => "Header",
+            HeaderOrHashUnionReader::BlockHashWithTarget(_) => "BlockHashWithTarget",
+        }
     }
 }
 #[derive(Clone)]
-pub struct SpvTypeArgs(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for SpvTypeArgs {
+pub struct HeaderProof(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for HeaderProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -4091,52 +8628,85 @@ impl ::core::fmt::LowerHex for SpvTypeArgs {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for SpvTypeArgs {
+impl ::core::fmt::Debug for HeaderProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for SpvTypeArgs {
+impl ::core::fmt::Display for HeaderProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "type_id", self.type_id())?;
-        write!(f, ", {}: {}", "clients_count", self.clients_count())?;
-        write!(f, ", {}: {}", "flags", self.flags())?;
+        write!(f, "{}: {}", "height", self.height())?;
+        write!(f, ", {}: {}", "header", self.header())?;
+        write!(f, ", {}: {}", "header_proof", self.header_proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for SpvTypeArgs {
+impl ::core::default::Default for HeaderProof {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        SpvTypeArgs::new_unchecked(v)
+        HeaderProof::new_unchecked(v)
     }
 }
-impl SpvTypeArgs {
-    const DEFAULT_VALUE: [u8; 34] = [
+impl HeaderProof {
+    const DEFAULT_VALUE: [u8; 108] = [
+        108, 0, 0, 0, 16, 0, 0, 0, 20, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const TOTAL_SIZE: usize = 34;
-    pub const FIELD_SIZES: [usize; 3] = [32, 1, 1];
     pub const FIELD_COUNT: usize = 3;
-    pub fn type_id(&self) -> Hash {
-        Hash::new_unchecked(self.0.slice(0..32))
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn clients_count(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(32..33))
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn flags(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(33..34))
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn as_reader<'r>(&'r self) -> SpvTypeArgsReader<'r> {
-        SpvTypeArgsReader::new_unchecked(self.as_slice())
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn height(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn header(&self) -> HeaderOrHash {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        HeaderOrHash::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn header_proof(&self) -> MmrProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            MmrProof::new_unchecked(self.0.slice(start..end))
+        } else {
+            MmrProof::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> HeaderProofReader<'r> {
+        HeaderProofReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for SpvTypeArgs {
-    type Builder = SpvTypeArgsBuilder;
-    const NAME: &'static str = "SpvTypeArgs";
+impl molecule::prelude::Entity for HeaderProof {
+    type Builder = HeaderProofBuilder;
+    const NAME: &'static str = "HeaderProof";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        SpvTypeArgs(data)
+        HeaderProof(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -4145,24 +8715,24 @@ impl molecule::prelude::Entity for SpvTypeArgs {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SpvTypeArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+        HeaderProofReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SpvTypeArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        HeaderProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .type_id(self.type_id())
-            .clients_count(self.clients_count())
-            .flags(self.flags())
+            .height(self.height())
+            .header(self.header())
+            .header_proof(self.header_proof())
     }
 }
 #[derive(Clone, Copy)]
-pub struct SpvTypeArgsReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for SpvTypeArgsReader<'r> {
+pub struct HeaderProofReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for HeaderProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -4171,100 +8741,175 @@ impl<'r> ::core::fmt::LowerHex for SpvTypeArgsReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for SpvTypeArgsReader<'r> {
+impl<'r> ::core::fmt::Debug for HeaderProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for SpvTypeArgsReader<'r> {
+impl<'r> ::core::fmt::Display for HeaderProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "type_id", self.type_id())?;
-        write!(f, ", {}: {}", "clients_count", self.clients_count())?;
-        write!(f, ", {}: {}", "flags", self.flags())?;
+        write!(f, "{}: {}", "height", self.height())?;
+        write!(f, ", {}: {}", "header", self.header())?;
+        write!(f, ", {}: {}", "header_proof", self.header_proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
         write!(f, " }}")
     }
 }
-impl<'r> SpvTypeArgsReader<'r> {
-    pub const TOTAL_SIZE: usize = 34;
-    pub const FIELD_SIZES: [usize; 3] = [32, 1, 1];
+impl<'r> HeaderProofReader<'r> {
     pub const FIELD_COUNT: usize = 3;
-    pub fn type_id(&self) -> HashReader<'r> {
-        HashReader::new_unchecked(&self.as_slice()[0..32])
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn clients_count(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[32..33])
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn flags(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[33..34])
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn height(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn header(&self) -> HeaderOrHashReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        HeaderOrHashReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn header_proof(&self) -> MmrProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            MmrProofReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            MmrProofReader::new_unchecked(&self.as_slice()[start..])
+        }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for SpvTypeArgsReader<'r> {
-    type Entity = SpvTypeArgs;
-    const NAME: &'static str = "SpvTypeArgsReader";
+impl<'r> molecule::prelude::Reader<'r> for HeaderProofReader<'r> {
+    type Entity = HeaderProof;
+    const NAME: &'static str = "HeaderProofReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        SpvTypeArgsReader(slice)
+        HeaderProofReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
         }
+        Uint32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        HeaderOrHashReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        MmrProofReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct SpvTypeArgsBuilder {
-    pub(crate) type_id: Hash,
-    pub(crate) clients_count: Byte,
-    pub(crate) flags: Byte,
+pub struct HeaderProofBuilder {
+    pub(crate) height: Uint32,
+    pub(crate) header: HeaderOrHash,
+    pub(crate) header_proof: MmrProof,
 }
-impl SpvTypeArgsBuilder {
-    pub const TOTAL_SIZE: usize = 34;
-    pub const FIELD_SIZES: [usize; 3] = [32, 1, 1];
+impl HeaderProofBuilder {
     pub const FIELD_COUNT: usize = 3;
-    pub fn type_id(mut self, v: Hash) -> Self {
-        self.type_id = v;
+    pub fn height(mut self, v: Uint32) -> Self {
+        self.height = v;
         self
     }
-    pub fn clients_count(mut self, v: Byte) -> Self {
-        self.clients_count = v;
+    pub fn header(mut self, v: HeaderOrHash) -> Self {
+        self.header = v;
         self
     }
-    pub fn flags(mut self, v: Byte) -> Self {
-        self.flags = v;
+    pub fn header_proof(mut self, v: MmrProof) -> Self {
+        self.header_proof = v;
         self
     }
 }
-impl molecule::prelude::Builder for SpvTypeArgsBuilder {
-    type Entity = SpvTypeArgs;
-    const NAME: &'static str = "SpvTypeArgsBuilder";
+impl molecule::prelude::Builder for HeaderProofBuilder {
+    type Entity = HeaderProof;
+    const NAME: &'static str = "HeaderProofBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.height.as_slice().len()
+            + self.header.as_slice().len()
+            + self.header_proof.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.type_id.as_slice())?;
-        writer.write_all(self.clients_count.as_slice())?;
-        writer.write_all(self.flags.as_slice())?;
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.height.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.header.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.header_proof.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.height.as_slice())?;
+        writer.write_all(self.header.as_slice())?;
+        writer.write_all(self.header_proof.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        SpvTypeArgs::new_unchecked(inner.into())
+        HeaderProof::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct SpvBootstrap(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for SpvBootstrap {
+pub struct TransactionWitnessProof(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for TransactionWitnessProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -4273,49 +8918,112 @@ impl ::core::fmt::LowerHex for SpvBootstrap {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for SpvBootstrap {
+impl ::core::fmt::Debug for TransactionWitnessProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for SpvBootstrap {
+impl ::core::fmt::Display for TransactionWitnessProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "height", self.height())?;
+        write!(f, "{}: {}", "tx_index", self.tx_index())?;
+        write!(f, ", {}: {}", "height", self.height())?;
         write!(f, ", {}: {}", "header", self.header())?;
+        write!(f, ", {}: {}", "coinbase_proof", self.coinbase_proof())?;
+        write!(
+            f,
+            ", {}: {}",
+            "witness_merkle_branch",
+            self.witness_merkle_branch()
+        )?;
+        write!(f, ", {}: {}", "header_proof", self.header_proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
         write!(f, " }}")
     }
-}
-impl ::core::default::Default for SpvBootstrap {
-    fn default() -> Self {
-        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        SpvBootstrap::new_unchecked(v)
+}
+impl ::core::default::Default for TransactionWitnessProof {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        TransactionWitnessProof::new_unchecked(v)
+    }
+}
+impl TransactionWitnessProof {
+    const DEFAULT_VALUE: [u8; 144] = [
+        144, 0, 0, 0, 28, 0, 0, 0, 32, 0, 0, 0, 36, 0, 0, 0, 116, 0, 0, 0, 136, 0, 0, 0, 140, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20,
+        0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 6;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn tx_index(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
     }
-}
-impl SpvBootstrap {
-    const DEFAULT_VALUE: [u8; 84] = [
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    ];
-    pub const TOTAL_SIZE: usize = 84;
-    pub const FIELD_SIZES: [usize; 2] = [4, 80];
-    pub const FIELD_COUNT: usize = 2;
     pub fn height(&self) -> Uint32 {
-        Uint32::new_unchecked(self.0.slice(0..4))
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
     }
     pub fn header(&self) -> Header {
-        Header::new_unchecked(self.0.slice(4..84))
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Header::new_unchecked(self.0.slice(start..end))
     }
-    pub fn as_reader<'r>(&'r self) -> SpvBootstrapReader<'r> {
-        SpvBootstrapReader::new_unchecked(self.as_slice())
+    pub fn coinbase_proof(&self) -> CoinbaseProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        CoinbaseProof::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn witness_merkle_branch(&self) -> HashVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        HashVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn header_proof(&self) -> MmrProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            MmrProof::new_unchecked(self.0.slice(start..end))
+        } else {
+            MmrProof::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> TransactionWitnessProofReader<'r> {
+        TransactionWitnessProofReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for SpvBootstrap {
-    type Builder = SpvBootstrapBuilder;
-    const NAME: &'static str = "SpvBootstrap";
+impl molecule::prelude::Entity for TransactionWitnessProof {
+    type Builder = TransactionWitnessProofBuilder;
+    const NAME: &'static str = "TransactionWitnessProof";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        SpvBootstrap(data)
+        TransactionWitnessProof(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -4324,23 +9032,27 @@ impl molecule::prelude::Entity for SpvBootstrap {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SpvBootstrapReader::from_slice(slice).map(|reader| reader.to_entity())
+        TransactionWitnessProofReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SpvBootstrapReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        TransactionWitnessProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
+            .tx_index(self.tx_index())
             .height(self.height())
             .header(self.header())
+            .coinbase_proof(self.coinbase_proof())
+            .witness_merkle_branch(self.witness_merkle_branch())
+            .header_proof(self.header_proof())
     }
 }
 #[derive(Clone, Copy)]
-pub struct SpvBootstrapReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for SpvBootstrapReader<'r> {
+pub struct TransactionWitnessProofReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TransactionWitnessProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -4349,60 +9061,161 @@ impl<'r> ::core::fmt::LowerHex for SpvBootstrapReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for SpvBootstrapReader<'r> {
+impl<'r> ::core::fmt::Debug for TransactionWitnessProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for SpvBootstrapReader<'r> {
+impl<'r> ::core::fmt::Display for TransactionWitnessProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "height", self.height())?;
+        write!(f, "{}: {}", "tx_index", self.tx_index())?;
+        write!(f, ", {}: {}", "height", self.height())?;
         write!(f, ", {}: {}", "header", self.header())?;
+        write!(f, ", {}: {}", "coinbase_proof", self.coinbase_proof())?;
+        write!(
+            f,
+            ", {}: {}",
+            "witness_merkle_branch",
+            self.witness_merkle_branch()
+        )?;
+        write!(f, ", {}: {}", "header_proof", self.header_proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
         write!(f, " }}")
     }
 }
-impl<'r> SpvBootstrapReader<'r> {
-    pub const TOTAL_SIZE: usize = 84;
-    pub const FIELD_SIZES: [usize; 2] = [4, 80];
-    pub const FIELD_COUNT: usize = 2;
+impl<'r> TransactionWitnessProofReader<'r> {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn tx_index(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
     pub fn height(&self) -> Uint32Reader<'r> {
-        Uint32Reader::new_unchecked(&self.as_slice()[0..4])
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
     }
     pub fn header(&self) -> HeaderReader<'r> {
-        HeaderReader::new_unchecked(&self.as_slice()[4..84])
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        HeaderReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn coinbase_proof(&self) -> CoinbaseProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        CoinbaseProofReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn witness_merkle_branch(&self) -> HashVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        HashVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn header_proof(&self) -> MmrProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            MmrProofReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            MmrProofReader::new_unchecked(&self.as_slice()[start..])
+        }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for SpvBootstrapReader<'r> {
-    type Entity = SpvBootstrap;
-    const NAME: &'static str = "SpvBootstrapReader";
+impl<'r> molecule::prelude::Reader<'r> for TransactionWitnessProofReader<'r> {
+    type Entity = TransactionWitnessProof;
+    const NAME: &'static str = "TransactionWitnessProofReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        SpvBootstrapReader(slice)
+        TransactionWitnessProofReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        HeaderReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        CoinbaseProofReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        HashVecReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        MmrProofReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct SpvBootstrapBuilder {
+pub struct TransactionWitnessProofBuilder {
+    pub(crate) tx_index: Uint32,
     pub(crate) height: Uint32,
     pub(crate) header: Header,
+    pub(crate) coinbase_proof: CoinbaseProof,
+    pub(crate) witness_merkle_branch: HashVec,
+    pub(crate) header_proof: MmrProof,
 }
-impl SpvBootstrapBuilder {
-    pub const TOTAL_SIZE: usize = 84;
-    pub const FIELD_SIZES: [usize; 2] = [4, 80];
-    pub const FIELD_COUNT: usize = 2;
+impl TransactionWitnessProofBuilder {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn tx_index(mut self, v: Uint32) -> Self {
+        self.tx_index = v;
+        self
+    }
     pub fn height(mut self, v: Uint32) -> Self {
         self.height = v;
         self
@@ -4411,28 +9224,68 @@ impl SpvBootstrapBuilder {
         self.header = v;
         self
     }
+    pub fn coinbase_proof(mut self, v: CoinbaseProof) -> Self {
+        self.coinbase_proof = v;
+        self
+    }
+    pub fn witness_merkle_branch(mut self, v: HashVec) -> Self {
+        self.witness_merkle_branch = v;
+        self
+    }
+    pub fn header_proof(mut self, v: MmrProof) -> Self {
+        self.header_proof = v;
+        self
+    }
 }
-impl molecule::prelude::Builder for SpvBootstrapBuilder {
-    type Entity = SpvBootstrap;
-    const NAME: &'static str = "SpvBootstrapBuilder";
+impl molecule::prelude::Builder for TransactionWitnessProofBuilder {
+    type Entity = TransactionWitnessProof;
+    const NAME: &'static str = "TransactionWitnessProofBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.tx_index.as_slice().len()
+            + self.height.as_slice().len()
+            + self.header.as_slice().len()
+            + self.coinbase_proof.as_slice().len()
+            + self.witness_merkle_branch.as_slice().len()
+            + self.header_proof.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.tx_index.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.height.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.header.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.coinbase_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.witness_merkle_branch.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.header_proof.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.tx_index.as_slice())?;
         writer.write_all(self.height.as_slice())?;
         writer.write_all(self.header.as_slice())?;
+        writer.write_all(self.coinbase_proof.as_slice())?;
+        writer.write_all(self.witness_merkle_branch.as_slice())?;
+        writer.write_all(self.header_proof.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        SpvBootstrap::new_unchecked(inner.into())
+        TransactionWitnessProof::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct SpvUpdate(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for SpvUpdate {
+pub struct HeadersProof(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for HeadersProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -4441,21 +9294,17 @@ impl ::core::fmt::LowerHex for SpvUpdate {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for SpvUpdate {
+impl ::core::fmt::Debug for HeadersProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for SpvUpdate {
+impl ::core::fmt::Display for HeadersProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "headers", self.headers())?;
-        write!(
-            f,
-            ", {}: {}",
-            "new_headers_mmr_proof",
-            self.new_headers_mmr_proof()
-        )?;
+        write!(f, "{}: {}", "heights", self.heights())?;
+        write!(f, ", {}: {}", "headers", self.headers())?;
+        write!(f, ", {}: {}", "headers_proof", self.headers_proof())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -4463,17 +9312,17 @@ impl ::core::fmt::Display for SpvUpdate {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for SpvUpdate {
+impl ::core::default::Default for HeadersProof {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        SpvUpdate::new_unchecked(v)
+        HeadersProof::new_unchecked(v)
     }
 }
-impl SpvUpdate {
-    const DEFAULT_VALUE: [u8; 20] = [
-        20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl HeadersProof {
+    const DEFAULT_VALUE: [u8; 28] = [
+        28, 0, 0, 0, 16, 0, 0, 0, 20, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 2;
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -4490,31 +9339,37 @@ impl SpvUpdate {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn headers(&self) -> HeaderVec {
+    pub fn heights(&self) -> Uint32Vec {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        HeaderVec::new_unchecked(self.0.slice(start..end))
+        Uint32Vec::new_unchecked(self.0.slice(start..end))
     }
-    pub fn new_headers_mmr_proof(&self) -> MmrProof {
+    pub fn headers(&self) -> HeaderVec {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        HeaderVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn headers_proof(&self) -> MmrProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
+            let end = molecule::unpack_number(&slice[16..]) as usize;
             MmrProof::new_unchecked(self.0.slice(start..end))
         } else {
             MmrProof::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> SpvUpdateReader<'r> {
-        SpvUpdateReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> HeadersProofReader<'r> {
+        HeadersProofReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for SpvUpdate {
-    type Builder = SpvUpdateBuilder;
-    const NAME: &'static str = "SpvUpdate";
+impl molecule::prelude::Entity for HeadersProof {
+    type Builder = HeadersProofBuilder;
+    const NAME: &'static str = "HeadersProof";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        SpvUpdate(data)
+        HeadersProof(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -4523,23 +9378,24 @@ impl molecule::prelude::Entity for SpvUpdate {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SpvUpdateReader::from_slice(slice).map(|reader| reader.to_entity())
+        HeadersProofReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SpvUpdateReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        HeadersProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
+            .heights(self.heights())
             .headers(self.headers())
-            .new_headers_mmr_proof(self.new_headers_mmr_proof())
+            .headers_proof(self.headers_proof())
     }
 }
 #[derive(Clone, Copy)]
-pub struct SpvUpdateReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for SpvUpdateReader<'r> {
+pub struct HeadersProofReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for HeadersProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -4548,21 +9404,17 @@ impl<'r> ::core::fmt::LowerHex for SpvUpdateReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for SpvUpdateReader<'r> {
+impl<'r> ::core::fmt::Debug for HeadersProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for SpvUpdateReader<'r> {
+impl<'r> ::core::fmt::Display for HeadersProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "headers", self.headers())?;
-        write!(
-            f,
-            ", {}: {}",
-            "new_headers_mmr_proof",
-            self.new_headers_mmr_proof()
-        )?;
+        write!(f, "{}: {}", "heights", self.heights())?;
+        write!(f, ", {}: {}", "headers", self.headers())?;
+        write!(f, ", {}: {}", "headers_proof", self.headers_proof())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -4570,8 +9422,8 @@ impl<'r> ::core::fmt::Display for SpvUpdateReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> SpvUpdateReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
+impl<'r> HeadersProofReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -4588,31 +9440,37 @@ impl<'r> SpvUpdateReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn headers(&self) -> HeaderVecReader<'r> {
+    pub fn heights(&self) -> Uint32VecReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        HeaderVecReader::new_unchecked(&self.as_slice()[start..end])
+        Uint32VecReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn new_headers_mmr_proof(&self) -> MmrProofReader<'r> {
+    pub fn headers(&self) -> HeaderVecReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        HeaderVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn headers_proof(&self) -> MmrProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
+            let end = molecule::unpack_number(&slice[16..]) as usize;
             MmrProofReader::new_unchecked(&self.as_slice()[start..end])
         } else {
             MmrProofReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for SpvUpdateReader<'r> {
-    type Entity = SpvUpdate;
-    const NAME: &'static str = "SpvUpdateReader";
+impl<'r> molecule::prelude::Reader<'r> for HeadersProofReader<'r> {
+    type Entity = HeadersProof;
+    const NAME: &'static str = "HeadersProofReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        SpvUpdateReader(slice)
+        HeadersProofReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -4651,60 +9509,70 @@ impl<'r> molecule::prelude::Reader<'r> for SpvUpdateReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        HeaderVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        MmrProofReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint32VecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        HeaderVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        MmrProofReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct SpvUpdateBuilder {
+pub struct HeadersProofBuilder {
+    pub(crate) heights: Uint32Vec,
     pub(crate) headers: HeaderVec,
-    pub(crate) new_headers_mmr_proof: MmrProof,
+    pub(crate) headers_proof: MmrProof,
 }
-impl SpvUpdateBuilder {
-    pub const FIELD_COUNT: usize = 2;
+impl HeadersProofBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn heights(mut self, v: Uint32Vec) -> Self {
+        self.heights = v;
+        self
+    }
     pub fn headers(mut self, v: HeaderVec) -> Self {
         self.headers = v;
         self
     }
-    pub fn new_headers_mmr_proof(mut self, v: MmrProof) -> Self {
-        self.new_headers_mmr_proof = v;
+    pub fn headers_proof(mut self, v: MmrProof) -> Self {
+        self.headers_proof = v;
         self
     }
 }
-impl molecule::prelude::Builder for SpvUpdateBuilder {
-    type Entity = SpvUpdate;
-    const NAME: &'static str = "SpvUpdateBuilder";
+impl molecule::prelude::Builder for HeadersProofBuilder {
+    type Entity = HeadersProof;
+    const NAME: &'static str = "HeadersProofBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.heights.as_slice().len()
             + self.headers.as_slice().len()
-            + self.new_headers_mmr_proof.as_slice().len()
+            + self.headers_proof.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
+        total_size += self.heights.as_slice().len();
+        offsets.push(total_size);
         total_size += self.headers.as_slice().len();
         offsets.push(total_size);
-        total_size += self.new_headers_mmr_proof.as_slice().len();
+        total_size += self.headers_proof.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
+        writer.write_all(self.heights.as_slice())?;
         writer.write_all(self.headers.as_slice())?;
-        writer.write_all(self.new_headers_mmr_proof.as_slice())?;
+        writer.write_all(self.headers_proof.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        SpvUpdate::new_unchecked(inner.into())
+        HeadersProof::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct TransactionProof(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for TransactionProof {
+pub struct ScriptCondition(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ScriptCondition {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -4713,18 +9581,313 @@ impl ::core::fmt::LowerHex for TransactionProof {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for TransactionProof {
+impl ::core::fmt::Debug for ScriptCondition {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for TransactionProof {
+impl ::core::fmt::Display for ScriptCondition {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl ::core::default::Default for ScriptCondition {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        ScriptCondition::new_unchecked(v)
+    }
+}
+impl ScriptCondition {
+    const DEFAULT_VALUE: [u8; 8] = [0, 0, 0, 0, 0, 0, 0, 0];
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> ScriptConditionUnion {
+        let inner = self.0.slice(molecule::NUMBER_SIZE..);
+        match self.item_id() {
+            0 => Bytes::new_unchecked(inner).into(),
+            1 => Hash::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ScriptConditionReader<'r> {
+        ScriptConditionReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ScriptCondition {
+    type Builder = ScriptConditionBuilder;
+    const NAME: &'static str = "ScriptCondition";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ScriptCondition(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ScriptConditionReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ScriptConditionReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_enum())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ScriptConditionReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ScriptConditionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ScriptConditionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ScriptConditionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl<'r> ScriptConditionReader<'r> {
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> ScriptConditionUnionReader<'r> {
+        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
+        match self.item_id() {
+            0 => BytesReader::new_unchecked(inner).into(),
+            1 => HashReader::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ScriptConditionReader<'r> {
+    type Entity = ScriptCondition;
+    const NAME: &'static str = "ScriptConditionReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ScriptConditionReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_id = molecule::unpack_number(slice);
+        let inner_slice = &slice[molecule::NUMBER_SIZE..];
+        match item_id {
+            0 => BytesReader::verify(inner_slice, compatible),
+            1 => HashReader::verify(inner_slice, compatible),
+            _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
+        }?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ScriptConditionBuilder(pub(crate) ScriptConditionUnion);
+impl ScriptConditionBuilder {
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn set<I>(mut self, v: I) -> Self
+    where
+        I: ::core::convert::Into<ScriptConditionUnion>,
+    {
+        self.0 = v.into();
+        self
+    }
+}
+impl molecule::prelude::Builder for ScriptConditionBuilder {
+    type Entity = ScriptCondition;
+    const NAME: &'static str = "ScriptConditionBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + self.0.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
+        writer.write_all(self.0.as_slice())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ScriptCondition::new_unchecked(inner.into())
+    }
+}
+#[derive(Debug, Clone)]
+pub enum ScriptConditionUnion {
+    Bytes(Bytes),
+    Hash(Hash),
+}
+#[derive(Debug, Clone, Copy)]
+pub enum ScriptConditionUnionReader<'r> {
+    Bytes(BytesReader<'r>),
+    Hash(HashReader<'r>),
+}
+impl ::core::default::Default for ScriptConditionUnion {
+    fn default() -> Self {
+        ScriptConditionUnion::Bytes(::core::default::Default::default())
+    }
+}
+impl ::core::fmt::Display for ScriptConditionUnion {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            ScriptConditionUnion::Bytes(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Bytes::NAME, item)
+            }
+            ScriptConditionUnion::Hash(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Hash::NAME, item)
+            }
+        }
+    }
+}
+impl<'r> ::core::fmt::Display for ScriptConditionUnionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            ScriptConditionUnionReader::Bytes(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Bytes::NAME, item)
+            }
+            ScriptConditionUnionReader::Hash(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Hash::NAME, item)
+            }
+        }
+    }
+}
+impl ScriptConditionUnion {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            ScriptConditionUnion::Bytes(ref item) => write!(f, "{}", item),
+            ScriptConditionUnion::Hash(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl<'r> ScriptConditionUnionReader<'r> {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            ScriptConditionUnionReader::Bytes(ref item) => write!(f, "{}", item),
+            ScriptConditionUnionReader::Hash(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl ::core::convert::From<Bytes> for ScriptConditionUnion {
+    fn from(item: Bytes) -> Self {
+        ScriptConditionUnion::Bytes(item)
+    }
+}
+impl ::core::convert::From<Hash> for ScriptConditionUnion {
+    fn from(item: Hash) -> Self {
+        ScriptConditionUnion::Hash(item)
+    }
+}
+impl<'r> ::core::convert::From<BytesReader<'r>> for ScriptConditionUnionReader<'r> {
+    fn from(item: BytesReader<'r>) -> Self {
+        ScriptConditionUnionReader::Bytes(item)
+    }
+}
+impl<'r> ::core::convert::From<HashReader<'r>> for ScriptConditionUnionReader<'r> {
+    fn from(item: HashReader<'r>) -> Self {
+        ScriptConditionUnionReader::Hash(item)
+    }
+}
+impl ScriptConditionUnion {
+    pub const NAME: &'static str = "ScriptConditionUnion";
+    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
+        match self {
+            ScriptConditionUnion::Bytes(item) => item.as_bytes(),
+            ScriptConditionUnion::Hash(item) => item.as_bytes(),
+        }
+    }
+    pub fn as_slice(&self) -> &[u8] {
+        match self {
+            ScriptConditionUnion::Bytes(item) => item.as_slice(),
+            ScriptConditionUnion::Hash(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            ScriptConditionUnion::Bytes(_) => 0,
+            ScriptConditionUnion::Hash(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            ScriptConditionUnion::Bytes(_) => "Bytes",
+            ScriptConditionUnion::Hash(_) => "Hash",
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ScriptConditionUnionReader<'r> {
+        match self {
+            ScriptConditionUnion::Bytes(item) => item.as_reader().into(),
+            ScriptConditionUnion::Hash(item) => item.as_reader().into(),
+        }
+    }
+}
+impl<'r> ScriptConditionUnionReader<'r> {
+    pub const NAME: &'r str = "ScriptConditionUnionReader";
+    pub fn as_slice(&self) -> &'r [u8] {
+        match self {
+            ScriptConditionUnionReader::Bytes(item) => item.as_slice(),
+            ScriptConditionUnionReader::Hash(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            ScriptConditionUnionReader::Bytes(_) => 0,
+            ScriptConditionUnionReader::Hash(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            ScriptConditionUnionReader::Bytes(_) => "Bytes",
+            ScriptConditionUnionReader::Hash(_) => "Hash",
+        }
+    }
+}
+#[derive(Clone)]
+pub struct PaymentCondition(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for PaymentCondition {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for PaymentCondition {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for PaymentCondition {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "tx_index", self.tx_index())?;
-        write!(f, ", {}: {}", "height", self.height())?;
-        write!(f, ", {}: {}", "transaction_proof", self.transaction_proof())?;
-        write!(f, ", {}: {}", "header_proof", self.header_proof())?;
+        write!(f, "{}: {}", "output_index", self.output_index())?;
+        write!(f, ", {}: {}", "script", self.script())?;
+        write!(f, ", {}: {}", "min_value", self.min_value())?;
+        write!(f, ", {}: {}", "min_confirmations", self.min_confirmations())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -4732,16 +9895,16 @@ impl ::core::fmt::Display for TransactionProof {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for TransactionProof {
+impl ::core::default::Default for PaymentCondition {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        TransactionProof::new_unchecked(v)
+        PaymentCondition::new_unchecked(v)
     }
 }
-impl TransactionProof {
-    const DEFAULT_VALUE: [u8; 36] = [
-        36, 0, 0, 0, 20, 0, 0, 0, 24, 0, 0, 0, 28, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0,
+impl PaymentCondition {
+    const DEFAULT_VALUE: [u8; 40] = [
+        40, 0, 0, 0, 20, 0, 0, 0, 20, 0, 0, 0, 28, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
     pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
@@ -4760,43 +9923,43 @@ impl TransactionProof {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn tx_index(&self) -> Uint32 {
+    pub fn output_index(&self) -> Uint32Opt {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
+        Uint32Opt::new_unchecked(self.0.slice(start..end))
     }
-    pub fn height(&self) -> Uint32 {
+    pub fn script(&self) -> ScriptCondition {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
+        ScriptCondition::new_unchecked(self.0.slice(start..end))
     }
-    pub fn transaction_proof(&self) -> Bytes {
+    pub fn min_value(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
-        Bytes::new_unchecked(self.0.slice(start..end))
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn header_proof(&self) -> MmrProof {
+    pub fn min_confirmations(&self) -> Uint32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[20..]) as usize;
-            MmrProof::new_unchecked(self.0.slice(start..end))
+            Uint32::new_unchecked(self.0.slice(start..end))
         } else {
-            MmrProof::new_unchecked(self.0.slice(start..))
+            Uint32::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> TransactionProofReader<'r> {
-        TransactionProofReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> PaymentConditionReader<'r> {
+        PaymentConditionReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for TransactionProof {
-    type Builder = TransactionProofBuilder;
-    const NAME: &'static str = "TransactionProof";
+impl molecule::prelude::Entity for PaymentCondition {
+    type Builder = PaymentConditionBuilder;
+    const NAME: &'static str = "PaymentCondition";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        TransactionProof(data)
+        PaymentCondition(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -4805,25 +9968,25 @@ impl molecule::prelude::Entity for TransactionProof {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TransactionProofReader::from_slice(slice).map(|reader| reader.to_entity())
+        PaymentConditionReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TransactionProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        PaymentConditionReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .tx_index(self.tx_index())
-            .height(self.height())
-            .transaction_proof(self.transaction_proof())
-            .header_proof(self.header_proof())
+            .output_index(self.output_index())
+            .script(self.script())
+            .min_value(self.min_value())
+            .min_confirmations(self.min_confirmations())
     }
 }
 #[derive(Clone, Copy)]
-pub struct TransactionProofReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for TransactionProofReader<'r> {
+pub struct PaymentConditionReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for PaymentConditionReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -4832,18 +9995,18 @@ impl<'r> ::core::fmt::LowerHex for TransactionProofReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for TransactionProofReader<'r> {
+impl<'r> ::core::fmt::Debug for PaymentConditionReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for TransactionProofReader<'r> {
+impl<'r> ::core::fmt::Display for PaymentConditionReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "tx_index", self.tx_index())?;
-        write!(f, ", {}: {}", "height", self.height())?;
-        write!(f, ", {}: {}", "transaction_proof", self.transaction_proof())?;
-        write!(f, ", {}: {}", "header_proof", self.header_proof())?;
+        write!(f, "{}: {}", "output_index", self.output_index())?;
+        write!(f, ", {}: {}", "script", self.script())?;
+        write!(f, ", {}: {}", "min_value", self.min_value())?;
+        write!(f, ", {}: {}", "min_confirmations", self.min_confirmations())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -4851,7 +10014,7 @@ impl<'r> ::core::fmt::Display for TransactionProofReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> TransactionProofReader<'r> {
+impl<'r> PaymentConditionReader<'r> {
     pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -4869,43 +10032,43 @@ impl<'r> TransactionProofReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn tx_index(&self) -> Uint32Reader<'r> {
+    pub fn output_index(&self) -> Uint32OptReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+        Uint32OptReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn height(&self) -> Uint32Reader<'r> {
+    pub fn script(&self) -> ScriptConditionReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+        ScriptConditionReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn transaction_proof(&self) -> BytesReader<'r> {
+    pub fn min_value(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
-        BytesReader::new_unchecked(&self.as_slice()[start..end])
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn header_proof(&self) -> MmrProofReader<'r> {
+    pub fn min_confirmations(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[20..]) as usize;
-            MmrProofReader::new_unchecked(&self.as_slice()[start..end])
+            Uint32Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            MmrProofReader::new_unchecked(&self.as_slice()[start..])
+            Uint32Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for TransactionProofReader<'r> {
-    type Entity = TransactionProof;
-    const NAME: &'static str = "TransactionProofReader";
+impl<'r> molecule::prelude::Reader<'r> for PaymentConditionReader<'r> {
+    type Entity = PaymentCondition;
+    const NAME: &'static str = "PaymentConditionReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        TransactionProofReader(slice)
+        PaymentConditionReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -4944,74 +10107,74 @@ impl<'r> molecule::prelude::Reader<'r> for TransactionProofReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        Uint32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        BytesReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        MmrProofReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Uint32OptReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ScriptConditionReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct TransactionProofBuilder {
-    pub(crate) tx_index: Uint32,
-    pub(crate) height: Uint32,
-    pub(crate) transaction_proof: Bytes,
-    pub(crate) header_proof: MmrProof,
+pub struct PaymentConditionBuilder {
+    pub(crate) output_index: Uint32Opt,
+    pub(crate) script: ScriptCondition,
+    pub(crate) min_value: Uint64,
+    pub(crate) min_confirmations: Uint32,
 }
-impl TransactionProofBuilder {
+impl PaymentConditionBuilder {
     pub const FIELD_COUNT: usize = 4;
-    pub fn tx_index(mut self, v: Uint32) -> Self {
-        self.tx_index = v;
+    pub fn output_index(mut self, v: Uint32Opt) -> Self {
+        self.output_index = v;
         self
     }
-    pub fn height(mut self, v: Uint32) -> Self {
-        self.height = v;
+    pub fn script(mut self, v: ScriptCondition) -> Self {
+        self.script = v;
         self
     }
-    pub fn transaction_proof(mut self, v: Bytes) -> Self {
-        self.transaction_proof = v;
+    pub fn min_value(mut self, v: Uint64) -> Self {
+        self.min_value = v;
         self
     }
-    pub fn header_proof(mut self, v: MmrProof) -> Self {
-        self.header_proof = v;
+    pub fn min_confirmations(mut self, v: Uint32) -> Self {
+        self.min_confirmations = v;
         self
     }
 }
-impl molecule::prelude::Builder for TransactionProofBuilder {
-    type Entity = TransactionProof;
-    const NAME: &'static str = "TransactionProofBuilder";
+impl molecule::prelude::Builder for PaymentConditionBuilder {
+    type Entity = PaymentCondition;
+    const NAME: &'static str = "PaymentConditionBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.tx_index.as_slice().len()
-            + self.height.as_slice().len()
-            + self.transaction_proof.as_slice().len()
-            + self.header_proof.as_slice().len()
+            + self.output_index.as_slice().len()
+            + self.script.as_slice().len()
+            + self.min_value.as_slice().len()
+            + self.min_confirmations.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.tx_index.as_slice().len();
+        total_size += self.output_index.as_slice().len();
         offsets.push(total_size);
-        total_size += self.height.as_slice().len();
+        total_size += self.script.as_slice().len();
         offsets.push(total_size);
-        total_size += self.transaction_proof.as_slice().len();
+        total_size += self.min_value.as_slice().len();
         offsets.push(total_size);
-        total_size += self.header_proof.as_slice().len();
+        total_size += self.min_confirmations.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.tx_index.as_slice())?;
-        writer.write_all(self.height.as_slice())?;
-        writer.write_all(self.transaction_proof.as_slice())?;
-        writer.write_all(self.header_proof.as_slice())?;
+        writer.write_all(self.output_index.as_slice())?;
+        writer.write_all(self.script.as_slice())?;
+        writer.write_all(self.min_value.as_slice())?;
+        writer.write_all(self.min_confirmations.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        TransactionProof::new_unchecked(inner.into())
+        PaymentCondition::new_unchecked(inner.into())
     }
 }
@@ -2,15 +2,32 @@
 //!
 //! [Bitcoin]: https://bitcoin.org/
 
-use bitcoin::{blockdata::constants::DIFFCHANGE_TIMESPAN, pow::Target};
+use bitcoin::{
+    block::Header,
+    blockdata::{
+        constants::{DIFFCHANGE_INTERVAL, DIFFCHANGE_TIMESPAN, TARGET_BLOCK_SPACING},
+        script,
+    },
+    pow::{CompactTarget, Target},
+};
 use primitive_types::U256;
 
-use crate::types::core::BitcoinChainType;
+use crate::{
+    constants::{MAX_TIMEWARP, MEDIAN_TIME_SPAN},
+    types::core::{BitcoinChainType, Hash, Transaction},
+    utilities::mmr::hash_concat,
+};
 
 /// Calculates the next target.
 ///
 /// N.B. The end time is not the block time of the next 2016-th header.
 ///
+/// N.B. For testnet4, the previous target should be the target of the first
+/// block for current difficulty, rather than the last one (BIP94).
+///
+/// N.B. For regtest, the difficulty is never adjusted, so the previous target
+/// is returned directly.
+///
 /// Ref:
 /// - [What is the Target in Bitcoin?](https://learnmeabitcoin.com/technical/target)
 /// - [`CalculateNextWorkRequired(..)` in Bitcoin source code](https://github.com/bitcoin/bitcoin/blob/v26.0/src/pow.cpp#L49)
@@ -20,6 +37,11 @@ pub fn calculate_next_target(
     end_time: u32,
     flags: u8,
 ) -> Target {
+    if BitcoinChainType::Regtest == flags.into() {
+        trace!("no retargeting, use the previous target");
+        return prev_target;
+    }
+
     let expected = DIFFCHANGE_TIMESPAN as i64;
     let actual = {
         let mut actual = (end_time as i64) - (start_time as i64);
@@ -47,10 +69,7 @@ pub fn calculate_next_target(
     };
 
     let target = Target::from_le_bytes(le_bytes);
-    let max_target = match flags.into() {
-        BitcoinChainType::Signet => Target::MAX_ATTAINABLE_SIGNET,
-        _ => Target::MAX,
-    };
+    let max_target = pow_limit(flags);
     if target > max_target {
         trace!("fallback to the max target");
         max_target
@@ -59,3 +78,164 @@ pub fn calculate_next_target(
         target
     }
 }
+
+/// Calculates the `nbits` of the first block for a new difficulty, with the
+/// cached target adjust info and the last block for current difficulty.
+///
+/// The cached `nbits` is the `nbits` of the first block for current
+/// difficulty, and it's the previous target for testnet4 (BIP94); for other
+/// chains, the previous target is the `nbits` of the last block.
+pub fn calculate_next_compact_target(
+    cached_bits: CompactTarget,
+    start_time: u32,
+    last_header: &Header,
+    flags: u8,
+) -> CompactTarget {
+    let prev_target = if BitcoinChainType::Testnet4 == flags.into() {
+        cached_bits.into()
+    } else {
+        last_header.bits.into()
+    };
+    calculate_next_target(prev_target, start_time, last_header.time, flags).to_compact_lossy()
+}
+
+/// Returns the easiest target (the proof of work limit) of the chain.
+pub fn pow_limit(flags: u8) -> Target {
+    match flags.into() {
+        BitcoinChainType::Signet => Target::MAX_ATTAINABLE_SIGNET,
+        BitcoinChainType::Regtest => Target::MAX_ATTAINABLE_REGTEST,
+        _ => Target::MAX,
+    }
+}
+
+/// Calculates the required `nbits` of a header which is not at the first
+/// height of a new difficulty.
+///
+/// For most chains, the required `nbits` is always the cached `nbits`.
+///
+/// But for testnet, testnet4 and regtest, if the time of a header is more than 20 minutes after
+/// its previous header, the header is required to be a minimum-difficulty
+/// header.
+/// Otherwise, the required `nbits` is the last non-minimum-difficulty one,
+/// which is always the cached `nbits`, since it is not changed until the
+/// next difficulty adjustment.
+///
+/// Ref:
+/// - [`GetNextWorkRequired(..)` in Bitcoin source code](https://github.com/bitcoin/bitcoin/blob/v26.0/src/pow.cpp#L14)
+pub fn calculate_required_compact_target(
+    cached_bits: CompactTarget,
+    prev_time: u32,
+    height: u32,
+    time: u32,
+    flags: u8,
+) -> CompactTarget {
+    let chain_type: BitcoinChainType = flags.into();
+    if matches!(
+        chain_type,
+        BitcoinChainType::Testnet | BitcoinChainType::Testnet4 | BitcoinChainType::Regtest
+    ) && height % DIFFCHANGE_INTERVAL != 0
+        && u64::from(time) > u64::from(prev_time) + u64::from(TARGET_BLOCK_SPACING) * 2
+    {
+        trace!("use the minimum difficulty for header-{height}");
+        pow_limit(flags).to_compact_lossy()
+    } else {
+        cached_bits
+    }
+}
+
+/// Verifies the timewarp protection rule (BIP94).
+///
+/// For testnet4, the time of the first block of a new difficulty should not be
+/// earlier than [`MAX_TIMEWARP`] seconds before the time of its previous block.
+///
+/// Ref:
+/// - [BIP94: Testnet 4](https://github.com/bitcoin/bips/blob/master/bip-0094.mediawiki)
+pub fn verify_timewarp(prev_time: u32, height: u32, time: u32, flags: u8) -> bool {
+    BitcoinChainType::Testnet4 != flags.into()
+        || height % DIFFCHANGE_INTERVAL != 0
+        || u64::from(time) + u64::from(MAX_TIMEWARP) >= u64::from(prev_time)
+}
+
+/// Calculates the merkle root with a leaf, its index and its merkle branch.
+///
+/// The merkle branch should be ordered from bottom to top.
+///
+/// Returns `None` if the index is out of the range of the merkle branch.
+pub fn calculate_merkle_root<I>(leaf: Hash, index: u32, merkle_branch: I) -> Option<Hash>
+where
+    I: IntoIterator<Item = Hash>,
+{
+    let mut index = index;
+    let mut root = leaf;
+    for sibling in merkle_branch {
+        root = if index & 1 == 1 {
+            hash_concat(&sibling, &root)
+        } else {
+            hash_concat(&root, &sibling)
+        };
+        index >>= 1;
+    }
+    if index == 0 {
+        Some(root)
+    } else {
+        None
+    }
+}
+
+/// Finds the index of the output which contains the witness commitment (BIP141).
+///
+/// If there are multiple outputs match, the last one is returned.
+///
+/// Ref:
+/// - [`GetWitnessCommitmentIndex(..)` in Bitcoin source code](https://github.com/bitcoin/bitcoin/blob/v26.0/src/consensus/validation.h#L150)
+pub fn witness_commitment_index(coinbase: &Transaction) -> Option<usize> {
+    const WITNESS_COMMITMENT_HEADER: [u8; 6] = [0x6a, 0x24, 0xaa, 0x21, 0xa9, 0xed];
+    coinbase.output.iter().rposition(|output| {
+        let script = output.script_pubkey.as_bytes();
+        script.len() >= 38 && script.starts_with(&WITNESS_COMMITMENT_HEADER)
+    })
+}
+
+/// Returns the height since which the coinbase transactions are required to
+/// contain their heights (BIP34).
+///
+/// Ref:
+/// - [`BIP34Height` in Bitcoin source code](https://github.com/bitcoin/bitcoin/blob/v26.0/src/kernel/chainparams.cpp#L81)
+pub fn bip34_height(flags: u8) -> u32 {
+    match flags.into() {
+        BitcoinChainType::Mainnet => 227931,
+        BitcoinChainType::Testnet => 21111,
+        BitcoinChainType::Signet | BitcoinChainType::Testnet4 | BitcoinChainType::Regtest => 1,
+        BitcoinChainType::Other => u32::MAX,
+    }
+}
+
+/// Checks whether the `scriptSig` of a coinbase transaction starts with its height (BIP34).
+///
+/// Ref:
+/// - [`ContextualCheckBlock(..)` in Bitcoin source code](https://github.com/bitcoin/bitcoin/blob/v26.0/src/validation.cpp#L3942)
+pub fn verify_coinbase_height(coinbase: &Transaction, height: u32) -> bool {
+    let expected = script::Builder::new()
+        .push_int(i64::from(height))
+        .into_script();
+    coinbase
+        .input
+        .first()
+        .is_some_and(|input| input.script_sig.as_bytes().starts_with(expected.as_bytes()))
+}
+
+/// Calculates the median time past of the latest 11 headers.
+///
+/// Ref:
+/// - [`GetMedianTimePast()` in Bitcoin source code](https://github.com/bitcoin/bitcoin/blob/v26.0/src/chain.h#L291)
+pub fn calculate_median_time_past(timestamps: &[u32; MEDIAN_TIME_SPAN]) -> u32 {
+    let mut sorted = *timestamps;
+    sorted.sort_unstable();
+    sorted[MEDIAN_TIME_SPAN / 2]
+}
+
+/// Appends the time of a new header into the latest timestamps.
+pub fn append_timestamp(timestamps: &mut [u32; MEDIAN_TIME_SPAN], time: u32) {
+    timestamps.rotate_left(1);
+    timestamps[MEDIAN_TIME_SPAN - 1] = time;
+}
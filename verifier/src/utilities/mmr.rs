@@ -33,8 +33,17 @@ pub fn hash_concat(lhs: &core::Hash, rhs: &core::Hash) -> core::Hash {
 impl core::HeaderDigest {
     /// Creates a new header digest for a leaf node.
     pub fn new_leaf(height: u32, header: &core::Header) -> Self {
-        let block_hash = header.block_hash().into();
-        let target: Target = header.bits.into();
+        Self::new_leaf_with_block_hash(height, header.block_hash().into(), header.bits)
+    }
+
+    /// Creates a new header digest for a leaf node, with the block hash and
+    /// the compact target of the header.
+    pub fn new_leaf_with_block_hash(
+        height: u32,
+        block_hash: core::Hash,
+        bits: core::CompactTarget,
+    ) -> Self {
+        let target: Target = bits.into();
         let blockwork = U256::from_little_endian(&target.to_work().to_le_bytes());
         Self {
             min_height: height,
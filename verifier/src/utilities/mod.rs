@@ -2,3 +2,4 @@
 
 pub mod bitcoin;
 pub mod mmr;
+pub mod signet;
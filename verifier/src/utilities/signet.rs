@@ -0,0 +1,355 @@
+//! The utilities for [Signet] block solutions.
+//!
+//! Only the following challenges are supported, since there is no full script
+//! interpreter:
+//! - `OP_TRUE`, the script sig of the solution should be push-only.
+//! - Pay to public key.
+//! - Pay to public key hash.
+//! - Bare multisig.
+//! - Pay to witness public key hash.
+//! - Pay to taproot, key path spending only.
+//!
+//! [Signet]: https://github.com/bitcoin/bips/blob/master/bip-0325.mediawiki
+
+use alloc::{borrow::ToOwned, vec, vec::Vec};
+
+use bitcoin::{
+    absolute::LockTime,
+    blockdata::{
+        constants::MAX_SCRIPT_ELEMENT_SIZE,
+        opcodes::all::{
+            OP_CHECKMULTISIG, OP_PUSHBYTES_0, OP_PUSHNUM_1, OP_PUSHNUM_16, OP_PUSHNUM_NEG1,
+            OP_RETURN,
+        },
+        script::{Builder, Instruction, PushBytesBuf},
+    },
+    consensus::{deserialize_partial, serialize},
+    hashes::hash160,
+    secp256k1::{ecdsa, Message, Secp256k1, VerifyOnly, XOnlyPublicKey},
+    sighash::{EcdsaSighashType, Prevouts, SighashCache},
+    taproot,
+    transaction::Version,
+    Amount, OutPoint, PublicKey, Script, ScriptBuf, Sequence, TxIn, TxOut, Witness,
+};
+use bitcoin_hashes::Hash as _;
+
+use crate::{
+    types::core::{Hash, Header, Transaction},
+    utilities::bitcoin::{calculate_merkle_root, witness_commitment_index},
+};
+
+/// The header of the signet commitment section in the witness commitment.
+pub const SIGNET_HEADER: [u8; 4] = [0xec, 0xc7, 0xda, 0xa2];
+
+/// Verifies the signet block solution of a header (BIP325).
+///
+/// The coinbase transaction should be already proven to be included in the
+/// header with the merkle branch.
+///
+/// Ref:
+/// - [`CheckSignetBlockSolution(..)` in Bitcoin source code](https://github.com/bitcoin/bitcoin/blob/v26.0/src/signet.cpp#L125)
+pub fn verify_block_solution(
+    header: &Header,
+    coinbase: &Transaction,
+    merkle_branch: &[Hash],
+    challenge: &Script,
+) -> bool {
+    let Some((_, tx_spending)) =
+        create_signet_transactions(header, coinbase, merkle_branch, challenge)
+    else {
+        return false;
+    };
+    let result = verify_challenge(&tx_spending, challenge);
+    if !result {
+        error!("failed: the signet solution doesn't satisfy the challenge");
+    }
+    result
+}
+
+/// Creates the virtual transactions `to_spend` and `spending` of a block (BIP325).
+///
+/// The `spending` transaction carries the signet solution of the block, and
+/// it should be signed by the signet signers, without the solution in the
+/// coinbase transaction.
+///
+/// Same as Bitcoin Core, returns `None` if the coinbase transaction has no
+/// witness commitment, even the challenge is `OP_TRUE`; and a block without
+/// the signet commitment section has an empty solution.
+///
+/// Ref:
+/// - [`SignetTxs::Create(..)` in Bitcoin source code](https://github.com/bitcoin/bitcoin/blob/v26.0/src/signet.cpp#L68)
+pub fn create_signet_transactions(
+    header: &Header,
+    coinbase: &Transaction,
+    merkle_branch: &[Hash],
+    challenge: &Script,
+) -> Option<(Transaction, Transaction)> {
+    let Some(index) = witness_commitment_index(coinbase) else {
+        error!("failed: no witness commitment in the coinbase transaction");
+        return None;
+    };
+    let mut modified_coinbase = coinbase.to_owned();
+    let (script_sig, witness) = {
+        let commitment = &mut modified_coinbase.output[index].script_pubkey;
+        if let Some((replacement, solution)) = fetch_and_clear_commitment_section(commitment) {
+            *commitment = replacement;
+            let Some(parsed) = parse_solution(&solution) else {
+                error!("failed: the signet solution is invalid");
+                return None;
+            };
+            parsed
+        } else {
+            // No signet solution, it could only satisfy a trivial challenge, e.g. `OP_TRUE`.
+            (ScriptBuf::new(), Witness::new())
+        }
+    };
+    let signet_merkle = {
+        let leaf = modified_coinbase.txid().to_raw_hash();
+        calculate_merkle_root(leaf, 0, merkle_branch.iter().copied())
+            .expect("index 0 is always in the range")
+    };
+
+    let block_data = {
+        let mut data = Vec::with_capacity(72);
+        data.extend_from_slice(&serialize(&header.version));
+        data.extend_from_slice(&serialize(&header.prev_blockhash));
+        data.extend_from_slice(signet_merkle.as_byte_array());
+        data.extend_from_slice(&serialize(&header.time));
+        PushBytesBuf::try_from(data).expect("72 bytes is always pushable")
+    };
+    let tx_to_spend = Transaction {
+        version: Version(0),
+        lock_time: LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint::null(),
+            script_sig: Builder::new()
+                .push_opcode(OP_PUSHBYTES_0)
+                .push_slice(block_data)
+                .into_script(),
+            sequence: Sequence(0),
+            witness: Witness::new(),
+        }],
+        output: vec![TxOut {
+            value: Amount::ZERO,
+            script_pubkey: challenge.to_owned(),
+        }],
+    };
+    let tx_spending = Transaction {
+        version: Version(0),
+        lock_time: LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint::new(tx_to_spend.txid(), 0),
+            script_sig,
+            sequence: Sequence(0),
+            witness,
+        }],
+        output: vec![TxOut {
+            value: Amount::ZERO,
+            script_pubkey: ScriptBuf::from(vec![OP_RETURN.to_u8()]),
+        }],
+    };
+    Some((tx_to_spend, tx_spending))
+}
+
+// Extracts the signet solution from the witness commitment script,
+// and returns the script without the solution.
+fn fetch_and_clear_commitment_section(script: &Script) -> Option<(ScriptBuf, Vec<u8>)> {
+    let mut replacement = ScriptBuf::new();
+    let mut solution = None;
+    for instruction in script.instructions() {
+        match instruction {
+            Ok(Instruction::PushBytes(bytes)) => {
+                let data = bytes.as_bytes();
+                if solution.is_none()
+                    && data.len() > SIGNET_HEADER.len()
+                    && data.starts_with(&SIGNET_HEADER)
+                {
+                    solution = Some(data[SIGNET_HEADER.len()..].to_vec());
+                    replacement.push_slice(SIGNET_HEADER);
+                } else {
+                    replacement.push_slice(bytes);
+                }
+            }
+            Ok(Instruction::Op(opcode)) => replacement.push_opcode(opcode),
+            Err(_) => break,
+        }
+    }
+    solution.map(|solution| (replacement, solution))
+}
+
+// Parses the signet solution into a script sig and a witness.
+//
+// Same as Bitcoin Core, both of them are required, and no extra bytes are allowed.
+fn parse_solution(solution: &[u8]) -> Option<(ScriptBuf, Witness)> {
+    let (script_sig, script_sig_size): (ScriptBuf, _) = deserialize_partial(solution).ok()?;
+    let (witness, witness_size): (Witness, _) =
+        deserialize_partial(&solution[script_sig_size..]).ok()?;
+    if script_sig_size + witness_size == solution.len() {
+        Some((script_sig, witness))
+    } else {
+        None
+    }
+}
+
+fn verify_challenge(tx: &Transaction, challenge: &Script) -> bool {
+    let secp = Secp256k1::verification_only();
+    let input = &tx.input[0];
+    if challenge.is_p2wpkh() {
+        if !input.script_sig.is_empty() || input.witness.len() != 2 {
+            return false;
+        }
+        let sig = &input.witness[0];
+        let pubkey = &input.witness[1];
+        if hash160::Hash::hash(pubkey).as_byte_array()[..] != challenge.as_bytes()[2..] {
+            return false;
+        }
+        let Some((sighash_type, der)) = sig.split_last() else {
+            return false;
+        };
+        let sighash_type = EcdsaSighashType::from_consensus(u32::from(*sighash_type));
+        SighashCache::new(tx)
+            .p2wpkh_signature_hash(0, challenge, Amount::ZERO, sighash_type)
+            .map(|sighash| verify_ecdsa(&secp, sighash.to_byte_array(), der, pubkey))
+            .unwrap_or(false)
+    } else if challenge.is_p2tr() {
+        if !input.script_sig.is_empty() || input.witness.len() != 1 {
+            return false;
+        }
+        let Ok(sig) = taproot::Signature::from_slice(&input.witness[0]) else {
+            return false;
+        };
+        let Ok(pubkey) = XOnlyPublicKey::from_slice(&challenge.as_bytes()[2..]) else {
+            return false;
+        };
+        let prevouts = [TxOut {
+            value: Amount::ZERO,
+            script_pubkey: challenge.to_owned(),
+        }];
+        SighashCache::new(tx)
+            .taproot_key_spend_signature_hash(0, &Prevouts::All(&prevouts), sig.hash_ty)
+            .map(|sighash| {
+                let msg = Message::from_digest(sighash.to_byte_array());
+                secp.verify_schnorr(&sig.sig, &msg, &pubkey).is_ok()
+            })
+            .unwrap_or(false)
+    } else {
+        if !input.witness.is_empty() {
+            return false;
+        }
+        if challenge.as_bytes() == [OP_PUSHNUM_1.to_u8()] {
+            // Any script sig, which is executed successfully, satisfies `OP_TRUE`.
+            return is_push_only(&input.script_sig);
+        }
+        let Some(pushes) = collect_pushes(&input.script_sig) else {
+            return false;
+        };
+        if let Some(pubkey) = challenge.p2pk_public_key() {
+            pushes.len() == 1 && verify_legacy(&secp, tx, challenge, &pushes[0], &pubkey.to_bytes())
+        } else if challenge.is_p2pkh() {
+            pushes.len() == 2
+                && hash160::Hash::hash(&pushes[1]).as_byte_array()[..]
+                    == challenge.as_bytes()[3..23]
+                && verify_legacy(&secp, tx, challenge, &pushes[0], &pushes[1])
+        } else if let Some((required, pubkeys)) = parse_multisig(challenge) {
+            // The dummy element should be empty (NULLDUMMY).
+            if pushes.len() != required + 1 || !pushes[0].is_empty() {
+                return false;
+            }
+            // Same as `OP_CHECKMULTISIG`, signatures should be in the same order as public keys.
+            let sigs = &pushes[1..];
+            let mut sig_index = 0;
+            for (key_index, pubkey) in pubkeys.iter().enumerate() {
+                if sig_index == sigs.len() || sigs.len() - sig_index > pubkeys.len() - key_index {
+                    break;
+                }
+                if verify_legacy(&secp, tx, challenge, &sigs[sig_index], pubkey) {
+                    sig_index += 1;
+                }
+            }
+            sig_index == sigs.len()
+        } else {
+            error!("failed: unsupported signet challenge");
+            false
+        }
+    }
+}
+
+// Checks if a script only pushes data onto the stack, so it is always executed successfully.
+fn is_push_only(script: &Script) -> bool {
+    script.instructions().all(|instruction| match instruction {
+        Ok(Instruction::PushBytes(bytes)) => bytes.len() <= MAX_SCRIPT_ELEMENT_SIZE,
+        Ok(Instruction::Op(op)) => {
+            op == OP_PUSHNUM_NEG1
+                || (OP_PUSHNUM_1.to_u8()..=OP_PUSHNUM_16.to_u8()).contains(&op.to_u8())
+        }
+        Err(_) => false,
+    })
+}
+
+// Collects all pushed data from a push-only script.
+fn collect_pushes(script: &Script) -> Option<Vec<Vec<u8>>> {
+    script
+        .instructions()
+        .map(|instruction| match instruction {
+            Ok(Instruction::PushBytes(bytes)) => Some(bytes.as_bytes().to_vec()),
+            _ => None,
+        })
+        .collect()
+}
+
+// Parses a bare multisig script into the required signatures count and the public keys.
+fn parse_multisig(script: &Script) -> Option<(usize, Vec<Vec<u8>>)> {
+    let mut instructions = script.instructions();
+    let decode_num = |instruction: Option<Result<Instruction, _>>| match instruction {
+        Some(Ok(Instruction::Op(op)))
+            if (OP_PUSHNUM_1.to_u8()..=OP_PUSHNUM_16.to_u8()).contains(&op.to_u8()) =>
+        {
+            Some(usize::from(op.to_u8() - OP_PUSHNUM_1.to_u8() + 1))
+        }
+        _ => None,
+    };
+    let required = decode_num(instructions.next())?;
+    let mut pubkeys = Vec::new();
+    let total = loop {
+        match instructions.next() {
+            Some(Ok(Instruction::PushBytes(bytes))) => pubkeys.push(bytes.as_bytes().to_vec()),
+            other => break decode_num(other)?,
+        }
+    };
+    match instructions.next() {
+        Some(Ok(Instruction::Op(OP_CHECKMULTISIG)))
+            if instructions.next().is_none() && total == pubkeys.len() && required <= total =>
+        {
+            Some((required, pubkeys))
+        }
+        _ => None,
+    }
+}
+
+fn verify_legacy(
+    secp: &Secp256k1<VerifyOnly>,
+    tx: &Transaction,
+    script_code: &Script,
+    sig: &[u8],
+    pubkey: &[u8],
+) -> bool {
+    let Some((sighash_type, der)) = sig.split_last() else {
+        return false;
+    };
+    SighashCache::new(tx)
+        .legacy_signature_hash(0, script_code, u32::from(*sighash_type))
+        .map(|sighash| verify_ecdsa(secp, sighash.to_byte_array(), der, pubkey))
+        .unwrap_or(false)
+}
+
+fn verify_ecdsa(secp: &Secp256k1<VerifyOnly>, digest: [u8; 32], der: &[u8], pubkey: &[u8]) -> bool {
+    let Ok(mut sig) = ecdsa::Signature::from_der(der) else {
+        return false;
+    };
+    let Ok(pubkey) = PublicKey::from_slice(pubkey) else {
+        return false;
+    };
+    sig.normalize_s();
+    let msg = Message::from_digest(digest);
+    secp.verify_ecdsa(&msg, &sig, &pubkey.inner).is_ok()
+}